@@ -231,7 +231,7 @@ pub fn init_array(codegen: &mut codegen::CodeGen) {
     let mut getfntp: DataType = crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::WrapperFunc.to_string()).unwrap().clone();
     getfntp.names = Some(vec![String::from("self")]);
     getfntp.rettp = Some(Box::new(crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::Unknown.to_string()).unwrap().clone()));
-    getfntp.types = vec![crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::Array.to_string()).unwrap().clone()];
+    getfntp.types = vec![crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::Array.to_string()).unwrap().clone(), crate::codegen::CodeGen::datatypes_get(codegen, &String::from("usize")).unwrap().clone()];
     getfntp.wrapperfn = Some(array_get);
 
     methods.insert(String::from("get"), Method {
@@ -1,20 +1,14 @@
-use crate::codegen::types::{Trait, TraitType, Data, DataType, new_datatype, BasicDataType};
+use crate::codegen::types::{Trait, TraitType, Data, DataType, new_datatype, BasicDataType, Method, MethodType};
 use crate::codegen;
 use crate::codegen::builtin_types;
 use crate::parser;
 use crate::errors;
 use std::collections::HashMap;
 
-fn bool_add<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
-    if args.get(1).unwrap().tp != BasicDataType::Bool {
-        let fmt: String = format!("invalid types for bool +, got '{}' and '{}'.", BasicDataType::Bool, args.get(1).unwrap().tp);
-        errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, pos, codegen.info);
-    }
-    
+fn bool_bool<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, _pos: &parser::Position) -> Data<'a> {    
     let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();
-    let otherv: inkwell::values::IntValue = args.get(1).unwrap().data.unwrap().into_int_value();
 
-    let res: inkwell::values::IntValue = codegen.builder.build_int_add(selfv, otherv, "i8sum");
+    let res: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::NE, selfv, codegen.inkwell_types.booltp.const_zero(), "bool");
 
     return Data {
         data: Some(inkwell::values::BasicValueEnum::IntValue(res)),
@@ -23,34 +17,16 @@ fn bool_add<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &p
     };
 }
 
-fn bool_mul<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+fn bool_eq<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {    
     if args.get(1).unwrap().tp != BasicDataType::Bool {
-        let fmt: String = format!("invalid types for bool *, got '{}' and '{}'.", BasicDataType::Bool, args.get(1).unwrap().tp);
+        let fmt: String = format!("invalid types for bool Eq, got '{}'.", args.get(1).unwrap().tp);
         errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, pos, codegen.info);
     }
-    
-    let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();
-    let otherv: inkwell::values::IntValue = args.get(1).unwrap().data.unwrap().into_int_value();
 
-    let res: inkwell::values::IntValue = codegen.builder.build_int_mul(selfv, otherv, "i8mul");
-
-    return Data {
-        data: Some(inkwell::values::BasicValueEnum::IntValue(res)),
-        tp: crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::Bool.to_string()).unwrap().clone(),
-        owned: true,
-    };
-}
-
-fn bool_sub<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
-    if args.get(1).unwrap().tp != BasicDataType::Bool {
-        let fmt: String = format!("invalid types for bool -, got '{}' and '{}'.", BasicDataType::Bool, args.get(1).unwrap().tp);
-        errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, pos, codegen.info);
-    }
-    
-    let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();
+    let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();  
     let otherv: inkwell::values::IntValue = args.get(1).unwrap().data.unwrap().into_int_value();
 
-    let res: inkwell::values::IntValue = codegen.builder.build_int_sub(selfv, otherv, "i8sub");
+    let res: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::EQ, selfv, otherv, "booleq");
 
     return Data {
         data: Some(inkwell::values::BasicValueEnum::IntValue(res)),
@@ -59,45 +35,16 @@ fn bool_sub<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &p
     };
 }
 
-fn bool_div<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+fn bool_lt<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {    
     if args.get(1).unwrap().tp != BasicDataType::Bool {
-        let fmt: String = format!("invalid types for bool /, got '{}' and '{}'.", BasicDataType::Bool, args.get(1).unwrap().tp);
+        let fmt: String = format!("invalid types for bool Lt, got '{}'.", args.get(1).unwrap().tp);
         errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, pos, codegen.info);
     }
-    
-    let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();
-    let otherv: inkwell::values::IntValue = args.get(1).unwrap().data.unwrap().into_int_value();
-
-    let res: inkwell::values::IntValue = codegen.builder.build_int_signed_div(selfv, otherv, "i8div");
 
-    return Data {
-        data: Some(inkwell::values::BasicValueEnum::IntValue(res)),
-        tp: crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::Bool.to_string()).unwrap().clone(),
-        owned: true,
-    };
-}
-
-fn bool_pos<'a>(_codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, _pos: &parser::Position) -> Data<'a> {
-    return args.get(0).unwrap().clone();
-}
-
-fn bool_neg<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, _pos: &parser::Position) -> Data<'a> {    
-    let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();
-    let otherv: inkwell::values::IntValue = codegen.inkwell_types.i8tp.const_int_from_string("-1", inkwell::types::StringRadix::Decimal).unwrap();
-
-    let res: inkwell::values::IntValue = codegen.builder.build_int_mul(selfv, otherv, "i8neg");
-
-    return Data {
-        data: Some(inkwell::values::BasicValueEnum::IntValue(res)),
-        tp: crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::Bool.to_string()).unwrap().clone(),
-        owned: true,
-    };
-}
-
-fn bool_bool<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, _pos: &parser::Position) -> Data<'a> {    
-    let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();
+    let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();  
+    let otherv: inkwell::values::IntValue = args.get(1).unwrap().data.unwrap().into_int_value();
 
-    let res: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::NE, selfv, codegen.inkwell_types.booltp.const_zero(), "bool");
+    let res: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::ULT, selfv, otherv, "boollt");
 
     return Data {
         data: Some(inkwell::values::BasicValueEnum::IntValue(res)),
@@ -106,16 +53,16 @@ fn bool_bool<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, _pos:
     };
 }
 
-fn bool_eq<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {    
+fn bool_gt<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {    
     if args.get(1).unwrap().tp != BasicDataType::Bool {
-        let fmt: String = format!("invalid types for bool Eq, got '{}'.", args.get(1).unwrap().tp);
+        let fmt: String = format!("invalid types for bool Gt, got '{}'.", args.get(1).unwrap().tp);
         errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, pos, codegen.info);
     }
 
     let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();  
-    let otherv: inkwell::values::IntValue = args.get(0).unwrap().data.unwrap().into_int_value();
+    let otherv: inkwell::values::IntValue = args.get(1).unwrap().data.unwrap().into_int_value();
 
-    let res: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::EQ, selfv, otherv, "i8eq");
+    let res: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::UGT, selfv, otherv, "boolgt");
 
     return Data {
         data: Some(inkwell::values::BasicValueEnum::IntValue(res)),
@@ -124,16 +71,16 @@ fn bool_eq<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &pa
     };
 }
 
-fn bool_lt<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {    
+fn bool_le<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {    
     if args.get(1).unwrap().tp != BasicDataType::Bool {
-        let fmt: String = format!("invalid types for bool Lt, got '{}'.", args.get(1).unwrap().tp);
+        let fmt: String = format!("invalid types for bool Le, got '{}'.", args.get(1).unwrap().tp);
         errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, pos, codegen.info);
     }
 
     let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();  
-    let otherv: inkwell::values::IntValue = args.get(0).unwrap().data.unwrap().into_int_value();
+    let otherv: inkwell::values::IntValue = args.get(1).unwrap().data.unwrap().into_int_value();
 
-    let res: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::SGE, selfv, otherv, "i8lt");
+    let res: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::ULE, selfv, otherv, "boolle");
 
     return Data {
         data: Some(inkwell::values::BasicValueEnum::IntValue(res)),
@@ -142,16 +89,16 @@ fn bool_lt<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &pa
     };
 }
 
-fn bool_gt<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {    
+fn bool_ge<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {    
     if args.get(1).unwrap().tp != BasicDataType::Bool {
-        let fmt: String = format!("invalid types for bool Gt, got '{}'.", args.get(1).unwrap().tp);
+        let fmt: String = format!("invalid types for bool Ge, got '{}'.", args.get(1).unwrap().tp);
         errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, pos, codegen.info);
     }
 
     let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();  
-    let otherv: inkwell::values::IntValue = args.get(0).unwrap().data.unwrap().into_int_value();
+    let otherv: inkwell::values::IntValue = args.get(1).unwrap().data.unwrap().into_int_value();
 
-    let res: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::SGE, selfv, otherv, "i8gt");
+    let res: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::UGE, selfv, otherv, "boolge");
 
     return Data {
         data: Some(inkwell::values::BasicValueEnum::IntValue(res)),
@@ -160,16 +107,16 @@ fn bool_gt<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &pa
     };
 }
 
-fn bool_le<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {    
+fn bool_ne<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {    
     if args.get(1).unwrap().tp != BasicDataType::Bool {
-        let fmt: String = format!("invalid types for bool Le, got '{}'.", args.get(1).unwrap().tp);
+        let fmt: String = format!("invalid types for bool Ne, got '{}'.", args.get(1).unwrap().tp);
         errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, pos, codegen.info);
     }
 
     let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();  
-    let otherv: inkwell::values::IntValue = args.get(0).unwrap().data.unwrap().into_int_value();
+    let otherv: inkwell::values::IntValue = args.get(1).unwrap().data.unwrap().into_int_value();
 
-    let res: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::SGE, selfv, otherv, "i8lt");
+    let res: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::NE, selfv, otherv, "boolne");
 
     return Data {
         data: Some(inkwell::values::BasicValueEnum::IntValue(res)),
@@ -178,16 +125,10 @@ fn bool_le<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &pa
     };
 }
 
-fn bool_ge<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {    
-    if args.get(1).unwrap().tp != BasicDataType::Bool {
-        let fmt: String = format!("invalid types for bool Ge, got '{}'.", args.get(1).unwrap().tp);
-        errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, pos, codegen.info);
-    }
-
-    let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();  
-    let otherv: inkwell::values::IntValue = args.get(0).unwrap().data.unwrap().into_int_value();
+fn bool_not<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, _pos: &parser::Position) -> Data<'a> {    
+    let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();
 
-    let res: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::SGE, selfv, otherv, "i8gt");
+    let res: inkwell::values::IntValue = codegen.builder.build_not(selfv, "boolnot");
 
     return Data {
         data: Some(inkwell::values::BasicValueEnum::IntValue(res)),
@@ -196,19 +137,21 @@ fn bool_ge<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &pa
     };
 }
 
-fn bool_ne<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {    
-    if args.get(1).unwrap().tp != BasicDataType::Bool {
-        let fmt: String = format!("invalid types for bool Ne, got '{}'.", args.get(1).unwrap().tp);
-        errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, pos, codegen.info);
-    }
+fn bool_string<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, _pos: &parser::Position) -> Data<'a> {
+    let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();
 
-    let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();  
-    let otherv: inkwell::values::IntValue = args.get(0).unwrap().data.unwrap().into_int_value();
+    let true_ptr: inkwell::values::PointerValue = codegen.builder.build_global_string_ptr("true", "str").as_pointer_value();
+    let false_ptr: inkwell::values::PointerValue = codegen.builder.build_global_string_ptr("false", "str").as_pointer_value();
+    let ptr: inkwell::values::PointerValue = codegen.builder.build_select(selfv, true_ptr, false_ptr, "str_data").into_pointer_value();
+    let len: inkwell::values::IntValue = codegen.builder.build_select(selfv, codegen.inkwell_types.i64tp.const_int(4, false), codegen.inkwell_types.i64tp.const_int(5, false), "str_len").into_int_value();
 
-    let res: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::NE, selfv, otherv, "i8ne");
+    let text: Data = builtin_types::structs::stringtype::string_from_parts(codegen, ptr, len);
+    return builtin_types::structs::stringtype::string_concat(codegen, &vec![text.data.unwrap().into_struct_value()]);
+}
 
+fn bool_default<'a>(codegen: &mut codegen::CodeGen<'a>, _args: Vec<Data<'a>>, _pos: &parser::Position) -> Data<'a> {
     return Data {
-        data: Some(inkwell::values::BasicValueEnum::IntValue(res)),
+        data: Some(inkwell::values::BasicValueEnum::IntValue(codegen.inkwell_types.booltp.const_zero())),
         tp: crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::Bool.to_string()).unwrap().clone(),
         owned: true,
     };
@@ -217,18 +160,27 @@ fn bool_ne<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &pa
 pub fn init_bool(codegen: &mut codegen::CodeGen) {
     let mut traits: HashMap<String, Trait> = HashMap::new();
 
-    let tp: DataType = new_datatype(BasicDataType::Bool, BasicDataType::Bool.to_string(), None, Vec::new(), Vec::new(), None, false, None, std::collections::HashMap::new());
+    let mut tp: DataType = new_datatype(BasicDataType::Bool, BasicDataType::Bool.to_string(), None, Vec::new(), Vec::new(), None, false, None, std::collections::HashMap::new());
+
+    let mut fntp: DataType = crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::WrapperFunc.to_string()).unwrap().clone();
+    fntp.names = Some(vec![String::from("self")]);
+    fntp.rettp = Some(Box::new(crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::Unknown.to_string()).unwrap().clone()));
+    fntp.types = vec![tp.clone()];
+    fntp.wrapperfn = Some(bool_string);
+    tp.methods.insert(String::from("to_string"), Method {
+        tp: MethodType::Builtin,
+        builtin: Some(bool_string),
+        func: None,
+        functp: fntp,
+        isinstance: true,
+        isinstanceptr: false,
+        ismutinstanceptr: false,
+    });
 
     codegen.cur_module.datatypes.insert(BasicDataType::Bool.to_string(), tp.clone());
     
     codegen.cur_module.datatypes.insert(String::from("bool"), tp.clone()); //Alias        
 
-    traits.insert(TraitType::Add.to_string(), builtin_types::create_trait_func(bool_add, 2, TraitType::Add, tp.clone()));
-    traits.insert(TraitType::Mul.to_string(), builtin_types::create_trait_func(bool_mul, 2, TraitType::Mul, tp.clone()));
-    traits.insert(TraitType::Sub.to_string(), builtin_types::create_trait_func(bool_sub, 2, TraitType::Sub, tp.clone()));
-    traits.insert(TraitType::Div.to_string(), builtin_types::create_trait_func(bool_div, 2, TraitType::Div, tp.clone()));
-    traits.insert(TraitType::Pos.to_string(), builtin_types::create_trait_func(bool_pos, 1, TraitType::Pos, tp.clone()));
-    traits.insert(TraitType::Neg.to_string(), builtin_types::create_trait_func(bool_neg, 1, TraitType::Neg, tp.clone()));
     traits.insert(TraitType::Bool.to_string(), builtin_types::create_trait_func(bool_bool, 1, TraitType::Bool, tp.clone()));
     traits.insert(TraitType::Eq.to_string(), builtin_types::create_trait_func(bool_eq, 2, TraitType::Eq, tp.clone()));
     traits.insert(TraitType::Ne.to_string(), builtin_types::create_trait_func(bool_ne, 2, TraitType::Ne, tp.clone()));
@@ -236,6 +188,9 @@ pub fn init_bool(codegen: &mut codegen::CodeGen) {
     traits.insert(TraitType::Lt.to_string(), builtin_types::create_trait_func(bool_lt, 2, TraitType::Lt, tp.clone()));
     traits.insert(TraitType::Ge.to_string(), builtin_types::create_trait_func(bool_ge, 2, TraitType::Ge, tp.clone()));
     traits.insert(TraitType::Le.to_string(), builtin_types::create_trait_func(bool_le, 2, TraitType::Le, tp.clone()));
+    traits.insert(TraitType::Not.to_string(), builtin_types::create_trait_func(bool_not, 1, TraitType::Not, tp.clone()));
+    traits.insert(TraitType::Default.to_string(), builtin_types::create_trait_func(bool_default, 0, TraitType::Default, tp.clone()));
+    traits.insert(TraitType::Clone.to_string(), builtin_types::create_trait_func(builtin_types::value_clone, 1, TraitType::Clone, tp.clone()));
 
     builtin_types::add_simple_type(codegen, traits, BasicDataType::Bool, BasicDataType::Bool.to_string().as_str());
 }
\ No newline at end of file
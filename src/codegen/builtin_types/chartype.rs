@@ -0,0 +1,380 @@
+use crate::codegen::types::{Trait, TraitType, Data, DataType, new_datatype, BasicDataType, Method, MethodType};
+use crate::codegen::{self, CodeGen};
+use crate::codegen::builtin_types;
+use crate::parser;
+use crate::errors;
+use std::collections::HashMap;
+
+//Largest Unicode scalar value
+pub const MAX_CODE_POINT: u64 = 0x10FFFF;
+
+//Surrogates are code points, but not Unicode scalar values
+const SURROGATE_START: u64 = 0xD800;
+const SURROGATE_END: u64 = 0xDFFF;
+
+//Rows of (first code point, last code point, value). The value of a case mapping is the offset to the mapped code point.
+type CharTable = Vec<(u32, u32, u32)>;
+
+fn get_char_arg<'a>(codegen: &mut codegen::CodeGen<'a>, args: &Vec<Data<'a>>, pos: &parser::Position) -> inkwell::values::IntValue<'a> {
+    if args.len() != 1 {
+        let fmt: String = format!("Expected 0 arguments, got {}.", args.len()-1);
+        errors::raise_error(&fmt, errors::ErrorType::ArgumentCountMismatch, pos, codegen.info);
+    }
+
+    return args.first().unwrap().data.unwrap().into_int_value();
+}
+
+fn char_compare<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position, predicate: inkwell::IntPredicate, traittp: TraitType) -> Data<'a> {
+    if args.get(1).unwrap().tp != BasicDataType::Char {
+        let fmt: String = format!("invalid types for char {}, got '{}'.", traittp, args.get(1).unwrap().tp);
+        errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, pos, codegen.info);
+    }
+
+    let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();
+    let otherv: inkwell::values::IntValue = args.get(1).unwrap().data.unwrap().into_int_value();
+
+    let res: inkwell::values::IntValue = codegen.builder.build_int_compare(predicate, selfv, otherv, "charcmp");
+
+    return Data {
+        data: Some(inkwell::values::BasicValueEnum::IntValue(res)),
+        tp: CodeGen::datatypes_get(codegen, &BasicDataType::Bool.to_string()).unwrap().clone(),
+        owned: true,
+    };
+}
+
+fn char_eq<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    return char_compare(codegen, args, pos, inkwell::IntPredicate::EQ, TraitType::Eq);
+}
+
+fn char_ne<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    return char_compare(codegen, args, pos, inkwell::IntPredicate::NE, TraitType::Ne);
+}
+
+fn char_lt<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    return char_compare(codegen, args, pos, inkwell::IntPredicate::ULT, TraitType::Lt);
+}
+
+fn char_gt<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    return char_compare(codegen, args, pos, inkwell::IntPredicate::UGT, TraitType::Gt);
+}
+
+fn char_le<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    return char_compare(codegen, args, pos, inkwell::IntPredicate::ULE, TraitType::Le);
+}
+
+fn char_ge<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    return char_compare(codegen, args, pos, inkwell::IntPredicate::UGE, TraitType::Ge);
+}
+
+//Check whether an integer is a Unicode scalar value, which is not a surrogate and at most MAX_CODE_POINT
+pub fn build_is_scalar<'a>(codegen: &mut codegen::CodeGen<'a>, v: inkwell::values::IntValue<'a>) -> inkwell::values::IntValue<'a> {
+    let inttp: inkwell::types::IntType = v.get_type();
+
+    let in_range: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::ULE, v, inttp.const_int(MAX_CODE_POINT, false), "in_range");
+    let offset: inkwell::values::IntValue = codegen.builder.build_int_sub(v, inttp.const_int(SURROGATE_START, false), "surrogate_offset");
+    let not_surrogate: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::UGT, offset, inttp.const_int(SURROGATE_END-SURROGATE_START, false), "not_surrogate");
+
+    return codegen.builder.build_and(in_range, not_surrogate, "is_scalar");
+}
+
+pub fn is_scalar(v: u64) -> bool {
+    return v <= MAX_CODE_POINT && !(SURROGATE_START..=SURROGATE_END).contains(&v);
+}
+
+//Build a table of the code points for which get_value gives a value, merging runs of code points with the same value
+fn build_char_table(get_value: fn(char) -> Option<u32>) -> CharTable {
+    let mut table: CharTable = Vec::new();
+
+    for c in (0..=MAX_CODE_POINT as u32).filter_map(char::from_u32) {
+        let value: Option<u32> = get_value(c);
+        if value.is_none() {
+            continue;
+        }
+
+        let last: Option<&mut (u32, u32, u32)> = table.last_mut();
+        if last.is_some() && last.as_ref().unwrap().1 + 1 == c as u32 && last.as_ref().unwrap().2 == value.unwrap() {
+            last.unwrap().1 = c as u32;
+        }
+        else {
+            table.push((c as u32, c as u32, value.unwrap()));
+        }
+    }
+
+    return table;
+}
+
+//Offset to the single code point a character maps to, if it maps to a different single code point
+fn case_offset(c: char, mapped: &mut dyn Iterator<Item = char>) -> Option<u32> {
+    let first: Option<char> = mapped.next();
+    if first.is_none() || mapped.next().is_some() || first.unwrap() == c {
+        return None;
+    }
+    return Some((first.unwrap() as u32).wrapping_sub(c as u32));
+}
+
+fn alphabetic_value(c: char) -> Option<u32> {
+    return if c.is_alphabetic() { Some(0) } else { None };
+}
+
+fn whitespace_value(c: char) -> Option<u32> {
+    return if c.is_whitespace() { Some(0) } else { None };
+}
+
+fn upper_value(c: char) -> Option<u32> {
+    return case_offset(c, &mut c.to_uppercase());
+}
+
+fn lower_value(c: char) -> Option<u32> {
+    return case_offset(c, &mut c.to_lowercase());
+}
+
+//Look up a code point in a table with a binary search, giving whether it was found and the value of its row.
+//The table is generated from the Unicode data of the compiler and emitted once per module.
+fn build_table_lookup<'a>(codegen: &mut codegen::CodeGen<'a>, c: inkwell::values::IntValue<'a>, name: &str, get_value: fn(char) -> Option<u32>) -> (inkwell::values::IntValue<'a>, inkwell::values::IntValue<'a>) {
+    let i32tp: inkwell::types::IntType = *codegen.inkwell_types.i32tp;
+
+    let global: inkwell::values::GlobalValue = match codegen.module.get_global(name) {
+        Some(v) => v,
+        None => {
+            let table: CharTable = build_char_table(get_value);
+            let mut rows: Vec<inkwell::values::IntValue> = Vec::new();
+            for (start, end, value) in &table {
+                rows.push(i32tp.const_int(*start as u64, false));
+                rows.push(i32tp.const_int(*end as u64, false));
+                rows.push(i32tp.const_int(*value as u64, false));
+            }
+
+            let global: inkwell::values::GlobalValue = codegen.module.add_global(i32tp.array_type(rows.len() as u32), None, name);
+            global.set_initializer(&i32tp.const_array(&rows[..]));
+            global.set_constant(true);
+            global.set_linkage(inkwell::module::Linkage::Private);
+            global
+        }
+    };
+
+    let nrows: u32 = global.as_pointer_value().get_type().get_element_type().into_array_type().len() / 3;
+
+    let entry_block: inkwell::basic_block::BasicBlock = codegen.current_block.unwrap();
+    let head_block: inkwell::basic_block::BasicBlock = codegen.context.append_basic_block(entry_block.get_parent().unwrap(), "lookup_head");
+    let body_block: inkwell::basic_block::BasicBlock = codegen.context.append_basic_block(entry_block.get_parent().unwrap(), "lookup_body");
+    let end_block: inkwell::basic_block::BasicBlock = codegen.context.append_basic_block(entry_block.get_parent().unwrap(), "lookup_end");
+
+    codegen.builder.build_unconditional_branch(head_block);
+
+    //Search the rows in [lo, hi)
+    codegen.builder.position_at_end(head_block);
+    let lo: inkwell::values::PhiValue = codegen.builder.build_phi(i32tp, "lo");
+    let hi: inkwell::values::PhiValue = codegen.builder.build_phi(i32tp, "hi");
+    let lov: inkwell::values::IntValue = lo.as_basic_value().into_int_value();
+    let hiv: inkwell::values::IntValue = hi.as_basic_value().into_int_value();
+    let searching: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::ULT, lov, hiv, "searching");
+    codegen.builder.build_conditional_branch(searching, body_block, end_block);
+
+    codegen.builder.position_at_end(body_block);
+    let mid: inkwell::values::IntValue = codegen.builder.build_right_shift(codegen.builder.build_int_add(lov, hiv, "sum"), i32tp.const_int(1, false), false, "mid");
+    let row: inkwell::values::IntValue = codegen.builder.build_int_mul(mid, i32tp.const_int(3, false), "row");
+
+    let mut fields: Vec<inkwell::values::IntValue> = Vec::new();
+    for (idx, fieldname) in ["start", "end", "value"].iter().enumerate() {
+        let idxv: inkwell::values::IntValue = codegen.builder.build_int_add(row, i32tp.const_int(idx as u64, false), "idx");
+        let ptr: inkwell::values::PointerValue = unsafe { codegen.builder.build_in_bounds_gep(global.as_pointer_value(), &[i32tp.const_zero(), idxv], fieldname) };
+        fields.push(codegen.builder.build_load(ptr, fieldname).into_int_value());
+    }
+
+    let below: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::ULT, c, fields[0], "below");
+    let above: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::UGT, c, fields[1], "above");
+    let outside: inkwell::values::IntValue = codegen.builder.build_or(below, above, "outside");
+    let next_lo: inkwell::values::IntValue = codegen.builder.build_select(above, codegen.builder.build_int_add(mid, i32tp.const_int(1, false), "mid_next"), lov, "next_lo").into_int_value();
+    let next_hi: inkwell::values::IntValue = codegen.builder.build_select(below, mid, hiv, "next_hi").into_int_value();
+    codegen.builder.build_conditional_branch(outside, head_block, end_block);
+
+    lo.add_incoming(&[(&i32tp.const_zero(), entry_block), (&next_lo, body_block)]);
+    hi.add_incoming(&[(&i32tp.const_int(nrows as u64, false), entry_block), (&next_hi, body_block)]);
+
+    codegen.builder.position_at_end(end_block);
+    codegen.current_block = Some(end_block);
+
+    let found: inkwell::values::PhiValue = codegen.builder.build_phi(*codegen.inkwell_types.booltp, "found");
+    found.add_incoming(&[(&codegen.inkwell_types.booltp.const_zero(), head_block), (&codegen.inkwell_types.booltp.const_all_ones(), body_block)]);
+    let value: inkwell::values::PhiValue = codegen.builder.build_phi(i32tp, "value");
+    value.add_incoming(&[(&i32tp.const_zero(), head_block), (&fields[2], body_block)]);
+
+    return (found.as_basic_value().into_int_value(), value.as_basic_value().into_int_value());
+}
+
+fn bool_data<'a>(codegen: &mut codegen::CodeGen<'a>, res: inkwell::values::IntValue<'a>) -> Data<'a> {
+    return Data {
+        data: Some(inkwell::values::BasicValueEnum::IntValue(res)),
+        tp: CodeGen::datatypes_get(codegen, &BasicDataType::Bool.to_string()).unwrap().clone(),
+        owned: true,
+    };
+}
+
+fn char_is_alphabetic<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    let selfv: inkwell::values::IntValue = get_char_arg(codegen, &args, pos);
+    let (found, _) = build_table_lookup(codegen, selfv, "char_alphabetic_table", alphabetic_value);
+    return bool_data(codegen, found);
+}
+
+fn char_is_whitespace<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    let selfv: inkwell::values::IntValue = get_char_arg(codegen, &args, pos);
+    let (found, _) = build_table_lookup(codegen, selfv, "char_whitespace_table", whitespace_value);
+    return bool_data(codegen, found);
+}
+
+//Only the ASCII digits 0-9
+fn char_is_digit<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    let selfv: inkwell::values::IntValue = get_char_arg(codegen, &args, pos);
+    let offset: inkwell::values::IntValue = codegen.builder.build_int_sub(selfv, codegen.inkwell_types.i32tp.const_int('0' as u64, false), "digit");
+    let res: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::ULT, offset, codegen.inkwell_types.i32tp.const_int(10, false), "is_digit");
+    return bool_data(codegen, res);
+}
+
+fn build_case_mapping<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position, name: &str, get_value: fn(char) -> Option<u32>) -> Data<'a> {
+    let selfv: inkwell::values::IntValue = get_char_arg(codegen, &args, pos);
+    let (found, offset) = build_table_lookup(codegen, selfv, name, get_value);
+
+    let mapped: inkwell::values::IntValue = codegen.builder.build_int_add(selfv, offset, "mapped");
+    let res: inkwell::values::BasicValueEnum = codegen.builder.build_select(found, mapped, selfv, "case");
+
+    return Data {
+        data: Some(res),
+        tp: args.first().unwrap().tp.clone(),
+        owned: true,
+    };
+}
+
+fn char_to_upper<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    return build_case_mapping(codegen, args, pos, "char_upper_table", upper_value);
+}
+
+fn char_to_lower<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    return build_case_mapping(codegen, args, pos, "char_lower_table", lower_value);
+}
+
+//Number of bytes in the UTF-8 encoding, as an i32
+fn build_len_utf8<'a>(codegen: &mut codegen::CodeGen<'a>, c: inkwell::values::IntValue<'a>) -> inkwell::values::IntValue<'a> {
+    let i32tp: inkwell::types::IntType = *codegen.inkwell_types.i32tp;
+
+    let mut len: inkwell::values::IntValue = i32tp.const_int(4, false);
+    for (limit, bytes) in [(0x10000, 3), (0x800, 2), (0x80, 1)] {
+        let below: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::ULT, c, i32tp.const_int(limit, false), "below");
+        len = codegen.builder.build_select(below, i32tp.const_int(bytes, false), len, "len_utf8").into_int_value();
+    }
+    return len;
+}
+
+fn char_len_utf8<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    let selfv: inkwell::values::IntValue = get_char_arg(codegen, &args, pos);
+    let len: inkwell::values::IntValue = build_len_utf8(codegen, selfv);
+
+    let usizetp: DataType = CodeGen::datatypes_get(codegen, "usize").unwrap().clone();
+    let inttp: inkwell::types::IntType = CodeGen::get_anytp_from_tp(codegen.context, &codegen.inkwell_types, usizetp.clone(), &codegen.cur_module.datatypes).unwrap().into_int_type();
+
+    return Data {
+        data: Some(inkwell::values::BasicValueEnum::IntValue(codegen.builder.build_int_z_extend_or_bit_cast(len, inttp, "len"))),
+        tp: usizetp,
+        owned: true,
+    };
+}
+
+//Encode the character as UTF-8 in a String
+fn char_string<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    let selfv: inkwell::values::IntValue = get_char_arg(codegen, &args, pos);
+    let i32tp: inkwell::types::IntType = *codegen.inkwell_types.i32tp;
+    let i8tp: inkwell::types::IntType = *codegen.inkwell_types.i8tp;
+
+    let len: inkwell::values::IntValue = build_len_utf8(codegen, selfv);
+
+    //The bytes of each length of encoding, selected by the actual length. Unused bytes stay zero, which terminates the string.
+    let mut bytes: Vec<inkwell::values::IntValue> = vec![i32tp.const_zero(); 4];
+    for nbytes in 1..=4u64 {
+        let prefix: u64 = [0x00, 0xC0, 0xE0, 0xF0][nbytes as usize - 1];
+        let is_len: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::EQ, len, i32tp.const_int(nbytes, false), "is_len");
+
+        for idx in 0..nbytes {
+            let shift: u64 = 6 * (nbytes - 1 - idx);
+            let shifted: inkwell::values::IntValue = codegen.builder.build_right_shift(selfv, i32tp.const_int(shift, false), false, "shifted");
+            let byte: inkwell::values::IntValue = if idx == 0 {
+                codegen.builder.build_or(shifted, i32tp.const_int(prefix, false), "lead")
+            }
+            else {
+                let bits: inkwell::values::IntValue = codegen.builder.build_and(shifted, i32tp.const_int(0x3F, false), "bits");
+                codegen.builder.build_or(bits, i32tp.const_int(0x80, false), "cont")
+            };
+            bytes[idx as usize] = codegen.builder.build_select(is_len, byte, bytes[idx as usize], "byte").into_int_value();
+        }
+    }
+
+    let arraytp: inkwell::types::ArrayType = i8tp.array_type(5);
+    let mut array: inkwell::values::ArrayValue = arraytp.const_zero();
+    for (idx, byte) in bytes.iter().enumerate() {
+        let byte: inkwell::values::IntValue = codegen.builder.build_int_truncate(*byte, i8tp, "byte");
+        array = codegen.builder.build_insert_value(array, byte, idx as u32, "utf8").unwrap().into_array_value();
+    }
+
+    let buf: inkwell::values::PointerValue = codegen.builder.build_malloc(arraytp, "str_buf").expect("Malloc error");
+    codegen.builder.build_store(buf, array);
+    let data: inkwell::values::PointerValue = codegen.builder.build_pointer_cast(buf, i8tp.ptr_type(inkwell::AddressSpace::from(0u16)), "str_data");
+    let len: inkwell::values::IntValue = codegen.builder.build_int_z_extend(len, *codegen.inkwell_types.i64tp, "str_len");
+
+    return builtin_types::structs::stringtype::string_from_parts(codegen, data, len);
+}
+
+fn char_default<'a>(codegen: &mut codegen::CodeGen<'a>, _args: Vec<Data<'a>>, _pos: &parser::Position) -> Data<'a> {
+    return Data {
+        data: Some(inkwell::values::BasicValueEnum::IntValue(codegen.inkwell_types.i32tp.const_zero())),
+        tp: CodeGen::datatypes_get(codegen, &BasicDataType::Char.to_string()).unwrap().clone(),
+        owned: true,
+    };
+}
+
+fn add_char_method<'a>(codegen: &mut codegen::CodeGen<'a>, tp: &mut DataType<'a>, name: &str, function: fn(&mut codegen::CodeGen<'a>, Vec<Data<'a>>, &parser::Position) -> Data<'a>, rettp: DataType<'a>) {
+    let mut fntp: DataType = CodeGen::datatypes_get(codegen, &BasicDataType::WrapperFunc.to_string()).unwrap().clone();
+    fntp.names = Some(vec![String::from("self")]);
+    fntp.rettp = Some(Box::new(rettp));
+    fntp.types = vec![tp.clone()];
+    fntp.wrapperfn = Some(function);
+
+    tp.methods.insert(String::from(name), Method {
+        tp: MethodType::Builtin,
+        builtin: Some(function),
+        func: None,
+        functp: fntp,
+        isinstance: true,
+        isinstanceptr: false,
+        ismutinstanceptr: false,
+    });
+}
+
+pub fn init_char(codegen: &mut codegen::CodeGen) {
+    let mut traits: HashMap<String, Trait> = HashMap::new();
+
+    let mut tp: DataType = new_datatype(BasicDataType::Char, BasicDataType::Char.to_string(), None, Vec::new(), Vec::new(), None, false, None, std::collections::HashMap::new());
+
+    let booltp: DataType = CodeGen::datatypes_get(codegen, &BasicDataType::Bool.to_string()).unwrap().clone();
+    let unknown: DataType = CodeGen::datatypes_get(codegen, &BasicDataType::Unknown.to_string()).unwrap().clone();
+    let usizetp: DataType = CodeGen::datatypes_get(codegen, "usize").unwrap().clone();
+    let chartp: DataType = tp.clone();
+
+    add_char_method(codegen, &mut tp, "is_alphabetic", char_is_alphabetic, booltp.clone());
+    add_char_method(codegen, &mut tp, "is_digit", char_is_digit, booltp.clone());
+    add_char_method(codegen, &mut tp, "is_whitespace", char_is_whitespace, booltp.clone());
+    add_char_method(codegen, &mut tp, "to_upper", char_to_upper, chartp.clone());
+    add_char_method(codegen, &mut tp, "to_lower", char_to_lower, chartp.clone());
+    add_char_method(codegen, &mut tp, "to_string", char_string, unknown.clone());
+    add_char_method(codegen, &mut tp, "len_utf8", char_len_utf8, usizetp.clone());
+
+    codegen.cur_module.datatypes.insert(BasicDataType::Char.to_string(), tp.clone());
+
+    traits.insert(TraitType::Eq.to_string(), builtin_types::create_trait_func(char_eq, 2, TraitType::Eq, tp.clone()));
+    traits.insert(TraitType::Ne.to_string(), builtin_types::create_trait_func(char_ne, 2, TraitType::Ne, tp.clone()));
+    traits.insert(TraitType::Gt.to_string(), builtin_types::create_trait_func(char_gt, 2, TraitType::Gt, tp.clone()));
+    traits.insert(TraitType::Lt.to_string(), builtin_types::create_trait_func(char_lt, 2, TraitType::Lt, tp.clone()));
+    traits.insert(TraitType::Ge.to_string(), builtin_types::create_trait_func(char_ge, 2, TraitType::Ge, tp.clone()));
+    traits.insert(TraitType::Le.to_string(), builtin_types::create_trait_func(char_le, 2, TraitType::Le, tp.clone()));
+    traits.insert(TraitType::Default.to_string(), builtin_types::create_trait_func(char_default, 0, TraitType::Default, tp.clone()));
+    traits.insert(TraitType::Clone.to_string(), builtin_types::create_trait_func(builtin_types::value_clone, 1, TraitType::Clone, tp.clone()));
+
+    builtin_types::add_simple_type(codegen, traits, BasicDataType::Char, BasicDataType::Char.to_string().as_str());
+}
\ No newline at end of file
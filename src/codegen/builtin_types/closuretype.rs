@@ -0,0 +1,76 @@
+use crate::codegen::types::{Trait, TraitType, Data, DataType, new_datatype, BasicDataType, basic_to_metadata};
+use crate::codegen::{self, CodeGen};
+use crate::codegen::builtin_types;
+use crate::parser;
+use crate::errors;
+use std::collections::HashMap;
+
+//A closure holds the function pointer, the environment pointer and the function dropping an owned environment. The function takes the environment as its first argument
+pub fn closure_call<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    let selfv: &Data = args.first().unwrap();
+    let args_: &[Data] = &args[1..];
+    let types: &Vec<DataType> = &selfv.tp.types;
+
+    if args_.len() != types.len() {
+        let fmt: String = format!("Expected {} arguments, got {}.", types.len(), args_.len());
+        errors::raise_error(&fmt, errors::ErrorType::ArgumentCountMismatch, pos, codegen.info);
+    }
+
+    let closure: inkwell::values::StructValue = selfv.data.unwrap().into_struct_value();
+    let fnptr: inkwell::values::PointerValue = codegen.builder.build_extract_value(closure, 0, "fnptr").unwrap().into_pointer_value();
+    let env: inkwell::values::BasicValueEnum = codegen.builder.build_extract_value(closure, 1, "env").unwrap();
+
+    let mut args_basic: Vec<inkwell::values::BasicMetadataValueEnum> = vec![basic_to_metadata(env)];
+    let mut paramtps: Vec<inkwell::types::BasicMetadataTypeEnum> = vec![env.get_type().into()];
+
+    for (arg, tp) in std::iter::zip(args_, types) {
+        if arg.tp != *tp {
+            let fmt: String = format!("expected '{}' type, got '{}' type.", tp, arg.tp);
+            errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, pos, codegen.info);
+        }
+        if arg.data.is_some() {
+            args_basic.push(basic_to_metadata(arg.data.unwrap()));
+            paramtps.push(arg.data.unwrap().get_type().into());
+        }
+    }
+
+    let rettp: DataType = *selfv.tp.rettp.as_ref().unwrap().clone();
+    let fntp: inkwell::types::FunctionType = match CodeGen::get_anytp_from_tp(codegen.context, &codegen.inkwell_types, rettp.clone(), &codegen.cur_module.datatypes) {
+        Some(tp) => {
+            CodeGen::fn_type_from_any(tp, &paramtps[..])
+        }
+        None => {
+            let fmt: String = format!("Closure cannot return '{}' type.", rettp);
+            errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, pos, codegen.info);
+        }
+    };
+
+    let func: inkwell::values::PointerValue = codegen.builder.build_pointer_cast(fnptr, fntp.ptr_type(inkwell::AddressSpace::from(0u16)), "closure_fn");
+
+    let res: inkwell::values::CallSiteValue = codegen.builder.build_call(inkwell::values::CallableValue::try_from(func).unwrap(), &args_basic[..], "res");
+
+    if res.try_as_basic_value().is_left() {
+        return Data {
+            data: Some(res.try_as_basic_value().left().unwrap()),
+            tp: rettp,
+            owned: true,
+        };
+    }
+
+    return Data {
+        data: None,
+        tp: CodeGen::datatypes_get(codegen, &BasicDataType::Void.to_string()).unwrap().clone(),
+        owned: true,
+    };
+}
+
+pub fn init_closure(codegen: &mut codegen::CodeGen) {
+    let mut traits: HashMap<String, Trait> = HashMap::new();
+    traits.insert(TraitType::Call.to_string(), builtin_types::create_trait_func(closure_call, 0, TraitType::Call, CodeGen::datatypes_get(codegen, &BasicDataType::Unknown.to_string()).unwrap().clone()));
+
+    let tp: DataType = new_datatype(BasicDataType::Closure, BasicDataType::Closure.to_string(), None, Vec::new(), Vec::new(), None, false, None, std::collections::HashMap::new());
+
+    codegen.cur_module.datatypes.insert(BasicDataType::Closure.to_string(), tp.clone());
+
+    builtin_types::add_simple_type(codegen, traits, BasicDataType::Closure, BasicDataType::Closure.to_string().as_str());
+}
\ No newline at end of file
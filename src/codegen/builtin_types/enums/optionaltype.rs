@@ -35,7 +35,7 @@ pub fn optional_none<'a>(codegen: &mut codegen::CodeGen<'a>, types_raw: Vec<Data
 
     debug_assert_eq!(optional.names.as_ref().unwrap().get(1).unwrap(), &String::from("None"));
     let id: inkwell::values::PointerValue = codegen.builder.build_struct_gep(st, 0, "variant_id").expect("GEP Error");
-    codegen.builder.build_store(id, codegen.inkwell_types.i32tp.const_int(0, false));
+    codegen.builder.build_store(id, codegen.inkwell_types.i32tp.const_int(1, false));
 
     return Data {
         data: Some(inkwell::values::BasicValueEnum::PointerValue(st)),
@@ -53,10 +53,12 @@ pub fn init_optional(codegen: &mut codegen::CodeGen) {
 
     let typ: crate::parser::Type = crate::parser::Type {
         isfn: false,
+        isclosure: false,
         isarr: false,
         isdyn: false,
         isgenum: false,
         isref: false,
+        istuple: false,
         basetp: None,
         arrlen: None,
         data: Some(String::from("T")),
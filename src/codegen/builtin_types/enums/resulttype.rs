@@ -60,10 +60,12 @@ pub fn init_result(codegen: &mut codegen::CodeGen) {
 
     let typ_t: crate::parser::Type = crate::parser::Type {
         isfn: false,
+        isclosure: false,
         isarr: false,
         isdyn: false,
         isgenum: false,
         isref: false,
+        istuple: false,
         basetp: None,
         arrlen: None,
         data: Some(String::from("T")),
@@ -75,10 +77,12 @@ pub fn init_result(codegen: &mut codegen::CodeGen) {
 
     let typ_e: crate::parser::Type = crate::parser::Type {
         isfn: false,
+        isclosure: false,
         isarr: false,
         isdyn: false,
         isgenum: false,
         isref: false,
+        istuple: false,
         basetp: None,
         arrlen: None,
         data: Some(String::from("E")),
@@ -12,7 +12,7 @@ fn enum_eq<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &pa
     }
 
     let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();  
-    let otherv: inkwell::values::IntValue = args.get(0).unwrap().data.unwrap().into_int_value();
+    let otherv: inkwell::values::IntValue = args.get(1).unwrap().data.unwrap().into_int_value();
 
     let res: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::EQ, selfv, otherv, "enum_eq");
 
@@ -30,7 +30,7 @@ fn enum_ne<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &pa
     }
 
     let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();  
-    let otherv: inkwell::values::IntValue = args.get(0).unwrap().data.unwrap().into_int_value();
+    let otherv: inkwell::values::IntValue = args.get(1).unwrap().data.unwrap().into_int_value();
 
     let res: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::NE, selfv, otherv, "enum_ne");
 
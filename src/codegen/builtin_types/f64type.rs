@@ -120,7 +120,7 @@ fn f64_eq<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &par
     }
 
     let selfv: inkwell::values::FloatValue = args.first().unwrap().data.unwrap().into_float_value();  
-    let otherv: inkwell::values::FloatValue = args.get(0).unwrap().data.unwrap().into_float_value();
+    let otherv: inkwell::values::FloatValue = args.get(1).unwrap().data.unwrap().into_float_value();
 
     let res: inkwell::values::IntValue = codegen.builder.build_float_compare(inkwell::FloatPredicate::OEQ, selfv, otherv, "f64eq");
 
@@ -138,7 +138,7 @@ fn f64_lt<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &par
     }
 
     let selfv: inkwell::values::FloatValue = args.first().unwrap().data.unwrap().into_float_value();  
-    let otherv: inkwell::values::FloatValue = args.get(0).unwrap().data.unwrap().into_float_value();
+    let otherv: inkwell::values::FloatValue = args.get(1).unwrap().data.unwrap().into_float_value();
 
     let res: inkwell::values::IntValue = codegen.builder.build_float_compare(inkwell::FloatPredicate::OLT, selfv, otherv, "f64lt");
 
@@ -156,7 +156,7 @@ fn f64_gt<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &par
     }
 
     let selfv: inkwell::values::FloatValue = args.first().unwrap().data.unwrap().into_float_value();  
-    let otherv: inkwell::values::FloatValue = args.get(0).unwrap().data.unwrap().into_float_value();
+    let otherv: inkwell::values::FloatValue = args.get(1).unwrap().data.unwrap().into_float_value();
 
     let res: inkwell::values::IntValue = codegen.builder.build_float_compare(inkwell::FloatPredicate::OGT, selfv, otherv, "f64gt");
 
@@ -174,7 +174,7 @@ fn f64_le<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &par
     }
 
     let selfv: inkwell::values::FloatValue = args.first().unwrap().data.unwrap().into_float_value();  
-    let otherv: inkwell::values::FloatValue = args.get(0).unwrap().data.unwrap().into_float_value();
+    let otherv: inkwell::values::FloatValue = args.get(1).unwrap().data.unwrap().into_float_value();
 
     let res: inkwell::values::IntValue = codegen.builder.build_float_compare(inkwell::FloatPredicate::OLE, selfv, otherv, "f64lt");
 
@@ -192,7 +192,7 @@ fn f64_ge<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &par
     }
 
     let selfv: inkwell::values::FloatValue = args.first().unwrap().data.unwrap().into_float_value();  
-    let otherv: inkwell::values::FloatValue = args.get(0).unwrap().data.unwrap().into_float_value();
+    let otherv: inkwell::values::FloatValue = args.get(1).unwrap().data.unwrap().into_float_value();
 
     let res: inkwell::values::IntValue = codegen.builder.build_float_compare(inkwell::FloatPredicate::OGE, selfv, otherv, "f64gt");
 
@@ -210,7 +210,7 @@ fn f64_ne<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &par
     }
 
     let selfv: inkwell::values::FloatValue = args.first().unwrap().data.unwrap().into_float_value();  
-    let otherv: inkwell::values::FloatValue = args.get(0).unwrap().data.unwrap().into_float_value();
+    let otherv: inkwell::values::FloatValue = args.get(1).unwrap().data.unwrap().into_float_value();
 
     let res: inkwell::values::IntValue = codegen.builder.build_float_compare(inkwell::FloatPredicate::ONE, selfv, otherv, "f32eq");
 
@@ -224,39 +224,15 @@ fn f64_ne<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &par
 fn f64_string<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, _pos: &parser::Position) -> Data<'a> {
     let selfv: inkwell::values::FloatValue = args.first().unwrap().data.unwrap().into_float_value();
 
-    //int sprintf(char* str, char* format)
-    let sprintf: inkwell::values::FunctionValue = codegen.module.add_function("sprintf", codegen.inkwell_types.i32tp.fn_type(&[inkwell::types::BasicMetadataTypeEnum::PointerType(codegen.inkwell_types.i8tp.ptr_type(inkwell::AddressSpace::from(0u16))), inkwell::types::BasicMetadataTypeEnum::PointerType(codegen.inkwell_types.i8tp.ptr_type(inkwell::AddressSpace::from(0u16)))], true), Some(inkwell::module::Linkage::External));
-    
-
-    let struct_tp: inkwell::types::StructType = codegen.context.struct_type(&[inkwell::types::BasicTypeEnum::ArrayType(codegen.inkwell_types.i8tp.array_type(20))], false);
-
-    let ptr: inkwell::values::PointerValue = codegen::CodeGen::alloca(codegen, struct_tp, "String");
-
-    let arrptr: inkwell::values::PointerValue = codegen.builder.build_struct_gep(ptr, 0 as u32, "arr").expect("GEP Error");
-    let data_ptr: inkwell::values::PointerValue = unsafe { codegen.builder.build_in_bounds_gep(arrptr, &[codegen.inkwell_types.i32tp.const_zero(), codegen.inkwell_types.i32tp.const_zero()], "data_ptr") };
-
-        
-    let arraytp: inkwell::types::ArrayType = codegen.inkwell_types.i8tp.array_type(3);
-
-    let mut arrdata: Vec<inkwell::values::IntValue> = Vec::new();
-    for c in b"%f" {
-        arrdata.push(codegen.inkwell_types.i8tp.const_int(c.clone() as u64, false));
-    }
-    arrdata.push(codegen.inkwell_types.i8tp.const_zero());
-
-    let array: inkwell::values::ArrayValue = codegen.inkwell_types.i8tp.const_array(&arrdata[..]);
-    let formatptr: inkwell::values::PointerValue = codegen::CodeGen::alloca(codegen, arraytp, "format");
-    codegen.builder.build_store(formatptr, array);
-    let format_ptr: inkwell::values::PointerValue = unsafe { codegen.builder.build_in_bounds_gep(formatptr, &[codegen.inkwell_types.i32tp.const_zero(), codegen.inkwell_types.i32tp.const_zero()], "data_ptr") };
-
-    codegen.builder.build_call(inkwell::values::CallableValue::try_from(sprintf.as_global_value().as_pointer_value()).unwrap(), &[inkwell::values::BasicMetadataValueEnum::PointerValue(data_ptr), inkwell::values::BasicMetadataValueEnum::PointerValue(format_ptr), inkwell::values::BasicMetadataValueEnum::FloatValue(selfv)], "sprintf_call");
+    return builtin_types::structs::stringtype::string_format(codegen, "%f", vec![selfv.into()]);
+}
 
-    let data: Data = Data {
-        data: Some(inkwell::values::BasicValueEnum::PointerValue(ptr)),
-        tp: crate::codegen::CodeGen::datatypes_get(codegen, &String::from("String")).unwrap().clone(),
+fn f64_default<'a>(codegen: &mut codegen::CodeGen<'a>, _args: Vec<Data<'a>>, _pos: &parser::Position) -> Data<'a> {
+    return Data {
+        data: Some(inkwell::values::BasicValueEnum::FloatValue(codegen.inkwell_types.f64tp.const_zero())),
+        tp: crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::F64.to_string()).unwrap().clone(),
         owned: true,
     };
-    return data;
 }
 
 pub fn init_f64(codegen: &mut codegen::CodeGen) {
@@ -296,6 +272,8 @@ pub fn init_f64(codegen: &mut codegen::CodeGen) {
     traits.insert(TraitType::Lt.to_string(), builtin_types::create_trait_func(f64_lt, 2, TraitType::Lt, tp.clone()));
     traits.insert(TraitType::Ge.to_string(), builtin_types::create_trait_func(f64_ge, 2, TraitType::Ge, tp.clone()));
     traits.insert(TraitType::Le.to_string(), builtin_types::create_trait_func(f64_le, 2, TraitType::Le, tp.clone()));
+    traits.insert(TraitType::Default.to_string(), builtin_types::create_trait_func(f64_default, 0, TraitType::Default, tp.clone()));
+    traits.insert(TraitType::Clone.to_string(), builtin_types::create_trait_func(builtin_types::value_clone, 1, TraitType::Clone, tp.clone()));
 
     builtin_types::add_simple_type(codegen, traits, BasicDataType::F64, BasicDataType::F64.to_string().as_str());
 }
\ No newline at end of file
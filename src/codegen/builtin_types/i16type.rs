@@ -21,7 +21,7 @@ fn i16_add<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &pa
     let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();
     let otherv: inkwell::values::IntValue = args.get(1).unwrap().data.unwrap().into_int_value();
 
-    let res: inkwell::values::IntValue = codegen.builder.build_int_add(selfv, otherv, "i16sum");
+    let res: inkwell::values::IntValue = builtin_types::build_int_arith(codegen, builtin_types::IntArith::Add, selfv, otherv, true, "i16sum", pos);
 
     return Data {
         data: Some(inkwell::values::BasicValueEnum::IntValue(res)),
@@ -39,7 +39,7 @@ fn i16_mul<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &pa
     let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();
     let otherv: inkwell::values::IntValue = args.get(1).unwrap().data.unwrap().into_int_value();
 
-    let res: inkwell::values::IntValue = codegen.builder.build_int_mul(selfv, otherv, "i16mul");
+    let res: inkwell::values::IntValue = builtin_types::build_int_arith(codegen, builtin_types::IntArith::Mul, selfv, otherv, true, "i16mul", pos);
 
     return Data {
         data: Some(inkwell::values::BasicValueEnum::IntValue(res)),
@@ -57,7 +57,7 @@ fn i16_sub<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &pa
     let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();
     let otherv: inkwell::values::IntValue = args.get(1).unwrap().data.unwrap().into_int_value();
 
-    let res: inkwell::values::IntValue = codegen.builder.build_int_sub(selfv, otherv, "i16sub");
+    let res: inkwell::values::IntValue = builtin_types::build_int_arith(codegen, builtin_types::IntArith::Sub, selfv, otherv, true, "i16sub", pos);
 
     return Data {
         data: Some(inkwell::values::BasicValueEnum::IntValue(res)),
@@ -75,7 +75,7 @@ fn i16_div<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &pa
     let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();
     let otherv: inkwell::values::IntValue = args.get(1).unwrap().data.unwrap().into_int_value();
 
-    let res: inkwell::values::IntValue = codegen.builder.build_int_signed_div(selfv, otherv, "i16div");
+    let res: inkwell::values::IntValue = builtin_types::build_int_div(codegen, selfv, otherv, true, "i16div", pos);
 
     return Data {
         data: Some(inkwell::values::BasicValueEnum::IntValue(res)),
@@ -88,11 +88,10 @@ fn i16_pos<'a>(_codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, _pos: &
     return args.get(0).unwrap().clone();
 }
 
-fn i16_neg<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, _pos: &parser::Position) -> Data<'a> {    
+fn i16_neg<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {    
     let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();
-    let otherv: inkwell::values::IntValue = codegen.inkwell_types.i16tp.const_int_from_string("-1", inkwell::types::StringRadix::Decimal).unwrap();
 
-    let res: inkwell::values::IntValue = codegen.builder.build_int_mul(selfv, otherv, "i16neg");
+    let res: inkwell::values::IntValue = builtin_types::build_int_arith(codegen, builtin_types::IntArith::Neg, selfv, selfv, true, "i16neg", pos);
 
     return Data {
         data: Some(inkwell::values::BasicValueEnum::IntValue(res)),
@@ -120,7 +119,7 @@ fn i16_eq<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &par
     }
 
     let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();  
-    let otherv: inkwell::values::IntValue = args.get(0).unwrap().data.unwrap().into_int_value();
+    let otherv: inkwell::values::IntValue = args.get(1).unwrap().data.unwrap().into_int_value();
 
     let res: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::EQ, selfv, otherv, "i16eq");
 
@@ -138,7 +137,7 @@ fn i16_lt<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &par
     }
 
     let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();  
-    let otherv: inkwell::values::IntValue = args.get(0).unwrap().data.unwrap().into_int_value();
+    let otherv: inkwell::values::IntValue = args.get(1).unwrap().data.unwrap().into_int_value();
 
     let res: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::SLT, selfv, otherv, "i16lt");
 
@@ -156,7 +155,7 @@ fn i16_gt<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &par
     }
 
     let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();  
-    let otherv: inkwell::values::IntValue = args.get(0).unwrap().data.unwrap().into_int_value();
+    let otherv: inkwell::values::IntValue = args.get(1).unwrap().data.unwrap().into_int_value();
 
     let res: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::SGT, selfv, otherv, "i16gt");
 
@@ -174,7 +173,7 @@ fn i16_le<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &par
     }
 
     let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();  
-    let otherv: inkwell::values::IntValue = args.get(0).unwrap().data.unwrap().into_int_value();
+    let otherv: inkwell::values::IntValue = args.get(1).unwrap().data.unwrap().into_int_value();
 
     let res: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::SLE, selfv, otherv, "i16lt");
 
@@ -192,7 +191,7 @@ fn i16_ge<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &par
     }
 
     let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();  
-    let otherv: inkwell::values::IntValue = args.get(0).unwrap().data.unwrap().into_int_value();
+    let otherv: inkwell::values::IntValue = args.get(1).unwrap().data.unwrap().into_int_value();
 
     let res: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::SGE, selfv, otherv, "i16gt");
 
@@ -210,7 +209,7 @@ fn i16_ne<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &par
     }
 
     let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();  
-    let otherv: inkwell::values::IntValue = args.get(0).unwrap().data.unwrap().into_int_value();
+    let otherv: inkwell::values::IntValue = args.get(1).unwrap().data.unwrap().into_int_value();
 
     let res: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::NE, selfv, otherv, "i16ne");
 
@@ -221,44 +220,12 @@ fn i16_ne<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &par
     };
 }
 
-fn i16_string<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, _pos: &parser::Position) -> Data<'a> {
-    let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();
-
-    //int sprintf(char* str, char* format)
-    let sprintf: inkwell::values::FunctionValue = codegen.module.add_function("sprintf", codegen.inkwell_types.i32tp.fn_type(&[inkwell::types::BasicMetadataTypeEnum::PointerType(codegen.inkwell_types.i8tp.ptr_type(inkwell::AddressSpace::from(0u16))), inkwell::types::BasicMetadataTypeEnum::PointerType(codegen.inkwell_types.i8tp.ptr_type(inkwell::AddressSpace::from(0u16)))], true), Some(inkwell::module::Linkage::External));
-    
-
-    let struct_tp: inkwell::types::StructType = codegen.context.struct_type(&[inkwell::types::BasicTypeEnum::ArrayType(codegen.inkwell_types.i8tp.array_type(20))], false);
-
-    let ptr: inkwell::values::PointerValue = codegen::CodeGen::alloca(codegen, struct_tp, "String");
-
-    let arrptr: inkwell::values::PointerValue = codegen.builder.build_struct_gep(ptr, 0 as u32, "arr").expect("GEP Error");
-    let data_ptr: inkwell::values::PointerValue = unsafe { codegen.builder.build_in_bounds_gep(arrptr, &[codegen.inkwell_types.i32tp.const_zero(), codegen.inkwell_types.i32tp.const_zero()], "data_ptr") };
-
-        
-    let arraytp: inkwell::types::ArrayType = codegen.inkwell_types.i8tp.array_type(3);
-
-    let mut arrdata: Vec<inkwell::values::IntValue> = Vec::new();
-    for c in b"%d" {
-        arrdata.push(codegen.inkwell_types.i8tp.const_int(c.clone() as u64, false));
-    }
-    arrdata.push(codegen.inkwell_types.i8tp.const_zero());
-
-    let intv: inkwell::values::IntValue = codegen.builder.build_int_z_extend(selfv, *codegen.inkwell_types.i32tp, "extend");
-
-    let array: inkwell::values::ArrayValue = codegen.inkwell_types.i8tp.const_array(&arrdata[..]);
-    let formatptr: inkwell::values::PointerValue = codegen::CodeGen::alloca(codegen, arraytp, "format");
-    codegen.builder.build_store(formatptr, array);
-    let format_ptr: inkwell::values::PointerValue = unsafe { codegen.builder.build_in_bounds_gep(formatptr, &[codegen.inkwell_types.i32tp.const_zero(), codegen.inkwell_types.i32tp.const_zero()], "data_ptr") };
-
-    codegen.builder.build_call(inkwell::values::CallableValue::try_from(sprintf.as_global_value().as_pointer_value()).unwrap(), &[inkwell::values::BasicMetadataValueEnum::PointerValue(data_ptr), inkwell::values::BasicMetadataValueEnum::PointerValue(format_ptr), inkwell::values::BasicMetadataValueEnum::IntValue(intv)], "sprintf_call");
-
-    let data: Data = Data {
-        data: Some(inkwell::values::BasicValueEnum::PointerValue(ptr)),
-        tp: crate::codegen::CodeGen::datatypes_get(codegen, &String::from("String")).unwrap().clone(),
+fn i16_default<'a>(codegen: &mut codegen::CodeGen<'a>, _args: Vec<Data<'a>>, _pos: &parser::Position) -> Data<'a> {
+    return Data {
+        data: Some(inkwell::values::BasicValueEnum::IntValue(codegen.inkwell_types.i16tp.const_zero())),
+        tp: crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::I16.to_string()).unwrap().clone(),
         owned: true,
     };
-    return data;
 }
 
 pub fn init_i16(codegen: &mut codegen::CodeGen) {
@@ -266,22 +233,7 @@ pub fn init_i16(codegen: &mut codegen::CodeGen) {
 
     let mut tp: DataType = new_datatype(BasicDataType::I16, BasicDataType::I16.to_string(), None, Vec::new(), Vec::new(), None, false, None, std::collections::HashMap::new());
 
-    //to_string
-    let mut tostrfntp: DataType = crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::WrapperFunc.to_string()).unwrap().clone();
-    tostrfntp.names = Some(vec![String::from("self")]);
-    tostrfntp.rettp = Some(Box::new(crate::codegen::CodeGen::datatypes_get(codegen, &crate::codegen::types::BasicDataType::I32.to_string()).unwrap().clone()));
-    tostrfntp.wrapperfn = Some(i16_string);
-    
-    tp.methods.insert(String::from("to_string"), crate::codegen::types::Method {
-        tp: crate::codegen::types::MethodType::Builtin,
-        builtin: Some(i16_string),
-        func: None,
-        functp: tostrfntp,
-        isinstance: true,
-        isinstanceptr: false,
-        ismutinstanceptr: false,
-    });
-    //
+    builtin_types::intmethods::add_int_methods(codegen, &mut tp);
 
     codegen.cur_module.datatypes.insert(BasicDataType::I16.to_string(), tp.clone());
 
@@ -298,6 +250,9 @@ pub fn init_i16(codegen: &mut codegen::CodeGen) {
     traits.insert(TraitType::Lt.to_string(), builtin_types::create_trait_func(i16_lt, 2, TraitType::Lt, tp.clone()));
     traits.insert(TraitType::Ge.to_string(), builtin_types::create_trait_func(i16_ge, 2, TraitType::Ge, tp.clone()));
     traits.insert(TraitType::Le.to_string(), builtin_types::create_trait_func(i16_le, 2, TraitType::Le, tp.clone()));
+    builtin_types::intmethods::add_int_bitwise_traits(&mut traits, &tp);
+    traits.insert(TraitType::Default.to_string(), builtin_types::create_trait_func(i16_default, 0, TraitType::Default, tp.clone()));
+    traits.insert(TraitType::Clone.to_string(), builtin_types::create_trait_func(builtin_types::value_clone, 1, TraitType::Clone, tp.clone()));
 
     
     builtin_types::add_simple_type(codegen, traits, BasicDataType::I16, BasicDataType::I16.to_string().as_str());
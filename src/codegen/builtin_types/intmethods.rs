@@ -0,0 +1,466 @@
+use crate::codegen::types::{Trait, TraitType, Data, DataType, BasicDataType, Method, MethodType};
+use crate::codegen::{self, CodeGen};
+use crate::codegen::builtin_types::{self, IntArith, enums};
+use crate::errors;
+use crate::parser;
+use std::collections::HashMap;
+
+//Check the argument count and that all arguments have the type of self
+fn get_int_args<'a>(codegen: &mut codegen::CodeGen<'a>, args: &Vec<Data<'a>>, nargs: usize, name: &str, pos: &parser::Position) -> Vec<inkwell::values::IntValue<'a>> {
+    if args.len() != nargs {
+        let fmt: String = format!("Expected {} arguments, got {}.", nargs-1, args.len()-1);
+        errors::raise_error(&fmt, errors::ErrorType::ArgumentCountMismatch, pos, codegen.info);
+    }
+
+    for arg in &args[1..] {
+        if arg.tp.tp != args.first().unwrap().tp.tp {
+            let fmt: String = format!("Invalid types for {}.{}, expected '{}', got '{}'.", args.first().unwrap().tp, name, args.first().unwrap().tp, arg.tp);
+            errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, pos, codegen.info);
+        }
+    }
+
+    return args.iter().map(|x| x.data.unwrap().into_int_value()).collect();
+}
+
+fn int_data<'a>(res: inkwell::values::IntValue<'a>, tp: &DataType<'a>) -> Data<'a> {
+    return Data {
+        data: Some(inkwell::values::BasicValueEnum::IntValue(res)),
+        tp: tp.clone(),
+        owned: true,
+    };
+}
+
+fn int_max<'a>(codegen: &mut codegen::CodeGen<'a>, inttp: inkwell::types::IntType<'a>, signed: bool) -> inkwell::values::IntValue<'a> {
+    if signed {
+        return codegen.builder.build_right_shift(inttp.const_all_ones(), inttp.const_int(1, false), false, "max");
+    }
+    return inttp.const_all_ones();
+}
+
+fn int_min<'a>(codegen: &mut codegen::CodeGen<'a>, inttp: inkwell::types::IntType<'a>, signed: bool) -> inkwell::values::IntValue<'a> {
+    if signed {
+        let max: inkwell::values::IntValue = int_max(codegen, inttp, signed);
+        return codegen.builder.build_not(max, "min");
+    }
+    return inttp.const_zero();
+}
+
+//Build an Optional that is None if is_none is true, otherwise Some(value)
+fn build_optional<'a>(codegen: &mut codegen::CodeGen<'a>, value: inkwell::values::IntValue<'a>, is_none: inkwell::values::IntValue<'a>, tp: &DataType<'a>) -> Data<'a> {
+    let mut opt: DataType = crate::codegen::CodeGen::datatypes_get(codegen, &String::from("Optional")).unwrap().clone();
+    opt.types = vec![tp.clone(), crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::I32.to_string()).unwrap().clone()];
+
+    let res_some: Data = enums::optionaltype::optional_some(codegen, Some(inkwell::values::BasicValueEnum::IntValue(value)), opt.types.clone());
+    let res_none: Data = enums::optionaltype::optional_none(codegen, opt.types.clone());
+
+    let some: inkwell::values::BasicValueEnum = codegen.builder.build_load(res_some.data.unwrap().into_pointer_value(), "some_case");
+    let none: inkwell::values::BasicValueEnum = codegen.builder.build_load(res_none.data.unwrap().into_pointer_value(), "none_case");
+
+    return Data {
+        data: Some(codegen.builder.build_select(is_none, none, some, "checked")),
+        tp: opt,
+        owned: true,
+    };
+}
+
+fn int_checked<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, op: IntArith, name: &str, pos: &parser::Position) -> Data<'a> {
+    let values: Vec<inkwell::values::IntValue> = get_int_args(codegen, &args, 2, name, pos);
+    let tp: DataType = args.first().unwrap().tp.clone();
+
+    let (res, overflow) = builtin_types::build_int_overflow(codegen, op, values[0], values[1], builtin_types::int_issigned(tp.clone()), name);
+
+    return build_optional(codegen, res, overflow, &tp);
+}
+
+fn int_checked_add<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    return int_checked(codegen, args, IntArith::Add, "checked_add", pos);
+}
+
+fn int_checked_sub<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    return int_checked(codegen, args, IntArith::Sub, "checked_sub", pos);
+}
+
+fn int_checked_mul<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    return int_checked(codegen, args, IntArith::Mul, "checked_mul", pos);
+}
+
+fn int_checked_div<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    let values: Vec<inkwell::values::IntValue> = get_int_args(codegen, &args, 2, "checked_div", pos);
+    let tp: DataType = args.first().unwrap().tp.clone();
+    let signed: bool = builtin_types::int_issigned(tp.clone());
+    let inttp: inkwell::types::IntType = values[0].get_type();
+
+    let mut invalid: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::EQ, values[1], inttp.const_zero(), "div_zero");
+    if signed {
+        //MIN / -1 overflows
+        let min: inkwell::values::IntValue = int_min(codegen, inttp, signed);
+        let is_min: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::EQ, values[0], min, "is_min");
+        let is_neg_one: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::EQ, values[1], inttp.const_all_ones(), "is_neg_one");
+        let overflow: inkwell::values::IntValue = codegen.builder.build_and(is_min, is_neg_one, "div_overflow");
+        invalid = codegen.builder.build_or(invalid, overflow, "div_invalid");
+    }
+
+    //Divide by 1 in the None case, so the division itself never traps
+    let divisor: inkwell::values::IntValue = codegen.builder.build_select(invalid, inttp.const_int(1, false), values[1], "divisor").into_int_value();
+    let res: inkwell::values::IntValue = if signed {
+        codegen.builder.build_int_signed_div(values[0], divisor, "checked_div")
+    }
+    else {
+        codegen.builder.build_int_unsigned_div(values[0], divisor, "checked_div")
+    };
+
+    return build_optional(codegen, res, invalid, &tp);
+}
+
+fn int_wrapping<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, op: IntArith, name: &str, pos: &parser::Position) -> Data<'a> {
+    let values: Vec<inkwell::values::IntValue> = get_int_args(codegen, &args, 2, name, pos);
+
+    let res: inkwell::values::IntValue = match op {
+        IntArith::Add => codegen.builder.build_int_add(values[0], values[1], name),
+        IntArith::Sub | IntArith::Neg => codegen.builder.build_int_sub(values[0], values[1], name),
+        IntArith::Mul => codegen.builder.build_int_mul(values[0], values[1], name),
+    };
+
+    return int_data(res, &args.first().unwrap().tp);
+}
+
+fn int_wrapping_add<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    return int_wrapping(codegen, args, IntArith::Add, "wrapping_add", pos);
+}
+
+fn int_wrapping_sub<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    return int_wrapping(codegen, args, IntArith::Sub, "wrapping_sub", pos);
+}
+
+fn int_wrapping_mul<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    return int_wrapping(codegen, args, IntArith::Mul, "wrapping_mul", pos);
+}
+
+fn int_saturating<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, op: IntArith, name: &str, pos: &parser::Position) -> Data<'a> {
+    let values: Vec<inkwell::values::IntValue> = get_int_args(codegen, &args, 2, name, pos);
+    let signed: bool = builtin_types::int_issigned(args.first().unwrap().tp.clone());
+    let inttp: inkwell::types::IntType = values[0].get_type();
+
+    let (res, overflow) = builtin_types::build_int_overflow(codegen, op, values[0], values[1], signed, name);
+
+    //The value to saturate to if the operation overflowed
+    let saturated: inkwell::values::IntValue = if !signed {
+        if op == IntArith::Sub { inttp.const_zero() } else { int_max(codegen, inttp, signed) }
+    }
+    else {
+        let self_neg: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::SLT, values[0], inttp.const_zero(), "self_neg");
+        let negative: inkwell::values::IntValue = if op == IntArith::Mul {
+            let other_neg: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::SLT, values[1], inttp.const_zero(), "other_neg");
+            codegen.builder.build_xor(self_neg, other_neg, "res_neg")
+        }
+        else {
+            self_neg
+        };
+        let min: inkwell::values::IntValue = int_min(codegen, inttp, signed);
+        let max: inkwell::values::IntValue = int_max(codegen, inttp, signed);
+        codegen.builder.build_select(negative, min, max, "saturated").into_int_value()
+    };
+
+    let res: inkwell::values::IntValue = codegen.builder.build_select(overflow, saturated, res, name).into_int_value();
+
+    return int_data(res, &args.first().unwrap().tp);
+}
+
+fn int_saturating_add<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    return int_saturating(codegen, args, IntArith::Add, "saturating_add", pos);
+}
+
+fn int_saturating_sub<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    return int_saturating(codegen, args, IntArith::Sub, "saturating_sub", pos);
+}
+
+fn int_saturating_mul<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    return int_saturating(codegen, args, IntArith::Mul, "saturating_mul", pos);
+}
+
+fn int_pow<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    if args.len() != 2 {
+        let fmt: String = format!("Expected 1 arguments, got {}.", args.len()-1);
+        errors::raise_error(&fmt, errors::ErrorType::ArgumentCountMismatch, pos, codegen.info);
+    }
+    if args.get(1).unwrap().tp.tp != BasicDataType::U32 {
+        let fmt: String = format!("Invalid types for {}.pow, expected 'u32', got '{}'.", args.first().unwrap().tp, args.get(1).unwrap().tp);
+        errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, pos, codegen.info);
+    }
+
+    let base: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();
+    let exp: inkwell::values::IntValue = args.get(1).unwrap().data.unwrap().into_int_value();
+    let signed: bool = builtin_types::int_issigned(args.first().unwrap().tp.clone());
+
+    let start_block: inkwell::basic_block::BasicBlock = codegen.current_block.unwrap();
+    let cond_block: inkwell::basic_block::BasicBlock = codegen.context.append_basic_block(codegen.current_block.unwrap().get_parent().unwrap(), "pow_cond");
+    let body_block: inkwell::basic_block::BasicBlock = codegen.context.append_basic_block(codegen.current_block.unwrap().get_parent().unwrap(), "pow_body");
+    let end_block: inkwell::basic_block::BasicBlock = codegen.context.append_basic_block(codegen.current_block.unwrap().get_parent().unwrap(), "pow_end");
+
+    codegen.builder.build_unconditional_branch(cond_block);
+
+    //Multiply exp times, so overflow is checked the same as for *
+    codegen.builder.position_at_end(cond_block);
+    codegen.current_block = Some(cond_block);
+
+    let res: inkwell::values::PhiValue = codegen.builder.build_phi(base.get_type(), "pow_res");
+    let idx: inkwell::values::PhiValue = codegen.builder.build_phi(exp.get_type(), "pow_idx");
+    let cond: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::ULT, idx.as_basic_value().into_int_value(), exp, "pow_check");
+    codegen.builder.build_conditional_branch(cond, body_block, end_block);
+
+    codegen.builder.position_at_end(body_block);
+    codegen.current_block = Some(body_block);
+
+    let next_res: inkwell::values::IntValue = builtin_types::build_int_arith(codegen, IntArith::Mul, res.as_basic_value().into_int_value(), base, signed, "pow_mul", pos);
+    let next_idx: inkwell::values::IntValue = codegen.builder.build_int_add(idx.as_basic_value().into_int_value(), exp.get_type().const_int(1, false), "pow_next");
+    let body_end: inkwell::basic_block::BasicBlock = codegen.current_block.unwrap();
+    codegen.builder.build_unconditional_branch(cond_block);
+
+    res.add_incoming(&[(&base.get_type().const_int(1, false), start_block), (&next_res, body_end)]);
+    idx.add_incoming(&[(&exp.get_type().const_zero(), start_block), (&next_idx, body_end)]);
+
+    codegen.builder.position_at_end(end_block);
+    codegen.current_block = Some(end_block);
+
+    return int_data(res.as_basic_value().into_int_value(), &args.first().unwrap().tp);
+}
+
+fn int_abs<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    let values: Vec<inkwell::values::IntValue> = get_int_args(codegen, &args, 1, "abs", pos);
+    if !builtin_types::int_issigned(args.first().unwrap().tp.clone()) {
+        return args.first().unwrap().clone();
+    }
+
+    let neg: inkwell::values::IntValue = builtin_types::build_int_arith(codegen, IntArith::Neg, values[0], values[0], true, "abs_neg", pos);
+    let is_neg: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::SLT, values[0], values[0].get_type().const_zero(), "is_neg");
+    let res: inkwell::values::IntValue = codegen.builder.build_select(is_neg, neg, values[0], "abs").into_int_value();
+
+    return int_data(res, &args.first().unwrap().tp);
+}
+
+fn int_minmax<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, ismin: bool, pos: &parser::Position) -> Data<'a> {
+    let values: Vec<inkwell::values::IntValue> = get_int_args(codegen, &args, 2, if ismin { "min" } else { "max" }, pos);
+    let signed: bool = builtin_types::int_issigned(args.first().unwrap().tp.clone());
+
+    let pred: inkwell::IntPredicate = if signed { inkwell::IntPredicate::SLT } else { inkwell::IntPredicate::ULT };
+    let lt: inkwell::values::IntValue = codegen.builder.build_int_compare(pred, values[0], values[1], "lt");
+    let res: inkwell::values::IntValue = if ismin {
+        codegen.builder.build_select(lt, values[0], values[1], "min").into_int_value()
+    }
+    else {
+        codegen.builder.build_select(lt, values[1], values[0], "max").into_int_value()
+    };
+
+    return int_data(res, &args.first().unwrap().tp);
+}
+
+fn int_min_method<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    return int_minmax(codegen, args, true, pos);
+}
+
+fn int_max_method<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    return int_minmax(codegen, args, false, pos);
+}
+
+//The value of a constant integer that fits in 64 bits
+fn int_constant(value: inkwell::values::IntValue, signed: bool) -> Option<i128> {
+    if !value.is_const() {
+        return None;
+    }
+    if signed {
+        return value.get_sign_extended_constant().map(|x| x as i128);
+    }
+    return value.get_zero_extended_constant().map(|x| x as i128);
+}
+
+fn int_clamp<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    let values: Vec<inkwell::values::IntValue> = get_int_args(codegen, &args, 3, "clamp", pos);
+    let signed: bool = builtin_types::int_issigned(args.first().unwrap().tp.clone());
+
+    let lt_pred: inkwell::IntPredicate = if signed { inkwell::IntPredicate::SLT } else { inkwell::IntPredicate::ULT };
+    let gt_pred: inkwell::IntPredicate = if signed { inkwell::IntPredicate::SGT } else { inkwell::IntPredicate::UGT };
+
+    //An inverted range is a compile time error if both bounds are constant, otherwise the program traps on it at runtime
+    let min: Option<i128> = int_constant(values[1], signed);
+    let max: Option<i128> = int_constant(values[2], signed);
+    if min.is_some() && max.is_some() {
+        if min.unwrap() > max.unwrap() {
+            let fmt: String = format!("Expected 'min' not greater than 'max', got {} and {}.", min.unwrap(), max.unwrap());
+            errors::raise_error(&fmt, errors::ErrorType::InvalidClampRange, pos, codegen.info);
+        }
+    }
+    else {
+        let inverted: inkwell::values::IntValue = codegen.builder.build_int_compare(gt_pred, values[1], values[2], "clamp_inverted");
+        let msg: String = format!("attempt to clamp with 'min' greater than 'max' at {}:{}:{}", codegen.info.name, pos.line+1, pos.startcol+1);
+        codegen::CodeGen::build_trap_if(codegen, inverted, "clamp_inverted", Some(msg));
+    }
+
+    let below: inkwell::values::IntValue = codegen.builder.build_int_compare(lt_pred, values[0], values[1], "below");
+    let lower: inkwell::values::IntValue = codegen.builder.build_select(below, values[1], values[0], "clamp_lower").into_int_value();
+    let above: inkwell::values::IntValue = codegen.builder.build_int_compare(gt_pred, lower, values[2], "above");
+    let res: inkwell::values::IntValue = codegen.builder.build_select(above, values[2], lower, "clamp").into_int_value();
+
+    return int_data(res, &args.first().unwrap().tp);
+}
+
+//Check that both operands of a binary operator have the same integer type
+fn get_int_operands<'a>(codegen: &mut codegen::CodeGen<'a>, args: &Vec<Data<'a>>, op: &str, pos: &parser::Position) -> (inkwell::values::IntValue<'a>, inkwell::values::IntValue<'a>) {
+    if args.get(1).unwrap().tp.tp != args.first().unwrap().tp.tp {
+        let fmt: String = format!("invalid types for {} {}, got '{}' and '{}'.", args.first().unwrap().tp, op, args.first().unwrap().tp, args.get(1).unwrap().tp);
+        errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, pos, codegen.info);
+    }
+
+    return (args.first().unwrap().data.unwrap().into_int_value(), args.get(1).unwrap().data.unwrap().into_int_value());
+}
+
+fn int_bitand<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    let (selfv, otherv) = get_int_operands(codegen, &args, "&", pos);
+    let res: inkwell::values::IntValue = codegen.builder.build_and(selfv, otherv, "bitand");
+    return int_data(res, &args.first().unwrap().tp);
+}
+
+fn int_bitor<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    let (selfv, otherv) = get_int_operands(codegen, &args, "|", pos);
+    let res: inkwell::values::IntValue = codegen.builder.build_or(selfv, otherv, "bitor");
+    return int_data(res, &args.first().unwrap().tp);
+}
+
+fn int_bitxor<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    let (selfv, otherv) = get_int_operands(codegen, &args, "^", pos);
+    let res: inkwell::values::IntValue = codegen.builder.build_xor(selfv, otherv, "bitxor");
+    return int_data(res, &args.first().unwrap().tp);
+}
+
+fn int_shl<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    let (selfv, otherv) = get_int_operands(codegen, &args, "<<", pos);
+    let signed: bool = builtin_types::int_issigned(args.first().unwrap().tp.clone());
+    let res: inkwell::values::IntValue = builtin_types::build_int_shift(codegen, selfv, otherv, true, signed, "shl", pos);
+    return int_data(res, &args.first().unwrap().tp);
+}
+
+fn int_shr<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &parser::Position) -> Data<'a> {
+    let (selfv, otherv) = get_int_operands(codegen, &args, ">>", pos);
+    let signed: bool = builtin_types::int_issigned(args.first().unwrap().tp.clone());
+    let res: inkwell::values::IntValue = builtin_types::build_int_shift(codegen, selfv, otherv, false, signed, "shr", pos);
+    return int_data(res, &args.first().unwrap().tp);
+}
+
+fn int_bitnot<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, _pos: &parser::Position) -> Data<'a> {
+    let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();
+    let res: inkwell::values::IntValue = codegen.builder.build_not(selfv, "bitnot");
+    return int_data(res, &args.first().unwrap().tp);
+}
+
+//Format an integer as a String. printf has no format for 128 bit integers, so they are split into parts of 18 decimal digits.
+pub fn int_to_string<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, _pos: &parser::Position) -> Data<'a> {
+    let selfv: inkwell::values::IntValue = args.first().unwrap().data.unwrap().into_int_value();
+    let signed: bool = builtin_types::int_issigned(args.first().unwrap().tp.clone());
+    let i64tp: inkwell::types::IntType = *codegen.inkwell_types.i64tp;
+
+    if selfv.get_type().get_bit_width() <= 64 {
+        if signed {
+            let v: inkwell::values::IntValue = codegen.builder.build_int_s_extend_or_bit_cast(selfv, i64tp, "value");
+            return builtin_types::structs::stringtype::string_format(codegen, "%lld", vec![v.into()]);
+        }
+        let v: inkwell::values::IntValue = codegen.builder.build_int_z_extend_or_bit_cast(selfv, i64tp, "value");
+        return builtin_types::structs::stringtype::string_format(codegen, "%llu", vec![v.into()]);
+    }
+
+    let inttp: inkwell::types::IntType = selfv.get_type();
+    let isneg: inkwell::values::IntValue = if signed {
+        codegen.builder.build_int_compare(inkwell::IntPredicate::SLT, selfv, inttp.const_zero(), "is_neg")
+    }
+    else {
+        codegen.context.bool_type().const_zero()
+    };
+    //The magnitude of the minimum is correct as an unsigned value
+    let negv: inkwell::values::IntValue = codegen.builder.build_int_sub(inttp.const_zero(), selfv, "neg");
+    let magnitude: inkwell::values::IntValue = codegen.builder.build_select(isneg, negv, selfv, "magnitude").into_int_value();
+
+    let base: inkwell::values::IntValue = inttp.const_int(1_000_000_000_000_000_000, false);
+    let lo: inkwell::values::IntValue = codegen.builder.build_int_unsigned_rem(magnitude, base, "lo");
+    let rest: inkwell::values::IntValue = codegen.builder.build_int_unsigned_div(magnitude, base, "rest");
+    let mid: inkwell::values::IntValue = codegen.builder.build_int_unsigned_rem(rest, base, "mid");
+    let hi: inkwell::values::IntValue = codegen.builder.build_int_unsigned_div(rest, base, "hi");
+
+    let has_hi: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::NE, hi, inttp.const_zero(), "has_hi");
+    let has_mid: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::NE, rest, inttp.const_zero(), "has_mid");
+
+    let lo: inkwell::values::IntValue = codegen.builder.build_int_truncate(lo, i64tp, "lo");
+    let mid: inkwell::values::IntValue = codegen.builder.build_int_truncate(mid, i64tp, "mid");
+    let hi: inkwell::values::IntValue = codegen.builder.build_int_truncate(hi, i64tp, "hi");
+
+    //Leading parts which are zero are left out, and the others are zero padded
+    let format_hi: inkwell::values::PointerValue = codegen.builder.build_global_string_ptr("%s%llu%018llu%018llu", "format").as_pointer_value();
+    let format_mid: inkwell::values::PointerValue = codegen.builder.build_global_string_ptr("%s%llu%018llu", "format").as_pointer_value();
+    let format_lo: inkwell::values::PointerValue = codegen.builder.build_global_string_ptr("%s%llu", "format").as_pointer_value();
+    let format_rest: inkwell::values::BasicValueEnum = codegen.builder.build_select(has_mid, format_mid, format_lo, "format");
+    let format: inkwell::values::PointerValue = codegen.builder.build_select(has_hi, format_hi.into(), format_rest, "format").into_pointer_value();
+
+    let first_rest: inkwell::values::BasicValueEnum = codegen.builder.build_select(has_mid, mid, lo, "first");
+    let first: inkwell::values::BasicValueEnum = codegen.builder.build_select(has_hi, hi.into(), first_rest, "first");
+    let second: inkwell::values::BasicValueEnum = codegen.builder.build_select(has_hi, mid, lo, "second");
+
+    let minus: inkwell::values::PointerValue = codegen.builder.build_global_string_ptr("-", "minus").as_pointer_value();
+    let empty: inkwell::values::PointerValue = codegen.builder.build_global_string_ptr("", "empty").as_pointer_value();
+    let sign: inkwell::values::BasicValueEnum = codegen.builder.build_select(isneg, minus, empty, "sign");
+
+    return builtin_types::structs::stringtype::string_format_ptr(codegen, format, vec![sign.into(), first.into(), second.into(), lo.into()]);
+}
+
+fn add_method<'a>(codegen: &mut codegen::CodeGen<'a>, tp: &mut DataType<'a>, name: &str, function: fn(&mut codegen::CodeGen<'a>, Vec<Data<'a>>, &parser::Position) -> Data<'a>, argtps: Vec<DataType<'a>>, rettp: DataType<'a>) {
+    let mut fntp: DataType = CodeGen::datatypes_get(codegen, &BasicDataType::WrapperFunc.to_string()).unwrap().clone();
+    fntp.names = Some(vec![String::from("self")]);
+    fntp.rettp = Some(Box::new(rettp));
+    fntp.types = vec![tp.clone()];
+    fntp.types.extend(argtps);
+    fntp.wrapperfn = Some(function);
+
+    tp.methods.insert(String::from(name), Method {
+        tp: MethodType::Builtin,
+        builtin: Some(function),
+        func: None,
+        functp: fntp,
+        isinstance: true,
+        isinstanceptr: false,
+        ismutinstanceptr: false,
+    });
+}
+
+//Add the overflow control, arithmetic and formatting methods shared by all integer types
+pub fn add_int_methods<'a>(codegen: &mut codegen::CodeGen<'a>, tp: &mut DataType<'a>) {
+    let inttp: DataType = tp.clone();
+    let unknown: DataType = CodeGen::datatypes_get(codegen, &BasicDataType::Unknown.to_string()).unwrap().clone();
+    let u32tp: DataType = CodeGen::datatypes_get(codegen, &BasicDataType::U32.to_string()).unwrap().clone();
+
+    add_method(codegen, tp, "checked_add", int_checked_add, vec![inttp.clone()], unknown.clone());
+    add_method(codegen, tp, "checked_sub", int_checked_sub, vec![inttp.clone()], unknown.clone());
+    add_method(codegen, tp, "checked_mul", int_checked_mul, vec![inttp.clone()], unknown.clone());
+    add_method(codegen, tp, "checked_div", int_checked_div, vec![inttp.clone()], unknown.clone());
+
+    add_method(codegen, tp, "wrapping_add", int_wrapping_add, vec![inttp.clone()], inttp.clone());
+    add_method(codegen, tp, "wrapping_sub", int_wrapping_sub, vec![inttp.clone()], inttp.clone());
+    add_method(codegen, tp, "wrapping_mul", int_wrapping_mul, vec![inttp.clone()], inttp.clone());
+
+    add_method(codegen, tp, "saturating_add", int_saturating_add, vec![inttp.clone()], inttp.clone());
+    add_method(codegen, tp, "saturating_sub", int_saturating_sub, vec![inttp.clone()], inttp.clone());
+    add_method(codegen, tp, "saturating_mul", int_saturating_mul, vec![inttp.clone()], inttp.clone());
+
+    add_method(codegen, tp, "pow", int_pow, vec![u32tp.clone()], inttp.clone());
+    add_method(codegen, tp, "abs", int_abs, vec![], inttp.clone());
+    add_method(codegen, tp, "min", int_min_method, vec![inttp.clone()], inttp.clone());
+    add_method(codegen, tp, "max", int_max_method, vec![inttp.clone()], inttp.clone());
+    add_method(codegen, tp, "clamp", int_clamp, vec![inttp.clone(), inttp.clone()], inttp.clone());
+
+    add_method(codegen, tp, "to_string", int_to_string, vec![], unknown.clone());
+}
+
+//Add the bitwise and shift operator traits shared by all integer types
+pub fn add_int_bitwise_traits<'a>(traits: &mut HashMap<String, Trait<'a>>, tp: &DataType<'a>) {
+    traits.insert(TraitType::BitAnd.to_string(), builtin_types::create_trait_func(int_bitand, 2, TraitType::BitAnd, tp.clone()));
+    traits.insert(TraitType::BitOr.to_string(), builtin_types::create_trait_func(int_bitor, 2, TraitType::BitOr, tp.clone()));
+    traits.insert(TraitType::BitXor.to_string(), builtin_types::create_trait_func(int_bitxor, 2, TraitType::BitXor, tp.clone()));
+    traits.insert(TraitType::Shl.to_string(), builtin_types::create_trait_func(int_shl, 2, TraitType::Shl, tp.clone()));
+    traits.insert(TraitType::Shr.to_string(), builtin_types::create_trait_func(int_shr, 2, TraitType::Shr, tp.clone()));
+    traits.insert(TraitType::BitNot.to_string(), builtin_types::create_trait_func(int_bitnot, 1, TraitType::BitNot, tp.clone()));
+}
\ No newline at end of file
@@ -3,6 +3,7 @@ use crate::codegen;
 use crate::codegen::types::{Type, BasicDataType, Trait, TraitType, Data};
 use super::types::DataType;
 use super::types;
+use crate::errors;
 
 pub mod i32type;
 pub mod u32type;
@@ -15,16 +16,19 @@ pub mod u64type;
 pub mod i128type;
 pub mod u128type;
 pub mod booltype;
+pub mod chartype;
 
 pub mod f32type;
 pub mod f64type;
 
 pub mod voidtype;
 pub mod functype;
+pub mod closuretype;
 pub mod arrtype;
 pub mod structtype;
 pub mod wrapperfntype;
 pub mod enumtype;
+pub mod intmethods;
 
 pub mod structs;
 pub mod enums;
@@ -68,6 +72,54 @@ pub fn create_empty_trait<'a>() -> Trait<'a>{
     }
 }
 
+//Clone a value that is copied whole, such as a number
+pub fn value_clone<'a>(_codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, _pos: &crate::parser::Position) -> Data<'a> {
+    let selfv: &Data = args.first().unwrap();
+
+    return Data {
+        data: selfv.data,
+        tp: selfv.tp.clone(),
+        owned: true,
+    };
+}
+
+//Hash a number, bool or char to a u64 from its bits
+pub fn value_hash<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, _pos: &crate::parser::Position) -> Data<'a> {
+    let selfv: &Data = args.first().unwrap();
+    let i64tp: inkwell::types::IntType = *codegen.inkwell_types.i64tp;
+
+    let hash: inkwell::values::IntValue = match selfv.tp.tp {
+        BasicDataType::F32 => {
+            let bits: inkwell::values::IntValue = codegen.builder.build_bitcast(selfv.data.unwrap(), *codegen.inkwell_types.i32tp, "bits").into_int_value();
+            codegen.builder.build_int_z_extend(bits, i64tp, "hash")
+        }
+        BasicDataType::F64 => {
+            codegen.builder.build_bitcast(selfv.data.unwrap(), i64tp, "hash").into_int_value()
+        }
+        BasicDataType::I128 | BasicDataType::U128 => {
+            let v: inkwell::values::IntValue = selfv.data.unwrap().into_int_value();
+            let high: inkwell::values::IntValue = codegen.builder.build_right_shift(v, v.get_type().const_int(64, false), false, "high");
+            let folded: inkwell::values::IntValue = codegen.builder.build_xor(v, high, "folded");
+            codegen.builder.build_int_truncate(folded, i64tp, "hash")
+        }
+        BasicDataType::I8 | BasicDataType::I16 | BasicDataType::I32 => {
+            codegen.builder.build_int_s_extend(selfv.data.unwrap().into_int_value(), i64tp, "hash")
+        }
+        BasicDataType::I64 | BasicDataType::U64 => {
+            selfv.data.unwrap().into_int_value()
+        }
+        _ => {
+            codegen.builder.build_int_z_extend(selfv.data.unwrap().into_int_value(), i64tp, "hash")
+        }
+    };
+
+    return Data {
+        data: Some(inkwell::values::BasicValueEnum::IntValue(hash)),
+        tp: codegen::CodeGen::datatypes_get(codegen, &BasicDataType::U64.to_string()).unwrap().clone(),
+        owned: true,
+    };
+}
+
 pub fn int_issigned(val: DataType) -> bool {
     match val.tp {
         BasicDataType::I8 |
@@ -83,6 +135,130 @@ pub fn int_issigned(val: DataType) -> bool {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum IntArith {
+    Add,
+    Sub,
+    Mul,
+    Neg,
+}
+
+impl IntArith {
+    fn intrinsic(&self) -> &str {
+        match self {
+            IntArith::Add => "add",
+            IntArith::Sub | IntArith::Neg => "sub",
+            IntArith::Mul => "mul",
+        }
+    }
+
+    fn verb(&self) -> &str {
+        match self {
+            IntArith::Add => "add",
+            IntArith::Sub => "subtract",
+            IntArith::Mul => "multiply",
+            IntArith::Neg => "negate",
+        }
+    }
+}
+
+//Build integer arithmetic. If overflow checks are enabled, the LLVM overflow intrinsics are used and the program traps on overflow,
+//otherwise the result wraps. For Neg, selfv is negated and otherv is ignored.
+pub fn build_int_arith<'a>(codegen: &mut codegen::CodeGen<'a>, op: IntArith, selfv: inkwell::values::IntValue<'a>, otherv: inkwell::values::IntValue<'a>, signed: bool, name: &str, pos: &crate::parser::Position) -> inkwell::values::IntValue<'a> {
+    let (left, right) = if op == IntArith::Neg { (selfv.get_type().const_zero(), selfv) } else { (selfv, otherv) };
+
+    if !codegen.overflow_checks {
+        return match op {
+            IntArith::Add => codegen.builder.build_int_add(left, right, name),
+            IntArith::Sub | IntArith::Neg => codegen.builder.build_int_sub(left, right, name),
+            IntArith::Mul => codegen.builder.build_int_mul(left, right, name),
+        };
+    }
+
+    let (res, overflow) = build_int_overflow(codegen, op, left, right, signed, name);
+
+    let msg: String = format!("attempt to {} with overflow at {}:{}:{}", op.verb(), codegen.info.name, pos.line+1, pos.startcol+1);
+    codegen::CodeGen::build_trap_if(codegen, overflow, "overflow", Some(msg));
+
+    return res;
+}
+
+//Build integer arithmetic with the LLVM overflow intrinsics, returning the wrapped result and whether it overflowed
+pub fn build_int_overflow<'a>(codegen: &mut codegen::CodeGen<'a>, op: IntArith, left: inkwell::values::IntValue<'a>, right: inkwell::values::IntValue<'a>, signed: bool, name: &str) -> (inkwell::values::IntValue<'a>, inkwell::values::IntValue<'a>) {
+    let inttp: inkwell::types::IntType = left.get_type();
+    let intrinsic: String = format!("llvm.{}{}.with.overflow.i{}", if signed { "s" } else { "u" }, op.intrinsic(), inttp.get_bit_width());
+    let func: inkwell::values::FunctionValue = match codegen.module.get_function(&intrinsic) {
+        Some(v) => v,
+        None => {
+            let rettp: inkwell::types::StructType = codegen.context.struct_type(&[inkwell::types::BasicTypeEnum::IntType(inttp), inkwell::types::BasicTypeEnum::IntType(codegen.context.bool_type())], false);
+            codegen.module.add_function(&intrinsic, rettp.fn_type(&[inkwell::types::BasicMetadataTypeEnum::IntType(inttp), inkwell::types::BasicMetadataTypeEnum::IntType(inttp)], false), None)
+        }
+    };
+
+    let res: inkwell::values::StructValue = codegen.builder.build_call(func, &[inkwell::values::BasicMetadataValueEnum::IntValue(left), inkwell::values::BasicMetadataValueEnum::IntValue(right)], name).try_as_basic_value().left().unwrap().into_struct_value();
+    let overflow: inkwell::values::IntValue = codegen.builder.build_extract_value(res, 1, "overflow").unwrap().into_int_value();
+
+    return (codegen.builder.build_extract_value(res, 0, name).unwrap().into_int_value(), overflow);
+}
+
+//Build integer division, trapping at runtime on division by zero and, for signed integers, on MIN / -1.
+//A constant zero divisor is a compile time error, and checks that a constant divisor cannot fail are omitted.
+pub fn build_int_div<'a>(codegen: &mut codegen::CodeGen<'a>, selfv: inkwell::values::IntValue<'a>, otherv: inkwell::values::IntValue<'a>, signed: bool, name: &str, pos: &crate::parser::Position) -> inkwell::values::IntValue<'a> {
+    if otherv.is_const() && otherv.is_null() {
+        let fmt: String = format!("Attempt to divide by zero.");
+        errors::raise_error(&fmt, errors::ErrorType::DivisionByZero, pos, codegen.info);
+    }
+
+    if !otherv.is_const() {
+        let is_zero: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::EQ, otherv, otherv.get_type().const_zero(), "div_zero");
+        let msg: String = format!("attempt to divide by zero at {}:{}:{}", codegen.info.name, pos.line+1, pos.startcol+1);
+        codegen::CodeGen::build_trap_if(codegen, is_zero, "div_zero", Some(msg));
+    }
+
+    if signed && !(otherv.is_const() && otherv.get_sign_extended_constant().is_some() && otherv.get_sign_extended_constant().unwrap() != -1) {
+        let min: inkwell::values::IntValue = codegen.builder.build_not(codegen.builder.build_right_shift(selfv.get_type().const_all_ones(), selfv.get_type().const_int(1, false), false, "max"), "min");
+        let is_min: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::EQ, selfv, min, "is_min");
+        let is_neg_one: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::EQ, otherv, otherv.get_type().const_all_ones(), "is_neg_one");
+        let overflow: inkwell::values::IntValue = codegen.builder.build_and(is_min, is_neg_one, "div_overflow");
+        let msg: String = format!("attempt to divide with overflow at {}:{}:{}", codegen.info.name, pos.line+1, pos.startcol+1);
+        codegen::CodeGen::build_trap_if(codegen, overflow, "div_overflow", Some(msg));
+    }
+
+    if signed {
+        return codegen.builder.build_int_signed_div(selfv, otherv, name);
+    }
+    return codegen.builder.build_int_unsigned_div(selfv, otherv, name);
+}
+
+//Build an integer shift. A shift amount of at least the bit width is a compile time error if constant. Otherwise, if overflow checks
+//are enabled the program traps on it at runtime, and if not the amount is masked to the bit width.
+pub fn build_int_shift<'a>(codegen: &mut codegen::CodeGen<'a>, selfv: inkwell::values::IntValue<'a>, otherv: inkwell::values::IntValue<'a>, left: bool, signed: bool, name: &str, pos: &crate::parser::Position) -> inkwell::values::IntValue<'a> {
+    let inttp: inkwell::types::IntType = selfv.get_type();
+    let width: u64 = inttp.get_bit_width() as u64;
+    let verb: &str = if left { "shift left" } else { "shift right" };
+
+    let mut amount: inkwell::values::IntValue = otherv;
+    if otherv.is_const() && otherv.get_zero_extended_constant().is_some() {
+        if otherv.get_zero_extended_constant().unwrap() >= width {
+            let fmt: String = format!("Attempt to {} with overflow.", verb);
+            errors::raise_error(&fmt, errors::ErrorType::ShiftOverflow, pos, codegen.info);
+        }
+    }
+    else if codegen.overflow_checks {
+        let overflow: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::UGE, otherv, inttp.const_int(width, false), "shift_overflow");
+        let msg: String = format!("attempt to {} with overflow at {}:{}:{}", verb, codegen.info.name, pos.line+1, pos.startcol+1);
+        codegen::CodeGen::build_trap_if(codegen, overflow, "shift_overflow", Some(msg));
+    }
+    else {
+        amount = codegen.builder.build_and(otherv, inttp.const_int(width-1, false), "shift_mask");
+    }
+
+    if left {
+        return codegen.builder.build_left_shift(selfv, amount, name);
+    }
+    return codegen.builder.build_right_shift(selfv, amount, signed, name);
+}
+
 pub fn init(codegen: &mut codegen::CodeGen) {
     codegen.cur_module.datatypes.insert(String::from(types::BasicDataType::Unknown.to_string()), types::new_datatype(BasicDataType::Unknown, BasicDataType::Unknown.to_string(), None, Vec::new(), Vec::new(), None, false, None, std::collections::HashMap::new()));
 
@@ -99,6 +275,8 @@ pub fn init(codegen: &mut codegen::CodeGen) {
     i128type::init_i128(codegen);
     u128type::init_u128(codegen);
     booltype::init_bool(codegen);
+    chartype::init_char(codegen);
+    closuretype::init_closure(codegen);
 
     f32type::init_f32(codegen);
     f64type::init_f64(codegen);
@@ -111,55 +289,111 @@ pub fn init(codegen: &mut codegen::CodeGen) {
 
 pub fn init_traits(codegen: &mut codegen::CodeGen) {
     codegen.traits.insert(types::TraitType::Add.to_string(), types::TraitSignature {
-         nargs: Some(2), trait_sig: None, name: String::from("add"), traittp: types::TraitMetatype::Builtin, vars: None,
+         nargs: Some(2), trait_sig: None, name: String::from("add"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
          implementations: std::collections::HashMap::new(),
         });
     codegen.traits.insert(types::TraitType::Sub.to_string(), types::TraitSignature {
-         nargs: Some(2), trait_sig: None, name: String::from("sub"), traittp: types::TraitMetatype::Builtin, vars: None,
+         nargs: Some(2), trait_sig: None, name: String::from("sub"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
          implementations: std::collections::HashMap::new(),
         });
     codegen.traits.insert(types::TraitType::Mul.to_string(), types::TraitSignature {
-         nargs: Some(2), trait_sig: None, name: String::from("mul"), traittp: types::TraitMetatype::Builtin, vars: None,
+         nargs: Some(2), trait_sig: None, name: String::from("mul"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
          implementations: std::collections::HashMap::new(),
         });
     codegen.traits.insert(types::TraitType::Div.to_string(), types::TraitSignature {
-         nargs: Some(2), trait_sig: None, name: String::from("div"), traittp: types::TraitMetatype::Builtin, vars: None,
+         nargs: Some(2), trait_sig: None, name: String::from("div"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
          implementations: std::collections::HashMap::new(),
         });
     codegen.traits.insert(types::TraitType::Pos.to_string(), types::TraitSignature {
-         nargs: Some(1), trait_sig: None, name: String::from("pos"), traittp: types::TraitMetatype::Builtin, vars: None,
+         nargs: Some(1), trait_sig: None, name: String::from("pos"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
          implementations: std::collections::HashMap::new(),
         });
     codegen.traits.insert(types::TraitType::Neg.to_string(), types::TraitSignature {
-            nargs: Some(1), trait_sig: None, name: String::from("neg"), traittp: types::TraitMetatype::Builtin, vars: None,
+            nargs: Some(1), trait_sig: None, name: String::from("neg"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
             implementations: std::collections::HashMap::new(),
         });
     codegen.traits.insert(types::TraitType::Bool.to_string(), types::TraitSignature {
-            nargs: Some(1), trait_sig: None, name: String::from("bool"), traittp: types::TraitMetatype::Builtin, vars: None,
+            nargs: Some(1), trait_sig: None, name: String::from("bool"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
             implementations: std::collections::HashMap::new(),
         });
     codegen.traits.insert(types::TraitType::Eq.to_string(), types::TraitSignature {
-            nargs: Some(2), trait_sig: None, name: String::from("eq"), traittp: types::TraitMetatype::Builtin, vars: None,
+            nargs: Some(2), trait_sig: None, name: String::from("eq"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
             implementations: std::collections::HashMap::new(),
         });
     codegen.traits.insert(types::TraitType::Ne.to_string(), types::TraitSignature {
-        nargs: Some(2), trait_sig: None, name: String::from("ne"), traittp: types::TraitMetatype::Builtin, vars: None,
+        nargs: Some(2), trait_sig: None, name: String::from("ne"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
         implementations: std::collections::HashMap::new(),
         });
     codegen.traits.insert(types::TraitType::Gt.to_string(), types::TraitSignature {
-            nargs: Some(2), trait_sig: None, name: String::from("gt"), traittp: types::TraitMetatype::Builtin, vars: None,
+            nargs: Some(2), trait_sig: None, name: String::from("gt"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
             implementations: std::collections::HashMap::new(),
         });
     codegen.traits.insert(types::TraitType::Lt.to_string(), types::TraitSignature {
-            nargs: Some(2), trait_sig: None, name: String::from("lt"), traittp: types::TraitMetatype::Builtin, vars: None,
+            nargs: Some(2), trait_sig: None, name: String::from("lt"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
             implementations: std::collections::HashMap::new(),
         });
     codegen.traits.insert(types::TraitType::Ge.to_string(), types::TraitSignature {
-            nargs: Some(2), trait_sig: None, name: String::from("ge"), traittp: types::TraitMetatype::Builtin, vars: None,
+            nargs: Some(2), trait_sig: None, name: String::from("ge"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
             implementations: std::collections::HashMap::new(),
         });
     codegen.traits.insert(types::TraitType::Le.to_string(), types::TraitSignature {
-            nargs: Some(2), trait_sig: None, name: String::from("le"), traittp: types::TraitMetatype::Builtin, vars: None,
+            nargs: Some(2), trait_sig: None, name: String::from("le"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
+            implementations: std::collections::HashMap::new(),
+        });
+    codegen.traits.insert(types::TraitType::Not.to_string(), types::TraitSignature {
+            nargs: Some(1), trait_sig: None, name: String::from("not"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
+            implementations: std::collections::HashMap::new(),
+        });
+    codegen.traits.insert(types::TraitType::BitAnd.to_string(), types::TraitSignature {
+            nargs: Some(2), trait_sig: None, name: String::from("bitand"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
+            implementations: std::collections::HashMap::new(),
+        });
+    codegen.traits.insert(types::TraitType::BitOr.to_string(), types::TraitSignature {
+            nargs: Some(2), trait_sig: None, name: String::from("bitor"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
+            implementations: std::collections::HashMap::new(),
+        });
+    codegen.traits.insert(types::TraitType::BitXor.to_string(), types::TraitSignature {
+            nargs: Some(2), trait_sig: None, name: String::from("bitxor"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
+            implementations: std::collections::HashMap::new(),
+        });
+    codegen.traits.insert(types::TraitType::Shl.to_string(), types::TraitSignature {
+            nargs: Some(2), trait_sig: None, name: String::from("shl"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
+            implementations: std::collections::HashMap::new(),
+        });
+    codegen.traits.insert(types::TraitType::Shr.to_string(), types::TraitSignature {
+            nargs: Some(2), trait_sig: None, name: String::from("shr"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
+            implementations: std::collections::HashMap::new(),
+        });
+    codegen.traits.insert(types::TraitType::BitNot.to_string(), types::TraitSignature {
+            nargs: Some(1), trait_sig: None, name: String::from("bitnot"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
+            implementations: std::collections::HashMap::new(),
+        });
+    codegen.traits.insert(types::TraitType::Index.to_string(), types::TraitSignature {
+            nargs: Some(2), trait_sig: None, name: String::from("index"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
+            implementations: std::collections::HashMap::new(),
+        });
+    codegen.traits.insert(types::TraitType::IndexMut.to_string(), types::TraitSignature {
+            nargs: Some(3), trait_sig: None, name: String::from("index_mut"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
+            implementations: std::collections::HashMap::new(),
+        });
+    codegen.traits.insert(types::TraitType::Cmp.to_string(), types::TraitSignature {
+            nargs: Some(2), trait_sig: None, name: String::from("cmp"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
+            implementations: std::collections::HashMap::new(),
+        });
+    codegen.traits.insert(types::TraitType::ToString.to_string(), types::TraitSignature {
+            nargs: Some(1), trait_sig: None, name: String::from("to_string"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
+            implementations: std::collections::HashMap::new(),
+        });
+    codegen.traits.insert(types::TraitType::Clone.to_string(), types::TraitSignature {
+            nargs: Some(1), trait_sig: None, name: String::from("clone"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
+            implementations: std::collections::HashMap::new(),
+        });
+    codegen.traits.insert(types::TraitType::Default.to_string(), types::TraitSignature {
+            nargs: Some(0), trait_sig: None, name: String::from("default"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
+            implementations: std::collections::HashMap::new(),
+        });
+    codegen.traits.insert(types::TraitType::Hash.to_string(), types::TraitSignature {
+            nargs: Some(1), trait_sig: None, name: String::from("hash"), traittp: types::TraitMetatype::Builtin, vars: None, supertraits: None, consts: None, types: None,
             implementations: std::collections::HashMap::new(),
         });
 }
@@ -168,6 +402,25 @@ pub fn init_structs(codegen: &mut codegen::CodeGen) {
     structs::stringtype::init_string(codegen);
 }
 
+//ToString and Hash of the simple types return a String and a u64, which do not exist when the simple types are initialized
+pub fn init_value_traits(codegen: &mut codegen::CodeGen) {
+    let stringtp: DataType = codegen::CodeGen::datatypes_get(codegen, &String::from("String")).unwrap().clone();
+    let u64tp: DataType = codegen::CodeGen::datatypes_get(codegen, &BasicDataType::U64.to_string()).unwrap().clone();
+
+    for basictype in [BasicDataType::I8, BasicDataType::U8, BasicDataType::I16, BasicDataType::U16, BasicDataType::I32, BasicDataType::U32, BasicDataType::I64, BasicDataType::U64, BasicDataType::I128, BasicDataType::U128, BasicDataType::F32, BasicDataType::F64, BasicDataType::Bool, BasicDataType::Char] {
+        let name: String = basictype.to_string();
+
+        let tp: &mut DataType = codegen.datatypes.get_mut(&name).unwrap();
+        let method: &mut types::Method = tp.methods.get_mut("to_string").unwrap();
+        method.functp.rettp = Some(Box::new(stringtp.clone()));
+        let to_string = method.builtin.unwrap();
+
+        let traits: &mut HashMap<String, Trait> = &mut codegen.cur_module.types.get_mut(&name).unwrap().traits;
+        traits.insert(TraitType::ToString.to_string(), create_trait_func(to_string, 1, TraitType::ToString, stringtp.clone()));
+        traits.insert(TraitType::Hash.to_string(), create_trait_func(value_hash, 1, TraitType::Hash, u64tp.clone()));
+    }
+}
+
 pub fn init_enums(codegen: &mut codegen::CodeGen) {
     enums::optionaltype::init_optional(codegen);
     enums::resulttype::init_result(codegen);
@@ -1,50 +1,116 @@
 use crate::codegen::{self, CodeGen};
-use crate::codegen::builtin_types::enums;
+use crate::codegen::builtin_types::{self, enums};
 use std::collections::HashMap;
 use crate::codegen::types::*;
 use crate::errors;
 
-fn string_length<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &crate::parser::Position) -> Data<'a> {
-    if args.len()!=1 {
-        let fmt: String = format!("Expected 1 argument, got {}.", args.len());
-        errors::raise_error(&fmt, errors::ErrorType::ArgumentCountMismatch, pos, codegen.info);
+//Get a function of the C library, declaring it if it is not yet declared
+pub fn get_libc_function<'a>(codegen: &mut codegen::CodeGen<'a>, name: &str, fn_type: inkwell::types::FunctionType<'a>) -> inkwell::values::FunctionValue<'a> {
+    match codegen.module.get_function(name) {
+        Some(v) => v,
+        None => codegen.module.add_function(name, fn_type, Some(inkwell::module::Linkage::External)),
     }
-    
-    let itmptr: inkwell::values::PointerValue = codegen.builder.build_struct_gep(args.get(0).unwrap().data.unwrap().into_pointer_value(), 0 as u32, "arr").expect("GEP Error");
-    let arr: inkwell::values::ArrayValue = codegen.builder.build_load(itmptr, "arr").into_array_value();
+}
 
-    let len: u32 = arr.get_type().len();
+//Build a String from a pointer to NUL terminated data and its length in bytes
+pub fn string_from_parts<'a>(codegen: &mut codegen::CodeGen<'a>, data: inkwell::values::PointerValue<'a>, len: inkwell::values::IntValue<'a>) -> Data<'a> {
+    let st: inkwell::values::StructValue = codegen.inkwell_types.stringtp.get_undef();
+    let st: inkwell::values::StructValue = codegen.builder.build_insert_value(st, data, 0, "str_data").unwrap().into_struct_value();
+    let st: inkwell::values::StructValue = codegen.builder.build_insert_value(st, len, 1, "str_len").unwrap().into_struct_value();
 
     return Data {
-        data: Some(inkwell::values::BasicValueEnum::IntValue(if std::mem::size_of::<usize>() == std::mem::size_of::<u32>() {
-            codegen.inkwell_types.i32tp.const_int(len.into(), false)
-        }
-        else {
-            codegen.inkwell_types.i64tp.const_int(len.into(), false)
-        })),
-        tp: crate::codegen::CodeGen::datatypes_get(codegen, "usize").unwrap().clone(),
+        data: Some(inkwell::values::BasicValueEnum::StructValue(st)),
+        tp: CodeGen::datatypes_get(codegen, &String::from("String")).unwrap().clone(),
         owned: true,
     };
 }
 
-fn string_get_array<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &crate::parser::Position) -> Data<'a> {
+//Build a String of constant text. The text is copied to the heap, as a String may be written by index.
+pub fn string_from_str<'a>(codegen: &mut codegen::CodeGen<'a>, text: &str) -> Data<'a> {
+    let ptr: inkwell::values::PointerValue = codegen.builder.build_global_string_ptr(text, "str").as_pointer_value();
+    let len: inkwell::values::IntValue = codegen.inkwell_types.i64tp.const_int(text.len() as u64, false);
+    let text: Data = string_from_parts(codegen, ptr, len);
+    return string_concat(codegen, &vec![text.data.unwrap().into_struct_value()]);
+}
+
+//Build a String with snprintf
+pub fn string_format<'a>(codegen: &mut codegen::CodeGen<'a>, format: &str, args: Vec<inkwell::values::BasicMetadataValueEnum<'a>>) -> Data<'a> {
+    let format_ptr: inkwell::values::PointerValue = codegen.builder.build_global_string_ptr(format, "format").as_pointer_value();
+    return string_format_ptr(codegen, format_ptr, args);
+}
+
+//Build a String with snprintf and a format selected at runtime. The length is measured with a first call, then the data is allocated and written.
+pub fn string_format_ptr<'a>(codegen: &mut codegen::CodeGen<'a>, format_ptr: inkwell::values::PointerValue<'a>, args: Vec<inkwell::values::BasicMetadataValueEnum<'a>>) -> Data<'a> {
+    let i8ptrtp: inkwell::types::PointerType = codegen.inkwell_types.i8tp.ptr_type(inkwell::AddressSpace::from(0u16));
+
+    //int snprintf(char* str, size_t size, char* format, ...)
+    let snprintf_tp: inkwell::types::FunctionType = codegen.inkwell_types.i32tp.fn_type(&[inkwell::types::BasicMetadataTypeEnum::PointerType(i8ptrtp), inkwell::types::BasicMetadataTypeEnum::IntType(*codegen.inkwell_types.i64tp), inkwell::types::BasicMetadataTypeEnum::PointerType(i8ptrtp)], true);
+    let snprintf: inkwell::values::FunctionValue = get_libc_function(codegen, "snprintf", snprintf_tp);
+
+    let mut measure_args: Vec<inkwell::values::BasicMetadataValueEnum> = vec![i8ptrtp.const_null().into(), codegen.inkwell_types.i64tp.const_zero().into(), format_ptr.into()];
+    measure_args.extend(args.iter().cloned());
+    let len: inkwell::values::IntValue = codegen.builder.build_call(snprintf, &measure_args[..], "str_len").try_as_basic_value().left().unwrap().into_int_value();
+    let len: inkwell::values::IntValue = codegen.builder.build_int_z_extend(len, *codegen.inkwell_types.i64tp, "str_len");
+    let size: inkwell::values::IntValue = codegen.builder.build_int_add(len, codegen.inkwell_types.i64tp.const_int(1, false), "str_size");
+
+    let buf: inkwell::values::PointerValue = codegen.builder.build_array_malloc(*codegen.inkwell_types.i8tp, size, "str_buf").expect("Malloc error");
+
+    let mut write_args: Vec<inkwell::values::BasicMetadataValueEnum> = vec![buf.into(), size.into(), format_ptr.into()];
+    write_args.extend(args.iter().cloned());
+    codegen.builder.build_call(snprintf, &write_args[..], "snprintf_call");
+
+    return string_from_parts(codegen, buf, len);
+}
+
+//Build a String of the given Strings joined in order
+pub fn string_concat<'a>(codegen: &mut codegen::CodeGen<'a>, parts: &Vec<inkwell::values::StructValue<'a>>) -> Data<'a> {
+    let i64tp: inkwell::types::IntType = *codegen.inkwell_types.i64tp;
+
+    let mut lens: Vec<inkwell::values::IntValue> = Vec::new();
+    let mut total: inkwell::values::IntValue = i64tp.const_zero();
+    for part in parts {
+        let len: inkwell::values::IntValue = codegen.builder.build_extract_value(*part, 1, "part_len").unwrap().into_int_value();
+        total = codegen.builder.build_int_add(total, len, "total_len");
+        lens.push(len);
+    }
+
+    let size: inkwell::values::IntValue = codegen.builder.build_int_add(total, i64tp.const_int(1, false), "str_size");
+    let buf: inkwell::values::PointerValue = codegen.builder.build_array_malloc(*codegen.inkwell_types.i8tp, size, "str_buf").expect("Malloc error");
+
+    let mut offset: inkwell::values::IntValue = i64tp.const_zero();
+    for (part, len) in std::iter::zip(parts, &lens) {
+        let src: inkwell::values::PointerValue = codegen.builder.build_extract_value(*part, 0, "part_data").unwrap().into_pointer_value();
+        let dest: inkwell::values::PointerValue = unsafe { codegen.builder.build_in_bounds_gep(buf, &[offset], "dest") };
+        codegen.builder.build_memcpy(dest, 1, src, 1, *len).expect("Memcpy error");
+        offset = codegen.builder.build_int_add(offset, *len, "offset");
+    }
+
+    let end: inkwell::values::PointerValue = unsafe { codegen.builder.build_in_bounds_gep(buf, &[total], "end") };
+    codegen.builder.build_store(end, codegen.inkwell_types.i8tp.const_zero());
+
+    return string_from_parts(codegen, buf, total);
+}
+
+//Get the length of a String as a usize
+fn string_usize<'a>(codegen: &mut codegen::CodeGen<'a>, len: inkwell::values::IntValue<'a>) -> inkwell::values::IntValue<'a> {
+    if std::mem::size_of::<usize>() == std::mem::size_of::<u32>() {
+        return codegen.builder.build_int_truncate(len, *codegen.inkwell_types.i32tp, "len");
+    }
+    return len;
+}
+
+fn string_length<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos: &crate::parser::Position) -> Data<'a> {
     if args.len()!=1 {
         let fmt: String = format!("Expected 1 argument, got {}.", args.len());
         errors::raise_error(&fmt, errors::ErrorType::ArgumentCountMismatch, pos, codegen.info);
     }
-    
-    let itmptr: inkwell::values::PointerValue = codegen.builder.build_struct_gep(args.get(0).unwrap().data.unwrap().into_pointer_value(), 0 as u32, "arr").expect("GEP Error");
-    let arr: inkwell::values::ArrayValue = codegen.builder.build_load(itmptr, "arr").into_array_value();
 
-    let mut tp: DataType = crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::Array.to_string()).unwrap().clone();
-    tp.name = codegen::CodeGen::array_repr(arr.get_type());
-    tp.arrtp = Some(arr.get_type());
-    tp.types = vec![crate::codegen::CodeGen::datatypes_get(codegen, &crate::codegen::types::BasicDataType::U8.to_string()).unwrap().clone()];
+    let len: inkwell::values::IntValue = codegen.builder.build_extract_value(args.get(0).unwrap().data.unwrap().into_struct_value(), 1, "len").unwrap().into_int_value();
 
     return Data {
-        data: Some(inkwell::values::BasicValueEnum::ArrayValue(arr)),
-        tp,
-        owned: false,
+        data: Some(inkwell::values::BasicValueEnum::IntValue(string_usize(codegen, len))),
+        tp: crate::codegen::CodeGen::datatypes_get(codegen, "usize").unwrap().clone(),
+        owned: true,
     };
 }
 
@@ -52,7 +118,7 @@ fn string_get<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos:
     if args.len()!=2 {
         let fmt: String = format!("Expected 2 arguments, got {}.", args.len());
         errors::raise_error(&fmt, errors::ErrorType::ArgumentCountMismatch, pos, codegen.info);
-    } 
+    }
 
     if args.get(1).unwrap().tp != crate::codegen::CodeGen::datatypes_get(codegen, &String::from("usize")).unwrap() {
         let fmt: String = format!("Invalid types for String.get, expected 'usize', got '{}'.", args.get(1).unwrap().tp);
@@ -62,44 +128,37 @@ fn string_get<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos:
     let mut opt: DataType = crate::codegen::CodeGen::datatypes_get(codegen, &String::from("Optional")).unwrap().clone();
     opt.types = vec![crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::U8.to_string()).unwrap().clone(), crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::I32.to_string()).unwrap().clone()];
 
-    let ptr: inkwell::values::PointerValue = codegen.builder.build_struct_gep(args.get(0).unwrap().data.unwrap().into_pointer_value(), 0 as u32, "arr").expect("GEP Error");
-    let arr: inkwell::values::ArrayValue = codegen.builder.build_load(ptr, "load_arr").into_array_value();
-
-    let len: u32 = arr.get_type().len();
+    let st: inkwell::values::StructValue = args.get(0).unwrap().data.unwrap().into_struct_value();
+    let data: inkwell::values::PointerValue = codegen.builder.build_extract_value(st, 0, "data").unwrap().into_pointer_value();
+    let len: inkwell::values::IntValue = codegen.builder.build_extract_value(st, 1, "len").unwrap().into_int_value();
+    let len: inkwell::values::IntValue = string_usize(codegen, len);
 
     let end_block: inkwell::basic_block::BasicBlock = codegen.context.append_basic_block(codegen.current_block.unwrap().get_parent().unwrap(), "end");
     let then_block: inkwell::basic_block::BasicBlock = codegen.context.append_basic_block(codegen.current_block.unwrap().get_parent().unwrap(), "then");
     let else_block: inkwell::basic_block::BasicBlock = codegen.context.append_basic_block(codegen.current_block.unwrap().get_parent().unwrap(), "else");
 
-    let lhs: inkwell::values::IntValue = if std::mem::size_of::<usize>() == std::mem::size_of::<u32>() {
-        codegen.inkwell_types.i32tp.const_int(len.into(), false)
-    }
-    else {
-        codegen.inkwell_types.i64tp.const_int(len.into(), false)
-    };
-
-    codegen.builder.build_conditional_branch(codegen.builder.build_int_compare(inkwell::IntPredicate::UGT, lhs, args.get(1).unwrap().data.unwrap().into_int_value(), "size_check"), then_block, else_block);
+    codegen.builder.build_conditional_branch(codegen.builder.build_int_compare(inkwell::IntPredicate::UGT, len, args.get(1).unwrap().data.unwrap().into_int_value(), "size_check"), then_block, else_block);
 
     //
-    
+
     codegen.builder.position_at_end(then_block);
     codegen.current_block = Some(then_block);
 
-    let itmptr: inkwell::values::PointerValue = unsafe { codegen.builder.build_in_bounds_gep(ptr, &[codegen.inkwell_types.i32tp.const_zero(), args.get(1).unwrap().data.unwrap().into_int_value()], "itmptr") };
-    
+    let itmptr: inkwell::values::PointerValue = unsafe { codegen.builder.build_in_bounds_gep(data, &[args.get(1).unwrap().data.unwrap().into_int_value()], "itmptr") };
+
     let itm: inkwell::values::IntValue = codegen.builder.build_load(itmptr, "item").into_int_value();
 
     let res_some: Data = enums::optionaltype::optional_some(codegen, Some(inkwell::values::BasicValueEnum::IntValue(itm)), opt.types.clone());
 
     codegen.builder.build_unconditional_branch(end_block);
-    
+
     //
 
     codegen.builder.position_at_end(else_block);
     codegen.current_block = Some(else_block);
 
     let res_none: Data = enums::optionaltype::optional_none(codegen, opt.types.clone());
-    
+
     codegen.builder.build_unconditional_branch(end_block);
 
     //
@@ -108,9 +167,6 @@ fn string_get<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos:
     codegen.builder.position_at_end(end_block);
     codegen.current_block = Some(end_block);
 
-    codegen.current_block = Some(end_block);
-
-
     let mut types: Vec<DataType> = opt.types.clone();
     types.insert(0, crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::I32.to_string()).unwrap().clone());
 
@@ -144,35 +200,95 @@ fn string_new<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, pos:
         errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, pos, codegen.info);
     }
 
-    let struct_tp: inkwell::types::StructType = codegen.context.struct_type(&[data.data.unwrap().get_type()], false);
+    //The array is copied to the heap. A string literal holds its NUL terminator after the elements of its type.
+    let arr: inkwell::values::ArrayValue = data.data.unwrap().into_array_value();
+    let len: inkwell::values::IntValue = codegen.inkwell_types.i64tp.const_int(data.tp.arrtp.unwrap().len() as u64, false);
+    let size: inkwell::values::IntValue = codegen.inkwell_types.i64tp.const_int(data.tp.arrtp.unwrap().len() as u64 + 1, false);
+
+    let buf: inkwell::values::PointerValue = codegen.builder.build_array_malloc(*codegen.inkwell_types.i8tp, size, "str_buf").expect("Malloc error");
+    let arrptr: inkwell::values::PointerValue = codegen.builder.build_pointer_cast(buf, arr.get_type().ptr_type(inkwell::AddressSpace::from(0u16)), "arr");
+    codegen.builder.build_store(arrptr, arr);
 
-    let ptr: inkwell::values::PointerValue = CodeGen::alloca(codegen, struct_tp, "String");
+    let end: inkwell::values::PointerValue = unsafe { codegen.builder.build_in_bounds_gep(buf, &[len], "end") };
+    codegen.builder.build_store(end, codegen.inkwell_types.i8tp.const_zero());
 
-    let itmptr: inkwell::values::PointerValue = codegen.builder.build_struct_gep(ptr, 0 as u32, "arr").expect("GEP Error");
-    codegen.builder.build_store(itmptr, data.data.unwrap());
-    
-    let data: Data = Data {
-        data: Some(codegen.builder.build_load(ptr, "string")),
-        tp: crate::codegen::CodeGen::datatypes_get(codegen, &String::from("String")).unwrap().clone(),
+    return string_from_parts(codegen, buf, len);
+}
+
+fn string_to_string<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, _pos: &crate::parser::Position) -> Data<'a> {
+    return Data {
+        data: args.get(0).unwrap().data,
+        tp: CodeGen::datatypes_get(codegen, &String::from("String")).unwrap().clone(),
+        owned: true,
+    };
+}
+
+fn string_clone<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, _pos: &crate::parser::Position) -> Data<'a> {
+    return string_concat(codegen, &vec![args.get(0).unwrap().data.unwrap().into_struct_value()]);
+}
+
+fn string_default<'a>(codegen: &mut codegen::CodeGen<'a>, _args: Vec<Data<'a>>, _pos: &crate::parser::Position) -> Data<'a> {
+    return string_from_str(codegen, "");
+}
+
+//FNV-1a over the bytes of the String
+fn string_hash<'a>(codegen: &mut codegen::CodeGen<'a>, args: Vec<Data<'a>>, _pos: &crate::parser::Position) -> Data<'a> {
+    let i64tp: inkwell::types::IntType = *codegen.inkwell_types.i64tp;
+
+    let st: inkwell::values::StructValue = args.get(0).unwrap().data.unwrap().into_struct_value();
+    let data: inkwell::values::PointerValue = codegen.builder.build_extract_value(st, 0, "data").unwrap().into_pointer_value();
+    let len: inkwell::values::IntValue = codegen.builder.build_extract_value(st, 1, "len").unwrap().into_int_value();
+
+    let start_block: inkwell::basic_block::BasicBlock = codegen.builder.get_insert_block().unwrap();
+    let loop_block: inkwell::basic_block::BasicBlock = codegen.context.append_basic_block(codegen.current_block.unwrap().get_parent().unwrap(), "hash_loop");
+    let body_block: inkwell::basic_block::BasicBlock = codegen.context.append_basic_block(codegen.current_block.unwrap().get_parent().unwrap(), "hash_body");
+    let end_block: inkwell::basic_block::BasicBlock = codegen.context.append_basic_block(codegen.current_block.unwrap().get_parent().unwrap(), "hash_end");
+
+    codegen.builder.build_unconditional_branch(loop_block);
+
+    codegen.builder.position_at_end(loop_block);
+    let idx: inkwell::values::PhiValue = codegen.builder.build_phi(i64tp, "idx");
+    let hash: inkwell::values::PhiValue = codegen.builder.build_phi(i64tp, "hash");
+    let done: inkwell::values::IntValue = codegen.builder.build_int_compare(inkwell::IntPredicate::UGE, idx.as_basic_value().into_int_value(), len, "done");
+    codegen.builder.build_conditional_branch(done, end_block, body_block);
+
+    codegen.builder.position_at_end(body_block);
+    let itmptr: inkwell::values::PointerValue = unsafe { codegen.builder.build_in_bounds_gep(data, &[idx.as_basic_value().into_int_value()], "itmptr") };
+    let byte: inkwell::values::IntValue = codegen.builder.build_load(itmptr, "byte").into_int_value();
+    let byte: inkwell::values::IntValue = codegen.builder.build_int_z_extend(byte, i64tp, "byte");
+    let next: inkwell::values::IntValue = codegen.builder.build_xor(hash.as_basic_value().into_int_value(), byte, "hash_xor");
+    let next: inkwell::values::IntValue = codegen.builder.build_int_mul(next, i64tp.const_int(0x100000001b3, false), "hash_mul");
+    let next_idx: inkwell::values::IntValue = codegen.builder.build_int_add(idx.as_basic_value().into_int_value(), i64tp.const_int(1, false), "next_idx");
+    codegen.builder.build_unconditional_branch(loop_block);
+
+    idx.add_incoming(&[(&i64tp.const_zero(), start_block), (&next_idx, body_block)]);
+    hash.add_incoming(&[(&i64tp.const_int(0xcbf29ce484222325, false), start_block), (&next, body_block)]);
+
+    codegen.builder.position_at_end(end_block);
+    codegen.current_block = Some(end_block);
+
+    return Data {
+        data: Some(hash.as_basic_value()),
+        tp: CodeGen::datatypes_get(codegen, &BasicDataType::U64.to_string()).unwrap().clone(),
         owned: true,
     };
-    return data;
 }
 
 pub fn init_string(codegen: &mut codegen::CodeGen) {
     let mut methods: HashMap<String, Method> = HashMap::new();
 
+    //The data and length are not fields, so that a String is only built by its methods
     let mut tp: DataType = crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::Struct.to_string()).unwrap().clone();
     tp.name = String::from("String");
-    tp.names = Some(vec![String::from("arr")]);
-    tp.types = vec![crate::codegen::CodeGen::datatypes_get(codegen, &String::from("char")).unwrap().clone()];
-    tp.mutability = vec![DataMutablility::Immutable];
+    tp.names = Some(vec![]);
+    tp.types = vec![];
+    tp.mutability = vec![];
 
     //length()
     let mut lengthfntp: DataType = crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::WrapperFunc.to_string()).unwrap().clone();
     lengthfntp.names = Some(vec![String::from("self")]);
     lengthfntp.rettp = Some(Box::new(crate::codegen::CodeGen::datatypes_get(codegen, "usize").unwrap().clone()));
-    lengthfntp.types = vec![crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::Array.to_string()).unwrap().clone()];
+    lengthfntp.types = vec![tp.clone()];
     lengthfntp.wrapperfn = Some(string_length);
 
     methods.insert(String::from("length"), Method {
@@ -186,29 +302,11 @@ pub fn init_string(codegen: &mut codegen::CodeGen) {
     });
     //
 
-    //get_array()
-    let mut getarrfntp: DataType = crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::WrapperFunc.to_string()).unwrap().clone();
-    getarrfntp.names = Some(vec![String::from("self")]);
-    getarrfntp.rettp = Some(Box::new(crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::Array.to_string()).unwrap().clone()));
-    getarrfntp.types = vec![crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::Array.to_string()).unwrap().clone()];
-    getarrfntp.wrapperfn = Some(string_get_array);
-
-    methods.insert(String::from("get_array"), Method {
-        tp: MethodType::Builtin,
-        builtin: Some(string_get_array),
-        func: None,
-        functp: getarrfntp,
-        isinstance: true,
-        isinstanceptr: false,
-        ismutinstanceptr: false,
-    });
-    //
-
     //get()
     let mut getfntp: DataType = crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::WrapperFunc.to_string()).unwrap().clone();
     getfntp.names = Some(vec![String::from("self"), String::from("index")]);
     getfntp.rettp = Some(Box::new(crate::codegen::CodeGen::datatypes_get(codegen, &crate::codegen::types::BasicDataType::U8.to_string()).unwrap().clone()));
-    getfntp.types = vec![crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::Array.to_string()).unwrap().clone(), crate::codegen::CodeGen::datatypes_get(codegen, &String::from("usize")).unwrap().clone()];
+    getfntp.types = vec![tp.clone(), crate::codegen::CodeGen::datatypes_get(codegen, &String::from("usize")).unwrap().clone()];
     getfntp.wrapperfn = Some(string_get);
 
     methods.insert(String::from("get"), Method {
@@ -241,13 +339,18 @@ pub fn init_string(codegen: &mut codegen::CodeGen) {
     });
     //
 
-    let mut idxmapping: HashMap<String, i32> = HashMap::new();
-    idxmapping.insert(String::from("arr"), 0);
-
     tp.methods = methods;
 
     codegen.cur_module.datatypes.insert(String::from("String"), tp.clone());
-    codegen.cur_module.namespaces.structs.insert(String::from("String"), (tp, None, idxmapping, codegen::ForwardDeclarationType::Real));
-    codegen::builtin_types::add_simple_type(codegen, std::collections::HashMap::new(), BasicDataType::Struct, &String::from("String"));
+    codegen.cur_module.namespaces.structs.insert(String::from("String"), (tp.clone(), Some(inkwell::types::AnyTypeEnum::StructType(*codegen.inkwell_types.stringtp)), HashMap::new(), codegen::ForwardDeclarationType::Real));
+
+    let u64tp: DataType = crate::codegen::CodeGen::datatypes_get(codegen, &BasicDataType::U64.to_string()).unwrap().clone();
+
+    let mut traits: HashMap<String, Trait> = HashMap::new();
+    traits.insert(TraitType::ToString.to_string(), builtin_types::create_trait_func(string_to_string, 1, TraitType::ToString, tp.clone()));
+    traits.insert(TraitType::Clone.to_string(), builtin_types::create_trait_func(string_clone, 1, TraitType::Clone, tp.clone()));
+    traits.insert(TraitType::Default.to_string(), builtin_types::create_trait_func(string_default, 0, TraitType::Default, tp.clone()));
+    traits.insert(TraitType::Hash.to_string(), builtin_types::create_trait_func(string_hash, 1, TraitType::Hash, u64tp));
 
+    codegen::builtin_types::add_simple_type(codegen, traits, BasicDataType::Struct, &String::from("String"));
 }
\ No newline at end of file
@@ -0,0 +1,792 @@
+use crate::codegen::types::{BasicDataType, DataType, DataMutablility};
+use crate::codegen::{CodeGen, Namespaces};
+use crate::codegen::builtin_types::chartype;
+use crate::parser;
+use crate::errors;
+use crate::fileinfo;
+use std::collections::HashMap;
+
+//Maximum depth of nested const function calls
+pub const MAX_CALL_DEPTH: usize = 128;
+
+//A value computed at compile time
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConstValue {
+    Int(i128, BasicDataType),
+    UInt(u128, BasicDataType),
+    Float(f64, BasicDataType),
+    Bool(bool),
+    Str(String),
+    Void,
+}
+
+impl ConstValue {
+    pub fn tp_name(&self) -> String {
+        match self {
+            ConstValue::Int(_, tp) |
+            ConstValue::UInt(_, tp) |
+            ConstValue::Float(_, tp) => tp.to_string(),
+            ConstValue::Bool(_) => BasicDataType::Bool.to_string(),
+            ConstValue::Str(_) => String::from("str"),
+            ConstValue::Void => BasicDataType::Void.to_string(),
+        }
+    }
+
+    fn basic_tp(&self) -> Option<BasicDataType> {
+        match self {
+            ConstValue::Int(_, tp) |
+            ConstValue::UInt(_, tp) |
+            ConstValue::Float(_, tp) => Some(tp.clone()),
+            ConstValue::Bool(_) => Some(BasicDataType::Bool),
+            ConstValue::Str(_) => None,
+            ConstValue::Void => Some(BasicDataType::Void),
+        }
+    }
+}
+
+impl std::fmt::Display for ConstValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConstValue::Int(v, _) => write!(f, "{}", v),
+            ConstValue::UInt(v, _) => write!(f, "{}", v),
+            ConstValue::Float(v, _) => write!(f, "{}", v),
+            ConstValue::Bool(v) => write!(f, "{}", v),
+            ConstValue::Str(v) => write!(f, "\"{}\"", v),
+            ConstValue::Void => write!(f, "void"),
+        }
+    }
+}
+
+fn is_signed(tp: &BasicDataType) -> bool {
+    *tp == BasicDataType::I8 || *tp == BasicDataType::I16 || *tp == BasicDataType::I32 || *tp == BasicDataType::I64 || *tp == BasicDataType::I128
+}
+
+fn is_unsigned(tp: &BasicDataType) -> bool {
+    *tp == BasicDataType::U8 || *tp == BasicDataType::U16 || *tp == BasicDataType::U32 || *tp == BasicDataType::U64 || *tp == BasicDataType::U128
+}
+
+fn is_float(tp: &BasicDataType) -> bool {
+    *tp == BasicDataType::F32 || *tp == BasicDataType::F64
+}
+
+fn bits(tp: &BasicDataType) -> u32 {
+    match tp {
+        BasicDataType::I8 | BasicDataType::U8 => 8,
+        BasicDataType::I16 | BasicDataType::U16 => 16,
+        BasicDataType::I32 | BasicDataType::U32 => 32,
+        BasicDataType::I64 | BasicDataType::U64 => 64,
+        _ => 128,
+    }
+}
+
+fn signed_range(tp: &BasicDataType) -> (i128, i128) {
+    let bits: u32 = bits(tp);
+    if bits == 128 {
+        return (i128::MIN, i128::MAX);
+    }
+    return (-(1i128 << (bits-1)), (1i128 << (bits-1)) - 1);
+}
+
+fn unsigned_max(tp: &BasicDataType) -> u128 {
+    let bits: u32 = bits(tp);
+    if bits == 128 {
+        return u128::MAX;
+    }
+    return (1u128 << bits) - 1;
+}
+
+//Truncate to the width of the type, like a runtime cast
+fn wrap_signed(v: i128, tp: &BasicDataType) -> i128 {
+    let shift: u32 = 128 - bits(tp);
+    return (v << shift) >> shift;
+}
+
+fn wrap_unsigned(v: u128, tp: &BasicDataType) -> u128 {
+    return v & unsigned_max(tp);
+}
+
+fn op_verb(op: parser::nodes::BinaryOpType) -> &'static str {
+    match op {
+        parser::nodes::BinaryOpType::ADD => "add",
+        parser::nodes::BinaryOpType::SUB => "subtract",
+        parser::nodes::BinaryOpType::MUL => "multiply",
+        parser::nodes::BinaryOpType::DIV => "divide",
+        parser::nodes::BinaryOpType::SHL => "shift left",
+        parser::nodes::BinaryOpType::SHR => "shift right",
+        _ => "evaluate",
+    }
+}
+
+//Evaluates constant expressions and calls to const functions over the AST
+pub struct ConstEvaluator<'a, 'ctx> {
+    namespaces: &'a Namespaces<'ctx>,
+    datatypes: &'a HashMap<String, DataType<'ctx>>,
+    cur_datatypes: &'a HashMap<String, DataType<'ctx>>,
+    info: &'a fileinfo::FileInfo<'a>,
+    locals: Vec<HashMap<String, (ConstValue, DataMutablility)>>,
+    retv: Option<ConstValue>,
+    rettp: Option<BasicDataType>,
+    depth: usize,
+}
+
+impl<'a, 'ctx> ConstEvaluator<'a, 'ctx> {
+    pub fn new(namespaces: &'a Namespaces<'ctx>, datatypes: &'a HashMap<String, DataType<'ctx>>, cur_datatypes: &'a HashMap<String, DataType<'ctx>>, info: &'a fileinfo::FileInfo<'a>) -> ConstEvaluator<'a, 'ctx> {
+        return ConstEvaluator {
+            namespaces,
+            datatypes,
+            cur_datatypes,
+            info,
+            locals: vec![HashMap::new()],
+            retv: None,
+            rettp: None,
+            depth: 0,
+        };
+    }
+
+    //Evaluate an expression of an expected type, so that an untyped literal takes that type
+    pub fn eval_expecting(&mut self, node: &parser::Node, tp: &BasicDataType) -> ConstValue {
+        let literal: Option<parser::Node> = CodeGen::type_literal(node, tp);
+        if literal.is_some() {
+            return self.eval(literal.as_ref().unwrap());
+        }
+        return self.eval(node);
+    }
+
+    pub fn eval(&mut self, node: &parser::Node) -> ConstValue {
+        match node.tp {
+            parser::NodeType::I32 => self.eval_int(node, BasicDataType::I32),
+            parser::NodeType::U32 => self.eval_int(node, BasicDataType::U32),
+            parser::NodeType::I8 => self.eval_int(node, BasicDataType::I8),
+            parser::NodeType::U8 => self.eval_int(node, BasicDataType::U8),
+            parser::NodeType::I16 => self.eval_int(node, BasicDataType::I16),
+            parser::NodeType::U16 => self.eval_int(node, BasicDataType::U16),
+            parser::NodeType::I64 => self.eval_int(node, BasicDataType::I64),
+            parser::NodeType::U64 => self.eval_int(node, BasicDataType::U64),
+            parser::NodeType::I128 => self.eval_int(node, BasicDataType::I128),
+            parser::NodeType::U128 => self.eval_int(node, BasicDataType::U128),
+            parser::NodeType::F32 => {
+                let v: f32 = node.data.num.as_ref().unwrap().left.parse::<f32>().unwrap_or(f32::INFINITY);
+                ConstValue::Float(v as f64, BasicDataType::F32)
+            }
+            parser::NodeType::F64 => {
+                let v: f64 = node.data.num.as_ref().unwrap().left.parse::<f64>().unwrap_or(f64::INFINITY);
+                ConstValue::Float(v, BasicDataType::F64)
+            }
+            parser::NodeType::CHAR => {
+                ConstValue::UInt(node.data.num.as_ref().unwrap().left.chars().next().unwrap() as u128, BasicDataType::Char)
+            }
+            parser::NodeType::BOOL => {
+                ConstValue::Bool(node.data.num.as_ref().unwrap().left == "true")
+            }
+            parser::NodeType::STRING => {
+                ConstValue::Str(node.data.str.as_ref().unwrap().data.clone())
+            }
+            parser::NodeType::VOID => {
+                ConstValue::Void
+            }
+            parser::NodeType::IDENTIFIER => {
+                self.eval_name(node)
+            }
+            parser::NodeType::UNARY => {
+                self.eval_unary(node)
+            }
+            parser::NodeType::BINARY => {
+                self.eval_binary(node)
+            }
+            parser::NodeType::AS => {
+                let value: ConstValue = self.eval(&node.data.to.as_ref().unwrap().left);
+                let tp: BasicDataType = self.get_tp(&node.data.to.as_ref().unwrap().tp, node);
+                self.cast(value, tp, node)
+            }
+            parser::NodeType::IF => {
+                self.eval_if(node)
+            }
+            parser::NodeType::CALL => {
+                self.eval_call(node)
+            }
+            parser::NodeType::LET => {
+                self.eval_let(node)
+            }
+            parser::NodeType::ASSIGN => {
+                let value: ConstValue = match self.local_tp(&node.data.assign.as_ref().unwrap().name) {
+                    Some(tp) => self.eval_expecting(&node.data.assign.as_ref().unwrap().expr, &tp),
+                    None => self.eval(&node.data.assign.as_ref().unwrap().expr),
+                };
+                self.assign(&node.data.assign.as_ref().unwrap().name, value, node);
+                ConstValue::Void
+            }
+            parser::NodeType::RETURN => {
+                let value: ConstValue = if node.data.ret.as_ref().unwrap().expr.is_some() && self.rettp.is_some() {
+                    let rettp: BasicDataType = self.rettp.clone().unwrap();
+                    self.eval_expecting(node.data.ret.as_ref().unwrap().expr.as_ref().unwrap(), &rettp)
+                }
+                else if node.data.ret.as_ref().unwrap().expr.is_some() {
+                    self.eval(node.data.ret.as_ref().unwrap().expr.as_ref().unwrap())
+                }
+                else {
+                    ConstValue::Void
+                };
+                self.retv = Some(value);
+                ConstValue::Void
+            }
+            _ => {
+                let fmt: String = format!("Expression cannot be evaluated at compile time.");
+                errors::raise_error(&fmt, errors::ErrorType::NonConstantExpression, &node.pos, self.info);
+            }
+        }
+    }
+
+    fn eval_int(&mut self, node: &parser::Node, tp: BasicDataType) -> ConstValue {
+        let data: &String = &node.data.num.as_ref().unwrap().left;
+        if is_signed(&tp) {
+            let v: Option<i128> = data.parse::<i128>().ok();
+            let (min, max) = signed_range(&tp);
+            if v.is_none() || v.unwrap() < min || v.unwrap() > max {
+                let fmt: String = format!("Invalid {} literal '{}'.", tp, data);
+                errors::raise_error(&fmt, errors::ErrorType::InvalidLiteralForRadix, &node.pos, self.info);
+            }
+            return ConstValue::Int(v.unwrap(), tp);
+        }
+
+        let v: Option<u128> = data.parse::<u128>().ok();
+        if v.is_none() || v.unwrap() > unsigned_max(&tp) {
+            let fmt: String = format!("Invalid {} literal '{}'.", tp, data);
+            errors::raise_error(&fmt, errors::ErrorType::InvalidLiteralForRadix, &node.pos, self.info);
+        }
+        return ConstValue::UInt(v.unwrap(), tp);
+    }
+
+    fn eval_name(&mut self, node: &parser::Node) -> ConstValue {
+        let name: &String = &node.data.identifier.as_ref().unwrap().name;
+        for locals in self.locals.iter().rev() {
+            if locals.contains_key(name) {
+                return locals.get(name).unwrap().0.clone();
+            }
+        }
+
+        if self.namespaces.consts.contains_key(name) {
+            return self.namespaces.consts.get(name).unwrap().0.clone();
+        }
+
+        let fmt: String = format!("Name '{}' is not a constant.", name);
+        errors::raise_error(&fmt, errors::ErrorType::NonConstantExpression, &node.pos, self.info);
+    }
+
+    fn local_tp(&self, name: &String) -> Option<BasicDataType> {
+        for locals in self.locals.iter().rev() {
+            if locals.contains_key(name) {
+                return locals.get(name).unwrap().0.basic_tp();
+            }
+        }
+        return None;
+    }
+
+    fn assign(&mut self, name: &String, value: ConstValue, node: &parser::Node) {
+        for locals in self.locals.iter_mut().rev() {
+            if locals.contains_key(name) {
+                let (old, mutability) = locals.get(name).unwrap().clone();
+                if mutability == DataMutablility::Immutable {
+                    let fmt: String = format!("Cannot assign to immutable variable.");
+                    errors::raise_error(&fmt, errors::ErrorType::ImmutableAssign, &node.pos, self.info);
+                }
+                if old.basic_tp() != value.basic_tp() {
+                    let fmt: String = format!("Expected '{}' type, got '{}' type.", old.tp_name(), value.tp_name());
+                    errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
+                }
+                locals.insert(name.to_owned(), (value, mutability));
+                return;
+            }
+        }
+
+        let fmt: String = format!("Name '{}' is not a constant.", name);
+        errors::raise_error(&fmt, errors::ErrorType::NonConstantExpression, &node.pos, self.info);
+    }
+
+    fn eval_unary(&mut self, node: &parser::Node) -> ConstValue {
+        let unary: &parser::nodes::UnaryNode = node.data.unary.as_ref().unwrap();
+        if unary.op == parser::nodes::UnaryOpType::REF {
+            let fmt: String = format!("Expression cannot be evaluated at compile time.");
+            errors::raise_error(&fmt, errors::ErrorType::NonConstantExpression, &node.pos, self.info);
+        }
+
+        let value: ConstValue = self.eval(&unary.right);
+
+        if unary.op != parser::nodes::UnaryOpType::STMT && value.basic_tp() == Some(BasicDataType::Char) {
+            let fmt: String = format!("Invalid type for unary operation, got '{}'.", value.tp_name());
+            errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, &node.pos, self.info);
+        }
+
+        match (unary.op, value.clone()) {
+            (parser::nodes::UnaryOpType::STMT, _) => ConstValue::Void,
+            (parser::nodes::UnaryOpType::POS, ConstValue::Int(..)) |
+            (parser::nodes::UnaryOpType::POS, ConstValue::UInt(..)) |
+            (parser::nodes::UnaryOpType::POS, ConstValue::Float(..)) => value,
+            (parser::nodes::UnaryOpType::NEG, ConstValue::Int(v, tp)) => {
+                if v == signed_range(&tp).0 {
+                    self.raise_overflow("negate", node);
+                }
+                ConstValue::Int(-v, tp)
+            }
+            (parser::nodes::UnaryOpType::NEG, ConstValue::UInt(v, tp)) => {
+                if v != 0 {
+                    self.raise_overflow("negate", node);
+                }
+                ConstValue::UInt(v, tp)
+            }
+            (parser::nodes::UnaryOpType::NEG, ConstValue::Float(v, tp)) => ConstValue::Float(-v, tp),
+            (parser::nodes::UnaryOpType::NOT, ConstValue::Bool(v)) => ConstValue::Bool(!v),
+            (parser::nodes::UnaryOpType::BITNOT, ConstValue::Int(v, tp)) => ConstValue::Int(!v, tp),
+            (parser::nodes::UnaryOpType::BITNOT, ConstValue::UInt(v, tp)) => ConstValue::UInt(wrap_unsigned(!v, &tp), tp),
+            (parser::nodes::UnaryOpType::BITNOT, ConstValue::Bool(v)) => ConstValue::Bool(!v),
+            _ => {
+                let fmt: String = format!("Invalid type for unary operation, got '{}'.", value.tp_name());
+                errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, &node.pos, self.info);
+            }
+        }
+    }
+
+    fn eval_binary(&mut self, node: &parser::Node) -> ConstValue {
+        let binary: &parser::nodes::BinaryNode = node.data.binary.as_ref().unwrap();
+
+        if  binary.op == parser::nodes::BinaryOpType::AND ||
+            binary.op == parser::nodes::BinaryOpType::OR {
+            let left: bool = self.eval_bool(&binary.left);
+            if (binary.op == parser::nodes::BinaryOpType::AND) != left {
+                return ConstValue::Bool(left);
+            }
+            return ConstValue::Bool(self.eval_bool(&binary.right));
+        }
+
+        //An untyped literal takes the type of the other operand
+        let (left, right) = if !binary.isassign && CodeGen::is_untyped_literal(&binary.left) && !CodeGen::is_untyped_literal(&binary.right) {
+            let right: ConstValue = self.eval(&binary.right);
+            let left: ConstValue = match right.basic_tp() {
+                Some(tp) => self.eval_expecting(&binary.left, &tp),
+                None => self.eval(&binary.left),
+            };
+            (left, right)
+        }
+        else {
+            let left: ConstValue = self.eval(&binary.left);
+            let right: ConstValue = match left.basic_tp() {
+                Some(tp) => self.eval_expecting(&binary.right, &tp),
+                None => self.eval(&binary.right),
+            };
+            (left, right)
+        };
+        let res: ConstValue = self.binary(binary.op, left, right, node);
+
+        if binary.isassign {
+            if binary.left.tp != parser::NodeType::IDENTIFIER {
+                let fmt: String = format!("Expression cannot be evaluated at compile time.");
+                errors::raise_error(&fmt, errors::ErrorType::NonConstantExpression, &node.pos, self.info);
+            }
+            self.assign(&binary.left.data.identifier.as_ref().unwrap().name, res, node);
+            return ConstValue::Void;
+        }
+
+        return res;
+    }
+
+    fn binary(&self, op: parser::nodes::BinaryOpType, left: ConstValue, right: ConstValue, node: &parser::Node) -> ConstValue {
+        use parser::nodes::BinaryOpType;
+
+        if left.basic_tp().is_none() || left.basic_tp() != right.basic_tp() {
+            let fmt: String = format!("Invalid types for '{}', got '{}' and '{}'.", op, left.tp_name(), right.tp_name());
+            errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, &node.pos, self.info);
+        }
+
+        match (left.clone(), right) {
+            (ConstValue::UInt(l, BasicDataType::Char), ConstValue::UInt(r, _)) => {
+                match op {
+                    BinaryOpType::EQ |
+                    BinaryOpType::NE |
+                    BinaryOpType::GT |
+                    BinaryOpType::GTE |
+                    BinaryOpType::LT |
+                    BinaryOpType::LTE => self.compare(op, l.cmp(&r), &left, node),
+                    _ => {
+                        let fmt: String = format!("Invalid types for '{}', got 'char' and 'char'.", op);
+                        errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, &node.pos, self.info);
+                    }
+                }
+            }
+            (ConstValue::Int(l, tp), ConstValue::Int(r, _)) => {
+                let (min, max) = signed_range(&tp);
+                let res: Option<i128> = match op {
+                    BinaryOpType::ADD => l.checked_add(r),
+                    BinaryOpType::SUB => l.checked_sub(r),
+                    BinaryOpType::MUL => l.checked_mul(r),
+                    BinaryOpType::DIV => {
+                        if r == 0 {
+                            self.raise_division_by_zero(node);
+                        }
+                        l.checked_div(r)
+                    }
+                    BinaryOpType::BITAND => Some(l & r),
+                    BinaryOpType::BITOR => Some(l | r),
+                    BinaryOpType::BITXOR => Some(l ^ r),
+                    BinaryOpType::SHL => {
+                        if r < 0 || r >= bits(&tp) as i128 {
+                            self.raise_overflow(op_verb(op), node);
+                        }
+                        Some(wrap_signed(l << r, &tp))
+                    }
+                    BinaryOpType::SHR => {
+                        if r < 0 || r >= bits(&tp) as i128 {
+                            self.raise_overflow(op_verb(op), node);
+                        }
+                        Some(l >> r)
+                    }
+                    _ => {
+                        return self.compare(op, l.partial_cmp(&r).unwrap(), &left, node);
+                    }
+                };
+                if res.is_none() || res.unwrap() < min || res.unwrap() > max {
+                    self.raise_overflow(op_verb(op), node);
+                }
+                ConstValue::Int(res.unwrap(), tp)
+            }
+            (ConstValue::UInt(l, tp), ConstValue::UInt(r, _)) => {
+                let res: Option<u128> = match op {
+                    BinaryOpType::ADD => l.checked_add(r),
+                    BinaryOpType::SUB => l.checked_sub(r),
+                    BinaryOpType::MUL => l.checked_mul(r),
+                    BinaryOpType::DIV => {
+                        if r == 0 {
+                            self.raise_division_by_zero(node);
+                        }
+                        l.checked_div(r)
+                    }
+                    BinaryOpType::BITAND => Some(l & r),
+                    BinaryOpType::BITOR => Some(l | r),
+                    BinaryOpType::BITXOR => Some(l ^ r),
+                    BinaryOpType::SHL => {
+                        if r >= bits(&tp) as u128 {
+                            self.raise_overflow(op_verb(op), node);
+                        }
+                        Some(wrap_unsigned(l << r, &tp))
+                    }
+                    BinaryOpType::SHR => {
+                        if r >= bits(&tp) as u128 {
+                            self.raise_overflow(op_verb(op), node);
+                        }
+                        Some(l >> r)
+                    }
+                    _ => {
+                        return self.compare(op, l.partial_cmp(&r).unwrap(), &left, node);
+                    }
+                };
+                if res.is_none() || res.unwrap() > unsigned_max(&tp) {
+                    self.raise_overflow(op_verb(op), node);
+                }
+                ConstValue::UInt(res.unwrap(), tp)
+            }
+            (ConstValue::Float(l, tp), ConstValue::Float(r, _)) => {
+                let res: f64 = match op {
+                    BinaryOpType::ADD => l + r,
+                    BinaryOpType::SUB => l - r,
+                    BinaryOpType::MUL => l * r,
+                    BinaryOpType::DIV => l / r,
+                    BinaryOpType::BITAND |
+                    BinaryOpType::BITOR |
+                    BinaryOpType::BITXOR |
+                    BinaryOpType::SHL |
+                    BinaryOpType::SHR => {
+                        let fmt: String = format!("Invalid types for '{}', got '{}' and '{}'.", op, tp, tp);
+                        errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, &node.pos, self.info);
+                    }
+                    _ => {
+                        if l.is_nan() || r.is_nan() {
+                            return ConstValue::Bool(op == BinaryOpType::NE);
+                        }
+                        return self.compare(op, l.partial_cmp(&r).unwrap(), &left, node);
+                    }
+                };
+                if tp == BasicDataType::F32 {
+                    return ConstValue::Float(res as f32 as f64, tp);
+                }
+                ConstValue::Float(res, tp)
+            }
+            (ConstValue::Bool(l), ConstValue::Bool(r)) => {
+                match op {
+                    BinaryOpType::BITAND => ConstValue::Bool(l & r),
+                    BinaryOpType::BITOR => ConstValue::Bool(l | r),
+                    BinaryOpType::BITXOR => ConstValue::Bool(l ^ r),
+                    BinaryOpType::EQ |
+                    BinaryOpType::NE |
+                    BinaryOpType::GT |
+                    BinaryOpType::GTE |
+                    BinaryOpType::LT |
+                    BinaryOpType::LTE => self.compare(op, l.cmp(&r), &left, node),
+                    _ => {
+                        let fmt: String = format!("Invalid types for '{}', got 'bool' and 'bool'.", op);
+                        errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, &node.pos, self.info);
+                    }
+                }
+            }
+            _ => {
+                let fmt: String = format!("Invalid types for '{}', got '{}' and '{}'.", op, left.tp_name(), left.tp_name());
+                errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, &node.pos, self.info);
+            }
+        }
+    }
+
+    fn compare(&self, op: parser::nodes::BinaryOpType, ord: std::cmp::Ordering, left: &ConstValue, node: &parser::Node) -> ConstValue {
+        use parser::nodes::BinaryOpType;
+
+        let res: bool = match op {
+            BinaryOpType::EQ => ord == std::cmp::Ordering::Equal,
+            BinaryOpType::NE => ord != std::cmp::Ordering::Equal,
+            BinaryOpType::GT => ord == std::cmp::Ordering::Greater,
+            BinaryOpType::GTE => ord != std::cmp::Ordering::Less,
+            BinaryOpType::LT => ord == std::cmp::Ordering::Less,
+            BinaryOpType::LTE => ord != std::cmp::Ordering::Greater,
+            _ => {
+                let fmt: String = format!("Invalid types for '{}', got '{}' and '{}'.", op, left.tp_name(), left.tp_name());
+                errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, &node.pos, self.info);
+            }
+        };
+        return ConstValue::Bool(res);
+    }
+
+    fn eval_bool(&mut self, node: &parser::Node) -> bool {
+        match self.eval(node) {
+            ConstValue::Bool(v) => v,
+            ConstValue::Int(v, _) => v != 0,
+            ConstValue::UInt(v, _) => v != 0,
+            ConstValue::Float(v, _) => v != 0.0,
+            value => {
+                let fmt: String = format!("Expected 'bool' type, got '{}' type.", value.tp_name());
+                errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
+            }
+        }
+    }
+
+    fn cast(&self, value: ConstValue, tp: BasicDataType, node: &parser::Node) -> ConstValue {
+        if value.basic_tp() == Some(BasicDataType::Char) || tp == BasicDataType::Char {
+            return self.cast_char(value, tp, node);
+        }
+
+        let int: Option<i128> = match value {
+            ConstValue::Int(v, _) => Some(v),
+            ConstValue::UInt(v, _) => Some(v as i128),
+            ConstValue::Bool(v) => Some(v as i128),
+            ConstValue::Float(v, _) => {
+                if is_signed(&tp) {
+                    let (min, max) = signed_range(&tp);
+                    if v.is_nan() || v < min as f64 || v > max as f64 {
+                        self.raise_overflow("cast", node);
+                    }
+                }
+                else if is_unsigned(&tp) && (v.is_nan() || v < 0.0 || v > unsigned_max(&tp) as f64) {
+                    self.raise_overflow("cast", node);
+                }
+                None
+            }
+            _ => None,
+        };
+
+        match (&value, &tp) {
+            (ConstValue::Float(v, _), tp) if is_signed(tp) => ConstValue::Int(v.trunc() as i128, tp.clone()),
+            (ConstValue::Float(v, _), tp) if is_unsigned(tp) => ConstValue::UInt(v.trunc() as u128, tp.clone()),
+            (ConstValue::Float(v, _), tp) if is_float(tp) => {
+                if *tp == BasicDataType::F32 {
+                    return ConstValue::Float(*v as f32 as f64, tp.clone());
+                }
+                ConstValue::Float(*v, tp.clone())
+            }
+            (ConstValue::UInt(v, _), tp) if is_signed(tp) => ConstValue::Int(wrap_signed(*v as i128, tp), tp.clone()),
+            (ConstValue::UInt(v, _), tp) if is_unsigned(tp) => ConstValue::UInt(wrap_unsigned(*v, tp), tp.clone()),
+            (_, tp) if int.is_some() && is_signed(tp) => ConstValue::Int(wrap_signed(int.unwrap(), tp), tp.clone()),
+            (_, tp) if int.is_some() && is_unsigned(tp) => ConstValue::UInt(wrap_unsigned(int.unwrap() as u128, tp), tp.clone()),
+            (ConstValue::UInt(v, _), tp) if is_float(tp) => {
+                if *tp == BasicDataType::F32 {
+                    return ConstValue::Float(*v as f32 as f64, tp.clone());
+                }
+                ConstValue::Float(*v as f64, tp.clone())
+            }
+            (_, tp) if int.is_some() && is_float(tp) => {
+                if *tp == BasicDataType::F32 {
+                    return ConstValue::Float(int.unwrap() as f32 as f64, tp.clone());
+                }
+                ConstValue::Float(int.unwrap() as f64, tp.clone())
+            }
+            (ConstValue::Bool(v), BasicDataType::Bool) => ConstValue::Bool(*v),
+            (_, BasicDataType::Bool) if int.is_some() => ConstValue::Bool(int.unwrap() != 0),
+            _ => {
+                let fmt: String = format!("Invalid cast from '{}' to '{}'.", value.tp_name(), tp);
+                errors::raise_error(&fmt, errors::ErrorType::InvalidCast, &node.pos, self.info);
+            }
+        }
+    }
+
+    //Only u32 and u8 convert to and from char
+    fn cast_char(&self, value: ConstValue, tp: BasicDataType, node: &parser::Node) -> ConstValue {
+        match (&value, &tp) {
+            (ConstValue::UInt(v, BasicDataType::Char), BasicDataType::Char) |
+            (ConstValue::UInt(v, BasicDataType::Char), BasicDataType::U32) => ConstValue::UInt(*v, tp.clone()),
+            (ConstValue::UInt(v, BasicDataType::Char), BasicDataType::U8) => {
+                if *v > u8::MAX as u128 {
+                    self.raise_overflow("cast", node);
+                }
+                ConstValue::UInt(*v, tp.clone())
+            }
+            (ConstValue::UInt(v, BasicDataType::U32), BasicDataType::Char) => {
+                if !chartype::is_scalar(*v as u64) {
+                    let fmt: String = format!("'{:#X}' is not a valid Unicode scalar value.", v);
+                    errors::raise_error(&fmt, errors::ErrorType::InvalidCodePoint, &node.pos, self.info);
+                }
+                ConstValue::UInt(*v, tp.clone())
+            }
+            (ConstValue::UInt(v, BasicDataType::U8), BasicDataType::Char) => ConstValue::UInt(*v, tp.clone()),
+            _ => {
+                let fmt: String = format!("Invalid cast from '{}' to '{}'.", value.tp_name(), tp);
+                errors::raise_error(&fmt, errors::ErrorType::InvalidCast, &node.pos, self.info);
+            }
+        }
+    }
+
+    fn eval_if(&mut self, node: &parser::Node) -> ConstValue {
+        let ifn: &parser::nodes::IfNode = node.data.ifn.as_ref().unwrap();
+        for (cond, blocks) in &ifn.ifs {
+            if self.eval_bool(cond) {
+                return self.eval_block(blocks);
+            }
+        }
+        if ifn.else_opt.is_some() {
+            return self.eval_block(ifn.else_opt.as_ref().unwrap());
+        }
+        return ConstValue::Void;
+    }
+
+    fn eval_block(&mut self, blocks: &Vec<parser::Node>) -> ConstValue {
+        self.locals.push(HashMap::new());
+        let mut retv: ConstValue = ConstValue::Void;
+        for node in blocks {
+            retv = self.eval(node);
+            if self.retv.is_some() {
+                break;
+            }
+        }
+        self.locals.pop();
+        return retv;
+    }
+
+    fn eval_let(&mut self, node: &parser::Node) -> ConstValue {
+        let letn: &parser::nodes::LetNode = node.data.letn.as_ref().unwrap();
+        if letn.expr.is_none() || letn.destructure.is_some() {
+            let fmt: String = format!("Expression cannot be evaluated at compile time.");
+            errors::raise_error(&fmt, errors::ErrorType::NonConstantExpression, &node.pos, self.info);
+        }
+
+        let value: ConstValue = if letn.tp.is_some() {
+            let tp: BasicDataType = self.get_tp(letn.tp.as_ref().unwrap(), node);
+            let value: ConstValue = self.eval_expecting(letn.expr.as_ref().unwrap(), &tp);
+            self.check_tp(&value, &tp, node);
+            value
+        }
+        else {
+            self.eval(letn.expr.as_ref().unwrap())
+        };
+
+        self.locals.last_mut().unwrap().insert(letn.name.clone(), (value, letn.mutability));
+        return ConstValue::Void;
+    }
+
+    fn eval_call(&mut self, node: &parser::Node) -> ConstValue {
+        let call: &parser::nodes::CallNode = node.data.call.as_ref().unwrap();
+        if call.name.tp != parser::NodeType::IDENTIFIER {
+            let fmt: String = format!("Expression cannot be evaluated at compile time.");
+            errors::raise_error(&fmt, errors::ErrorType::NonConstantExpression, &node.pos, self.info);
+        }
+
+        let name: &String = &call.name.data.identifier.as_ref().unwrap().name;
+        if !self.namespaces.const_functions.contains_key(name) {
+            let fmt: String = format!("Function '{}' is not const.", name);
+            errors::raise_error(&fmt, errors::ErrorType::NonConstantExpression, &node.pos, self.info);
+        }
+
+        let namespaces: &'a Namespaces<'ctx> = self.namespaces;
+        let func: &parser::nodes::FuncNode = namespaces.const_functions.get(name).unwrap().data.func.as_ref().unwrap();
+
+        if call.args.len() != func.args.args.len() {
+            let fmt: String = format!("Expected {} arguments, got {}.", func.args.args.len(), call.args.len());
+            errors::raise_error(&fmt, errors::ErrorType::ArgumentCountMismatch, &node.pos, self.info);
+        }
+
+        if self.depth >= MAX_CALL_DEPTH {
+            let fmt: String = format!("Constant evaluation exceeded the maximum call depth of {}.", MAX_CALL_DEPTH);
+            errors::raise_error(&fmt, errors::ErrorType::ConstEvalLimit, &node.pos, self.info);
+        }
+
+        let mut args: HashMap<String, (ConstValue, DataMutablility)> = HashMap::new();
+        for (argname, tp, arg) in itertools::izip![&func.args.name, &func.args.args, &call.args] {
+            let basictp: BasicDataType = self.get_tp(tp, arg);
+            let value: ConstValue = self.eval_expecting(arg, &basictp);
+            self.check_tp(&value, &basictp, arg);
+            args.insert(argname.to_owned(), (value, tp.mutability));
+        }
+
+        let rettp: BasicDataType = self.get_tp(func.args.rettp.last().unwrap(), node);
+        let prev_locals: Vec<HashMap<String, (ConstValue, DataMutablility)>> = std::mem::replace(&mut self.locals, vec![args]);
+        let prev_rettp: Option<BasicDataType> = std::mem::replace(&mut self.rettp, Some(rettp.clone()));
+        self.depth += 1;
+
+        let mut retv: ConstValue = self.eval_block(&func.blocks);
+        if self.retv.is_some() {
+            retv = self.retv.take().unwrap();
+        }
+
+        self.depth -= 1;
+        self.locals = prev_locals;
+        self.rettp = prev_rettp;
+
+        self.check_tp(&retv, &rettp, node);
+
+        return retv;
+    }
+
+    //Get the type of a value in a constant expression, which must be a simple type
+    fn get_tp(&self, tp: &parser::Type, node: &parser::Node) -> BasicDataType {
+        let alias: Option<parser::Type> = CodeGen::resolve_type_alias(self.namespaces, self.info, tp, node);
+        if alias.is_some() {
+            return self.get_tp(alias.as_ref().unwrap(), node);
+        }
+
+        if tp.isfn || tp.isarr || tp.istuple || tp.isdyn || tp.isgenum || tp.isref {
+            let fmt: String = format!("Type cannot be used in a constant expression.");
+            errors::raise_error(&fmt, errors::ErrorType::NonConstantExpression, &node.pos, self.info);
+        }
+
+        let datatp: Option<DataType> = CodeGen::datatypes_get_basic(self.cur_datatypes, self.datatypes, tp.data.as_ref().unwrap());
+        if datatp.is_none() {
+            let fmt: String = format!("Unknown type '{}'.", tp.data.as_ref().unwrap());
+            errors::raise_error(&fmt, errors::ErrorType::UnknownType, &node.pos, self.info);
+        }
+
+        let basictp: BasicDataType = datatp.unwrap().tp;
+        if !is_signed(&basictp) && !is_unsigned(&basictp) && !is_float(&basictp) && basictp != BasicDataType::Bool && basictp != BasicDataType::Char && basictp != BasicDataType::Void {
+            let fmt: String = format!("Type '{}' cannot be used in a constant expression.", tp.data.as_ref().unwrap());
+            errors::raise_error(&fmt, errors::ErrorType::NonConstantExpression, &node.pos, self.info);
+        }
+        return basictp;
+    }
+
+    fn check_tp(&self, value: &ConstValue, tp: &BasicDataType, node: &parser::Node) {
+        if value.basic_tp().as_ref() != Some(tp) {
+            let fmt: String = format!("Expected '{}' type, got '{}' type.", tp, value.tp_name());
+            errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
+        }
+    }
+
+    fn raise_overflow(&self, verb: &str, node: &parser::Node) -> ! {
+        let fmt: String = format!("Attempt to {} with overflow.", verb);
+        errors::raise_error(&fmt, errors::ErrorType::ConstEvalOverflow, &node.pos, self.info);
+    }
+
+    fn raise_division_by_zero(&self, node: &parser::Node) -> ! {
+        let fmt: String = format!("Attempt to divide by zero.");
+        errors::raise_error(&fmt, errors::ErrorType::ConstEvalDivisionByZero, &node.pos, self.info);
+    }
+}
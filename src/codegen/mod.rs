@@ -19,6 +19,7 @@ pub mod types;
 mod builtin_types;
 use crate::errors;
 mod modules;
+mod consteval;
 
 extern crate guess_host_triple;
 
@@ -35,6 +36,8 @@ pub struct InkwellTypes<'ctx> {
     booltp: &'ctx inkwell::types::IntType<'ctx>,
     dynptrtp: &'ctx inkwell::types::StructType<'ctx>,
     st_data_tp: &'ctx inkwell::types::StructType<'ctx>,
+    closuretp: &'ctx inkwell::types::StructType<'ctx>,
+    stringtp: &'ctx inkwell::types::StructType<'ctx>,
 }
 
 #[derive(PartialEq, Clone, Debug)]
@@ -74,6 +77,45 @@ pub struct Namespaces<'ctx> {
     structid_from: std::collections::HashMap<i32, String>,
     structid_max: i32,
     generic_enums: std::collections::HashMap<String, (Vec<String>, Vec<Option<parser::Type>>)>,
+    generic_structs: std::collections::HashMap<String, GenericStruct<'ctx>>,
+    type_aliases: std::collections::HashMap<String, (Vec<String>, parser::Type)>,
+    consts: std::collections::HashMap<String, (consteval::ConstValue, parser::Position)>,
+    statics: std::collections::HashMap<String, (Option<inkwell::values::PointerValue<'ctx>>, types::DataType<'ctx>, types::DataMutablility, types::DataOwnership, parser::Position, InitializationStatus)>,
+    const_functions: std::collections::HashMap<String, parser::Node>,
+    enum_discriminants: std::collections::HashMap<String, Vec<i32>>,
+    template_type_bounds: std::collections::HashMap<String, Vec<String>>,
+}
+
+//Definition of a generic struct, with its methods and impls, and the instances created so far
+#[derive(PartialEq, Clone, Debug)]
+pub struct GenericStruct<'ctx> {
+    template_types: Vec<String>,
+    node: parser::Node,
+    functions: Vec<parser::Node>,
+    impls: Vec<parser::Node>,
+    instances: std::collections::HashMap<String, Vec<types::DataType<'ctx>>>,
+}
+
+//A name captured by a closure, with its value if moved, or its pointer if borrowed
+#[derive(Clone, Debug)]
+pub struct ClosureCapture<'ctx> {
+    name: String,
+    data: Option<inkwell::values::BasicValueEnum<'ctx>>,
+    tp: types::DataType<'ctx>,
+    ismove: bool,
+    pos: parser::Position,
+}
+
+//Targets of break and continue for an enclosing loop, with the values of any value breaks
+#[derive(Clone, Debug)]
+pub struct LoopFrame<'ctx> {
+    label: Option<String>,
+    start_block: inkwell::basic_block::BasicBlock<'ctx>,
+    end_block: inkwell::basic_block::BasicBlock<'ctx>,
+    allow_value: bool,
+    breaks: Vec<(Option<inkwell::values::BasicValueEnum<'ctx>>, inkwell::basic_block::BasicBlock<'ctx>)>,
+    break_tp: Option<types::DataType<'ctx>>,
+    scopes: usize, //The number of scopes outside of the loop
 }
 
 pub struct CodeGen<'ctx> {
@@ -88,12 +130,17 @@ pub struct CodeGen<'ctx> {
     traits: std::collections::HashMap<String, types::TraitSignature<'ctx>>,
     current_block: Option<inkwell::basic_block::BasicBlock<'ctx>>,
     enclosing_block: Option<inkwell::basic_block::BasicBlock<'ctx>>,
-    start_block: Option<inkwell::basic_block::BasicBlock<'ctx>>,
-    end_block: Option<inkwell::basic_block::BasicBlock<'ctx>>,
-    loop_flow_broken: bool,
+    loops: Vec<LoopFrame<'ctx>>,
+    template_bounds: Vec<std::collections::HashMap<(String, parser::Position), (String, Vec<String>)>>,
+    expected_tp: Option<types::DataType<'ctx>>,
     cur_module: modules::Module<'ctx>,
     datatypes: std::collections::HashMap<String, crate::codegen::types::DataType<'ctx>>,
     alloc_head: Option<inkwell::values::InstructionValue<'ctx>>,
+    overflow_checks: bool,
+    pending_impls: Option<Vec<(String, String, parser::Node)>>,
+    pending_derives: Option<Vec<(String, inkwell::values::FunctionValue<'ctx>, types::TraitType, parser::Node)>>,
+    closure_owners: Vec<inkwell::values::PointerValue<'ctx>>, //Locals which own the closure they hold
+    drop_scope: usize, //The first scope of the current function whose locals are dropped when it returns
 }
 
 //Codegen functions
@@ -177,10 +224,42 @@ impl<'ctx> CodeGen<'ctx> {
         return ptr;
     }
 
+    //Trap at runtime if cond is true, otherwise continue in a new block. If msg is given, it is written to stderr before trapping
+    pub fn build_trap_if(codegen: &mut CodeGen<'ctx>, cond: inkwell::values::IntValue<'ctx>, name: &str, msg: Option<String>) {
+        let trap_block: inkwell::basic_block::BasicBlock = codegen.context.append_basic_block(codegen.current_block.unwrap().get_parent().unwrap(), name);
+        let cont_block: inkwell::basic_block::BasicBlock = codegen.context.append_basic_block(codegen.current_block.unwrap().get_parent().unwrap(), "cont");
+
+        codegen.builder.build_conditional_branch(cond, trap_block, cont_block);
+
+        codegen.builder.position_at_end(trap_block);
+        codegen.current_block = Some(trap_block);
+
+        if msg.is_some() {
+            let text: String = msg.unwrap() + "\n";
+            let write: inkwell::values::FunctionValue = match codegen.module.get_function("write") {
+                Some(v) => v,
+                None => codegen.module.add_function("write", codegen.inkwell_types.i64tp.fn_type(&[inkwell::types::BasicMetadataTypeEnum::IntType(*codegen.inkwell_types.i32tp), inkwell::types::BasicMetadataTypeEnum::PointerType(codegen.inkwell_types.i8tp.ptr_type(inkwell::AddressSpace::from(0u16))), inkwell::types::BasicMetadataTypeEnum::IntType(*codegen.inkwell_types.i64tp)], false), Some(inkwell::module::Linkage::External)),
+            };
+            let ptr: inkwell::values::PointerValue = codegen.builder.build_global_string_ptr(text.as_str(), "trap_msg").as_pointer_value();
+            codegen.builder.build_call(write, &[inkwell::values::BasicMetadataValueEnum::IntValue(codegen.inkwell_types.i32tp.const_int(2, false)), inkwell::values::BasicMetadataValueEnum::PointerValue(ptr), inkwell::values::BasicMetadataValueEnum::IntValue(codegen.inkwell_types.i64tp.const_int(text.len() as u64, false))], "write");
+        }
+
+        let trap: inkwell::values::FunctionValue = match codegen.module.get_function("llvm.trap") {
+            Some(v) => v,
+            None => codegen.module.add_function("llvm.trap", codegen.context.void_type().fn_type(&[], false), None),
+        };
+        codegen.builder.build_call(trap, &[], "trap");
+        codegen.builder.build_unreachable();
+
+        codegen.builder.position_at_end(cont_block);
+        codegen.current_block = Some(cont_block);
+    }
+
     fn get_anytp_from_tp(ctx: &'ctx Context, types: &InkwellTypes<'ctx>, tp: types::DataType<'ctx>, datatypes: &std::collections::HashMap<String, types::DataType<'ctx>>) -> Option<inkwell::types::AnyTypeEnum<'ctx>> {
         match tp.tp {
             types::BasicDataType::I32 |
-            types::BasicDataType::U32 => {
+            types::BasicDataType::U32 |
+            types::BasicDataType::Char => {
                 return Some(inkwell::types::AnyTypeEnum::IntType(*types.i32tp));
             }
             types::BasicDataType::I8 |
@@ -213,6 +292,9 @@ impl<'ctx> CodeGen<'ctx> {
                 return None;
             }
             types::BasicDataType::Struct => {
+                if tp.name == "String" {
+                    return Some(inkwell::types::AnyTypeEnum::StructType(*types.stringtp));
+                }
                 return Some(Self::build_struct_tp_from_types(ctx, types, &tp.types, datatypes));
             }
             types::BasicDataType::Array => {
@@ -229,14 +311,175 @@ impl<'ctx> CodeGen<'ctx> {
             types::BasicDataType::Dyn => {
                 return Some(inkwell::types::AnyTypeEnum::StructType(*types.dynptrtp));
             }
+            types::BasicDataType::Closure => {
+                return Some(inkwell::types::AnyTypeEnum::StructType(*types.closuretp));
+            }
             types::BasicDataType::Unknown => {
                 return None;
             }    
         }
     }
 
+    //Resolve a use of a type alias to the aliased type, substituting the template types of a generic alias
+    fn resolve_type_alias(namespaces: &Namespaces, info: &fileinfo::FileInfo, arg: &parser::Type, node: &parser::Node) -> Option<parser::Type> {
+        if !arg.isfn && !arg.isarr && !arg.istuple && !arg.isdyn && !arg.isgenum && !arg.isref &&
+            namespaces.type_aliases.contains_key(arg.data.as_ref().unwrap()) {
+            let (template_types, tp) = namespaces.type_aliases.get(arg.data.as_ref().unwrap()).unwrap();
+            if template_types.len() > 0 {
+                let fmt: String = format!("Expected generic types for type alias '{}'.", arg.data.as_ref().unwrap());
+                errors::raise_error(&fmt, errors::ErrorType::ExpectedGenericTypes, &node.pos, info);
+            }
+
+            let mut tp: parser::Type = tp.clone();
+            tp.mutability = arg.mutability;
+            return Some(tp);
+        }
+        else if arg.isgenum && namespaces.type_aliases.contains_key(arg.basetp.as_ref().unwrap().data.as_ref().unwrap()) {
+            let name: &String = arg.basetp.as_ref().unwrap().data.as_ref().unwrap();
+            let (template_types, tp) = namespaces.type_aliases.get(name).unwrap();
+            let generic_tps: &Vec<parser::Type> = arg.generic_tps.as_ref().unwrap();
+            if template_types.len() != generic_tps.len() {
+                let fmt: String = format!("Expected {} template types for '{}', got {}.", template_types.len(), name, generic_tps.len());
+                errors::raise_error(&fmt, errors::ErrorType::TemplateTypeCountMismatch, &node.pos, info);
+            }
+
+            let mut tp: parser::Type = Self::substitute_template_types(tp, template_types, generic_tps);
+            tp.mutability = arg.mutability;
+            return Some(tp);
+        }
+        return None;
+    }
+
+    //Replace the template types used by a type with the given types
+    fn substitute_template_types(tp: &parser::Type, template_types: &Vec<String>, generic_tps: &Vec<parser::Type>) -> parser::Type {
+        if !tp.isfn && !tp.isarr && !tp.istuple && !tp.isdyn && !tp.isgenum && !tp.isref &&
+            template_types.contains(tp.data.as_ref().unwrap()) {
+            let mut newtp: parser::Type = generic_tps.get(template_types.iter().position(|x| x == tp.data.as_ref().unwrap()).unwrap()).unwrap().clone();
+            newtp.mutability = tp.mutability;
+            return newtp;
+        }
+
+        let mut newtp: parser::Type = tp.clone();
+        if newtp.basetp.is_some() {
+            newtp.basetp = Some(Box::new(Self::substitute_template_types(tp.basetp.as_ref().unwrap(), template_types, generic_tps)));
+        }
+        if newtp.generic_tps.is_some() {
+            newtp.generic_tps = Some(tp.generic_tps.as_ref().unwrap().iter().map(|x| Self::substitute_template_types(x, template_types, generic_tps)).collect());
+        }
+        if newtp.args.is_some() {
+            let args: &mut parser::Args = newtp.args.as_mut().unwrap();
+            args.args = args.args.iter().map(|x| Self::substitute_template_types(x, template_types, generic_tps)).collect();
+            args.rettp = args.rettp.iter().map(|x| Self::substitute_template_types(x, template_types, generic_tps)).collect();
+        }
+        return newtp;
+    }
+
+    //Associated items are named under the struct and the trait declaring them, so traits declaring the same item do not collide.
+    //Through a template type, the bounds of the template type are searched, and otherwise the traits the struct implements.
+    fn get_associated_item(namespaces: &Namespaces, datatypes: &std::collections::HashMap<String, types::DataType<'ctx>>, traits: &std::collections::HashMap<String, types::TraitSignature<'ctx>>, info: &fileinfo::FileInfo, base: &str, item: &str, istype: bool, node: &parser::Node) -> Option<String> {
+        let structnm: String = match datatypes.get(base) {
+            Some(tp) => {
+                tp.name.clone()
+            }
+            None => {
+                base.to_owned()
+            }
+        };
+
+        let declares = |traitnm: &String| -> bool {
+            let sig: Option<&types::TraitSignature> = traits.get(traitnm);
+            if sig.is_none() {
+                return false;
+            }
+            if istype {
+                return sig.unwrap().types.is_some() && sig.unwrap().types.as_ref().unwrap().iter().any(|x| x == item);
+            }
+            return sig.unwrap().consts.is_some() && sig.unwrap().consts.as_ref().unwrap().contains_key(item);
+        };
+        let defined = |traitnm: &String| -> bool {
+            let name: String = structnm.clone() + "::" + traitnm.as_str() + "::" + item;
+            if istype {
+                return namespaces.type_aliases.contains_key(&name);
+            }
+            return namespaces.consts.contains_key(&name);
+        };
+
+        let mut candidates: Vec<String> = match namespaces.template_type_bounds.get(base) {
+            Some(bounds) => {
+                bounds.iter().filter(|x| declares(x)).cloned().collect()
+            }
+            None => {
+                traits.keys().filter(|x| declares(x) && defined(x)).cloned().collect()
+            }
+        };
+        candidates.sort();
+
+        if candidates.is_empty() {
+            return None;
+        }
+
+        let kind: &str = if istype { "type" } else { "constant" };
+        if candidates.len() > 1 {
+            let fmt: String = format!("Associated {} '{}' of '{}' is ambiguous, declared by traits '{}'.", kind, item, base, candidates.join("', '"));
+            errors::raise_error(&fmt, errors::ErrorType::AmbiguousAssociatedItem, &node.pos, info);
+        }
+
+        if !defined(candidates.first().unwrap()) {
+            let fmt: String = format!("Type '{}' has no associated {} '{}'.", structnm, kind, item);
+            errors::raise_error(&fmt, errors::ErrorType::MissingAssociatedItem, &node.pos, info);
+        }
+
+        return Some(structnm + "::" + candidates.first().unwrap().as_str() + "::" + item);
+    }
+
     pub fn get_llvm_from_type(ctx: &'ctx Context, namespaces: &Namespaces, types: &InkwellTypes<'ctx>, datatypes: &std::collections::HashMap<String, types::DataType<'ctx>>, cur_datatypes: &std::collections::HashMap<String, types::DataType<'ctx>>, traits: &std::collections::HashMap<String, types::TraitSignature<'ctx>>, info: &fileinfo::FileInfo, arg: &parser::Type, node: &parser::Node) -> (types::DataType<'ctx>, inkwell::types::AnyTypeEnum<'ctx>) {
-        if arg.isfn {
+        let alias: Option<parser::Type> = Self::resolve_type_alias(namespaces, info, arg, node);
+        if alias.is_some() {
+            let (mut tp, anytp) = Self::get_llvm_from_type(ctx, namespaces, types, datatypes, cur_datatypes, traits, info, alias.as_ref().unwrap(), node);
+
+            //Diagnostics name the type by the alias it was written as (e.g. 'Res<i32> = Result<Optional<i32>, String>')
+            tp.alias = if arg.isgenum {
+                let generic_tps: Vec<String> = arg.generic_tps.as_ref().unwrap().iter().map(|x| Self::get_llvm_from_type(ctx, namespaces, types, datatypes, cur_datatypes, traits, info, x, node).0).map(|x| types::datatype_repr(&x)).collect();
+                Some(format!("{}<{}>", arg.basetp.as_ref().unwrap().data.as_ref().unwrap(), generic_tps.join(", ")))
+            }
+            else {
+                Some(arg.data.as_ref().unwrap().to_owned())
+            };
+            return (tp, anytp);
+        }
+
+        if  !arg.isfn && !arg.isarr && !arg.istuple && !arg.isdyn && !arg.isgenum && !arg.isref &&
+            arg.data.as_ref().unwrap().contains("::") {
+            let (base, item) = arg.data.as_ref().unwrap().split_once("::").unwrap();
+            let name: Option<String> = Self::get_associated_item(namespaces, datatypes, traits, info, base, item, true, node);
+            if name.is_some() {
+                let mut tp: parser::Type = arg.clone();
+                tp.data = name;
+                return Self::get_llvm_from_type(ctx, namespaces, types, datatypes, cur_datatypes, traits, info, &tp, node);
+            }
+
+            if namespaces.template_type_bounds.contains_key(base) {
+                let fmt: String = format!("Template type '{}' has no bound providing associated type '{}'.", base, item);
+                errors::raise_error(&fmt, errors::ErrorType::MissingTemplateBound, &node.pos, info);
+            }
+
+            let fmt: String = format!("Type '{}' has no associated type '{}'.", base, item);
+            errors::raise_error(&fmt, errors::ErrorType::MissingAssociatedItem, &node.pos, info);
+        }
+
+        if arg.isclosure {
+            let mut datatypes_: Vec<types::DataType> = Vec::new();
+            for arg in &arg.args.as_ref().unwrap().args {
+                datatypes_.push(Self::get_llvm_from_type(ctx, namespaces, types, datatypes, cur_datatypes, traits, info, &arg, node).0);
+            }
+            let rettp: types::DataType = Self::get_llvm_from_type(ctx, namespaces, types, datatypes, cur_datatypes, traits, info, &arg.args.as_ref().unwrap().rettp.last().unwrap(), node).0;
+
+            let mut tp: types::DataType = datatypes.get(&types::BasicDataType::Closure.to_string()).unwrap().clone();
+            tp.types = datatypes_;
+            tp.rettp = Some(Box::new(rettp));
+            return (tp, inkwell::types::AnyTypeEnum::StructType(*types.closuretp));
+        }
+        else if arg.isfn {
             let args: &Vec<parser::Type> = &arg.args.as_ref().unwrap().args;
             let mut datatypes_: Vec<types::DataType> = Vec::new();
             let mut mutability: Vec<types::DataMutablility> = Vec::new();
@@ -279,26 +522,17 @@ impl<'ctx> CodeGen<'ctx> {
                 panic!("Unexpected type");
             }
 
-            let mut names: Option<Vec<String>> = None;
-            if node.tp == parser::NodeType::FUNC {
-                names=Some(node.data.func.as_ref().unwrap().args.name.clone());
-            }
+            let names: Vec<String> = vec![String::new(); datatypes_.len()];
             let mut tp: types::DataType = datatypes.get(&types::BasicDataType::Func.to_string()).unwrap().clone();
             tp.rettp = Some(Box::new(rettp_full.0.clone()));
-            tp.names = names;
+            tp.names = Some(names);
+            tp.types = datatypes_;
+            tp.mutability = mutability;
             return (tp.clone(), inkwell::types::AnyTypeEnum::FunctionType(fntp));
         }
         else if arg.isarr {
             let (_, tp) = Self::get_llvm_from_type(ctx, namespaces, types, datatypes, cur_datatypes, traits, info, &arg.basetp.as_ref().unwrap(), node);
-            let len: u32 = match u32::from_str_radix(arg.arrlen.as_ref().unwrap().first().unwrap().as_str(), 10) {
-                Ok(v) => {
-                    v
-                }
-                Err(_) => {
-                    let fmt: String = format!("Value '{}' out of range for 'u32'.", arg.arrlen.as_ref().unwrap().first().unwrap());
-                    errors::raise_error(&fmt, errors::ErrorType::ArrayLengthOutOfRange, &node.pos, info);
-                }
-            };
+            let len: u32 = Self::get_array_length(namespaces, datatypes, cur_datatypes, info, arg.arrlen.as_ref().unwrap().first().unwrap());
 
             if len == 0 {
                 let fmt: String = format!("Cannot define zero-length array.");
@@ -331,8 +565,8 @@ impl<'ctx> CodeGen<'ctx> {
                 panic!("Unexpected type");
             }
             
-            for item in arg.arrlen.as_ref().unwrap().split_at(1).1.to_vec() {
-                let len: u32 = u32::from_str_radix(item.as_str(), 10).unwrap();
+            for item in arg.arrlen.as_ref().unwrap().split_at(1).1 {
+                let len: u32 = Self::get_array_length(namespaces, datatypes, cur_datatypes, info, item);
                 arrtp = arrtp.array_type(len);
             }
 
@@ -341,6 +575,17 @@ impl<'ctx> CodeGen<'ctx> {
             tp.arrtp = Some(arrtp);
             return (tp.clone(), inkwell::types::AnyTypeEnum::ArrayType(arrtp));
         }
+        else if arg.istuple {
+            let mut tps: Vec<types::DataType> = Vec::new();
+            for tp in arg.generic_tps.as_ref().unwrap() {
+                tps.push(Self::get_llvm_from_type(ctx, namespaces, types, datatypes, cur_datatypes, traits, info, tp, node).0);
+            }
+
+            let tp: types::DataType = types::new_tuple_datatype(tps);
+            let anytp: inkwell::types::AnyTypeEnum = Self::build_struct_tp_from_types(ctx, types, &tp.types, datatypes);
+
+            return (tp, anytp);
+        }
         else if arg.isdyn {
             let traitnm: String = arg.data.as_ref().unwrap().to_owned();
             if !traits.contains_key(&traitnm) {
@@ -351,6 +596,17 @@ impl<'ctx> CodeGen<'ctx> {
             
             return (tp, inkwell::types::AnyTypeEnum::StructType(*types.dynptrtp));
         }
+        else if arg.isgenum && namespaces.generic_structs.contains_key(arg.basetp.as_ref().unwrap().data.as_ref().unwrap()) {
+            let mut generic_tps: Vec<types::DataType> = Vec::new();
+            for generic_tp in arg.generic_tps.as_ref().unwrap() {
+                generic_tps.push(Self::get_llvm_from_type(ctx, namespaces, types, datatypes, cur_datatypes, traits, info, generic_tp, node).0);
+            }
+
+            let tp: types::DataType = Self::get_generic_struct_tp(ctx, namespaces, types, datatypes, cur_datatypes, traits, info, arg.basetp.as_ref().unwrap().data.as_ref().unwrap(), &generic_tps, node);
+            let anytp: inkwell::types::AnyTypeEnum = Self::build_struct_tp_from_types(ctx, types, &tp.types, datatypes);
+
+            return (tp, anytp);
+        }
         else if arg.isgenum {
             let (mut tp, _) = Self::get_llvm_from_type(ctx, namespaces, types, datatypes, cur_datatypes, traits, info, &arg.basetp.as_ref().unwrap(), node);
             
@@ -393,6 +649,7 @@ impl<'ctx> CodeGen<'ctx> {
 
             tp.types = newtypes.clone();
             tp.mutability = mutabilities;
+            tp.alias = None;
             
             newtypes.insert(0, datatypes.get(&types::BasicDataType::I32.to_string()).unwrap().clone());
 
@@ -443,6 +700,33 @@ impl<'ctx> CodeGen<'ctx> {
         }
     }
 
+    //Get the discriminant stored as the id of an enum variant
+    fn get_enum_discriminant(&self, name: &String, idx: usize) -> u64 {
+        match self.cur_module.namespaces.enum_discriminants.get(name) {
+            Some(discriminants) => *discriminants.get(idx).unwrap() as u32 as u64,
+            None => idx as u64,
+        }
+    }
+
+    //Evaluate an array length, which may be any constant expression
+    fn get_array_length(namespaces: &Namespaces, datatypes: &std::collections::HashMap<String, types::DataType<'ctx>>, cur_datatypes: &std::collections::HashMap<String, types::DataType<'ctx>>, info: &fileinfo::FileInfo, len: &parser::Node) -> u32 {
+        let value: consteval::ConstValue = consteval::ConstEvaluator::new(namespaces, datatypes, cur_datatypes, info).eval(len);
+        let res: Option<u32> = match value {
+            consteval::ConstValue::Int(v, _) => u32::try_from(v).ok(),
+            consteval::ConstValue::UInt(v, _) => u32::try_from(v).ok(),
+            _ => {
+                let fmt: String = format!("Expected integer array length, got '{}' type.", value.tp_name());
+                errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &len.pos, info);
+            }
+        };
+
+        if res.is_none() {
+            let fmt: String = format!("Value '{}' out of range for 'u32'.", value);
+            errors::raise_error(&fmt, errors::ErrorType::ArrayLengthOutOfRange, &len.pos, info);
+        }
+        return res.unwrap();
+    }
+
     fn mangle_name_main(&self, name: &String) -> String {
         let mut new: String = name.clone();
         if *name == String::from("main") {
@@ -451,6 +735,289 @@ impl<'ctx> CodeGen<'ctx> {
         return new;
     }
 
+    //Resolve an instance of a generic struct, which is named after its template types (e.g. 'Pair<i32, u8>')
+    fn get_generic_struct_tp(ctx: &'ctx Context, namespaces: &Namespaces<'ctx>, types: &InkwellTypes<'ctx>, datatypes: &std::collections::HashMap<String, types::DataType<'ctx>>, cur_datatypes: &std::collections::HashMap<String, types::DataType<'ctx>>, traits: &std::collections::HashMap<String, types::TraitSignature<'ctx>>, info: &fileinfo::FileInfo, name: &String, generic_tps: &Vec<types::DataType<'ctx>>, node: &parser::Node) -> types::DataType<'ctx> {
+        let generic: &GenericStruct = namespaces.generic_structs.get(name).unwrap();
+
+        if generic.template_types.len() != generic_tps.len() {
+            let fmt: String = format!("Expected {} template types for '{}', got {}.", generic.template_types.len(), name, generic_tps.len());
+            errors::raise_error(&fmt, errors::ErrorType::TemplateTypeCountMismatch, &node.pos, info);
+        }
+
+        let instname: String = format!("{}<{}>", name, generic_tps.iter().map(|x| types::datatype_repr(x)).collect::<Vec<String>>().join(", "));
+
+        if namespaces.structs.contains_key(&instname) {
+            return namespaces.structs.get(&instname).unwrap().0.clone();
+        }
+
+        let mut instance_datatypes: std::collections::HashMap<String, types::DataType> = datatypes.clone();
+        for (template, tp) in izip![&generic.template_types, generic_tps] {
+            instance_datatypes.insert(template.to_owned(), tp.clone());
+        }
+
+        let st: &parser::nodes::StructNode = generic.node.data.st.as_ref().unwrap();
+
+        let mut simpletypes: Vec<types::DataType> = Vec::new();
+        let mut mutabilitites: Vec<types::DataMutablility> = Vec::new();
+
+        for member in &st.names {
+            let (tp, _) = Self::get_llvm_from_type(ctx, namespaces, types, &instance_datatypes, cur_datatypes, traits, info, st.members.get(member).unwrap(), node);
+            if tp.is_ref {
+                let fmt: String = format!("Structs may not contain references.");
+                errors::raise_error(&fmt, errors::ErrorType::ReferenceMemberStruct, &node.pos, info);
+            }
+            simpletypes.push(tp);
+            mutabilitites.push(st.members.get(member).unwrap().mutability);
+        }
+
+        let mut tp: types::DataType = Self::datatypes_get_basic(cur_datatypes, datatypes, &types::BasicDataType::Struct.to_string()).unwrap();
+        tp.name = instname;
+        tp.names = Some(st.names.clone());
+        tp.types = simpletypes;
+        tp.mutability = mutabilitites;
+
+        return tp;
+    }
+
+    //Infer template types by matching a type of a template function signature against the type of a value
+    fn infer_template_type(&self, arg: &parser::Type, tp: &types::DataType<'ctx>, func: &parser::Node, templates: &mut std::collections::HashMap<String, types::DataType<'ctx>>, node: &parser::Node) {
+        let alias: Option<parser::Type> = Self::resolve_type_alias(&self.cur_module.namespaces, self.info, arg, node);
+        if alias.is_some() {
+            self.infer_template_type(alias.as_ref().unwrap(), tp, func, templates, node);
+        }
+        else if arg.isref {
+            if tp.is_ref {
+                let basetp: Option<types::DataType> = Self::datatypes_get(self, &tp.name);
+                if basetp.is_some() {
+                    self.infer_template_type(arg.basetp.as_ref().unwrap(), &basetp.unwrap(), func, templates, node);
+                }
+            }
+        }
+        else if arg.istuple {
+            if types::is_tuple_datatype(tp) && !tp.is_ref && tp.types.len() == arg.generic_tps.as_ref().unwrap().len() {
+                for (elemarg, elemtp) in izip![arg.generic_tps.as_ref().unwrap(), &tp.types] {
+                    self.infer_template_type(elemarg, elemtp, func, templates, node);
+                }
+            }
+        }
+        else if arg.isgenum {
+            let name: &String = arg.basetp.as_ref().unwrap().data.as_ref().unwrap();
+            if  self.cur_module.namespaces.generic_structs.contains_key(name) &&
+                self.cur_module.namespaces.generic_structs.get(name).unwrap().instances.contains_key(&tp.name) {
+                let instance_tps: Vec<types::DataType> = self.cur_module.namespaces.generic_structs.get(name).unwrap().instances.get(&tp.name).unwrap().clone();
+                for (generic_tp, instance_tp) in izip![arg.generic_tps.as_ref().unwrap(), &instance_tps] {
+                    self.infer_template_type(generic_tp, instance_tp, func, templates, node);
+                }
+            }
+        }
+        else if !arg.isarr && !arg.isfn && !arg.isdyn &&
+                func.data.func.as_ref().unwrap().template_types.contains(arg.data.as_ref().unwrap()) {
+            let template: &String = arg.data.as_ref().unwrap();
+            if templates.contains_key(template) {
+                if templates.get(template).unwrap() != tp {
+                    let fmt: String = format!("Template type '{}' is ambiguous, inferred as both '{}' and '{}'.", template, templates.get(template).unwrap(), tp);
+                    errors::raise_error(&fmt, errors::ErrorType::AmbiguousTemplateType, &node.pos, self.info);
+                }
+            }
+            else {
+                templates.insert(template.to_owned(), tp.clone());
+            }
+        }
+    }
+
+    //Get the template types of a template function call, and its argument and return types.
+    //Explicit template types override the inference from the arguments and the expected type.
+    fn get_template_call_types(&mut self, func: &parser::Node, args: &Vec<types::Data<'ctx>>, explicit: Option<&Vec<parser::Type>>, expected: Option<types::DataType<'ctx>>, node: &parser::Node) -> (std::collections::HashMap<String, types::DataType<'ctx>>, Vec<types::DataType<'ctx>>, types::DataType<'ctx>) {
+        let template_types: Vec<String> = func.data.func.as_ref().unwrap().template_types.clone();
+        let mut templates: std::collections::HashMap<String, types::DataType> = std::collections::HashMap::new();
+
+        if explicit.is_some() {
+            if explicit.unwrap().len() != template_types.len() {
+                let fmt: String = format!("Expected {} template types for '{}', got {}.", template_types.len(), func.data.func.as_ref().unwrap().name, explicit.unwrap().len());
+                errors::raise_error(&fmt, errors::ErrorType::TemplateTypeCountMismatch, &node.pos, self.info);
+            }
+
+            for (template, tp) in izip![&template_types, explicit.unwrap()] {
+                self.build_generic_struct_types(tp, node);
+                templates.insert(template.to_owned(), Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, tp, node).0);
+            }
+        }
+        else {
+            for (data, arg) in izip![args, &func.data.func.as_ref().unwrap().args.args] {
+                self.infer_template_type(arg, &data.tp, func, &mut templates, node);
+            }
+
+            if expected.is_some() && template_types.iter().any(|x| !templates.contains_key(x)) {
+                self.infer_template_type(func.data.func.as_ref().unwrap().args.rettp.last().unwrap(), expected.as_ref().unwrap(), func, &mut templates, node);
+            }
+
+            for template in &template_types {
+                if !templates.contains_key(template) {
+                    let fmt: String = format!("Unable to infer template type '{}' of function '{}'.", template, func.data.func.as_ref().unwrap().name);
+                    errors::raise_error(&fmt, errors::ErrorType::UnknownTemplateType, &node.pos, self.info);
+                }
+            }
+        }
+
+        let prev_tps: Vec<Option<types::DataType>> = self.push_template_types(&template_types, &template_types.iter().map(|x| templates.get(x).unwrap().clone()).collect());
+        let prev_bounds: std::collections::HashMap<String, Vec<String>> = self.push_template_bounds(&template_types, &func.data.func.as_ref().unwrap().template_bounds);
+
+        let mut fn_types: Vec<types::DataType> = Vec::new();
+        for arg in &func.data.func.as_ref().unwrap().args.args {
+            self.build_generic_struct_types(arg, node);
+            fn_types.push(Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, arg, node).0);
+        }
+
+        let rettp: &parser::Type = func.data.func.as_ref().unwrap().args.rettp.last().unwrap();
+        self.build_generic_struct_types(rettp, node);
+        let rettp_tp: types::DataType = Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, rettp, node).0;
+
+        self.pop_template_bounds(prev_bounds);
+        self.pop_template_types(&template_types, prev_tps);
+
+        return (templates, fn_types, rettp_tp);
+    }
+
+    //Ordering traits are provided by Cmp, as the ordering operators are derived from it
+    fn traits_provide(traits: &Vec<String>, traitnm: &String) -> bool {
+        if traits.contains(traitnm) {
+            return true;
+        }
+
+        return  (traitnm == &types::TraitType::Gt.to_string() ||
+                traitnm == &types::TraitType::Ge.to_string() ||
+                traitnm == &types::TraitType::Lt.to_string() ||
+                traitnm == &types::TraitType::Le.to_string()) &&
+                traits.contains(&types::TraitType::Cmp.to_string());
+    }
+
+    //Check that the template types of a template function call satisfy their bounds
+    fn check_template_types_bounds(&self, func: &parser::Node, templates: &std::collections::HashMap<String, types::DataType<'ctx>>, node: &parser::Node) {
+        for (template, bounds) in &func.data.func.as_ref().unwrap().template_bounds {
+            if !templates.contains_key(template) {
+                continue;
+            }
+            let tp: &types::DataType = templates.get(template).unwrap();
+
+            let implemented: Vec<String> = match self.cur_module.types.get(&tp.name) {
+                Some(v) => {
+                    v.traits.keys().cloned().collect()
+                }
+                None => {
+                    Vec::new()
+                }
+            };
+
+            for bound in bounds {
+                if !Self::traits_provide(&implemented, bound) {
+                    let fmt: String = format!("Type '{}' does not implement trait '{}' required by template type '{}'.", tp, bound, template);
+                    errors::raise_error(&fmt, errors::ErrorType::TemplateBoundNotSatisfied, &node.pos, self.info);
+                }
+            }
+        }
+    }
+
+    //Get the template type and bounds of an expression of a template type of the current function.
+    //Arithmetic, bitwise and shift operators on a template typed operand give the template type.
+    fn get_template_bounds(&self, node: &parser::Node) -> Option<(String, Vec<String>)> {
+        if self.template_bounds.last().is_none() {
+            return None;
+        }
+
+        if node.tp == parser::NodeType::BINARY {
+            let binary: &parser::nodes::BinaryNode = node.data.binary.as_ref().unwrap();
+            return match binary.op {
+                parser::nodes::BinaryOpType::ADD |
+                parser::nodes::BinaryOpType::SUB |
+                parser::nodes::BinaryOpType::MUL |
+                parser::nodes::BinaryOpType::DIV |
+                parser::nodes::BinaryOpType::BITAND |
+                parser::nodes::BinaryOpType::BITOR |
+                parser::nodes::BinaryOpType::BITXOR if !binary.isassign => {
+                    self.get_template_bounds(&binary.left).or(self.get_template_bounds(&binary.right))
+                }
+                parser::nodes::BinaryOpType::SHL |
+                parser::nodes::BinaryOpType::SHR if !binary.isassign => {
+                    self.get_template_bounds(&binary.left)
+                }
+                _ => {
+                    None
+                }
+            };
+        }
+
+        if node.tp == parser::NodeType::UNARY {
+            let unary: &parser::nodes::UnaryNode = node.data.unary.as_ref().unwrap();
+            return match unary.op {
+                parser::nodes::UnaryOpType::POS |
+                parser::nodes::UnaryOpType::NEG |
+                parser::nodes::UnaryOpType::NOT |
+                parser::nodes::UnaryOpType::BITNOT => {
+                    self.get_template_bounds(&unary.right)
+                }
+                _ => {
+                    None
+                }
+            };
+        }
+
+        if node.tp != parser::NodeType::IDENTIFIER {
+            return None;
+        }
+
+        let name: &String = &node.data.identifier.as_ref().unwrap().name;
+
+        //Names are keyed with where they are declared, so a name shadowing an argument has no bounds
+        let var = self.get_variable(name).0;
+        if var.is_none() {
+            return None;
+        }
+
+        return self.template_bounds.last().unwrap().get(&(name.to_owned(), var.unwrap().4.clone())).cloned();
+    }
+
+    fn check_template_bound(&self, node: &parser::Node, traitnm: &String, errnode: &parser::Node) {
+        let (template, bounds) = match self.get_template_bounds(node) {
+            Some(v) => {
+                v
+            }
+            None => {
+                return;
+            }
+        };
+
+        if !Self::traits_provide(&self.get_bound_traits(&bounds), traitnm) {
+            let fmt: String = format!("Template type '{}' has no bound providing trait '{}'.", template, traitnm);
+            errors::raise_error(&fmt, errors::ErrorType::MissingTemplateBound, &errnode.pos, self.info);
+        }
+    }
+
+    fn check_template_bound_method(&self, node: &parser::Node, method: &String, errnode: &parser::Node) {
+        let (template, bounds) = match self.get_template_bounds(node) {
+            Some(v) => {
+                v
+            }
+            None => {
+                return;
+            }
+        };
+
+        for bound in &self.get_bound_traits(&bounds) {
+            let sig: &types::TraitSignature = self.traits.get(bound).unwrap();
+            if sig.trait_sig.is_some() && sig.trait_sig.as_ref().unwrap().iter().any(|x| &x.name == method) {
+                return;
+            }
+            //ToString, Clone and Hash are called as methods
+            let traittp: Option<types::TraitType> = types::get_traittp_from_str(bound.to_owned());
+            if &sig.name == method && (traittp == Some(types::TraitType::ToString) || traittp == Some(types::TraitType::Clone) || traittp == Some(types::TraitType::Hash)) {
+                return;
+            }
+        }
+
+        let fmt: String = format!("Template type '{}' has no bound providing method '{}'.", template, method);
+        errors::raise_error(&fmt, errors::ErrorType::MissingTemplateBound, &errnode.pos, self.info);
+    }
+
     fn get_type_from_data(types: std::collections::HashMap<String, types::Type<'ctx>>, data: &types::Data) -> types::Type<'ctx> {
         return types.get(&data.tp.name).unwrap().clone();
     }
@@ -509,6 +1076,92 @@ impl<'ctx> CodeGen<'ctx> {
         }
     }
 
+    //The supertraits of a trait, including those of its supertraits, each following its own supertraits
+    fn get_supertraits(&self, traitnm: &String) -> Vec<String> {
+        let mut supertraits: Vec<String> = Vec::new();
+        if self.traits.get(traitnm).is_none() || self.traits.get(traitnm).unwrap().supertraits.is_none() {
+            return supertraits;
+        }
+
+        for supertrait in self.traits.get(traitnm).unwrap().supertraits.as_ref().unwrap() {
+            for name in self.get_supertraits(supertrait).into_iter().chain(std::iter::once(supertrait.clone())) {
+                if !supertraits.contains(&name) {
+                    supertraits.push(name);
+                }
+            }
+        }
+        return supertraits;
+    }
+
+    //The traits provided by template bounds: each bound and its supertraits
+    fn get_bound_traits(&self, bounds: &Vec<String>) -> Vec<String> {
+        let mut traits: Vec<String> = Vec::new();
+        for bound in bounds {
+            for name in std::iter::once(bound.clone()).chain(self.get_supertraits(bound)) {
+                if !traits.contains(&name) {
+                    traits.push(name);
+                }
+            }
+        }
+        return traits;
+    }
+
+    //The functions in the vtable of a trait: those of its supertraits, followed by its own.
+    //A builtin supertrait returning bool is called through the vtable with 'dyn' values of the trait.
+    fn get_trait_functions(&self, traitnm: &String) -> Vec<types::TemplateTraitSignature> {
+        let mut functions: Vec<types::TemplateTraitSignature> = Vec::new();
+        for name in self.get_supertraits(traitnm).iter().chain(std::iter::once(traitnm)) {
+            let sig: &types::TraitSignature = self.traits.get(name).unwrap();
+            if sig.trait_sig.is_some() {
+                functions.extend(sig.trait_sig.as_ref().unwrap().iter().cloned());
+            }
+            else if Self::is_dyn_builtin_trait(name) {
+                let dyntp: parser::Type = parser::Type {
+                    isfn: false,
+                    isclosure: false,
+                    isarr: false,
+                    isdyn: true,
+                    isgenum: false,
+                    isref: false,
+                    istuple: false,
+                    basetp: None,
+                    arrlen: None,
+                    data: Some(traitnm.to_owned()),
+                    args: None,
+                    mutability: types::DataMutablility::Immutable,
+                    refmutability: None,
+                    generic_tps: None,
+                };
+                let mut booltp: parser::Type = dyntp.clone();
+                booltp.isdyn = false;
+                booltp.data = Some(types::BasicDataType::Bool.to_string());
+
+                let nargs: usize = sig.nargs.unwrap();
+                functions.push(types::TemplateTraitSignature {
+                    name: sig.name.clone(),
+                    methodname: None,
+                    namespacename: None,
+                    template_types: Vec::new(),
+                    args: parser::Args {
+                        name: vec![String::from("self"), String::from("other")][..nargs].to_vec(),
+                        args: vec![dyntp; nargs],
+                        rettp: vec![booltp],
+                    },
+                    default: None,
+                });
+            }
+        }
+        return functions;
+    }
+
+    fn is_dyn_builtin_trait(traitnm: &String) -> bool {
+        return matches!(types::get_traittp_from_str(traitnm.to_owned()),
+                        Some(types::TraitType::Eq) | Some(types::TraitType::Ne) |
+                        Some(types::TraitType::Gt) | Some(types::TraitType::Ge) |
+                        Some(types::TraitType::Lt) | Some(types::TraitType::Le) |
+                        Some(types::TraitType::Bool) | Some(types::TraitType::Not));
+    }
+
     fn append_struct_to_vtables(&mut self, tbl: Vec<inkwell::values::PointerValue<'ctx>>, idx: i32) {
         let mut structs: Vec<inkwell::values::BasicValueEnum> = Vec::new();
         for table in &self.cur_module.vtables_vec {
@@ -551,9 +1204,15 @@ impl<'ctx> CodeGen<'ctx> {
         else {
             let func: inkwell::values::PointerValue = t.inkfunc.unwrap();
 
+            //The operands are already checked by the caller, so the function type is taken from them
+            let mut tp: types::DataType = Self::datatypes_get(self, &types::BasicDataType::Func.to_string()).unwrap().clone();
+            tp.names = Some(args.iter().map(|_| String::new()).collect());
+            tp.types = args.iter().map(|x| x.tp.clone()).collect();
+            tp.rettp = Some(Box::new(t.rettp.clone().unwrap()));
+
             args.insert(0, types::Data {
                 data: Some(inkwell::values::BasicValueEnum::PointerValue(func)),
-                tp: Self::datatypes_get(self, &types::BasicDataType::Func.to_string()).unwrap().clone(),
+                tp,
                 owned: true,
             });
 
@@ -564,12 +1223,68 @@ impl<'ctx> CodeGen<'ctx> {
     fn build_binary(&mut self, node: &parser::Node) -> types::Data<'ctx> {
         let binary: &parser::nodes::BinaryNode = node.data.binary.as_ref().unwrap();
 
-        let left: types::Data = self.compile_expr(&binary.left, BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: false}, false, false);
-        let right: types::Data = self.compile_expr(&binary.right, BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: false}, false, false);
-
-        let mut args: Vec<types::Data> = Vec::new();
+        if  binary.op == parser::nodes::BinaryOpType::AND ||
+            binary.op == parser::nodes::BinaryOpType::OR {
+            return self.build_logical(node);
+        }
 
-        let tp: types::Type = Self::get_type_from_data(self.cur_module.types.clone(), &left);
+        //For an inplace operation on an index, only evaluate the indexed value and the index once
+        let indextarget: Option<(types::Data, types::Data)> = if binary.isassign && binary.left.tp == parser::NodeType::INDEX {
+            Some(self.build_index_target(&binary.left, true))
+        }
+        else {
+            None
+        };
+
+        //For an inplace operation on an attribute, only evaluate the attribute pointer once
+        let attrtarget: Option<(inkwell::values::PointerValue, types::DataType)> = if binary.isassign && binary.left.tp == parser::NodeType::ATTR {
+            let attrn: &parser::nodes::AttrNode = binary.left.data.attr.as_ref().unwrap();
+            self.build_attr_target(&attrn.name, &attrn.attr, &binary.left, true)
+        }
+        else {
+            None
+        };
+
+        //An untyped literal on the left takes the type of the right operand, so compile the right operand first
+        let right_first: Option<types::Data> = if !binary.isassign && Self::is_untyped_literal(&binary.left) && !Self::is_untyped_literal(&binary.right) {
+            Some(self.compile_expr(&binary.right, BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: false}, false, false))
+        }
+        else {
+            None
+        };
+
+        let left: types::Data = if indextarget.is_some() {
+            let (base, index) = indextarget.clone().unwrap();
+            self.build_index_load(base, index, &binary.left)
+        }
+        else if attrtarget.is_some() {
+            let (itmptr, attrtp) = attrtarget.clone().unwrap();
+            types::Data {
+                data: Some(self.builder.build_load(itmptr, "attr")),
+                tp: attrtp,
+                owned: true,
+            }
+        }
+        else if binary.isassign && binary.left.tp == parser::NodeType::ATTR {
+            let fmt: String = format!("Cannot apply inplace operation to void attribute.");
+            errors::raise_error(&fmt, errors::ErrorType::UnexpectedVoid, &node.pos, self.info);
+        }
+        else if right_first.is_some() {
+            self.compile_expr_expecting(&binary.left, BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: false}, &right_first.as_ref().unwrap().tp.clone())
+        }
+        else {
+            self.compile_expr(&binary.left, BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: false}, false, false)
+        };
+        let right: types::Data = if right_first.is_some() {
+            right_first.unwrap()
+        }
+        else {
+            self.compile_expr_expecting(&binary.right, BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: false}, &left.tp.clone())
+        };
+
+        let mut args: Vec<types::Data> = Vec::new();
+
+        let tp: types::Type = Self::get_type_from_data(self.cur_module.types.clone(), &left);
 
         let tp_str: &String = &left.tp.name.clone();
 
@@ -607,13 +1322,46 @@ impl<'ctx> CodeGen<'ctx> {
             parser::nodes::BinaryOpType::NE => {
                 types::TraitType::Ne
             }
+            parser::nodes::BinaryOpType::BITAND => {
+                types::TraitType::BitAnd
+            }
+            parser::nodes::BinaryOpType::BITOR => {
+                types::TraitType::BitOr
+            }
+            parser::nodes::BinaryOpType::BITXOR => {
+                types::TraitType::BitXor
+            }
+            parser::nodes::BinaryOpType::SHL => {
+                types::TraitType::Shl
+            }
+            parser::nodes::BinaryOpType::SHR => {
+                types::TraitType::Shr
+            }
+            parser::nodes::BinaryOpType::AND |
+            parser::nodes::BinaryOpType::OR => {
+                unreachable!();
+            }
         };
 
+        //The shift amount is not the shifted type, so only the left operand needs the trait
+        self.check_template_bound(&binary.left, &traittp.to_string(), node);
+        if traittp != types::TraitType::Shl && traittp != types::TraitType::Shr {
+            self.check_template_bound(&binary.right, &traittp.to_string(), node);
+        }
+
         let t: &types::Trait = match tp.traits.get(&traittp.to_string()) {
             Some (v) => {
                 v
             }
             None => {
+                //Ordering operators are derived from Cmp if they are not implemented directly
+                if  (traittp == types::TraitType::Gt ||
+                    traittp == types::TraitType::Ge ||
+                    traittp == types::TraitType::Lt ||
+                    traittp == types::TraitType::Le) &&
+                    tp.traits.contains_key(&types::TraitType::Cmp.to_string()) {
+                    return self.build_cmp_ordering(tp.traits.get(&types::TraitType::Cmp.to_string()).unwrap(), args, traittp, node);
+                }
                 let fmt: String = format!("Type '{}' has no trait '{}'.", tp_str, &traittp.to_string());
                 errors::raise_error(&fmt, errors::ErrorType::MissingTrait, &node.pos, self.info);
             }
@@ -621,7 +1369,21 @@ impl<'ctx> CodeGen<'ctx> {
 
         let data: types::Data = self.call_trait(t, args, node);
 
-        if binary.isassign {
+        if indextarget.is_some() {
+            let (base, index) = indextarget.unwrap();
+            self.build_index_store(base, index, data.clone(), &binary.left);
+        }
+        else if attrtarget.is_some() {
+            let (itmptr, attrtp) = attrtarget.unwrap();
+
+            if data.tp != attrtp {
+                let fmt: String = format!("Expected '{}' type, got '{}' type.", attrtp, data.tp);
+                errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
+            }
+
+            self.builder.build_store(itmptr, data.data.unwrap());
+        }
+        else if binary.isassign {
             let name: &String = &binary.left.data.identifier.as_ref().unwrap().name;
             let ptr: Option<inkwell::values::PointerValue> = self.get_variable(name).0.unwrap().0;
 
@@ -634,8 +1396,97 @@ impl<'ctx> CodeGen<'ctx> {
 
         return data;
     }
+
+    fn build_cmp_ordering(&mut self, t: &types::Trait<'ctx>, args: Vec<types::Data<'ctx>>, traittp: types::TraitType, node: &parser::Node) -> types::Data<'ctx> {
+        let res: types::Data = self.call_trait(t, args, node);
+
+        match res.tp.tp {
+            types::BasicDataType::I8 |
+            types::BasicDataType::I16 |
+            types::BasicDataType::I32 |
+            types::BasicDataType::I64 |
+            types::BasicDataType::I128 => {}
+            _ => {
+                let fmt: String = format!("Expected signed integer result from trait 'Cmp', got '{}'.", res.tp);
+                errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, &node.pos, self.info);
+            }
+        }
+
+        let predicate: inkwell::IntPredicate = match traittp {
+            types::TraitType::Gt => inkwell::IntPredicate::SGT,
+            types::TraitType::Ge => inkwell::IntPredicate::SGE,
+            types::TraitType::Lt => inkwell::IntPredicate::SLT,
+            types::TraitType::Le => inkwell::IntPredicate::SLE,
+            _ => unreachable!(),
+        };
+
+        let resv: inkwell::values::IntValue = res.data.unwrap().into_int_value();
+        let cmp: inkwell::values::IntValue = self.builder.build_int_compare(predicate, resv, resv.get_type().const_zero(), "cmp_ordering");
+
+        let data: types::Data = types::Data {
+            data: Some(inkwell::values::BasicValueEnum::IntValue(cmp)),
+            tp: Self::datatypes_get(self, &types::BasicDataType::Bool.to_string()).unwrap().clone(),
+            owned: true,
+        };
+        return data;
+    }
+
+    fn build_logical(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        let binary: &parser::nodes::BinaryNode = node.data.binary.as_ref().unwrap();
+
+        let booltp: types::DataType = Self::datatypes_get(self, &types::BasicDataType::Bool.to_string()).unwrap().clone();
+
+        let left: types::Data = self.compile_expr(&binary.left, BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: false}, false, false);
+
+        if left.tp != booltp {
+            let fmt: String = format!("Expected 'bool' type, got '{}' type.", left.tp);
+            errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &binary.left.pos, self.info);
+        }
+
+        let left_block: inkwell::basic_block::BasicBlock = self.builder.get_insert_block().unwrap();
+        let rhs_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(left_block.get_parent().unwrap(), "logical_rhs");
+        let end_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(left_block.get_parent().unwrap(), "logical_end");
+
+        //Only evaluate the right side if the left side does not decide the result
+        if binary.op == parser::nodes::BinaryOpType::AND {
+            self.builder.build_conditional_branch(left.data.unwrap().into_int_value(), rhs_block, end_block);
+        }
+        else {
+            self.builder.build_conditional_branch(left.data.unwrap().into_int_value(), end_block, rhs_block);
+        }
+
+        self.builder.position_at_end(rhs_block);
+        self.current_block = Some(rhs_block);
+
+        let right: types::Data = self.compile_expr(&binary.right, BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: false}, false, false);
+
+        if right.tp != booltp {
+            let fmt: String = format!("Expected 'bool' type, got '{}' type.", right.tp);
+            errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &binary.right.pos, self.info);
+        }
+
+        let right_block: inkwell::basic_block::BasicBlock = self.builder.get_insert_block().unwrap();
+        self.builder.build_unconditional_branch(end_block);
+
+        self.builder.position_at_end(end_block);
+        self.current_block = Some(end_block);
+
+        let phi: inkwell::values::PhiValue = self.builder.build_phi(*self.inkwell_types.booltp, "logical_phi");
+        phi.add_incoming(&[(&left.data.unwrap(), left_block), (&right.data.unwrap(), right_block)]);
+
+        let data: types::Data = types::Data {
+            data: Some(phi.as_basic_value()),
+            tp: booltp,
+            owned: true,
+        };
+        return data;
+    }
     
     fn build_let(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        if node.data.letn.as_ref().unwrap().destructure.is_some() {
+            return self.build_let_destructure(node);
+        }
+
         let name: String = node.data.letn.as_ref().unwrap().name.clone();
         
         if !name.is_snake_case() {
@@ -657,6 +1508,28 @@ impl<'ctx> CodeGen<'ctx> {
             errors::raise_error_multi(errors::ErrorType::RedefinitionAttempt, vec![here, fmt], vec![&self.cur_module.namespaces.locals.last().unwrap().get(&name).unwrap().4, &node.pos], self.info);
         }
 
+        if node.data.letn.as_ref().unwrap().tp.is_some() {
+            self.build_generic_struct_types(node.data.letn.as_ref().unwrap().tp.as_ref().unwrap(), node);
+        }
+
+        //A name of a template type, declared or bound to a template typed expression, keeps the bounds of its template type
+        let letn: &parser::nodes::LetNode = node.data.letn.as_ref().unwrap();
+        let declared: Option<&parser::Type> = letn.tp.as_ref().filter(|x| !x.isarr && !x.isfn && !x.isdyn && !x.isgenum && !x.isref && !x.istuple);
+        let bounds: Option<(String, Vec<String>)> = if declared.is_some() &&
+            self.cur_module.namespaces.template_type_bounds.contains_key(declared.unwrap().data.as_ref().unwrap()) {
+            let template: &String = declared.unwrap().data.as_ref().unwrap();
+            Some((template.to_owned(), self.cur_module.namespaces.template_type_bounds.get(template).unwrap().clone()))
+        }
+        else if letn.expr.is_some() {
+            self.get_template_bounds(letn.expr.as_ref().unwrap())
+        }
+        else {
+            None
+        };
+        if bounds.is_some() && self.template_bounds.last().is_some() {
+            self.template_bounds.last_mut().unwrap().insert((name.clone(), node.pos.clone()), bounds.unwrap());
+        }
+
         if node.data.letn.as_ref().unwrap().expr.is_some() {
             if  node.data.letn.as_ref().unwrap().tp != None &&
                 node.data.letn.as_ref().unwrap().tp.as_ref().unwrap().isdyn {
@@ -677,7 +1550,7 @@ impl<'ctx> CodeGen<'ctx> {
                     errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
                 }
 
-                for sig in self.traits.get(&dyntp.name).unwrap().trait_sig.as_ref().unwrap() {
+                for sig in self.get_trait_functions(&dyntp.name) {
                     if sig.template_types.len() > 0 {
                         let fmt: String = format!("Trait '{}' is not trait object safe because function '{}' is templated.", dyntp.name, sig.name);
                         errors::raise_error(&fmt, errors::ErrorType::TraitIsNotTraitObjSafe, &node.pos, self.info);
@@ -696,7 +1569,17 @@ impl<'ctx> CodeGen<'ctx> {
                 self.cur_module.namespaces.locals.last_mut().unwrap().insert(name, (Some(ptr), dyntp, node.data.letn.as_ref().unwrap().mutability, types::DataOwnership {owned: true, transferred: None, mut_borrowed: false}, node.pos.clone(), InitializationStatus::Initialized));
             }
             else { 
-                let right: types::Data = self.compile_expr(&node.data.letn.as_ref().unwrap().expr.as_ref().unwrap(), BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, false, false);
+                if node.data.letn.as_ref().unwrap().tp != None && node.data.letn.as_ref().unwrap().expr.as_ref().unwrap().tp == parser::NodeType::CALL {
+                    self.expected_tp = Some(Self::get_llvm_from_type(&self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, &node.data.letn.as_ref().unwrap().tp.as_ref().unwrap(), node).0);
+                }
+
+                let right: types::Data = if node.data.letn.as_ref().unwrap().tp != None {
+                    let (tp, _) = Self::get_llvm_from_type(&self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, &node.data.letn.as_ref().unwrap().tp.as_ref().unwrap(), node);
+                    self.compile_expr_expecting(&node.data.letn.as_ref().unwrap().expr.as_ref().unwrap(), BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, &tp)
+                }
+                else {
+                    self.compile_expr(&node.data.letn.as_ref().unwrap().expr.as_ref().unwrap(), BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, false, false)
+                };
                 if right.data.is_some() && !(right.tp.tp == types::BasicDataType::Array && right.data.unwrap().is_pointer_value()){
                     let rt_tp: types::DataType = right.tp.clone();
 
@@ -714,7 +1597,15 @@ impl<'ctx> CodeGen<'ctx> {
                     }
 
                     
-                    let ptr: inkwell::values::PointerValue = Self::alloca(self, right.data.unwrap().get_type(), name.as_str());
+                    //A local owning a closure drops the closure it held before, as a loop may bind it more than once
+                    let ptr: inkwell::values::PointerValue = if right.tp.tp == types::BasicDataType::Closure && !right.tp.is_ref {
+                        let ptr: inkwell::values::PointerValue = self.alloca_closure_owner(name.as_str());
+                        self.build_local_drop(ptr, node);
+                        ptr
+                    }
+                    else {
+                        Self::alloca(self, right.data.unwrap().get_type(), name.as_str())
+                    };
                         
                     self.builder.build_store(ptr, right.data.unwrap());
 
@@ -746,7 +1637,12 @@ impl<'ctx> CodeGen<'ctx> {
                 let (tp, inktp) = Self::get_llvm_from_type(&self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, &node.data.letn.as_ref().unwrap().tp.as_ref().unwrap(), node);
 
                 if tp.tp != types::BasicDataType::Void{
-                    let ptr: inkwell::values::PointerValue = Self::alloca(self, Self::get_basic_from_any(inktp).unwrap(), name.as_str());
+                    let ptr: inkwell::values::PointerValue = if tp.tp == types::BasicDataType::Closure && !tp.is_ref {
+                        self.alloca_closure_owner(name.as_str())
+                    }
+                    else {
+                        Self::alloca(self, Self::get_basic_from_any(inktp).unwrap(), name.as_str())
+                    };
     
                     let rt_tp: types::DataType = tp.clone();
                     if node.data.letn.as_ref().unwrap().tp != None {
@@ -773,11 +1669,183 @@ impl<'ctx> CodeGen<'ctx> {
         return data;
     }
     
+    //Define a variable for each element of a tuple
+    fn build_let_destructure(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        let letn: &parser::nodes::LetNode = node.data.letn.as_ref().unwrap();
+        let names: &Vec<(String, types::DataMutablility)> = letn.destructure.as_ref().unwrap();
+
+        if letn.tp.is_some() {
+            self.build_generic_struct_types(letn.tp.as_ref().unwrap(), node);
+            if letn.expr.as_ref().unwrap().tp == parser::NodeType::CALL {
+                self.expected_tp = Some(Self::get_llvm_from_type(&self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, letn.tp.as_ref().unwrap(), node).0);
+            }
+        }
+
+        let right: types::Data = self.compile_expr(letn.expr.as_ref().unwrap(), BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, false, false);
+
+        if letn.tp.is_some() {
+            let (tp, _) = Self::get_llvm_from_type(&self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, letn.tp.as_ref().unwrap(), node);
+            if tp != right.tp {
+                let fmt: String = format!("Expected '{}' type, got '{}' type.", tp.to_string(), right.tp.to_string());
+                errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
+            }
+        }
+
+        if !types::is_tuple_datatype(&right.tp) || right.tp.is_ref {
+            let fmt: String = format!("Expected tuple, got '{}'.", right.tp);
+            errors::raise_error(&fmt, errors::ErrorType::ExpectedTuple, &node.pos, self.info);
+        }
+
+        if names.len() != right.tp.types.len() {
+            let fmt: String = format!("Expected {} names for '{}', got {}.", right.tp.types.len(), right.tp, names.len());
+            errors::raise_error(&fmt, errors::ErrorType::TupleLengthMismatch, &node.pos, self.info);
+        }
+
+        let ptr: inkwell::values::PointerValue = if right.data.unwrap().is_pointer_value() {
+            right.data.unwrap().into_pointer_value()
+        }
+        else {
+            let ptr: inkwell::values::PointerValue = Self::alloca(self, right.data.unwrap().get_type(), "tuple");
+            self.builder.build_store(ptr, right.data.unwrap());
+            ptr
+        };
+
+        let mut idx: u32 = 0;
+        for ((name, mutability), tp) in izip![names, &right.tp.types] {
+            let elem: Option<inkwell::values::BasicValueEnum> = if tp.tp != types::BasicDataType::Void {
+                let itmptr: inkwell::values::PointerValue = self.builder.build_struct_gep(ptr, idx, name.as_str()).expect("GEP Error");
+                idx += 1;
+                Some(self.builder.build_load(itmptr, name.as_str()))
+            }
+            else {
+                None
+            };
+
+            if name.get(0..1).unwrap() == "_" {
+                continue;
+            }
+
+            if !name.is_snake_case() {
+                errors::show_warning(errors::WarningType::ExpectedSnakeCase, vec![String::from(""), name.to_snake_case()], vec![String::from("Expected snake case"), String::from("Convert to this: ")], &node.pos, self.info)
+            }
+
+            if self.cur_module.namespaces.locals.last().unwrap().get(name).is_some() {
+                let fmt: String = format!("Name '{}' is already defined in namespace.", name);
+                let here: String = format!("'{}' defined here.", name);
+                errors::raise_error_multi(errors::ErrorType::RedefinitionAttempt, vec![here, fmt], vec![&self.cur_module.namespaces.locals.last().unwrap().get(name).unwrap().4, &node.pos], self.info);
+            }
+
+            if elem.is_some() {
+                let elemptr: inkwell::values::PointerValue = Self::alloca(self, elem.unwrap().get_type(), name.as_str());
+                self.builder.build_store(elemptr, elem.unwrap());
+
+                self.cur_module.namespaces.locals.last_mut().unwrap().insert(name.clone(), (Some(elemptr), tp.clone(), *mutability, types::DataOwnership {owned: true, transferred: None, mut_borrowed: false}, node.pos.clone(), InitializationStatus::Initialized));
+            }
+            else {
+                self.cur_module.namespaces.locals.last_mut().unwrap().insert(name.clone(), (None, tp.clone(), *mutability, types::DataOwnership {owned: true, transferred: None, mut_borrowed: false}, node.pos.clone(), InitializationStatus::Initialized));
+            }
+        }
+
+        let data: types::Data = types::Data {
+            data: None,
+            tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
+            owned: true,
+        };
+        return data;
+    }
+
+    //Evaluate a 'const' item, or define the global of a 'static' item
+    fn build_global(&mut self, node: &parser::Node) {
+        let letn: &parser::nodes::LetNode = node.data.letn.as_ref().unwrap();
+        let name: &String = &letn.name;
+
+        if !name.is_screaming_snake_case() {
+            errors::show_warning(errors::WarningType::ExpectedScreamingSnakeCase, vec![String::from(""), name.to_screaming_snake_case()], vec![String::from("Expected screaming snake case"), String::from("Convert to this: ")], &node.pos, self.info)
+        }
+
+        if  self.cur_module.namespaces.consts.contains_key(name) ||
+            self.cur_module.namespaces.statics.contains_key(name) ||
+            self.get_function(name).is_some() {
+            let fmt: String = format!("Name '{}' is already defined.", name);
+            errors::raise_error(&fmt, errors::ErrorType::RedefinitionAttempt, &node.pos, self.info);
+        }
+
+        let (tp, anytp) = Self::get_llvm_from_type(&self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, letn.tp.as_ref().unwrap(), node);
+
+        let value: consteval::ConstValue = consteval::ConstEvaluator::new(&self.cur_module.namespaces, &self.cur_module.datatypes, &self.datatypes, self.info).eval_expecting(letn.expr.as_ref().unwrap(), &tp.tp);
+
+        let right: types::Data = self.build_const_value(&value);
+        if tp != right.tp {
+            let fmt: String = format!("Expected '{}' type, got '{}' type.", tp.to_string(), right.tp.to_string());
+            errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
+        }
+
+        if node.tp == parser::NodeType::CONST {
+            self.cur_module.namespaces.consts.insert(name.to_owned(), (value, node.pos.clone()));
+        }
+        else if right.data.is_some() {
+            let global: inkwell::values::GlobalValue = self.module.add_global(Self::get_basic_from_any(anytp).unwrap(), None, name.as_str());
+            global.set_initializer(&right.data.unwrap());
+            global.set_constant(letn.mutability == types::DataMutablility::Immutable);
+
+            self.cur_module.namespaces.statics.insert(name.to_owned(), (Some(global.as_pointer_value()), tp, letn.mutability, types::DataOwnership {owned: true, transferred: None, mut_borrowed: false}, node.pos.clone(), InitializationStatus::Initialized));
+        }
+        else {
+            self.cur_module.namespaces.statics.insert(name.to_owned(), (None, tp, letn.mutability, types::DataOwnership {owned: true, transferred: None, mut_borrowed: false}, node.pos.clone(), InitializationStatus::Initialized));
+        }
+    }
+
+    //Build the value of a constant, which is inlined wherever it is used
+    fn build_const_value(&mut self, value: &consteval::ConstValue) -> types::Data<'ctx> {
+        let data: Option<inkwell::values::BasicValueEnum> = match value {
+            consteval::ConstValue::Int(v, tp) => {
+                let inttp: inkwell::types::IntType = Self::get_anytp_from_tp(self.context, &self.inkwell_types, Self::datatypes_get(self, &tp.to_string()).unwrap(), &self.cur_module.datatypes).unwrap().into_int_type();
+                Some(inkwell::values::BasicValueEnum::IntValue(inttp.const_int_from_string(v.to_string().as_str(), inkwell::types::StringRadix::Decimal).unwrap()))
+            }
+            consteval::ConstValue::UInt(v, tp) => {
+                let inttp: inkwell::types::IntType = Self::get_anytp_from_tp(self.context, &self.inkwell_types, Self::datatypes_get(self, &tp.to_string()).unwrap(), &self.cur_module.datatypes).unwrap().into_int_type();
+                Some(inkwell::values::BasicValueEnum::IntValue(inttp.const_int_from_string(v.to_string().as_str(), inkwell::types::StringRadix::Decimal).unwrap()))
+            }
+            consteval::ConstValue::Float(v, tp) => {
+                let floattp: inkwell::types::FloatType = Self::get_anytp_from_tp(self.context, &self.inkwell_types, Self::datatypes_get(self, &tp.to_string()).unwrap(), &self.cur_module.datatypes).unwrap().into_float_type();
+                Some(inkwell::values::BasicValueEnum::FloatValue(floattp.const_float(*v)))
+            }
+            consteval::ConstValue::Bool(v) => {
+                Some(inkwell::values::BasicValueEnum::IntValue(self.inkwell_types.booltp.const_int(*v as u64, false)))
+            }
+            consteval::ConstValue::Str(v) => {
+                return self.build_string_data(v);
+            }
+            consteval::ConstValue::Void => {
+                None
+            }
+        };
+
+        let tp: types::DataType = match value {
+            consteval::ConstValue::Int(_, tp) |
+            consteval::ConstValue::UInt(_, tp) |
+            consteval::ConstValue::Float(_, tp) => Self::datatypes_get(self, &tp.to_string()).unwrap().clone(),
+            consteval::ConstValue::Bool(_) => Self::datatypes_get(self, &types::BasicDataType::Bool.to_string()).unwrap().clone(),
+            _ => Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
+        };
+
+        return types::Data {
+            data,
+            tp,
+            owned: true,
+        };
+    }
+
     fn build_loadname(&mut self, node: &parser::Node, borrow_options: BorrowOptions, get_enum_id: bool) -> types::Data<'ctx> {
         let name: String = node.data.identifier.as_ref().unwrap().name.clone();
 
         let (ptr, mut tp) = match self.get_variable(&name).0 {
             None => {
+                if self.cur_module.namespaces.consts.contains_key(&name) {
+                    let value: consteval::ConstValue = self.cur_module.namespaces.consts.get(&name).unwrap().0.clone();
+                    return self.build_const_value(&value);
+                }
+
                 let res: Option<(inkwell::values::PointerValue, types::DataType, ForwardDeclarationType)> = self.get_function(&name);
                 if res==None {
                     let mut corpus = CorpusBuilder::new()
@@ -823,18 +1891,19 @@ impl<'ctx> CodeGen<'ctx> {
         }
 
         let owner: types::DataOwnership = self.get_variable(&name).0.unwrap().3.clone();
+        let scope: usize = self.get_variable(&name).1;
 
         if borrow_options.give_ownership {
             let var = self.get_variable(&name);
             let mut locals = self.cur_module.namespaces.locals.last().unwrap().clone();
             if  borrow_options.mut_borrow &&
-                locals.get(&name).unwrap().3.mut_borrowed {
+                var.0.unwrap().3.mut_borrowed {
                 let transferred: String = String::from(format!("'{}' was transferred here.", name));
                 let fmt: String = format!("Name '{}' cannot be mutable borrowed more than once.", name);
                 errors::raise_error_multi(errors::ErrorType::NameMutableBorrowed, vec![transferred, fmt], vec![&self.get_variable(&name).0.unwrap().3.transferred.as_ref().unwrap(), &node.pos], self.info);
             }
             if  borrow_options.mut_borrow &&
-                locals.get(&name).unwrap().2 == types::DataMutablility::Immutable {
+                var.0.unwrap().2 == types::DataMutablility::Immutable {
                     let fmt: String = format!("Cannot take mutable reference from immutable name '{}'.", name);
                     errors::raise_error(&fmt, errors::ErrorType::MutableRefFromImmutable, &node.pos, self.info);
             }
@@ -863,8 +1932,13 @@ impl<'ctx> CodeGen<'ctx> {
                 errors::raise_error(&fmt, errors::ErrorType::ReferenceUnownedData, &node.pos, self.info);
             }
             if !borrow_options.get_ptr {
+                let mut value: inkwell::values::BasicValueEnum = self.builder.build_load(ptr.unwrap(), name.as_str());
+                if borrow_options.give_ownership && tp.tp == types::BasicDataType::Closure && !tp.is_ref {
+                    value = self.build_closure_move(&name, scope, ptr.unwrap(), value, &node.pos);
+                }
+
                 let data: types::Data = types::Data {
-                    data: Some(self.builder.build_load(ptr.unwrap(), name.as_str())),
+                    data: Some(value),
                     tp,
                     owned: owner.owned,
                 };
@@ -911,7 +1985,28 @@ impl<'ctx> CodeGen<'ctx> {
             errors::raise_error(&fmt, errors::ErrorType::RedefinitionAttempt, &node.pos, self.info);
         }
 
+        //Functions of generic structs are registered when forward declaring, and compiled per instance on use
+        if  altnm.is_none() && template_types.is_none() &&
+            (node.data.func.as_ref().unwrap().methodname.is_some() || node.data.func.as_ref().unwrap().namespacename.is_some()) &&
+            self.cur_module.namespaces.generic_structs.contains_key(&node.data.func.as_ref().unwrap().name) {
+            let data: types::Data = types::Data {
+                data: None,
+                tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
+                owned: true,
+            };
+            return data;
+        }
+
         if node.data.func.as_ref().unwrap().template_types.len() > 0 && template_types.is_none() {
+            for bounds in node.data.func.as_ref().unwrap().template_bounds.values() {
+                for bound in bounds {
+                    if !self.traits.contains_key(bound) {
+                        let fmt: String = format!("Trait '{}' not found.", bound);
+                        errors::raise_error(&fmt, errors::ErrorType::TraitNotFound, &node.pos, self.info);
+                    }
+                }
+            }
+
             let mut name: String = node.data.func.as_ref().unwrap().name.clone();
 
             let mut instance: TemplateFunctionInstance = TemplateFunctionInstance::Unrelated;
@@ -944,6 +2039,7 @@ impl<'ctx> CodeGen<'ctx> {
 
         if template_types.is_none() {
             for arg in &args.args {
+                self.build_generic_struct_types(arg, node);
                 let (data, tp) = Self::get_llvm_from_type(&self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, &arg, node);
                 datatypes.push(data);
                 mutability.push(arg.mutability);
@@ -977,6 +2073,7 @@ impl<'ctx> CodeGen<'ctx> {
         let rettp_any: inkwell::types::AnyTypeEnum;
         
         if rettp_opt.is_none() {
+            self.build_generic_struct_types(args.rettp.last().unwrap(), node);
             let rettp_full: (types::DataType, inkwell::types::AnyTypeEnum) = Self::get_llvm_from_type(&self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, &args.rettp.last().unwrap(), node);
             rettp_tp = rettp_full.0;
             rettp_any = rettp_full.1;
@@ -1164,7 +2261,10 @@ impl<'ctx> CodeGen<'ctx> {
         //Setup locals
         let prev_locals = self.cur_module.namespaces.locals.to_owned();
         self.cur_module.namespaces.locals = Vec::new();
+        self.cur_module.namespaces.locals.push(self.cur_module.namespaces.statics.clone());
         self.cur_module.namespaces.locals.push(std::collections::HashMap::new());
+        let drop_scope: usize = self.drop_scope;
+        self.drop_scope = 1;
         
         //Setup arguments
         let mut idx: u32 = 0;
@@ -1188,6 +2288,9 @@ impl<'ctx> CodeGen<'ctx> {
                     ptr = Self::alloca(self, argv.unwrap().get_type(), name.as_str());
                 
                     self.builder.build_store(ptr, argv.unwrap());
+                    if tp.tp == types::BasicDataType::Closure && !tp.is_ref {
+                        self.closure_owners.push(ptr);
+                    }
 
                     self.cur_module.namespaces.locals.last_mut().unwrap().insert(name.to_string(), (Some(ptr), tp.clone(), mutability.get(idx_mut).unwrap().clone(), types::DataOwnership {owned: true, transferred: None, mut_borrowed: false}, node.pos.clone(), InitializationStatus::Initialized));
                 }
@@ -1198,12 +2301,29 @@ impl<'ctx> CodeGen<'ctx> {
             idx_mut += 1;
         }
 
+        //Template typed arguments, keyed by their name and declaration, with the bounds of their template type
+        let mut template_bounds: std::collections::HashMap<(String, parser::Position), (String, Vec<String>)> = std::collections::HashMap::new();
+        if template_types.is_some() {
+            for (name, arg) in std::iter::zip(&args.name, &args.args) {
+                if  !arg.isarr && !arg.isfn && !arg.isdyn && !arg.isgenum && !arg.isref &&
+                    node.data.func.as_ref().unwrap().template_types.contains(arg.data.as_ref().unwrap()) {
+                    let bounds: Vec<String> = node.data.func.as_ref().unwrap().template_bounds.get(arg.data.as_ref().unwrap()).cloned().unwrap_or(Vec::new());
+                    template_bounds.insert((name.to_owned(), node.pos.clone()), (arg.data.as_ref().unwrap().to_owned(), bounds));
+                }
+            }
+        }
+        self.template_bounds.push(template_bounds);
+
         /////// Code generation start:
 
         let retv: types::Data = self.compile(&node.data.func.as_ref().unwrap().blocks, true, true);
+        self.build_scope_drops(self.drop_scope, node.data.func.as_ref().unwrap().blocks.last(), node);
+
+        self.template_bounds.pop();
         
         //Reset locals
         self.cur_module.namespaces.locals = prev_locals;
+        self.drop_scope = drop_scope;
 
         /////// End
         
@@ -1243,1173 +2363,3610 @@ impl<'ctx> CodeGen<'ctx> {
         return data;
     }
     
-    fn build_assign(&mut self, node: &parser::Node) -> types::Data<'ctx> {
-        let right: types::Data = self.compile_expr(&node.data.assign.as_ref().unwrap().expr, BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, false, false);
+    //The nodes directly contained in a node, in evaluation order
+    fn child_nodes(node: &parser::Node) -> Vec<&parser::Node> {
+        let data: &parser::nodes::NodeData = &node.data;
+        let mut children: Vec<&parser::Node> = Vec::new();
 
-        let name: String = node.data.assign.as_ref().unwrap().name.clone();
-        
-        if self.get_variable(&name).0.is_none() {
-            let fmt: String = format!("Name '{}' is not defined in namespace.", name);
-            errors::raise_error(&fmt, errors::ErrorType::NameNotFound, &node.pos, self.info);
+        if data.binary.is_some() {
+            children.push(&data.binary.as_ref().unwrap().left);
+            children.push(&data.binary.as_ref().unwrap().right);
         }
-
-        if right.data == None{
-            let fmt: String = format!("Cannot assign to '{}'.", right.tp.to_string());
-            errors::raise_error(&fmt, errors::ErrorType::CannotAssign, &node.pos, self.info);
+        if data.letn.is_some() && data.letn.as_ref().unwrap().expr.is_some() {
+            children.push(data.letn.as_ref().unwrap().expr.as_ref().unwrap());
         }
-
-        if  self.get_variable(&name).0.unwrap().2 == types::DataMutablility::Immutable &&
-            self.get_variable(&name).0.unwrap().5 == InitializationStatus::Initialized &&
-            !self.get_variable(&name).0.unwrap().3.mut_borrowed {
-            let fmt: String = format!("Cannot assign to immutable variable.");
-            errors::raise_error(&fmt, errors::ErrorType::ImmutableAssign, &node.pos, self.info);
+        if data.assign.is_some() {
+            children.push(&data.assign.as_ref().unwrap().expr);
         }
-
-        let mut alttp: types::DataType = self.get_variable(&name).0.unwrap().1.to_owned();
-        alttp.is_ref = false;
-
-        if self.get_variable(&name).0.unwrap().1 != right.tp && alttp != right.tp {
-            let fmt: String = format!("Expected '{}' type, got '{}' type.", self.get_variable(&name).0.unwrap().1.tp.to_string(), right.tp.to_string());
-            errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
+        if data.call.is_some() {
+            children.push(&data.call.as_ref().unwrap().name);
+            children.extend(data.call.as_ref().unwrap().args.iter());
+        }
+        if data.ret.is_some() && data.ret.as_ref().unwrap().expr.is_some() {
+            children.push(data.ret.as_ref().unwrap().expr.as_ref().unwrap());
+        }
+        if data.to.is_some() {
+            children.push(&data.to.as_ref().unwrap().left);
+        }
+        if data.unary.is_some() {
+            children.push(&data.unary.as_ref().unwrap().right);
+        }
+        if data.initst.is_some() {
+            let mut members: Vec<&parser::Node> = data.initst.as_ref().unwrap().members.values().collect();
+            members.sort_by_key(|x| (x.pos.line, x.pos.startcol));
+            children.extend(members);
+        }
+        if data.attr.is_some() {
+            children.push(&data.attr.as_ref().unwrap().name);
+            if data.attr.as_ref().unwrap().expr.is_some() {
+                children.push(data.attr.as_ref().unwrap().expr.as_ref().unwrap());
+            }
+        }
+        if data.attrassign.is_some() {
+            children.push(&data.attrassign.as_ref().unwrap().name);
+            children.push(&data.attrassign.as_ref().unwrap().expr);
+        }
+        if data.arr.is_some() {
+            children.extend(data.arr.as_ref().unwrap().elements.iter());
+        }
+        if data.ifn.is_some() {
+            for (cond, blocks) in &data.ifn.as_ref().unwrap().ifs {
+                children.push(cond);
+                children.extend(blocks.iter());
+            }
+            if data.ifn.as_ref().unwrap().else_opt.is_some() {
+                children.extend(data.ifn.as_ref().unwrap().else_opt.as_ref().unwrap().iter());
+            }
+        }
+        if data.loopn.is_some() {
+            if data.loopn.as_ref().unwrap().expr.is_some() {
+                children.push(data.loopn.as_ref().unwrap().expr.as_ref().unwrap());
+            }
+            children.extend(data.loopn.as_ref().unwrap().block.iter());
+        }
+        if data.is.is_some() {
+            children.push(&data.is.as_ref().unwrap().left);
+        }
+        if data.matchn.is_some() {
+            children.push(&data.matchn.as_ref().unwrap().expr);
+            for (_, _, blocks) in &data.matchn.as_ref().unwrap().patterns {
+                children.extend(blocks.iter());
+            }
+        }
+        if data.nameattr.is_some() && data.nameattr.as_ref().unwrap().expr.is_some() {
+            children.push(data.nameattr.as_ref().unwrap().expr.as_ref().unwrap());
+        }
+        if data.index.is_some() {
+            children.push(&data.index.as_ref().unwrap().name);
+            children.push(&data.index.as_ref().unwrap().index);
+            if data.index.as_ref().unwrap().expr.is_some() {
+                children.push(data.index.as_ref().unwrap().expr.as_ref().unwrap());
+            }
+        }
+        if data.range.is_some() {
+            children.push(&data.range.as_ref().unwrap().start);
+            children.push(&data.range.as_ref().unwrap().end);
+        }
+        if data.forn.is_some() {
+            children.push(&data.forn.as_ref().unwrap().expr);
+            children.extend(data.forn.as_ref().unwrap().block.iter());
+        }
+        if data.breakn.is_some() && data.breakn.as_ref().unwrap().expr.is_some() {
+            children.push(data.breakn.as_ref().unwrap().expr.as_ref().unwrap());
+        }
+        if data.closure.is_some() {
+            children.extend(data.closure.as_ref().unwrap().blocks.iter());
         }
 
-        let ptr: Option<inkwell::values::PointerValue> = self.get_variable(&name).0.unwrap().0;
-
-        if ptr.is_some() {
-            let ptr: inkwell::values::PointerValue = self.get_variable(&name).0.unwrap().0.unwrap().clone();
-            if self.get_variable(&name).0.unwrap().1.is_dyn {
-                let typ: types::Type = Self::get_type_from_data(self.cur_module.types.clone(), &right);
-                let (dyntp, _) = Self::get_llvm_from_type(&self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, &node.data.letn.as_ref().unwrap().tp.as_ref().unwrap(), node);
-
-                if !typ.traits.contains_key(&dyntp.name) {
-                    let fmt: String = format!("'{}' type does not implement '{}' trait.", right.tp.to_string(), dyntp.name);
-                    errors::raise_error(&fmt, errors::ErrorType::MissingTrait, &node.pos, self.info);
-                }
-
-                if right.tp.tp != types::BasicDataType::Struct {
-                    let fmt: String = format!("Expected struct, got '{}'.", right.tp.tp);
-                    errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
-                }
-
-                for sig in self.traits.get(&dyntp.name).unwrap().trait_sig.as_ref().unwrap() {
-                    if sig.template_types.len() > 0 {
-                        let fmt: String = format!("Trait '{}' is not trait object safe because function '{}' is templated.", dyntp.name, sig.name);
-                        errors::raise_error(&fmt, errors::ErrorType::TraitIsNotTraitObjSafe, &node.pos, self.info);
-                    }
+        return children;
+    }
+
+    //Names used by a closure body that are not bound inside of it, in order of first use
+    fn closure_free_names(node: &parser::Node, bound: &mut Vec<String>, names: &mut Vec<(String, parser::Position)>) {
+        let mut used: Option<&String> = None;
+        if node.tp == parser::NodeType::IDENTIFIER {
+            used = Some(&node.data.identifier.as_ref().unwrap().name);
+        }
+        else if node.tp == parser::NodeType::ASSIGN {
+            used = Some(&node.data.assign.as_ref().unwrap().name);
+        }
+        else if node.tp == parser::NodeType::MATCH {
+            for (_, binding, _) in &node.data.matchn.as_ref().unwrap().patterns {
+                if binding.is_some() {
+                    bound.push(binding.as_ref().unwrap().to_owned());
                 }
+            }
+        }
+        else if node.tp == parser::NodeType::CLOSURE {
+            bound.extend(node.data.closure.as_ref().unwrap().args.name.iter().cloned());
+        }
+        else if node.tp == parser::NodeType::FOR {
+            Self::closure_free_names(&node.data.forn.as_ref().unwrap().expr, bound, names);
+            bound.push(node.data.forn.as_ref().unwrap().name.to_owned());
+            for child in &node.data.forn.as_ref().unwrap().block {
+                Self::closure_free_names(child, bound, names);
+            }
+            return;
+        }
 
-                let idptr = self.builder.build_struct_gep(ptr, 0u32, "idptr").expect("GEP error");
-                self.builder.build_store(idptr, self.inkwell_types.i32tp.const_int(*self.cur_module.namespaces.structid.get(&right.tp.name).unwrap() as u64, false));
+        for child in Self::child_nodes(node) {
+            Self::closure_free_names(child, bound, names);
+        }
 
-                let itmptr = self.builder.build_struct_gep(ptr, 1u32, "item").expect("GEP error");
-                let structptr: inkwell::values::PointerValue = self.builder.build_malloc(right.data.unwrap().get_type(), "struct_ptr").expect("Malloc error");
-                self.builder.build_store(structptr, right.data.unwrap());
-                self.builder.build_store(itmptr, self.builder.build_pointer_cast(structptr, itmptr.get_type().get_element_type().into_pointer_type(), "st_bitcast"));
-                
-                let idx: usize = self.get_variable(&name).1;
+        if used.is_some() && !bound.contains(used.unwrap()) && !names.iter().any(|x| &x.0 == used.unwrap()) {
+            names.push((used.unwrap().to_owned(), node.pos.clone()));
+        }
+        if node.tp == parser::NodeType::LET {
+            bound.push(node.data.letn.as_ref().unwrap().name.to_owned());
+        }
+    }
 
-                self.cur_module.namespaces.locals.get_mut(idx).unwrap().insert(name, (Some(ptr), dyntp, node.data.letn.as_ref().unwrap().mutability, types::DataOwnership {owned: true, transferred: None, mut_borrowed: false}, node.pos.clone(), InitializationStatus::Initialized));
-            }
-            else {
-                self.builder.build_store(ptr, right.data.unwrap());
+    //Whether a closure body returns early, not counting the bodies of nested closures
+    fn closure_has_return(node: &parser::Node) -> bool {
+        if node.tp == parser::NodeType::RETURN {
+            return true;
+        }
+        if node.tp == parser::NodeType::CLOSURE {
+            return false;
+        }
+        return Self::child_nodes(node).iter().any(|x| Self::closure_has_return(x));
+    }
 
-                let idx: usize = self.get_variable(&name).1;
-                
-                self.cur_module.namespaces.locals.get_mut(idx).unwrap().insert(name, (Some(ptr), right.tp.clone(), types::DataMutablility::Mutable, types::DataOwnership {owned: true, transferred: None, mut_borrowed: false}, node.pos.clone(), InitializationStatus::Initialized));
-            }
+    fn fn_type_from_any(tp: inkwell::types::AnyTypeEnum<'ctx>, params: &[inkwell::types::BasicMetadataTypeEnum<'ctx>]) -> inkwell::types::FunctionType<'ctx> {
+        if tp.is_void_type() {
+            return tp.into_void_type().fn_type(params, false);
+        }
+        if tp.is_function_type() {
+            return tp.into_function_type().ptr_type(inkwell::AddressSpace::from(0u16)).fn_type(params, false);
         }
+        return Self::get_basic_from_any(tp).unwrap().fn_type(params, false);
+    }
 
-        return right;
+    fn closure_env_tp(&self, captures: &Vec<ClosureCapture<'ctx>>) -> inkwell::types::StructType<'ctx> {
+        let fields: Vec<inkwell::types::BasicTypeEnum> = captures.iter().filter(|x| x.data.is_some()).map(|x| x.data.unwrap().get_type()).collect();
+        return self.context.struct_type(&fields[..], false);
     }
-    
-    fn build_call(&mut self, node: &parser::Node) -> types::Data<'ctx> {
-        let mut args: Vec<types::Data> = Vec::new();
-        let mut tp_name: String = String::from("");
-        let mut tp: Option<types::Type> = None;
 
-        let mut have_template_method: bool = false;
+    //Attach debug information to a function generated by the compiler, and give the location of its instructions
+    fn generated_debug_location(&mut self, func: inkwell::values::FunctionValue<'ctx>, node: &parser::Node) -> inkwell::debug_info::DILocation<'ctx> {
+        let sub_type = self.dibuilder.create_subroutine_type(
+            self.dicompile_unit.get_file(),
+            None,
+            &[],
+            inkwell::debug_info::DIFlagsConstants::PUBLIC);
 
-        if  node.data.call.as_ref().unwrap().name.tp == parser::NodeType::ATTR {
-            let attr: &String = &node.data.call.as_ref().unwrap().name.data.attr.as_ref().unwrap().attr;
+        let func_scope: inkwell::debug_info::DISubprogram = self.dibuilder.create_function(
+            self.dicompile_unit.as_debug_info_scope(),
+            func.get_name().to_str().unwrap(),
+            Some(func.get_name().to_str().unwrap()),
+            self.dicompile_unit.get_file(),
+            node.pos.line as u32,
+            sub_type,
+            true,
+            true,
+            node.pos.line as u32,
+            inkwell::debug_info::DIFlagsConstants::PUBLIC,
+            true);
 
-            let base: types::Data = self.compile_expr(&node.data.call.as_ref().unwrap().name.data.attr.as_ref().unwrap().name, BorrowOptions{ give_ownership: false, get_ptr: true, mut_borrow: false}, false, false);
+        func.set_subprogram(func_scope);
 
-            if base.tp.is_dyn {
-                let idptr: inkwell::values::PointerValue = self.builder.build_struct_gep(base.data.unwrap().into_pointer_value(), 0u32, "id_ptr").expect("GEP error");
+        return self.dibuilder.create_debug_location(
+            self.context,
+            node.pos.line as u32,
+            node.pos.startcol as u32,
+            func_scope.as_debug_info_scope(),
+            None);
+    }
 
-                let vtable: inkwell::values::PointerValue = unsafe { self.builder.build_in_bounds_gep(self.cur_module.vtables.unwrap().as_pointer_value(), &[self.builder.build_load(idptr, "id").into_int_value(), self.inkwell_types.i32tp.const_zero()], "vtable") };
-                
-                let idx: usize = self.traits.get(&base.tp.name).unwrap().trait_sig.as_ref().unwrap().iter().position(|x| &x.name == attr).unwrap();
-                
-                let method: inkwell::values::PointerValue = self.builder.build_load( unsafe { self.builder.build_in_bounds_gep(vtable, &[self.inkwell_types.i32tp.const_int(idx as u64, false), self.inkwell_types.i32tp.const_zero()], "method_ptr") }, "method").into_pointer_value();
+    //The function dropping the environment of a closure which does not own it
+    fn get_closure_nodrop(&mut self, node: &parser::Node) -> inkwell::values::FunctionValue<'ctx> {
+        if self.module.get_function("closure.nodrop").is_some() {
+            return self.module.get_function("closure.nodrop").unwrap();
+        }
 
-                let mut mtp: types::DataType = Self::datatypes_get(self, &types::BasicDataType::Func.to_string()).unwrap().clone();
+        let i8ptrtp: inkwell::types::PointerType = self.inkwell_types.i8tp.ptr_type(inkwell::AddressSpace::from(0u16));
+        let func: inkwell::values::FunctionValue = self.module.add_function("closure.nodrop", self.context.void_type().fn_type(&[inkwell::types::BasicMetadataTypeEnum::PointerType(i8ptrtp)], false), None);
 
-                let mut tsig: Option<types::TemplateTraitSignature> = None;
-                for sig in self.traits.get(&base.tp.name).unwrap().trait_sig.as_ref().unwrap() {
-                    if &sig.name == attr {
-                        tsig = Some(sig.clone());
-                        break;
-                    }
-                }
+        let location: inkwell::debug_info::DILocation = self.generated_debug_location(func, node);
+        let prev_location: Option<inkwell::debug_info::DILocation> = self.builder.get_current_debug_location();
+        let prev_block: Option<inkwell::basic_block::BasicBlock> = self.builder.get_insert_block();
 
-                if tsig.is_none() {
-                    let fmt: String = format!("Type '{}' has no method '{}'.", base.tp, attr);
-                    errors::raise_error(&fmt, errors::ErrorType::StructAttrNotFound, &node.pos, self.info);
-                }
+        let entry: inkwell::basic_block::BasicBlock = self.context.append_basic_block(func, "entry");
+        self.builder.position_at_end(entry);
+        self.builder.set_current_debug_location(self.context, location);
+        self.builder.build_return(None);
 
-                let func_args = tsig.unwrap().args;
+        if prev_block.is_some() {
+            self.builder.position_at_end(prev_block.unwrap());
+        }
+        if prev_location.is_some() {
+            self.builder.set_current_debug_location(self.context, prev_location.unwrap());
+        }
 
-                let mut datatypes: Vec<types::DataType> = Vec::new();
-                let mut names: Vec<String> = Vec::new();
-                
-                for arg in &func_args.args {
-                    let (data, _) = Self::get_llvm_from_type(&self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, &arg, node);
-                    
-                    if data.tp != types::BasicDataType::Void {
-                        names.push(String::from(""));
-                    }   
+        return func;
+    }
 
-                    datatypes.push(data);                  
-                }
-                
-                let rettp_full: (types::DataType, inkwell::types::AnyTypeEnum) = Self::get_llvm_from_type(&self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, &func_args.rettp.last().unwrap(), node);
-                let rettp_tp: types::DataType = rettp_full.0;
+    //Build the function dropping the environment of a 'move' closure, which drops the closures it captured and frees it
+    fn build_closure_env_drop(&mut self, captures: &Vec<ClosureCapture<'ctx>>, node: &parser::Node) -> inkwell::values::FunctionValue<'ctx> {
+        let i8ptrtp: inkwell::types::PointerType = self.inkwell_types.i8tp.ptr_type(inkwell::AddressSpace::from(0u16));
+        let func: inkwell::values::FunctionValue = self.module.add_function("closure.drop", self.context.void_type().fn_type(&[inkwell::types::BasicMetadataTypeEnum::PointerType(i8ptrtp)], false), None);
 
-                mtp.types = datatypes;
-                mtp.names = Some(names);
-                mtp.rettp = Some(Box::new(rettp_tp));
-                
-                args.push(types::Data {
-                    data: Some(inkwell::values::BasicValueEnum::PointerValue(method)),
-                    tp: mtp,
-                    owned: true,
-                });
+        let location: inkwell::debug_info::DILocation = self.generated_debug_location(func, node);
+        let prev_location: Option<inkwell::debug_info::DILocation> = self.builder.get_current_debug_location();
+        let prev_block: Option<inkwell::basic_block::BasicBlock> = self.builder.get_insert_block();
 
-                args.push(types::Data {
-                    data: Some(self.builder.build_load(base.data.unwrap().into_pointer_value(), "instance")),
-                    tp: base.tp.clone(),
-                    owned: base.owned,
-                });
+        let entry: inkwell::basic_block::BasicBlock = self.context.append_basic_block(func, "entry");
+        self.builder.position_at_end(entry);
+        self.builder.set_current_debug_location(self.context, location);
 
-                tp_name = Self::datatypes_get(self, &types::BasicDataType::Func.to_string()).unwrap().clone().name.clone();
-                
-                tp = Some(Self::get_type_from_data(self.cur_module.types.clone(), args.first().unwrap()));
+        let envtp: inkwell::types::StructType = self.closure_env_tp(captures);
+        let env: inkwell::values::PointerValue = self.builder.build_pointer_cast(func.get_nth_param(0).unwrap().into_pointer_value(), envtp.ptr_type(inkwell::AddressSpace::from(0u16)), "env");
+
+        let mut field: u32 = 0;
+        for capture in captures.iter().filter(|x| x.data.is_some()) {
+            if capture.tp.tp == types::BasicDataType::Closure && !capture.tp.is_ref {
+                let fieldptr: inkwell::values::PointerValue = self.builder.build_struct_gep(env, field, capture.name.as_str()).expect("GEP error");
+                let value: inkwell::values::StructValue = self.builder.build_load(fieldptr, capture.name.as_str()).into_struct_value();
+                self.build_closure_drop(value, node);
             }
-            else if base.tp.methods.get(attr).is_some() {
-                let method: &types::Method = base.tp.methods.get(attr).unwrap();
-                if method.tp == types::MethodType::Fn {
-                    let data: types::Data = types::Data {
-                        data: Some(inkwell::values::BasicValueEnum::PointerValue(method.func.unwrap())),
-                        tp: method.functp.clone(),
-                        owned: true,
-                    };
+            field += 1;
+        }
 
-                    args.push(data.clone());
-                    if method.isinstance {
-                        args.push(types::Data {
-                            data: Some(self.builder.build_load(base.data.unwrap().into_pointer_value(), &base.tp.name)),
-                            tp: base.tp.clone(),
-                            owned: base.owned,
-                        });
-                    }
+        self.builder.build_free(env);
+        self.builder.build_return(None);
 
-                    tp_name = method.functp.name.clone();
-                    
-                    tp = Some(Self::get_type_from_data(self.cur_module.types.clone(), &data));
-                }
-                else {
-                    let data: types::Data = types::Data {
-                        data: None,
-                        tp: method.functp.to_owned(),
-                        owned: true,
-                    };
-                    
-                    tp_name = method.functp.name.clone();
+        if prev_block.is_some() {
+            self.builder.position_at_end(prev_block.unwrap());
+        }
+        if prev_location.is_some() {
+            self.builder.set_current_debug_location(self.context, prev_location.unwrap());
+        }
 
-                    tp = Some(Self::get_type_from_data(self.cur_module.types.clone(), &data.clone()));
+        return func;
+    }
 
-                    args.push(data);
-                    if method.isinstance {
-                        args.push(types::Data {
-                            data: Some(self.builder.build_load(base.data.unwrap().into_pointer_value(), &base.tp.name)),
-                            tp: base.tp.clone(),
-                            owned: base.owned,
-                        });
-                    }
-                    else if method.isinstanceptr {
-                        args.push(base.clone());
-                    }
-                    else if method.ismutinstanceptr {
-                        let base_alt: types::Data = self.compile_expr(&node.data.call.as_ref().unwrap().name.data.attr.as_ref().unwrap().name, BorrowOptions{ give_ownership: true, get_ptr: true, mut_borrow: true}, false, false);
-                        args.push(base_alt.clone());
-                    }
-                }
-            }
-            else if self.cur_module.namespaces.template_functions_sig.contains_key(&(base.tp.name.clone()+"."+node.data.call.as_ref().unwrap().name.data.attr.as_ref().unwrap().attr.to_owned().as_str()).to_owned()) {
-                have_template_method = true;
-            }
-            else{
-                let fmt: String = format!("Type '{}' has no method '{}'.", base.tp, attr);
-                errors::raise_error(&fmt, errors::ErrorType::StructAttrNotFound, &node.pos, self.info);
-            }
-        }
-        else if node.data.call.as_ref().unwrap().name.tp == parser::NodeType::IDENTIFIER &&
-                self.cur_module.namespaces.template_functions_sig.contains_key(&node.data.call.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name) {
-            // Do nothing yet
+    //Drop a closure, which frees its environment if the closure owns it
+    fn build_closure_drop(&mut self, value: inkwell::values::StructValue<'ctx>, node: &parser::Node) {
+        let i8ptrtp: inkwell::types::PointerType = self.inkwell_types.i8tp.ptr_type(inkwell::AddressSpace::from(0u16));
+        let droptp: inkwell::types::FunctionType = self.context.void_type().fn_type(&[inkwell::types::BasicMetadataTypeEnum::PointerType(i8ptrtp)], false);
+
+        let env: inkwell::values::PointerValue = self.builder.build_extract_value(value, 1, "env").unwrap().into_pointer_value();
+        let drop: inkwell::values::PointerValue = self.builder.build_extract_value(value, 2, "drop").unwrap().into_pointer_value();
+
+        //Selecting the function avoids branching, so dropping does not change the current block
+        let nodrop: inkwell::values::PointerValue = self.get_closure_nodrop(node).as_global_value().as_pointer_value();
+        let isnull: inkwell::values::IntValue = self.builder.build_is_null(drop, "no_drop");
+        let dropfn: inkwell::values::PointerValue = self.builder.build_select(isnull, self.builder.build_pointer_cast(nodrop, i8ptrtp, "nodrop"), drop, "drop_fn").into_pointer_value();
+        let dropfn: inkwell::values::PointerValue = self.builder.build_pointer_cast(dropfn, droptp.ptr_type(inkwell::AddressSpace::from(0u16)), "drop_fn");
+
+        self.builder.build_call(inkwell::values::CallableValue::try_from(dropfn).unwrap(), &[inkwell::values::BasicMetadataValueEnum::PointerValue(env)], "drop");
+    }
+
+    //Make a local no longer drop the closure it holds, as the closure was moved or dropped
+    fn build_closure_forget(&mut self, ptr: inkwell::values::PointerValue<'ctx>) {
+        let dropptr: inkwell::values::PointerValue = self.builder.build_struct_gep(ptr, 2, "drop_ptr").expect("GEP error");
+        self.builder.build_store(dropptr, self.inkwell_types.i8tp.ptr_type(inkwell::AddressSpace::from(0u16)).const_null());
+    }
+
+    //Drop the closure held by a local owning it
+    fn build_local_drop(&mut self, ptr: inkwell::values::PointerValue<'ctx>, node: &parser::Node) {
+        let value: inkwell::values::StructValue = self.builder.build_load(ptr, "dropped").into_struct_value();
+        self.build_closure_drop(value, node);
+        self.build_closure_forget(ptr);
+    }
+
+    //Allocate a local owning a closure, which starts without a closure to drop
+    fn alloca_closure_owner(&mut self, name: &str) -> inkwell::values::PointerValue<'ctx> {
+        let ptr: inkwell::values::PointerValue = Self::alloca(self, *self.inkwell_types.closuretp, name);
+
+        let instr: inkwell::values::InstructionValue = ptr.as_instruction().unwrap();
+        if instr.get_next_instruction().is_some() {
+            self.builder.position_before(&instr.get_next_instruction().unwrap());
         }
         else {
-            let callable: types::Data = self.compile_expr(&node.data.call.as_ref().unwrap().name, BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: false}, false, false);
-            tp_name = callable.tp.name.clone();
-            args.push(callable);
-            tp = Some(Self::get_type_from_data(self.cur_module.types.clone(), &args.first().unwrap()));
+            self.builder.position_at_end(instr.get_parent().unwrap());
         }
-        
-        if args.first().unwrap().tp.types.len() > 0 && args.first().unwrap().tp.tp == types::BasicDataType::WrapperFunc {
-            for (arg, tp) in izip![&node.data.call.as_ref().unwrap().args, &args.first().unwrap().tp.types.clone()]{
-                let v: types::Data = self.compile_expr(arg, BorrowOptions{ give_ownership: true, get_ptr: tp.is_ref, mut_borrow: false}, false, false); 
-                if v.tp.tp != types::BasicDataType::Struct || v.tp.is_ref || tp.is_ref {
-                    args.push(v);
-                }
-                else {
-                    args.push(types::Data {
-                        data: Some(self.builder.build_load(v.data.unwrap().into_pointer_value(), &v.tp.name)),
-                        tp: v.tp.clone(),
-                        owned: v.owned,
-                    });
-                }
-            }
+        self.builder.build_store(ptr, self.inkwell_types.closuretp.const_zero());
+        self.builder.position_at_end(self.current_block.unwrap());
+
+        self.closure_owners.push(ptr);
+        return ptr;
+    }
+
+    //Move the closure held by a local. An owning local no longer drops it, and a local which does not own its closure gives a copy which does not drop it
+    fn build_closure_move(&mut self, name: &String, idx: usize, ptr: inkwell::values::PointerValue<'ctx>, value: inkwell::values::BasicValueEnum<'ctx>, pos: &parser::Position) -> inkwell::values::BasicValueEnum<'ctx> {
+        if idx > 0 && idx < self.drop_scope {
+            let fmt: String = format!("Cannot move captured closure '{}' out of the closure.", name);
+            errors::raise_error(&fmt, errors::ErrorType::MoveOutOfCapture, pos, self.info);
         }
-        else {
-            for arg in &node.data.call.as_ref().unwrap().args {
-                let v: types::Data = self.compile_expr(arg, BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, false, false); 
-                
-                if v.tp.tp != types::BasicDataType::Struct || v.tp.is_ref {
-                    args.push(v);
+
+        if self.closure_owners.contains(&ptr) {
+            self.build_closure_forget(ptr);
+            return value;
+        }
+
+        let i8ptrtp: inkwell::types::PointerType = self.inkwell_types.i8tp.ptr_type(inkwell::AddressSpace::from(0u16));
+        return inkwell::values::BasicValueEnum::StructValue(self.builder.build_insert_value(value.into_struct_value(), i8ptrtp.const_null(), 2, "closure").unwrap().into_struct_value());
+    }
+
+    //Drop the closures owned by the locals of the scopes from 'from'. A local giving the result of the scopes moves its closure to the result instead
+    fn build_scope_drops(&mut self, from: usize, result: Option<&parser::Node>, node: &parser::Node) {
+        if self.builder.get_insert_block().unwrap().get_terminator().is_some() {
+            return;
+        }
+
+        let mut moved: Option<inkwell::values::PointerValue> = None;
+        if result.is_some() && result.unwrap().tp == parser::NodeType::IDENTIFIER {
+            moved = self.get_variable(&result.unwrap().data.identifier.as_ref().unwrap().name).0.and_then(|x| x.0);
+        }
+
+        let mut owners: Vec<(usize, String, inkwell::values::PointerValue)> = Vec::new();
+        for idx in from..self.cur_module.namespaces.locals.len() {
+            for (name, var) in self.cur_module.namespaces.locals.get(idx).unwrap() {
+                if var.0.is_none() || !self.closure_owners.contains(&var.0.unwrap()) {
+                    continue;
                 }
-                else {
-                    args.push(types::Data {
-                        data: Some(self.builder.build_load(v.data.unwrap().into_pointer_value(), &v.tp.name)),
-                        tp: v.tp.clone(),
-                        owned: v.owned,
-                    });
+                //A name moved in an inner scope is also recorded there, but it is dropped by the scope declaring it
+                if (0..idx).any(|x| self.cur_module.namespaces.locals.get(x).unwrap().get(name).map(|y| y.0) == Some(var.0)) {
+                    continue;
                 }
+                owners.push((idx, name.to_owned(), var.0.unwrap()));
             }
         }
 
-        if  node.data.call.as_ref().unwrap().name.tp == parser::NodeType::IDENTIFIER &&
-            self.cur_module.namespaces.template_functions_sig.contains_key(&node.data.call.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name) {
-            let func: parser::Node = self.cur_module.namespaces.template_functions_sig.get(&node.data.call.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name).unwrap().0.to_owned();
-            let mut fn_types: Vec<types::DataType> = Vec::new();
-            let mut templates: std::collections::HashMap<String, types::DataType> = std::collections::HashMap::new();
-            
-            for (data, arg) in izip![&args, &func.data.func.as_ref().unwrap().args.args] {
-                if  !arg.isarr &&
-                    !arg.isfn &&
-                    !arg.isdyn &&
-                    !arg.isgenum &&
-                    !arg.isref && !self.cur_module.datatypes.contains_key(&arg.data.as_ref().unwrap().clone()) {
-                    if !templates.contains_key(&arg.data.as_ref().unwrap().clone()) {
-                        if !func.data.func.as_ref().unwrap().template_types.contains(&arg.data.as_ref().unwrap().clone()) {
-                            let fmt: String = format!("Unknown type '{}'.", arg.data.as_ref().unwrap().clone());
-                            errors::raise_error(&fmt, errors::ErrorType::UnknownTemplateType, &node.pos, self.info);    
-                        }
-                        templates.insert(arg.data.as_ref().unwrap().clone(), data.tp.clone());
-                    }
-                    fn_types.push(templates.get(&arg.data.as_ref().unwrap().clone()).unwrap().to_owned());
-                }
-                else {
-                    fn_types.push(Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, arg, node).0.to_owned());
-                }
-            }
-
-            let rettp: &parser::Type = func.data.func.as_ref().unwrap().args.rettp.last().unwrap();
-            let rettp_tp: types::DataType;
-            if  !rettp.isarr &&
-                !rettp.isfn &&
-                !rettp.isdyn &&
-                !rettp.isgenum &&
-                !rettp.isref &&
-                !self.cur_module.datatypes.contains_key(&rettp.data.as_ref().unwrap().clone()) {
-                if !templates.contains_key(&rettp.data.as_ref().unwrap().clone()) {
-                    let fmt: String = format!("Unknown type '{}'.", rettp.data.as_ref().unwrap().clone());
-                    errors::raise_error(&fmt, errors::ErrorType::UnknownTemplateType, &node.pos, self.info); 
-                }
-                rettp_tp = templates.get(&rettp.data.as_ref().unwrap().clone()).unwrap().to_owned();
+        //Inner scopes are dropped first
+        owners.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        for (_, _, ptr) in owners {
+            if moved == Some(ptr) {
+                self.build_closure_forget(ptr);
             }
             else {
-                rettp_tp = Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, rettp, node).0.to_owned();
+                self.build_local_drop(ptr, node);
             }
-            
-            let current_block: inkwell::basic_block::BasicBlock = self.current_block.unwrap();
-            self.build_func(&func, None, Some(fn_types), Some(rettp_tp));
-            self.current_block = Some(current_block);
+        }
+    }
 
-            let func_v = self.cur_module.namespaces.template_functions.last().unwrap().to_owned();
-            self.cur_module.namespaces.template_functions.pop();
-            if !self.cur_module.namespaces.template_functions.contains(&func_v) {
-                self.cur_module.namespaces.template_functions.push(func_v.to_owned());
+    //Move the body of a function to one with the same parameters
+    fn move_function_body(&mut self, from: inkwell::values::FunctionValue<'ctx>, to: inkwell::values::FunctionValue<'ctx>) {
+        for (old, new) in std::iter::zip(from.get_param_iter(), to.get_param_iter()) {
+            match old {
+                inkwell::values::BasicValueEnum::IntValue(v) => v.replace_all_uses_with(new.into_int_value()),
+                inkwell::values::BasicValueEnum::FloatValue(v) => v.replace_all_uses_with(new.into_float_value()),
+                inkwell::values::BasicValueEnum::PointerValue(v) => v.replace_all_uses_with(new.into_pointer_value()),
+                inkwell::values::BasicValueEnum::StructValue(v) => v.replace_all_uses_with(new.into_struct_value()),
+                inkwell::values::BasicValueEnum::ArrayValue(v) => v.replace_all_uses_with(new.into_array_value()),
+                inkwell::values::BasicValueEnum::VectorValue(v) => v.replace_all_uses_with(new.into_vector_value()),
             }
-            
-            tp_name = func_v.1.name.clone();
-            let callable: types::Data = types::Data {
-                data: Some(inkwell::values::BasicValueEnum::PointerValue(func_v.2.as_global_value().as_pointer_value())),
-                tp: func_v.1.clone(),
-                owned: true,
-            };
+        }
 
-            args.insert(0usize, callable);
-            tp = Some(Self::get_type_from_data(self.cur_module.types.clone(), &args.first().unwrap()));
-            self.builder.position_at_end(self.current_block.unwrap());
+        let anchor: inkwell::basic_block::BasicBlock = self.context.append_basic_block(to, "entry");
+        let mut prev: inkwell::basic_block::BasicBlock = anchor;
+        for block in from.get_basic_blocks() {
+            let _ = block.move_after(prev);
+            prev = block;
         }
+        let _ = unsafe { anchor.delete() };
 
-        if have_template_method {
-            let base: types::Data = self.compile_expr(&node.data.call.as_ref().unwrap().name.data.attr.as_ref().unwrap().name, BorrowOptions{ give_ownership: false, get_ptr: true, mut_borrow: false}, false, false);
+        if from.get_subprogram().is_some() {
+            to.set_subprogram(from.get_subprogram().unwrap());
+        }
+    }
 
-            if  self.cur_module.namespaces.template_functions_sig.contains_key(&(base.tp.name.clone()+"."+node.data.call.as_ref().unwrap().name.data.attr.as_ref().unwrap().attr.to_owned().as_str()).to_owned()) {
-                let func: parser::Node = self.cur_module.namespaces.template_functions_sig.get(&(base.tp.name.clone()+"."+node.data.call.as_ref().unwrap().name.data.attr.as_ref().unwrap().attr.to_owned().as_str()).to_owned()).unwrap().0.to_owned();
-                let instance_meth: TemplateFunctionInstance = self.cur_module.namespaces.template_functions_sig.get(&(base.tp.name.clone()+"."+node.data.call.as_ref().unwrap().name.data.attr.as_ref().unwrap().attr.to_owned().as_str()).to_owned()).unwrap().1.to_owned();
-                
-                let mut fn_types: Vec<types::DataType> = Vec::new();
-                let mut templates: std::collections::HashMap<String, types::DataType> = std::collections::HashMap::new();
-                
-                let mut idx: i32 = 0;
-                for arg in &func.data.func.as_ref().unwrap().args.args {
-                    if  !arg.isarr &&
-                        !arg.isfn &&
-                        !arg.isdyn &&
-                        !arg.isgenum &&
-                        !arg.isref && !self.cur_module.datatypes.contains_key(&arg.data.as_ref().unwrap().clone()) && idx > 0 {
-                        if !templates.contains_key(&arg.data.as_ref().unwrap().clone()) {
-                            if !func.data.func.as_ref().unwrap().template_types.contains(&arg.data.as_ref().unwrap().clone()) {
-                                let fmt: String = format!("Unknown type '{}'.", arg.data.as_ref().unwrap().clone());
-                                errors::raise_error(&fmt, errors::ErrorType::UnknownTemplateType, &node.pos, self.info);    
-                            }
-                            templates.insert(arg.data.as_ref().unwrap().clone(), args.get((idx-1) as usize).unwrap().tp.clone());
-                        }
-                        fn_types.push(templates.get(&arg.data.as_ref().unwrap().clone()).unwrap().to_owned());
-                    }
-                    else if !arg.isarr &&
-                            !arg.isfn &&
-                            !arg.isdyn &&
-                            !arg.isgenum &&
-                            !arg.isref &&
-                            idx == 0 &&
-                            !self.cur_module.datatypes.contains_key(&arg.data.as_ref().unwrap().clone()) && instance_meth != TemplateFunctionInstance::Instance {
-                        if !templates.contains_key(&arg.data.as_ref().unwrap().clone()) {
-                            if !func.data.func.as_ref().unwrap().template_types.contains(&arg.data.as_ref().unwrap().clone()) {
-                                let fmt: String = format!("Unknown type '{}'.", arg.data.as_ref().unwrap().clone());
-                                errors::raise_error(&fmt, errors::ErrorType::UnknownTemplateType, &node.pos, self.info);    
-                            }
-                            templates.insert(arg.data.as_ref().unwrap().clone(), args.get(0).unwrap().tp.clone());
-                        }
-                        fn_types.push(templates.get(&arg.data.as_ref().unwrap().clone()).unwrap().to_owned());
-                    }
-                    else if idx == 0 && !self.cur_module.datatypes.contains_key(&arg.data.as_ref().unwrap().clone()) && instance_meth == TemplateFunctionInstance::Instance  {
-                        let fmt: String = format!("First argument for template method may not be template.");
-                        errors::raise_error(&fmt, errors::ErrorType::MethodTemplateFunctionHasFirstTemplate, &node.pos, self.info);
-                    }
-                    else {
-                        fn_types.push(Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, arg, node).0.to_owned());
-                    }
-                    idx += 1;
-                }
-
-                let rettp: &parser::Type = func.data.func.as_ref().unwrap().args.rettp.last().unwrap();
-                let rettp_tp: types::DataType;
-                if  !rettp.isarr &&
-                    !rettp.isfn &&
-                    !rettp.isdyn &&
-                    !rettp.isgenum &&
-                    !rettp.isref &&
-                    !self.cur_module.datatypes.contains_key(&rettp.data.as_ref().unwrap().clone()) {
-                    if !templates.contains_key(&rettp.data.as_ref().unwrap().clone()) {
-                        let fmt: String = format!("Unknown type '{}'.", rettp.data.as_ref().unwrap().clone());
-                        errors::raise_error(&fmt, errors::ErrorType::UnknownTemplateType, &node.pos, self.info); 
-                    }
-                    rettp_tp = templates.get(&rettp.data.as_ref().unwrap().clone()).unwrap().to_owned();
-                }
-                else {
-                    rettp_tp = Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, rettp, node).0.to_owned();
-                }
-                
-                let current_block: inkwell::basic_block::BasicBlock = self.current_block.unwrap();
-                self.build_func(&func, None, Some(fn_types), Some(rettp_tp));
-                self.current_block = Some(current_block);
+    fn build_closure(&mut self, node: &parser::Node, expected: Option<types::DataType<'ctx>>) -> types::Data<'ctx> {
+        let closure: &parser::nodes::ClosureNode = node.data.closure.as_ref().unwrap();
 
-                let func_v = self.cur_module.namespaces.template_functions.last().unwrap().to_owned();
-                self.cur_module.namespaces.template_functions.pop();
-                if !self.cur_module.namespaces.template_functions.contains(&func_v) {
-                    self.cur_module.namespaces.template_functions.push(func_v.to_owned());
-                }
-                
-                tp_name = func_v.1.name.clone();
-                let callable: types::Data = types::Data {
-                    data: Some(inkwell::values::BasicValueEnum::PointerValue(func_v.2.as_global_value().as_pointer_value())),
-                    tp: func_v.1.clone(),
-                    owned: true,
-                };
+        let mut datatypes: Vec<types::DataType> = Vec::new();
+        let mut inktypes: Vec<inkwell::types::BasicMetadataTypeEnum> = Vec::new();
+        for arg in &closure.args.args {
+            self.build_generic_struct_types(arg, node);
+            let (data, tp) = Self::get_llvm_from_type(&self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, &arg, node);
+            datatypes.push(data);
 
-                args.insert(0usize, callable.clone());
-                tp = Some(Self::get_type_from_data(self.cur_module.types.clone(), &callable));
-                self.builder.position_at_end(self.current_block.unwrap());
-                if instance_meth == TemplateFunctionInstance::Instance {
-                    args.insert(1usize, types::Data {
-                        data: Some(self.builder.build_load(base.data.unwrap().into_pointer_value(), &base.tp.name)),
-                        tp: base.tp.clone(),
-                        owned: base.owned,
-                    });
-                }
+            let res: Option<inkwell::types::BasicMetadataTypeEnum> = Self::get_basicmeta_from_any(tp);
+            if res.is_some() {
+                inktypes.push(res.unwrap());
             }
         }
 
+        //Captures are the names used by the body that are locals of the enclosing function, statics are not captured
+        let mut bound: Vec<String> = closure.args.name.clone();
+        let mut names: Vec<(String, parser::Position)> = Vec::new();
+        for block in &closure.blocks {
+            Self::closure_free_names(block, &mut bound, &mut names);
+        }
 
-        let t: &types::Trait = match tp.as_ref().unwrap().traits.get(&types::TraitType::Call.to_string()) {
-            Some (v) => {
-                v
+        let mut captures: Vec<ClosureCapture> = Vec::new();
+        for (name, pos) in names {
+            let (var, idx) = self.get_variable(&name);
+            if var.is_none() || idx == 0 {
+                continue;
             }
-            None => {
-                let fmt: String = format!("Type '{}' has no trait '{}'.", tp_name, &types::TraitType::Call.to_string());
-                errors::raise_error(&fmt, errors::ErrorType::MissingTrait, &node.pos, self.info);
+            let (ptr, tp, mutability, owner, declpos, status) = var.unwrap().clone();
+
+            if status == InitializationStatus::Uninitialized {
+                let fmt: String = format!("Name '{}' is not necessarily initialized.", name);
+                errors::raise_error(&fmt, errors::ErrorType::NameNotInitialized, &pos, self.info);
             }
-        };
-        
-        let data: types::Data = self.call_trait(t, args, node);
 
-        return data;
-    }
+            let data: Option<inkwell::values::BasicValueEnum> = if closure.ismove {
+                if !owner.owned && !tp.is_ref {
+                    let transferred: String = String::from(format!("'{}' was transferred here.", name));
+                    let fmt: String = format!("Name '{}' is not owned.", name);
+                    errors::raise_error_multi(errors::ErrorType::NameNotOwned, vec![transferred, fmt], vec![owner.transferred.as_ref().unwrap(), &pos], self.info);
+                }
 
-    fn build_return(&mut self, node: &parser::Node) -> types::Data<'ctx> {
-        let retv: types::Data = if node.data.ret.as_ref().unwrap().expr.is_some() { self.compile_expr(&node.data.ret.as_ref().unwrap().expr.as_ref().unwrap(), BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, false, false) } else { types::Data {
-            data: None,
-            tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
-            owned: true,
-        } };
+                //Moving a name into the closure transfers its ownership
+                self.cur_module.namespaces.locals.get_mut(idx).unwrap().insert(name.clone(), (ptr, tp.clone(), mutability, types::DataOwnership {owned: false, transferred: Some(pos.clone()), mut_borrowed: false}, declpos, status));
 
-        if self.expected_rettp==None {
-            let fmt: String = format!("Cannot return outside of function.");
-            errors::raise_error(&fmt, errors::ErrorType::ReturnOutsideOfFunction, &node.pos, self.info);            
-        }
+                let value: Option<inkwell::values::BasicValueEnum> = ptr.map(|x| self.builder.build_load(x, name.as_str()));
+                if value.is_some() && tp.tp == types::BasicDataType::Closure && !tp.is_ref {
+                    Some(self.build_closure_move(&name, idx, ptr.unwrap(), value.unwrap(), &pos))
+                }
+                else {
+                    value
+                }
+            }
+            else {
+                if ptr.is_some() && !owner.owned {
+                    let fmt: String = format!("Cannot take reference of unowned data.");
+                    errors::raise_error(&fmt, errors::ErrorType::ReferenceUnownedData, &pos, self.info);
+                }
 
-        if retv.tp != *self.expected_rettp.as_ref().unwrap() {
-            let fmt: String = format!("Expected '{}' return type, got '{}'.", &self.expected_rettp.as_ref().unwrap().name, retv.tp.name);
-            errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
-        }
+                ptr.map(|x| inkwell::values::BasicValueEnum::PointerValue(x))
+            };
 
-        if retv.data.is_some() {
-            if !retv.owned {
-                let fmt: String = format!("Return value is not owned.");
-                errors::raise_error(&fmt, errors::ErrorType::ReturnValueNotOwned, if node.data.ret.as_ref().unwrap().expr.is_some() { &node.data.ret.as_ref().unwrap().expr.as_ref().unwrap().pos } else { &node.pos }, self.info);
-            }
-            self.builder.build_return(Some(&retv.data.unwrap())); 
-        }
-        else {
-            self.builder.build_return(None);
+            captures.push(ClosureCapture {
+                name,
+                data,
+                tp,
+                ismove: closure.ismove,
+                pos,
+            });
         }
-  
-        return retv;
-    }
 
-    fn build_as(&mut self, node: &parser::Node) -> types::Data<'ctx> {
-        let left: types::Data = self.compile_expr(&node.data.to.as_ref().unwrap().left, BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: false}, false, false);     
-        let arg: &parser::Type = &node.data.to.as_ref().unwrap().tp;  
-        if arg.isfn {
-            let fmt: String = format!("Non primitive cast from '{}' to 'fn'.", left.tp);
-            errors::raise_error(&fmt, errors::ErrorType::InvalidCast, &node.pos, self.info);
+        let isfn: bool = expected.is_some() && expected.as_ref().unwrap().tp == types::BasicDataType::Func;
+        if isfn && captures.len() > 0 {
+            let fmt: String = format!("Closure capturing '{}' cannot be used as 'fn'.", captures.first().unwrap().name);
+            errors::raise_error(&fmt, errors::ErrorType::CapturingClosureAsFn, &captures.first().unwrap().pos, self.info);
         }
-        let tp_name: &String = &arg.data.as_ref().unwrap();
 
-        if Self::datatypes_get(self, tp_name).is_none() {
-            let fmt: String = format!("Unknown type '{}'.", tp_name);
-            errors::raise_error(&fmt, errors::ErrorType::UnknownType, &node.pos, self.info);
-        } 
-        let tp: &types::DataType = &Self::datatypes_get(self, tp_name).unwrap();
+        //Without a return type, it is the type of the body
+        let rettp: Option<(types::DataType, inkwell::types::AnyTypeEnum)> = if closure.args.rettp.len() > 0 {
+            self.build_generic_struct_types(closure.args.rettp.last().unwrap(), node);
+            Some(Self::get_llvm_from_type(&self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, &closure.args.rettp.last().unwrap(), node))
+        }
+        else {
+            if closure.blocks.iter().any(|x| Self::closure_has_return(x)) {
+                let fmt: String = format!("Closure containing 'return' must declare its return type.");
+                errors::raise_error(&fmt, errors::ErrorType::ExpectedClosureReturnType, &node.pos, self.info);
+            }
+            None
+        };
 
-        let anytp: Option<inkwell::types::AnyTypeEnum> = Self::get_anytp_from_tp(self.context, &self.inkwell_types, tp.clone(), &self.cur_module.datatypes);
+        let (func, rettp_tp) = self.build_closure_func(node, &datatypes, &inktypes, &captures, rettp, isfn);
 
-        if !anytp.is_none() && anytp.unwrap().is_int_type() && left.data.unwrap().is_int_value() {
-            let res: inkwell::values::IntValue = self.builder.build_int_cast(left.data.unwrap().into_int_value(), anytp.unwrap().into_int_type(), "cast");
+        if isfn {
+            let mut tp: types::DataType = Self::datatypes_get(self, &types::BasicDataType::Func.to_string()).unwrap().clone();
+            tp.names = Some(closure.args.name.clone());
+            tp.types = datatypes;
+            tp.mutability = expected.unwrap().mutability;
+            tp.rettp = Some(Box::new(rettp_tp));
 
             return types::Data {
-                data: Some(inkwell::values::BasicValueEnum::IntValue(res)),
-                tp: tp.clone(),
+                data: Some(inkwell::values::BasicValueEnum::PointerValue(func.as_global_value().as_pointer_value())),
+                tp,
                 owned: true,
             };
         }
-        else if !anytp.is_none() && anytp.unwrap().is_float_type() && left.data.unwrap().is_float_value() {
-            let res: inkwell::values::FloatValue = self.builder.build_float_cast(left.data.unwrap().into_float_value(), anytp.unwrap().into_float_type(), "fcast");
 
-            return types::Data {
-                data: Some(inkwell::values::BasicValueEnum::FloatValue(res)),
-                tp: tp.clone(),
-                owned: true,
-            };
-        }
-        else if !anytp.is_none() && anytp.unwrap().is_float_type() && left.data.unwrap().is_int_value() {
-            let res: inkwell::values::FloatValue = left.data.unwrap().into_int_value().const_signed_to_float(anytp.unwrap().into_float_type());
-            
-            return types::Data {
-                data: Some(inkwell::values::BasicValueEnum::FloatValue(res)),
-                tp: tp.clone(),
-                owned: true,
-            };
-        }
-        else if !anytp.is_none() && anytp.unwrap().is_int_type() && left.data.unwrap().is_float_value() {
-            let res: inkwell::values::IntValue = if builtin_types::int_issigned(tp.clone()) {
-                self.builder.build_float_to_signed_int(left.data.unwrap().into_float_value(), anytp.unwrap().into_int_type(), "ftoi")
+        let i8ptrtp: inkwell::types::PointerType = self.inkwell_types.i8tp.ptr_type(inkwell::AddressSpace::from(0u16));
+
+        //The environment of a 'move' closure lives on the heap, so the closure may outlive the enclosing function, and it is freed when the closure is dropped.
+        //Other closures borrow their captures, so they cannot outlive the enclosing function and their environment lives on its stack.
+        let (env, drop): (inkwell::values::PointerValue, inkwell::values::PointerValue) = if captures.iter().any(|x| x.data.is_some()) {
+            let envtp: inkwell::types::StructType = self.closure_env_tp(&captures);
+            let (envptr, drop): (inkwell::values::PointerValue, inkwell::values::PointerValue) = if closure.ismove {
+                let drop: inkwell::values::FunctionValue = self.build_closure_env_drop(&captures, node);
+                (self.builder.build_malloc(envtp, "env").expect("Malloc error"), self.builder.build_pointer_cast(drop.as_global_value().as_pointer_value(), i8ptrtp, "drop_bitcast"))
             }
             else {
-                self.builder.build_float_to_unsigned_int(left.data.unwrap().into_float_value(), anytp.unwrap().into_int_type(), "ftoui")
+                (Self::alloca(self, envtp, "env"), i8ptrtp.const_null())
             };
 
-            return types::Data {
-                data: Some(inkwell::values::BasicValueEnum::IntValue(res)),
-                tp: tp.clone(),
-                owned: true,
-            };
-        } 
-        else {
-            let fmt: String = format!("Non primitive cast from '{}' to '{}'.", left.tp, tp_name);
-            errors::raise_error(&fmt, errors::ErrorType::InvalidCast, &node.pos, self.info);
+            let mut field: u32 = 0;
+            for capture in captures.iter().filter(|x| x.data.is_some()) {
+                let fieldptr: inkwell::values::PointerValue = self.builder.build_struct_gep(envptr, field, capture.name.as_str()).expect("GEP error");
+                self.builder.build_store(fieldptr, capture.data.unwrap());
+                field += 1;
+            }
+
+            (self.builder.build_pointer_cast(envptr, i8ptrtp, "env_bitcast"), drop)
         }
-    }
+        else {
+            (i8ptrtp.const_null(), i8ptrtp.const_null())
+        };
 
-    fn build_ref(&mut self, node: &parser::Node) -> types::Data<'ctx> {
-        return self.compile_expr(&node.data.unary.as_ref().unwrap().right, BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: false}, false, false);
-    }
+        let fnptr: inkwell::values::PointerValue = self.builder.build_pointer_cast(func.as_global_value().as_pointer_value(), i8ptrtp, "fn_bitcast");
 
-    fn build_mutref(&mut self, node: &parser::Node) -> types::Data<'ctx> {
-        return self.compile_expr(&node.data.unary.as_ref().unwrap().right, BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: true}, false, false);
-    }
+        let mut value: inkwell::values::StructValue = self.inkwell_types.closuretp.get_undef();
+        value = self.builder.build_insert_value(value, fnptr, 0, "closure").unwrap().into_struct_value();
+        value = self.builder.build_insert_value(value, env, 1, "closure").unwrap().into_struct_value();
+        value = self.builder.build_insert_value(value, drop, 2, "closure").unwrap().into_struct_value();
 
-    fn build_unary(&mut self, node: &parser::Node) -> types::Data<'ctx> {
-        let unary: &parser::nodes::UnaryNode = node.data.unary.as_ref().unwrap();
+        let mut tp: types::DataType = Self::datatypes_get(self, &types::BasicDataType::Closure.to_string()).unwrap().clone();
+        tp.types = datatypes;
+        tp.rettp = Some(Box::new(rettp_tp));
 
-        let right: types::Data = self.compile_expr(&unary.right, BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: false}, false, false);
+        //A closure borrowing names may not outlive them
+        return types::Data {
+            data: Some(inkwell::values::BasicValueEnum::StructValue(value)),
+            tp,
+            owned: closure.ismove || captures.iter().all(|x| x.data.is_none()),
+        };
+    }
 
-        let mut args: Vec<types::Data> = Vec::new();
+    //Compile the function of a closure, which takes a pointer to its environment before its arguments, unless it is used as a 'fn'.
+    //Without a return type, the body is compiled into a function returning nothing, and then moved to one returning the type of the body.
+    fn build_closure_func(&mut self, node: &parser::Node, datatypes: &Vec<types::DataType<'ctx>>, inktypes: &Vec<inkwell::types::BasicMetadataTypeEnum<'ctx>>, captures: &Vec<ClosureCapture<'ctx>>, rettp: Option<(types::DataType<'ctx>, inkwell::types::AnyTypeEnum<'ctx>)>, isfn: bool) -> (inkwell::values::FunctionValue<'ctx>, types::DataType<'ctx>) {
+        let closure: &parser::nodes::ClosureNode = node.data.closure.as_ref().unwrap();
+        let i8ptrtp: inkwell::types::PointerType = self.inkwell_types.i8tp.ptr_type(inkwell::AddressSpace::from(0u16));
 
-        let tp: types::Type = Self::get_type_from_data(self.cur_module.types.clone(), &right);
+        let mut paramtps: Vec<inkwell::types::BasicMetadataTypeEnum> = inktypes.clone();
+        if !isfn {
+            paramtps.insert(0, inkwell::types::BasicMetadataTypeEnum::PointerType(i8ptrtp));
+        }
 
-        let tp_str: &String = &right.tp.name.clone();
+        let rettp_any: inkwell::types::AnyTypeEnum = if rettp.is_some() { rettp.as_ref().unwrap().1 } else { inkwell::types::AnyTypeEnum::VoidType(*self.inkwell_types.voidtp) };
+        let fn_type: inkwell::types::FunctionType = Self::fn_type_from_any(rettp_any, &paramtps[..]);
 
-        args.push(right);
+        let func: inkwell::values::FunctionValue = self.module.add_function(if rettp.is_some() { "closure" } else { "closure.inferring" }, fn_type, None);
 
-        let traittp: types::TraitType = match node.data.unary.as_ref().unwrap().op {
-            parser::nodes::UnaryOpType::NEG => {
-                types::TraitType::Neg
-            }
-            parser::nodes::UnaryOpType::POS => {
-                types::TraitType::Pos                
-            }
-            _ => {
-                unreachable!();
-            }
-        };
+        // Add debug information
+        let sub_type = self.dibuilder.create_subroutine_type(
+            self.dicompile_unit.get_file(),
+            None,
+            &[],
+            inkwell::debug_info::DIFlagsConstants::PUBLIC);
 
-        let t: &types::Trait = match tp.traits.get(&traittp.to_string()) {
-            Some (v) => {
-                v
-            }
-            None => {
-                let fmt: String = format!("Type '{}' has no trait '{}'.", tp_str, &traittp.to_string());
-                errors::raise_error(&fmt, errors::ErrorType::MissingTrait, &node.pos, self.info);
-            }
-        };
+        let func_scope: inkwell::debug_info::DISubprogram = self.dibuilder.create_function(
+            self.dicompile_unit.as_debug_info_scope(),
+            "closure",
+            Some(func.get_name().to_str().unwrap()),
+            self.dicompile_unit.get_file(),
+            node.pos.line as u32,
+            sub_type,
+            true,
+            true,
+            node.pos.line as u32,
+            inkwell::debug_info::DIFlagsConstants::PUBLIC,
+            true);
 
-        let data: types::Data = self.call_trait(t, args, node);
-        
-        return data;
-    }
+        func.set_subprogram(func_scope);
 
-    fn build_struct(&mut self, node: &parser::Node) -> types::Data<'ctx> {
-        if self.cur_module.namespaces.structs.get(&node.data.st.as_ref().unwrap().name.clone()).is_some() && self.cur_module.namespaces.structs.get(&node.data.st.as_ref().unwrap().name.clone()).unwrap().3 != ForwardDeclarationType::Forward {
-            let fmt: String = format!("Struct '{}' is already defined.", node.data.st.as_ref().unwrap().name.clone());
-            errors::raise_error(&fmt, errors::ErrorType::RedefinitionAttempt, &node.pos, self.info);
-        }
-        if Self::datatypes_get(self, &node.data.st.as_ref().unwrap().name.clone()).is_some() && self.cur_module.namespaces.structs.get(&node.data.st.as_ref().unwrap().name.clone()).unwrap().3 != ForwardDeclarationType::Forward {
-            let fmt: String = format!("Type '{}' is already defined.", node.data.st.as_ref().unwrap().name.clone());
-            errors::raise_error(&fmt, errors::ErrorType::TypeRedefinitionAttempt, &node.pos, self.info);
-        }
+        let location = self.dibuilder.create_debug_location(
+            self.context,
+            node.pos.line as u32,
+            node.pos.startcol as u32,
+            func_scope.as_debug_info_scope(),
+            None);
 
-        self.cur_module.namespaces.structid.insert(node.data.st.as_ref().unwrap().name.clone(), self.cur_module.namespaces.structid_max);
-        self.cur_module.namespaces.structid_from.insert(self.cur_module.namespaces.structid_max, node.data.st.as_ref().unwrap().name.clone());
-        self.cur_module.namespaces.structid_max += 1;
-        
-        if !node.data.st.as_ref().unwrap().name.is_camel_case() {
-            errors::show_warning(errors::WarningType::ExpectedCamelCase, vec![String::from(""), node.data.st.as_ref().unwrap().name.to_camel_case()], vec![String::from("Expected camel case"), String::from("Convert to this: ")], &node.pos, self.info)
+        //Save the state of the enclosing function
+        let prev_location: Option<inkwell::debug_info::DILocation> = self.builder.get_current_debug_location();
+        let current_block: Option<inkwell::basic_block::BasicBlock> = self.current_block;
+        let enclosing_block: Option<inkwell::basic_block::BasicBlock> = self.enclosing_block;
+        let alloc_head: Option<inkwell::values::InstructionValue> = self.alloc_head;
+        let expected_rettp: Option<types::DataType> = self.expected_rettp.clone();
+        let loops: Vec<LoopFrame> = std::mem::take(&mut self.loops);
+        let prev_locals = self.cur_module.namespaces.locals.to_owned();
+        let drop_scope: usize = self.drop_scope;
+
+        let basic_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(func, "entry");
+        self.current_block = Some(basic_block);
+        self.enclosing_block = Some(basic_block);
+        self.alloc_head = None;
+        self.expected_rettp = rettp.as_ref().map(|x| x.0.clone());
+        self.builder.position_at_end(basic_block);
+        self.builder.set_current_debug_location(self.context, location);
+
+        self.cur_module.namespaces.locals = Vec::new();
+        self.cur_module.namespaces.locals.push(self.cur_module.namespaces.statics.clone());
+        self.cur_module.namespaces.locals.push(std::collections::HashMap::new());
+
+        //Setup captures, which are immutable in the closure
+        let mut idx: u32 = 0;
+        if !isfn {
+            let envtp: inkwell::types::StructType = self.closure_env_tp(captures);
+            let env: inkwell::values::PointerValue = self.builder.build_pointer_cast(func.get_nth_param(0).unwrap().into_pointer_value(), envtp.ptr_type(inkwell::AddressSpace::from(0u16)), "env");
+            idx += 1;
+
+            let mut field: u32 = 0;
+            for capture in captures {
+                let mut ptr: Option<inkwell::values::PointerValue> = None;
+                if capture.data.is_some() {
+                    let fieldptr: inkwell::values::PointerValue = self.builder.build_struct_gep(env, field, capture.name.as_str()).expect("GEP error");
+                    let value: inkwell::values::BasicValueEnum = self.builder.build_load(fieldptr, capture.name.as_str());
+                    field += 1;
+
+                    if capture.ismove {
+                        ptr = Some(Self::alloca(self, value.get_type(), capture.name.as_str()));
+                        self.builder.build_store(ptr.unwrap(), value);
+                    }
+                    else {
+                        ptr = Some(value.into_pointer_value());
+                    }
+                }
+
+                self.cur_module.namespaces.locals.last_mut().unwrap().insert(capture.name.to_owned(), (ptr, capture.tp.clone(), types::DataMutablility::Immutable, types::DataOwnership {owned: true, transferred: None, mut_borrowed: false}, capture.pos.clone(), InitializationStatus::Initialized));
+            }
         }
 
-        let mut names: Vec<String> = Vec::new();
-        let mut types: Vec<(types::DataType, AnyTypeEnum)> = Vec::new();
-        let mut simpletypes: Vec<types::DataType> = Vec::new();
-        let mut mutabilitites: Vec<types::DataMutablility> = Vec::new();
-        let mut idxmapping: std::collections::HashMap<String, i32> = std::collections::HashMap::new();
+        //Arguments and locals are in their own scopes, so they are dropped when the closure returns, unlike the captures
+        self.cur_module.namespaces.locals.push(std::collections::HashMap::new());
+        self.drop_scope = 2;
 
-        let mut idx = 0;
-        for member in &node.data.st.as_ref().unwrap().names {
-            if !member.is_snake_case() {
-                errors::show_warning(errors::WarningType::ExpectedSnakeCase, vec![String::from(""), member.to_camel_case()], vec![String::from("Expected snake case"), String::from("Convert to this: ")], &node.pos, self.info)
+        //Setup arguments
+        for (name, (tp, arg)) in std::iter::zip(&closure.args.name, std::iter::zip(datatypes, &closure.args.args)) {
+            let mut argv: Option<inkwell::values::BasicValueEnum> = None;
+            if *tp != types::BasicDataType::Void {
+                argv = func.get_nth_param(idx);
+                idx += 1;
             }
-            if names.contains(&member.clone()) {
-                let fmt: String = format!("Field '{}' is already declared.", member.clone());
-                errors::raise_error(&fmt, errors::ErrorType::FieldRedeclaration, &node.pos, self.info);
+            if name.get(0..1).unwrap() == "_" {
+                continue;
             }
-            names.push(member.clone());
-            types.push(Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, node.data.st.as_ref().unwrap().members.get(member).unwrap(), node));
-            if types.last().unwrap().0.is_ref {
-                let fmt: String = format!("Structs may not contain references.");
-                errors::raise_error(&fmt, errors::ErrorType::ReferenceMemberStruct, &node.pos, self.info);
+
+            if argv.is_some() {
+                if tp.is_ref && tp.mutability.last().unwrap() == &types::DataMutablility::Mutable {
+                    self.cur_module.namespaces.locals.last_mut().unwrap().insert(name.to_string(), (Some(argv.unwrap().into_pointer_value()), tp.clone(), arg.mutability, types::DataOwnership {owned: false, transferred: Some(node.pos.clone()), mut_borrowed: true}, node.pos.clone(), InitializationStatus::Initialized));
+                }
+                else {
+                    let ptr: inkwell::values::PointerValue = Self::alloca(self, argv.unwrap().get_type(), name.as_str());
+                    self.builder.build_store(ptr, argv.unwrap());
+                    if tp.tp == types::BasicDataType::Closure && !tp.is_ref {
+                        self.closure_owners.push(ptr);
+                    }
+
+                    self.cur_module.namespaces.locals.last_mut().unwrap().insert(name.to_string(), (Some(ptr), tp.clone(), arg.mutability, types::DataOwnership {owned: true, transferred: None, mut_borrowed: false}, node.pos.clone(), InitializationStatus::Initialized));
+                }
+            }
+            else {
+                self.cur_module.namespaces.locals.last_mut().unwrap().insert(name.to_string(), (None, tp.clone(), types::DataMutablility::Immutable, types::DataOwnership {owned: true, transferred: None, mut_borrowed: false}, node.pos.clone(), InitializationStatus::Initialized));
             }
-            simpletypes.push(Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, node.data.st.as_ref().unwrap().members.get(member).unwrap(), node).0);
-            mutabilitites.push(node.data.st.as_ref().unwrap().members.get(member).unwrap().mutability);
-            idxmapping.insert(member.clone(), idx);
-            idx+=1;
         }
 
-        let mut tp: types::DataType = Self::datatypes_get(self, &types::BasicDataType::Struct.to_string()).unwrap().clone();
-        tp.name = node.data.st.as_ref().unwrap().name.clone();
-        tp.names = Some(names);
-        tp.types = simpletypes.clone();
-        tp.mutability = mutabilitites;
+        let retv: types::Data = self.compile(&closure.blocks, true, true);
+        self.build_scope_drops(self.drop_scope, closure.blocks.last(), node);
 
-        self.cur_module.datatypes.insert(node.data.st.as_ref().unwrap().name.clone(), tp.clone());
-        self.cur_module.namespaces.structs.insert(node.data.st.as_ref().unwrap().name.clone(), (tp, Some(Self::build_struct_tp_from_types(self.context, &self.inkwell_types, &simpletypes, &self.cur_module.datatypes)), idxmapping, ForwardDeclarationType::Real));
-        
-        let data: types::Data = types::Data {
-            data: None,
-            tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
-            owned: true,
-        };
-        return data;
-    }
+        let rettp_tp: types::DataType = if rettp.is_some() { rettp.as_ref().unwrap().0.clone() } else { retv.tp.clone() };
 
-    fn build_initstruct(&mut self, node: &parser::Node) -> types::Data<'ctx> {
-        let mut members: std::collections::HashMap<String, types::Data> = std::collections::HashMap::new();
-        let name: String = node.data.initst.as_ref().unwrap().name.clone();
+        let func: inkwell::values::FunctionValue = if rettp.is_none() {
+            let any: Option<inkwell::types::AnyTypeEnum> = Self::get_anytp_from_tp(self.context, &self.inkwell_types, rettp_tp.clone(), &self.cur_module.datatypes);
+            if any.is_none() {
+                let fmt: String = format!("Cannot infer closure return type '{}'.", rettp_tp);
+                errors::raise_error(&fmt, errors::ErrorType::ExpectedClosureReturnType, &node.pos, self.info);
+            }
 
-        if self.cur_module.namespaces.structs.get(&name).is_none() {
-            let fmt: String = format!("Struct '{}' is not defined.", name);
-            errors::raise_error(&fmt, errors::ErrorType::StructNotDefined, &node.pos, self.info);
+            let end_block: inkwell::basic_block::BasicBlock = self.builder.get_insert_block().unwrap();
+            let inferred: inkwell::values::FunctionValue = self.module.add_function("closure", Self::fn_type_from_any(any.unwrap(), &paramtps[..]), None);
+            self.move_function_body(func, inferred);
+            unsafe { func.delete(); }
+
+            self.builder.position_at_end(end_block);
+            if rettp_tp.tp != types::BasicDataType::Void {
+                self.builder.build_return(Some(&retv.data.unwrap()));
+            }
+            else {
+                self.builder.build_return(None);
+            }
+            inferred
         }
+        else {
+            func
+        };
 
-        let s: (types::DataType, Option<AnyTypeEnum>, std::collections::HashMap<String, i32>, ForwardDeclarationType) = self.cur_module.namespaces.structs.get(&name).unwrap().clone();
+        if rettp.is_some() && (closure.blocks.len() == 0 || closure.blocks.last().unwrap().tp != parser::NodeType::RETURN) {
+            if retv.tp != rettp_tp.tp {
+                let fmt: String = format!("Expected '{}' return type, got '{}'.", &rettp_tp, retv.tp);
+                errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
+            }
 
-        for member in &node.data.initst.as_ref().unwrap().members_vec {
-            if members.contains_key(member) {
-                let fmt: String = format!("Field '{}' is already declared.", member);
-                errors::raise_error(&fmt, errors::ErrorType::FieldReinitialization, &node.pos, self.info);
+            if rettp_tp.tp != types::BasicDataType::Void {
+                self.builder.build_return(Some(&retv.data.unwrap()));
             }
-            members.insert(member.clone(), self.compile_expr(&(&node.data.initst.as_ref().unwrap().members).get(member).unwrap().clone(), BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, false, false));
+            else {
+                self.builder.build_return(None);
+            }
+        }
+
+        if closure.blocks.len() > 0 && retv.data.is_some() && !retv.owned {
+            let fmt: String = format!("Return value is not owned.");
+            errors::raise_error(&fmt, errors::ErrorType::ReturnValueNotOwned, &closure.blocks.last().unwrap().pos, self.info);
+        }
+
+        //Restore the state of the enclosing function
+        self.cur_module.namespaces.locals = prev_locals;
+        self.current_block = current_block;
+        self.enclosing_block = enclosing_block;
+        self.alloc_head = alloc_head;
+        self.expected_rettp = expected_rettp;
+        self.loops = loops;
+        self.drop_scope = drop_scope;
+        if self.current_block.is_some() {
+            self.builder.position_at_end(self.current_block.unwrap());
+        }
+        if prev_location.is_some() {
+            self.builder.set_current_debug_location(self.context, prev_location.unwrap());
+        }
+
+        return (func, rettp_tp);
+    }
+    
+    fn build_assign(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        let expected: Option<types::DataType> = self.get_variable(&node.data.assign.as_ref().unwrap().name).0.map(|x| x.1.clone());
+        let right: types::Data = if expected.is_some() {
+            self.compile_expr_expecting(&node.data.assign.as_ref().unwrap().expr, BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, expected.as_ref().unwrap())
         }
+        else {
+            self.compile_expr(&node.data.assign.as_ref().unwrap().expr, BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, false, false)
+        };
+
+        let name: String = node.data.assign.as_ref().unwrap().name.clone();
         
-        if s.0.names.as_ref().unwrap().len() != members.len() {
-            let fmt: String = format!("Expected {} members, got {}.", s.0.names.as_ref().unwrap().len(), members.len());
-            errors::raise_error(&fmt, errors::ErrorType::InvalidMemberCount, &node.pos, self.info);
+        if self.get_variable(&name).0.is_none() && self.cur_module.namespaces.consts.contains_key(&name) {
+            let fmt: String = format!("Cannot assign to constant '{}'.", name);
+            errors::raise_error(&fmt, errors::ErrorType::ImmutableAssign, &node.pos, self.info);
         }
 
-        for member in &members {
-            if !s.0.names.as_ref().unwrap().contains(member.0) {
-                let fmt: String = format!("Member '{}' does not exist.", member.0);
-                errors::raise_error(&fmt, errors::ErrorType::MemberNameNotFound, &node.pos, self.info);
+        if self.get_variable(&name).0.is_none() {
+            let fmt: String = format!("Name '{}' is not defined in namespace.", name);
+            errors::raise_error(&fmt, errors::ErrorType::NameNotFound, &node.pos, self.info);
+        }
+
+        if right.data == None{
+            let fmt: String = format!("Cannot assign to '{}'.", right.tp.to_string());
+            errors::raise_error(&fmt, errors::ErrorType::CannotAssign, &node.pos, self.info);
+        }
+
+        if  self.get_variable(&name).0.unwrap().2 == types::DataMutablility::Immutable &&
+            self.get_variable(&name).0.unwrap().5 == InitializationStatus::Initialized &&
+            !self.get_variable(&name).0.unwrap().3.mut_borrowed {
+            let fmt: String = format!("Cannot assign to immutable variable.");
+            errors::raise_error(&fmt, errors::ErrorType::ImmutableAssign, &node.pos, self.info);
+        }
+
+        let mut alttp: types::DataType = self.get_variable(&name).0.unwrap().1.to_owned();
+        alttp.is_ref = false;
+
+        if self.get_variable(&name).0.unwrap().1 != right.tp && alttp != right.tp {
+            let fmt: String = format!("Expected '{}' type, got '{}' type.", self.get_variable(&name).0.unwrap().1.tp.to_string(), right.tp.to_string());
+            errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
+        }
+
+        let ptr: Option<inkwell::values::PointerValue> = self.get_variable(&name).0.unwrap().0;
+
+        if ptr.is_some() {
+            let ptr: inkwell::values::PointerValue = self.get_variable(&name).0.unwrap().0.unwrap().clone();
+            if self.get_variable(&name).0.unwrap().1.is_dyn {
+                let typ: types::Type = Self::get_type_from_data(self.cur_module.types.clone(), &right);
+                let (dyntp, _) = Self::get_llvm_from_type(&self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, &node.data.letn.as_ref().unwrap().tp.as_ref().unwrap(), node);
+
+                if !typ.traits.contains_key(&dyntp.name) {
+                    let fmt: String = format!("'{}' type does not implement '{}' trait.", right.tp.to_string(), dyntp.name);
+                    errors::raise_error(&fmt, errors::ErrorType::MissingTrait, &node.pos, self.info);
+                }
+
+                if right.tp.tp != types::BasicDataType::Struct {
+                    let fmt: String = format!("Expected struct, got '{}'.", right.tp.tp);
+                    errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
+                }
+
+                for sig in self.get_trait_functions(&dyntp.name) {
+                    if sig.template_types.len() > 0 {
+                        let fmt: String = format!("Trait '{}' is not trait object safe because function '{}' is templated.", dyntp.name, sig.name);
+                        errors::raise_error(&fmt, errors::ErrorType::TraitIsNotTraitObjSafe, &node.pos, self.info);
+                    }
+                }
+
+                let idptr = self.builder.build_struct_gep(ptr, 0u32, "idptr").expect("GEP error");
+                self.builder.build_store(idptr, self.inkwell_types.i32tp.const_int(*self.cur_module.namespaces.structid.get(&right.tp.name).unwrap() as u64, false));
+
+                let itmptr = self.builder.build_struct_gep(ptr, 1u32, "item").expect("GEP error");
+                let structptr: inkwell::values::PointerValue = self.builder.build_malloc(right.data.unwrap().get_type(), "struct_ptr").expect("Malloc error");
+                self.builder.build_store(structptr, right.data.unwrap());
+                self.builder.build_store(itmptr, self.builder.build_pointer_cast(structptr, itmptr.get_type().get_element_type().into_pointer_type(), "st_bitcast"));
+                
+                let idx: usize = self.get_variable(&name).1;
+
+                self.cur_module.namespaces.locals.get_mut(idx).unwrap().insert(name, (Some(ptr), dyntp, node.data.letn.as_ref().unwrap().mutability, types::DataOwnership {owned: true, transferred: None, mut_borrowed: false}, node.pos.clone(), InitializationStatus::Initialized));
+            }
+            else {
+                if self.closure_owners.contains(&ptr) {
+                    self.build_local_drop(ptr, node);
+                }
+                self.builder.build_store(ptr, right.data.unwrap());
+
+                let idx: usize = self.get_variable(&name).1;
+                
+                self.cur_module.namespaces.locals.get_mut(idx).unwrap().insert(name, (Some(ptr), right.tp.clone(), types::DataMutablility::Mutable, types::DataOwnership {owned: true, transferred: None, mut_borrowed: false}, node.pos.clone(), InitializationStatus::Initialized));
             }
         }
 
-        for (member, tp, name) in izip!(&node.data.initst.as_ref().unwrap().members_vec, &s.0.types, s.0.names.as_ref().unwrap()) {
-            if members.get(member).unwrap().tp != *tp {
-                let fmt: String = format!("Expected '{}' type for member '{}', got '{}'.", tp, name, members.get(member).unwrap().tp);
-                errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
+        return right;
+    }
+    
+    fn build_call(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        let mut args: Vec<types::Data> = Vec::new();
+        let mut tp_name: String = String::from("");
+        let mut tp: Option<types::Type> = None;
+
+        let expected_tp: Option<types::DataType> = self.expected_tp.take();
+
+        let mut have_template_method: bool = false;
+
+        //Template function, with the explicit template types if they are given
+        let callname: &parser::Node = &node.data.call.as_ref().unwrap().name;
+        let template_function: Option<(String, Option<Vec<parser::Type>>)> = if callname.tp == parser::NodeType::IDENTIFIER &&
+            self.cur_module.namespaces.template_functions_sig.contains_key(&callname.data.identifier.as_ref().unwrap().name) {
+            Some((callname.data.identifier.as_ref().unwrap().name.clone(), None))
+        }
+        else if callname.tp == parser::NodeType::GENERICENUM && callname.data.attr.as_ref().unwrap().attr.is_empty() {
+            let name: &String = &callname.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name;
+            if !self.cur_module.namespaces.template_functions_sig.contains_key(name) {
+                let fmt: String = format!("Function '{}' is not generic.", name);
+                errors::raise_error(&fmt, errors::ErrorType::FunctionNotGeneric, &node.pos, self.info);
             }
+            Some((name.clone(), callname.data.attr.as_ref().unwrap().template_types.clone()))
         }
-        
-        let ptr: inkwell::values::PointerValue = Self::alloca(self, s.1.unwrap().into_struct_type(), name.as_str());
-        
-        for member in &node.data.initst.as_ref().unwrap().members_vec {
-            if members.get(member).unwrap().data.is_some() {
-                let itmptr: inkwell::values::PointerValue = self.builder.build_struct_gep(ptr, *s.2.get(member).unwrap() as u32, &member.as_str()).expect("GEP Error");
-                self.builder.build_store(itmptr, members.get(member).unwrap().data.unwrap());
+        else {
+            None
+        };
+
+        if  node.data.call.as_ref().unwrap().name.tp == parser::NodeType::ATTR {
+            let attr: &String = &node.data.call.as_ref().unwrap().name.data.attr.as_ref().unwrap().attr;
+
+            self.check_template_bound_method(&node.data.call.as_ref().unwrap().name.data.attr.as_ref().unwrap().name, attr, node);
+
+            let base: types::Data = self.compile_expr(&node.data.call.as_ref().unwrap().name.data.attr.as_ref().unwrap().name, BorrowOptions{ give_ownership: false, get_ptr: true, mut_borrow: false}, false, false);
+
+            let generic_method: Option<types::Method> = self.get_generic_struct_method(&base.tp, attr);
+            let trait_method: Option<types::Method> = if generic_method.is_none() && base.tp.methods.get(attr).is_none() { self.get_trait_method(&base.tp, attr) } else { None };
+
+            if base.tp.is_dyn {
+                let idptr: inkwell::values::PointerValue = self.builder.build_struct_gep(base.data.unwrap().into_pointer_value(), 0u32, "id_ptr").expect("GEP error");
+
+                let vtable: inkwell::values::PointerValue = unsafe { self.builder.build_in_bounds_gep(self.cur_module.vtables.unwrap().as_pointer_value(), &[self.builder.build_load(idptr, "id").into_int_value(), self.inkwell_types.i32tp.const_zero()], "vtable") };
+                
+                let functions: Vec<types::TemplateTraitSignature> = self.get_trait_functions(&base.tp.name);
+
+                let mut mtp: types::DataType = Self::datatypes_get(self, &types::BasicDataType::Func.to_string()).unwrap().clone();
+
+                let mut tsig: Option<types::TemplateTraitSignature> = None;
+                for sig in &functions {
+                    if &sig.name == attr {
+                        tsig = Some(sig.clone());
+                        break;
+                    }
+                }
+
+                if tsig.is_none() {
+                    let fmt: String = format!("Type '{}' has no method '{}'.", base.tp, attr);
+                    errors::raise_error(&fmt, errors::ErrorType::StructAttrNotFound, &node.pos, self.info);
+                }
+
+                let idx: usize = functions.iter().position(|x| &x.name == attr).unwrap();
+                
+                let method: inkwell::values::PointerValue = self.builder.build_load( unsafe { self.builder.build_in_bounds_gep(vtable, &[self.inkwell_types.i32tp.const_int(idx as u64, false), self.inkwell_types.i32tp.const_zero()], "method_ptr") }, "method").into_pointer_value();
+
+                let func_args = tsig.unwrap().args;
+
+                let mut datatypes: Vec<types::DataType> = Vec::new();
+                let mut names: Vec<String> = Vec::new();
+                
+                for arg in &func_args.args {
+                    let (data, _) = Self::get_llvm_from_type(&self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, &arg, node);
+                    
+                    if data.tp != types::BasicDataType::Void {
+                        names.push(String::from(""));
+                    }   
+
+                    datatypes.push(data);                  
+                }
+                
+                let rettp_full: (types::DataType, inkwell::types::AnyTypeEnum) = Self::get_llvm_from_type(&self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, &func_args.rettp.last().unwrap(), node);
+                let rettp_tp: types::DataType = rettp_full.0;
+
+                mtp.types = datatypes;
+                mtp.names = Some(names);
+                mtp.rettp = Some(Box::new(rettp_tp));
+                
+                args.push(types::Data {
+                    data: Some(inkwell::values::BasicValueEnum::PointerValue(method)),
+                    tp: mtp,
+                    owned: true,
+                });
+
+                args.push(types::Data {
+                    data: Some(self.builder.build_load(base.data.unwrap().into_pointer_value(), "instance")),
+                    tp: base.tp.clone(),
+                    owned: base.owned,
+                });
+
+                tp_name = Self::datatypes_get(self, &types::BasicDataType::Func.to_string()).unwrap().clone().name.clone();
+                
+                tp = Some(Self::get_type_from_data(self.cur_module.types.clone(), args.first().unwrap()));
+            }
+            else if base.tp.methods.get(attr).is_some() || generic_method.is_some() || trait_method.is_some() {
+                let method: &types::Method = if generic_method.is_some() { generic_method.as_ref().unwrap() } else if trait_method.is_some() { trait_method.as_ref().unwrap() } else { base.tp.methods.get(attr).unwrap() };
+                if method.tp == types::MethodType::Fn {
+                    let data: types::Data = types::Data {
+                        data: Some(inkwell::values::BasicValueEnum::PointerValue(method.func.unwrap())),
+                        tp: method.functp.clone(),
+                        owned: true,
+                    };
+
+                    args.push(data.clone());
+                    if method.isinstance {
+                        args.push(types::Data {
+                            data: Some(self.builder.build_load(base.data.unwrap().into_pointer_value(), &base.tp.name)),
+                            tp: base.tp.clone(),
+                            owned: base.owned,
+                        });
+                    }
+
+                    tp_name = method.functp.name.clone();
+                    
+                    tp = Some(Self::get_type_from_data(self.cur_module.types.clone(), &data));
+                }
+                else {
+                    let data: types::Data = types::Data {
+                        data: None,
+                        tp: method.functp.to_owned(),
+                        owned: true,
+                    };
+                    
+                    tp_name = method.functp.name.clone();
+
+                    tp = Some(Self::get_type_from_data(self.cur_module.types.clone(), &data.clone()));
+
+                    args.push(data);
+                    if method.isinstance {
+                        args.push(types::Data {
+                            data: Some(self.builder.build_load(base.data.unwrap().into_pointer_value(), &base.tp.name)),
+                            tp: base.tp.clone(),
+                            owned: base.owned,
+                        });
+                    }
+                    else if method.isinstanceptr {
+                        args.push(base.clone());
+                    }
+                    else if method.ismutinstanceptr {
+                        let base_alt: types::Data = self.compile_expr(&node.data.call.as_ref().unwrap().name.data.attr.as_ref().unwrap().name, BorrowOptions{ give_ownership: true, get_ptr: true, mut_borrow: true}, false, false);
+                        args.push(base_alt.clone());
+                    }
+                }
+            }
+            else if self.cur_module.namespaces.template_functions_sig.contains_key(&(base.tp.name.clone()+"."+node.data.call.as_ref().unwrap().name.data.attr.as_ref().unwrap().attr.to_owned().as_str()).to_owned()) {
+                have_template_method = true;
+            }
+            else{
+                let fmt: String = format!("Type '{}' has no method '{}'.", base.tp, attr);
+                errors::raise_error(&fmt, errors::ErrorType::StructAttrNotFound, &node.pos, self.info);
+            }
+        }
+        else if template_function.is_some() {
+            // Do nothing yet
+        }
+        else {
+            let callable: types::Data = self.compile_expr(&node.data.call.as_ref().unwrap().name, BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: false}, false, false);
+            tp_name = callable.tp.name.clone();
+            args.push(callable);
+            tp = Some(Self::get_type_from_data(self.cur_module.types.clone(), &args.first().unwrap()));
+        }
+        
+        if args.first().is_some() && args.first().unwrap().tp.types.len() > 0 && args.first().unwrap().tp.tp == types::BasicDataType::WrapperFunc {
+            //The types of instance methods start with the type of self
+            let offset: usize = args.len()-1;
+            let argtps: Vec<types::DataType> = args.first().unwrap().tp.types.clone();
+            for (idx, (arg, tp)) in izip![&node.data.call.as_ref().unwrap().args, &argtps].enumerate() {
+                let v: types::Data = if argtps.get(idx+offset).is_some() {
+                    self.compile_expr_expecting(arg, BorrowOptions{ give_ownership: true, get_ptr: tp.is_ref, mut_borrow: false}, argtps.get(idx+offset).unwrap())
+                }
+                else {
+                    self.compile_expr(arg, BorrowOptions{ give_ownership: true, get_ptr: tp.is_ref, mut_borrow: false}, false, false)
+                };
+                if v.tp.tp != types::BasicDataType::Struct || v.tp.is_ref || tp.is_ref {
+                    args.push(v);
+                }
+                else {
+                    args.push(types::Data {
+                        data: Some(self.builder.build_load(v.data.unwrap().into_pointer_value(), &v.tp.name)),
+                        tp: v.tp.clone(),
+                        owned: v.owned,
+                    });
+                }
+            }
+        }
+        else {
+            for arg in &node.data.call.as_ref().unwrap().args {
+                //Arguments of a function take the types of its parameters
+                let expected: Option<types::DataType> = if args.first().is_some() && (args.first().unwrap().tp.tp == types::BasicDataType::Func || args.first().unwrap().tp.tp == types::BasicDataType::Closure) { args.first().unwrap().tp.types.get(args.len()-1).cloned() } else { None };
+                let v: types::Data = if expected.is_some() {
+                    self.compile_expr_expecting(arg, BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, expected.as_ref().unwrap())
+                }
+                else {
+                    self.compile_expr(arg, BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, false, false)
+                };
+                
+                if v.tp.tp != types::BasicDataType::Struct || v.tp.is_ref {
+                    args.push(v);
+                }
+                else {
+                    args.push(types::Data {
+                        data: Some(self.builder.build_load(v.data.unwrap().into_pointer_value(), &v.tp.name)),
+                        tp: v.tp.clone(),
+                        owned: v.owned,
+                    });
+                }
+            }
+        }
+
+        if template_function.is_some() {
+            let (name, explicit) = template_function.unwrap();
+            let func: parser::Node = self.cur_module.namespaces.template_functions_sig.get(&name).unwrap().0.to_owned();
+
+            let (templates, fn_types, rettp_tp) = self.get_template_call_types(&func, &args, explicit.as_ref(), expected_tp.clone(), node);
+
+            self.check_template_types_bounds(&func, &templates, node);
+
+            let template_types: Vec<String> = func.data.func.as_ref().unwrap().template_types.clone();
+            let prev_tps: Vec<Option<types::DataType>> = self.push_template_types(&template_types, &template_types.iter().map(|x| templates.get(x).unwrap().clone()).collect());
+            let prev_bounds: std::collections::HashMap<String, Vec<String>> = self.push_template_bounds(&template_types, &func.data.func.as_ref().unwrap().template_bounds);
+            let current_block: inkwell::basic_block::BasicBlock = self.current_block.unwrap();
+            self.build_func(&func, None, Some(fn_types), Some(rettp_tp));
+            self.current_block = Some(current_block);
+            self.pop_template_bounds(prev_bounds);
+            self.pop_template_types(&template_types, prev_tps);
+
+            let func_v = self.cur_module.namespaces.template_functions.last().unwrap().to_owned();
+            self.cur_module.namespaces.template_functions.pop();
+            if !self.cur_module.namespaces.template_functions.contains(&func_v) {
+                self.cur_module.namespaces.template_functions.push(func_v.to_owned());
+            }
+            
+            tp_name = func_v.1.name.clone();
+            let callable: types::Data = types::Data {
+                data: Some(inkwell::values::BasicValueEnum::PointerValue(func_v.2.as_global_value().as_pointer_value())),
+                tp: func_v.1.clone(),
+                owned: true,
+            };
+
+            args.insert(0usize, callable);
+            tp = Some(Self::get_type_from_data(self.cur_module.types.clone(), &args.first().unwrap()));
+            self.builder.position_at_end(self.current_block.unwrap());
+        }
+
+        if have_template_method {
+            let base: types::Data = self.compile_expr(&node.data.call.as_ref().unwrap().name.data.attr.as_ref().unwrap().name, BorrowOptions{ give_ownership: false, get_ptr: true, mut_borrow: false}, false, false);
+
+            if  self.cur_module.namespaces.template_functions_sig.contains_key(&(base.tp.name.clone()+"."+node.data.call.as_ref().unwrap().name.data.attr.as_ref().unwrap().attr.to_owned().as_str()).to_owned()) {
+                let func: parser::Node = self.cur_module.namespaces.template_functions_sig.get(&(base.tp.name.clone()+"."+node.data.call.as_ref().unwrap().name.data.attr.as_ref().unwrap().attr.to_owned().as_str()).to_owned()).unwrap().0.to_owned();
+                let instance_meth: TemplateFunctionInstance = self.cur_module.namespaces.template_functions_sig.get(&(base.tp.name.clone()+"."+node.data.call.as_ref().unwrap().name.data.attr.as_ref().unwrap().attr.to_owned().as_str()).to_owned()).unwrap().1.to_owned();
+                
+                let selftp: &parser::Type = func.data.func.as_ref().unwrap().args.args.first().unwrap();
+                if  !selftp.isarr && !selftp.isfn && !selftp.isdyn && !selftp.isgenum && !selftp.isref &&
+                    func.data.func.as_ref().unwrap().template_types.contains(selftp.data.as_ref().unwrap()) && instance_meth == TemplateFunctionInstance::Instance {
+                    let fmt: String = format!("First argument for template method may not be template.");
+                    errors::raise_error(&fmt, errors::ErrorType::MethodTemplateFunctionHasFirstTemplate, &node.pos, self.info);
+                }
+
+                let mut call_args: Vec<types::Data> = args.clone();
+                if instance_meth == TemplateFunctionInstance::Instance {
+                    call_args.insert(0, base.clone());
+                }
+
+                let (templates, fn_types, rettp_tp) = self.get_template_call_types(&func, &call_args, None, expected_tp.clone(), node);
+                
+                self.check_template_types_bounds(&func, &templates, node);
+
+                let template_types: Vec<String> = func.data.func.as_ref().unwrap().template_types.clone();
+                let prev_tps: Vec<Option<types::DataType>> = self.push_template_types(&template_types, &template_types.iter().map(|x| templates.get(x).unwrap().clone()).collect());
+                let prev_bounds: std::collections::HashMap<String, Vec<String>> = self.push_template_bounds(&template_types, &func.data.func.as_ref().unwrap().template_bounds);
+                let current_block: inkwell::basic_block::BasicBlock = self.current_block.unwrap();
+                self.build_func(&func, None, Some(fn_types), Some(rettp_tp));
+                self.current_block = Some(current_block);
+                self.pop_template_bounds(prev_bounds);
+                self.pop_template_types(&template_types, prev_tps);
+
+                let func_v = self.cur_module.namespaces.template_functions.last().unwrap().to_owned();
+                self.cur_module.namespaces.template_functions.pop();
+                if !self.cur_module.namespaces.template_functions.contains(&func_v) {
+                    self.cur_module.namespaces.template_functions.push(func_v.to_owned());
+                }
+                
+                tp_name = func_v.1.name.clone();
+                let callable: types::Data = types::Data {
+                    data: Some(inkwell::values::BasicValueEnum::PointerValue(func_v.2.as_global_value().as_pointer_value())),
+                    tp: func_v.1.clone(),
+                    owned: true,
+                };
+
+                args.insert(0usize, callable.clone());
+                tp = Some(Self::get_type_from_data(self.cur_module.types.clone(), &callable));
+                self.builder.position_at_end(self.current_block.unwrap());
+                if instance_meth == TemplateFunctionInstance::Instance {
+                    args.insert(1usize, types::Data {
+                        data: Some(self.builder.build_load(base.data.unwrap().into_pointer_value(), &base.tp.name)),
+                        tp: base.tp.clone(),
+                        owned: base.owned,
+                    });
+                }
+            }
+        }
+
+
+        let t: &types::Trait = match tp.as_ref().unwrap().traits.get(&types::TraitType::Call.to_string()) {
+            Some (v) => {
+                v
+            }
+            None => {
+                let fmt: String = format!("Type '{}' has no trait '{}'.", tp_name, &types::TraitType::Call.to_string());
+                errors::raise_error(&fmt, errors::ErrorType::MissingTrait, &node.pos, self.info);
+            }
+        };
+        
+        let data: types::Data = self.call_trait(t, args, node);
+
+        return data;
+    }
+
+    fn build_return(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        if node.data.ret.as_ref().unwrap().expr.is_some() && node.data.ret.as_ref().unwrap().expr.as_ref().unwrap().tp == parser::NodeType::CALL {
+            self.expected_tp = self.expected_rettp.clone();
+        }
+
+        let retv: types::Data = if node.data.ret.as_ref().unwrap().expr.is_some() && self.expected_rettp.is_some() { self.compile_expr_expecting(&node.data.ret.as_ref().unwrap().expr.as_ref().unwrap(), BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, &self.expected_rettp.clone().unwrap()) } else if node.data.ret.as_ref().unwrap().expr.is_some() { self.compile_expr(&node.data.ret.as_ref().unwrap().expr.as_ref().unwrap(), BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, false, false) } else { types::Data {
+            data: None,
+            tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
+            owned: true,
+        } };
+
+        if self.expected_rettp==None {
+            let fmt: String = format!("Cannot return outside of function.");
+            errors::raise_error(&fmt, errors::ErrorType::ReturnOutsideOfFunction, &node.pos, self.info);            
+        }
+
+        if retv.tp != *self.expected_rettp.as_ref().unwrap() {
+            let fmt: String = format!("Expected '{}' return type, got '{}'.", &self.expected_rettp.as_ref().unwrap().name, retv.tp.name);
+            errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
+        }
+
+        self.build_scope_drops(self.drop_scope, None, node);
+
+        if retv.data.is_some() {
+            if !retv.owned {
+                let fmt: String = format!("Return value is not owned.");
+                errors::raise_error(&fmt, errors::ErrorType::ReturnValueNotOwned, if node.data.ret.as_ref().unwrap().expr.is_some() { &node.data.ret.as_ref().unwrap().expr.as_ref().unwrap().pos } else { &node.pos }, self.info);
+            }
+            self.builder.build_return(Some(&retv.data.unwrap())); 
+        }
+        else {
+            self.builder.build_return(None);
+        }
+  
+        return retv;
+    }
+
+    fn build_as(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        let left: types::Data = self.compile_expr(&node.data.to.as_ref().unwrap().left, BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: false}, false, false);     
+        let arg: &parser::Type = &node.data.to.as_ref().unwrap().tp;  
+        if arg.isfn {
+            let fmt: String = format!("Non primitive cast from '{}' to 'fn'.", left.tp);
+            errors::raise_error(&fmt, errors::ErrorType::InvalidCast, &node.pos, self.info);
+        }
+        let tp_name: &String = &arg.data.as_ref().unwrap();
+
+        if Self::datatypes_get(self, tp_name).is_none() {
+            let fmt: String = format!("Unknown type '{}'.", tp_name);
+            errors::raise_error(&fmt, errors::ErrorType::UnknownType, &node.pos, self.info);
+        } 
+        let tp: &types::DataType = &Self::datatypes_get(self, tp_name).unwrap();
+
+        let anytp: Option<inkwell::types::AnyTypeEnum> = Self::get_anytp_from_tp(self.context, &self.inkwell_types, tp.clone(), &self.cur_module.datatypes);
+
+        if tp.tp == types::BasicDataType::Char || left.tp.tp == types::BasicDataType::Char {
+            return self.build_char_cast(left, tp, anytp, node);
+        }
+
+        if tp.tp == types::BasicDataType::Bool || left.tp.tp == types::BasicDataType::Bool {
+            return self.build_bool_cast(left, tp, anytp, node);
+        }
+
+        if !anytp.is_none() && anytp.unwrap().is_int_type() && left.data.unwrap().is_int_value() {
+            let res: inkwell::values::IntValue = self.builder.build_int_cast(left.data.unwrap().into_int_value(), anytp.unwrap().into_int_type(), "cast");
+
+            return types::Data {
+                data: Some(inkwell::values::BasicValueEnum::IntValue(res)),
+                tp: tp.clone(),
+                owned: true,
+            };
+        }
+        else if !anytp.is_none() && anytp.unwrap().is_float_type() && left.data.unwrap().is_float_value() {
+            let res: inkwell::values::FloatValue = self.builder.build_float_cast(left.data.unwrap().into_float_value(), anytp.unwrap().into_float_type(), "fcast");
+
+            return types::Data {
+                data: Some(inkwell::values::BasicValueEnum::FloatValue(res)),
+                tp: tp.clone(),
+                owned: true,
+            };
+        }
+        else if !anytp.is_none() && anytp.unwrap().is_float_type() && left.data.unwrap().is_int_value() {
+            let res: inkwell::values::FloatValue = left.data.unwrap().into_int_value().const_signed_to_float(anytp.unwrap().into_float_type());
+            
+            return types::Data {
+                data: Some(inkwell::values::BasicValueEnum::FloatValue(res)),
+                tp: tp.clone(),
+                owned: true,
+            };
+        }
+        else if !anytp.is_none() && anytp.unwrap().is_int_type() && left.data.unwrap().is_float_value() {
+            let res: inkwell::values::IntValue = if builtin_types::int_issigned(tp.clone()) {
+                self.builder.build_float_to_signed_int(left.data.unwrap().into_float_value(), anytp.unwrap().into_int_type(), "ftoi")
+            }
+            else {
+                self.builder.build_float_to_unsigned_int(left.data.unwrap().into_float_value(), anytp.unwrap().into_int_type(), "ftoui")
+            };
+
+            return types::Data {
+                data: Some(inkwell::values::BasicValueEnum::IntValue(res)),
+                tp: tp.clone(),
+                owned: true,
+            };
+        } 
+        else {
+            let fmt: String = format!("Non primitive cast from '{}' to '{}'.", left.tp, tp_name);
+            errors::raise_error(&fmt, errors::ErrorType::InvalidCast, &node.pos, self.info);
+        }
+    }
+
+    //An integer is true if it is not zero, and true is 1 as any numeric type
+    fn build_bool_cast(&mut self, left: types::Data<'ctx>, tp: &types::DataType<'ctx>, anytp: Option<inkwell::types::AnyTypeEnum<'ctx>>, node: &parser::Node) -> types::Data<'ctx> {
+        let res: inkwell::values::BasicValueEnum = if tp.tp == types::BasicDataType::Bool && left.tp.tp == types::BasicDataType::Bool {
+            left.data.unwrap()
+        }
+        else if tp.tp == types::BasicDataType::Bool && left.data.is_some() && left.data.unwrap().is_int_value() {
+            let selfv: inkwell::values::IntValue = left.data.unwrap().into_int_value();
+            inkwell::values::BasicValueEnum::IntValue(self.builder.build_int_compare(inkwell::IntPredicate::NE, selfv, selfv.get_type().const_zero(), "tobool"))
+        }
+        else if left.tp.tp == types::BasicDataType::Bool && !anytp.is_none() && anytp.unwrap().is_int_type() {
+            inkwell::values::BasicValueEnum::IntValue(self.builder.build_int_z_extend(left.data.unwrap().into_int_value(), anytp.unwrap().into_int_type(), "boolcast"))
+        }
+        else if left.tp.tp == types::BasicDataType::Bool && !anytp.is_none() && anytp.unwrap().is_float_type() {
+            inkwell::values::BasicValueEnum::FloatValue(self.builder.build_unsigned_int_to_float(left.data.unwrap().into_int_value(), anytp.unwrap().into_float_type(), "boolcast"))
+        }
+        else {
+            let fmt: String = format!("Invalid cast from '{}' to '{}'.", left.tp, tp);
+            errors::raise_error(&fmt, errors::ErrorType::InvalidCast, &node.pos, self.info);
+        };
+
+        return types::Data {
+            data: Some(res),
+            tp: tp.clone(),
+            owned: true,
+        };
+    }
+
+    //Only u32 and u8 convert to and from char. Narrowing to u8 and widening to char are checked.
+    fn build_char_cast(&mut self, left: types::Data<'ctx>, tp: &types::DataType<'ctx>, anytp: Option<inkwell::types::AnyTypeEnum<'ctx>>, node: &parser::Node) -> types::Data<'ctx> {
+        let res: inkwell::values::IntValue = if tp.tp == types::BasicDataType::Char && left.tp.tp == types::BasicDataType::Char {
+            left.data.unwrap().into_int_value()
+        }
+        else if left.tp.tp == types::BasicDataType::Char && tp.tp == types::BasicDataType::U32 {
+            left.data.unwrap().into_int_value()
+        }
+        else if left.tp.tp == types::BasicDataType::Char && tp.tp == types::BasicDataType::U8 {
+            let selfv: inkwell::values::IntValue = left.data.unwrap().into_int_value();
+            let max: inkwell::values::IntValue = selfv.get_type().const_int(u8::MAX as u64, false);
+
+            if selfv.is_const() && selfv.get_zero_extended_constant().is_some() && selfv.get_zero_extended_constant().unwrap() > u8::MAX as u64 {
+                let fmt: String = format!("Char '{:#X}' does not fit in 'u8'.", selfv.get_zero_extended_constant().unwrap());
+                errors::raise_error(&fmt, errors::ErrorType::InvalidCast, &node.pos, self.info);
+            }
+            if !selfv.is_const() {
+                let overflow: inkwell::values::IntValue = self.builder.build_int_compare(inkwell::IntPredicate::UGT, selfv, max, "char_overflow");
+                let msg: String = format!("attempt to cast char with overflow at {}:{}:{}", self.info.name, node.pos.line+1, node.pos.startcol+1);
+                Self::build_trap_if(self, overflow, "char_overflow", Some(msg));
+            }
+
+            self.builder.build_int_truncate(selfv, anytp.unwrap().into_int_type(), "charcast")
+        }
+        else if tp.tp == types::BasicDataType::Char && left.tp.tp == types::BasicDataType::U32 {
+            let selfv: inkwell::values::IntValue = left.data.unwrap().into_int_value();
+
+            if selfv.is_const() && selfv.get_zero_extended_constant().is_some() && !builtin_types::chartype::is_scalar(selfv.get_zero_extended_constant().unwrap()) {
+                let fmt: String = format!("'{:#X}' is not a valid Unicode scalar value.", selfv.get_zero_extended_constant().unwrap());
+                errors::raise_error(&fmt, errors::ErrorType::InvalidCodePoint, &node.pos, self.info);
+            }
+            if !selfv.is_const() {
+                let is_scalar: inkwell::values::IntValue = builtin_types::chartype::build_is_scalar(self, selfv);
+                let invalid: inkwell::values::IntValue = self.builder.build_not(is_scalar, "invalid_char");
+                let msg: String = format!("attempt to cast an invalid code point to char at {}:{}:{}", self.info.name, node.pos.line+1, node.pos.startcol+1);
+                Self::build_trap_if(self, invalid, "invalid_char", Some(msg));
+            }
+
+            selfv
+        }
+        else if tp.tp == types::BasicDataType::Char && left.tp.tp == types::BasicDataType::U8 {
+            self.builder.build_int_z_extend(left.data.unwrap().into_int_value(), anytp.unwrap().into_int_type(), "charcast")
+        }
+        else {
+            let fmt: String = format!("Invalid cast from '{}' to '{}'.", left.tp, tp);
+            errors::raise_error(&fmt, errors::ErrorType::InvalidCast, &node.pos, self.info);
+        };
+
+        return types::Data {
+            data: Some(inkwell::values::BasicValueEnum::IntValue(res)),
+            tp: tp.clone(),
+            owned: true,
+        };
+    }
+
+    fn build_ref(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        return self.compile_expr(&node.data.unary.as_ref().unwrap().right, BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: false}, false, false);
+    }
+
+    fn build_mutref(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        return self.compile_expr(&node.data.unary.as_ref().unwrap().right, BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: true}, false, false);
+    }
+
+    fn build_unary(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        let unary: &parser::nodes::UnaryNode = node.data.unary.as_ref().unwrap();
+
+        let right: types::Data = self.compile_expr(&unary.right, BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: false}, false, false);
+
+        let mut args: Vec<types::Data> = Vec::new();
+
+        let tp: types::Type = Self::get_type_from_data(self.cur_module.types.clone(), &right);
+
+        let tp_str: &String = &right.tp.name.clone();
+
+        args.push(right);
+
+        let traittp: types::TraitType = match node.data.unary.as_ref().unwrap().op {
+            parser::nodes::UnaryOpType::NEG => {
+                types::TraitType::Neg
+            }
+            parser::nodes::UnaryOpType::POS => {
+                types::TraitType::Pos                
+            }
+            parser::nodes::UnaryOpType::NOT => {
+                types::TraitType::Not
+            }
+            parser::nodes::UnaryOpType::BITNOT => {
+                types::TraitType::BitNot
+            }
+            _ => {
+                unreachable!();
+            }
+        };
+
+        self.check_template_bound(&unary.right, &traittp.to_string(), node);
+
+        let t: &types::Trait = match tp.traits.get(&traittp.to_string()) {
+            Some (v) => {
+                v
+            }
+            None => {
+                let fmt: String = format!("Type '{}' has no trait '{}'.", tp_str, &traittp.to_string());
+                errors::raise_error(&fmt, errors::ErrorType::MissingTrait, &node.pos, self.info);
+            }
+        };
+
+        let data: types::Data = self.call_trait(t, args, node);
+        
+        return data;
+    }
+
+    fn build_struct(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        //Generic structs are registered when forward declaring, and their instances are built on use
+        if node.data.st.as_ref().unwrap().template_types.len() > 0 {
+            let data: types::Data = types::Data {
+                data: None,
+                tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
+                owned: true,
+            };
+            return data;
+        }
+
+        if  (self.cur_module.namespaces.structs.get(&node.data.st.as_ref().unwrap().name.clone()).is_some() && self.cur_module.namespaces.structs.get(&node.data.st.as_ref().unwrap().name.clone()).unwrap().3 != ForwardDeclarationType::Forward) ||
+            self.cur_module.namespaces.generic_structs.contains_key(&node.data.st.as_ref().unwrap().name) {
+            let fmt: String = format!("Struct '{}' is already defined.", node.data.st.as_ref().unwrap().name.clone());
+            errors::raise_error(&fmt, errors::ErrorType::RedefinitionAttempt, &node.pos, self.info);
+        }
+        if Self::datatypes_get(self, &node.data.st.as_ref().unwrap().name.clone()).is_some() && self.cur_module.namespaces.structs.get(&node.data.st.as_ref().unwrap().name.clone()).unwrap().3 != ForwardDeclarationType::Forward {
+            let fmt: String = format!("Type '{}' is already defined.", node.data.st.as_ref().unwrap().name.clone());
+            errors::raise_error(&fmt, errors::ErrorType::TypeRedefinitionAttempt, &node.pos, self.info);
+        }
+
+        self.cur_module.namespaces.structid.insert(node.data.st.as_ref().unwrap().name.clone(), self.cur_module.namespaces.structid_max);
+        self.cur_module.namespaces.structid_from.insert(self.cur_module.namespaces.structid_max, node.data.st.as_ref().unwrap().name.clone());
+        self.cur_module.namespaces.structid_max += 1;
+        
+        if !node.data.st.as_ref().unwrap().name.is_camel_case() {
+            errors::show_warning(errors::WarningType::ExpectedCamelCase, vec![String::from(""), node.data.st.as_ref().unwrap().name.to_camel_case()], vec![String::from("Expected camel case"), String::from("Convert to this: ")], &node.pos, self.info)
+        }
+
+        let mut names: Vec<String> = Vec::new();
+        let mut types: Vec<(types::DataType, AnyTypeEnum)> = Vec::new();
+        let mut simpletypes: Vec<types::DataType> = Vec::new();
+        let mut mutabilitites: Vec<types::DataMutablility> = Vec::new();
+        let mut idxmapping: std::collections::HashMap<String, i32> = std::collections::HashMap::new();
+
+        let mut idx = 0;
+        for member in &node.data.st.as_ref().unwrap().names {
+            if !member.is_snake_case() {
+                errors::show_warning(errors::WarningType::ExpectedSnakeCase, vec![String::from(""), member.to_camel_case()], vec![String::from("Expected snake case"), String::from("Convert to this: ")], &node.pos, self.info)
+            }
+            if names.contains(&member.clone()) {
+                let fmt: String = format!("Field '{}' is already declared.", member.clone());
+                errors::raise_error(&fmt, errors::ErrorType::FieldRedeclaration, &node.pos, self.info);
+            }
+            names.push(member.clone());
+            self.build_generic_struct_types(node.data.st.as_ref().unwrap().members.get(member).unwrap(), node);
+            types.push(Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, node.data.st.as_ref().unwrap().members.get(member).unwrap(), node));
+            if types.last().unwrap().0.is_ref {
+                let fmt: String = format!("Structs may not contain references.");
+                errors::raise_error(&fmt, errors::ErrorType::ReferenceMemberStruct, &node.pos, self.info);
+            }
+            simpletypes.push(Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, node.data.st.as_ref().unwrap().members.get(member).unwrap(), node).0);
+            mutabilitites.push(node.data.st.as_ref().unwrap().members.get(member).unwrap().mutability);
+            idxmapping.insert(member.clone(), idx);
+            idx+=1;
+        }
+
+        let mut tp: types::DataType = Self::datatypes_get(self, &types::BasicDataType::Struct.to_string()).unwrap().clone();
+        tp.name = node.data.st.as_ref().unwrap().name.clone();
+        tp.names = Some(names);
+        tp.types = simpletypes.clone();
+        tp.mutability = mutabilitites;
+
+        self.cur_module.datatypes.insert(node.data.st.as_ref().unwrap().name.clone(), tp.clone());
+        self.cur_module.namespaces.structs.insert(node.data.st.as_ref().unwrap().name.clone(), (tp, Some(Self::build_struct_tp_from_types(self.context, &self.inkwell_types, &simpletypes, &self.cur_module.datatypes)), idxmapping, ForwardDeclarationType::Real));
+        
+        let data: types::Data = types::Data {
+            data: None,
+            tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
+            owned: true,
+        };
+        return data;
+    }
+
+    fn build_initstruct(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        let mut members: std::collections::HashMap<String, types::Data> = std::collections::HashMap::new();
+        let mut name: String = node.data.initst.as_ref().unwrap().name.clone();
+
+        if self.cur_module.namespaces.structs.get(&name).is_none() && !self.cur_module.namespaces.generic_structs.contains_key(&name) {
+            let fmt: String = format!("Struct '{}' is not defined.", name);
+            errors::raise_error(&fmt, errors::ErrorType::StructNotDefined, &node.pos, self.info);
+        }
+
+        for member in &node.data.initst.as_ref().unwrap().members_vec {
+            if members.contains_key(member) {
+                let fmt: String = format!("Field '{}' is already declared.", member);
+                errors::raise_error(&fmt, errors::ErrorType::FieldReinitialization, &node.pos, self.info);
+            }
+            members.insert(member.clone(), self.compile_expr(&(&node.data.initst.as_ref().unwrap().members).get(member).unwrap().clone(), BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, false, false));
+        }
+
+        //Infer the template types of a generic struct from the members declared with them
+        if self.cur_module.namespaces.generic_structs.contains_key(&name) {
+            let generic: GenericStruct = self.cur_module.namespaces.generic_structs.get(&name).unwrap().clone();
+            let st: &parser::nodes::StructNode = generic.node.data.st.as_ref().unwrap();
+
+            let mut generic_tps: Vec<types::DataType> = Vec::new();
+            for template in &generic.template_types {
+                let mut inferred: Option<types::DataType> = None;
+                for member in &st.names {
+                    let tp: &parser::Type = st.members.get(member).unwrap();
+                    if  !tp.isarr && !tp.isfn && !tp.isdyn && !tp.isgenum && !tp.isref &&
+                        tp.data.as_ref().unwrap() == template && members.contains_key(member) {
+                        inferred = Some(members.get(member).unwrap().tp.clone());
+                        break;
+                    }
+                }
+
+                if inferred.is_none() {
+                    let fmt: String = format!("Unable to infer template type '{}' of struct '{}'.", template, name);
+                    errors::raise_error(&fmt, errors::ErrorType::UnknownTemplateType, &node.pos, self.info);
+                }
+                generic_tps.push(inferred.unwrap());
+            }
+
+            name = self.build_generic_struct_instance(&name, generic_tps, node).name;
+        }
+
+        let s: (types::DataType, Option<AnyTypeEnum>, std::collections::HashMap<String, i32>, ForwardDeclarationType) = self.cur_module.namespaces.structs.get(&name).unwrap().clone();
+        
+        if s.0.names.as_ref().unwrap().len() != members.len() {
+            let fmt: String = format!("Expected {} members, got {}.", s.0.names.as_ref().unwrap().len(), members.len());
+            errors::raise_error(&fmt, errors::ErrorType::InvalidMemberCount, &node.pos, self.info);
+        }
+
+        for member in &members {
+            if !s.0.names.as_ref().unwrap().contains(member.0) {
+                let fmt: String = format!("Member '{}' does not exist.", member.0);
+                errors::raise_error(&fmt, errors::ErrorType::MemberNameNotFound, &node.pos, self.info);
+            }
+        }
+
+        for (member, tp, name) in izip!(&node.data.initst.as_ref().unwrap().members_vec, &s.0.types, s.0.names.as_ref().unwrap()) {
+            if members.get(member).unwrap().tp != *tp {
+                let fmt: String = format!("Expected '{}' type for member '{}', got '{}'.", tp, name, members.get(member).unwrap().tp);
+                errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
+            }
+        }
+        
+        let ptr: inkwell::values::PointerValue = Self::alloca(self, s.1.unwrap().into_struct_type(), name.as_str());
+        
+        for member in &node.data.initst.as_ref().unwrap().members_vec {
+            if members.get(member).unwrap().data.is_some() {
+                let itmptr: inkwell::values::PointerValue = self.builder.build_struct_gep(ptr, *s.2.get(member).unwrap() as u32, &member.as_str()).expect("GEP Error");
+                self.builder.build_store(itmptr, members.get(member).unwrap().data.unwrap());
+            }
+        }
+        
+        let data: types::Data = types::Data {
+            data: Some(self.builder.build_load(ptr, name.as_str())),
+            tp: s.0.clone(),
+            owned: true,
+        };
+        return data;
+    }
+
+    //Register an instance of a generic struct, and apply the impls of the generic struct to it
+    fn build_generic_struct_instance(&mut self, name: &String, generic_tps: Vec<types::DataType<'ctx>>, node: &parser::Node) -> types::DataType<'ctx> {
+        let mut tp: types::DataType = Self::get_generic_struct_tp(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, name, &generic_tps, node);
+        if self.cur_module.namespaces.structs.contains_key(&tp.name) {
+            return tp;
+        }
+
+        let generic: GenericStruct = self.cur_module.namespaces.generic_structs.get(name).unwrap().clone();
+        let st: &parser::nodes::StructNode = generic.node.data.st.as_ref().unwrap();
+
+        //Instances used by the members must exist first
+        let prev_tps: Vec<Option<types::DataType>> = self.push_template_types(&generic.template_types, &generic_tps);
+        for member in &st.names {
+            self.build_generic_struct_types(st.members.get(member).unwrap(), node);
+        }
+        self.pop_template_types(&generic.template_types, prev_tps);
+
+        tp = Self::get_generic_struct_tp(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, name, &generic_tps, node);
+        let instname: String = tp.name.clone();
+
+        self.cur_module.namespaces.structid_max += 1;
+        self.cur_module.namespaces.structid.insert(instname.clone(), self.cur_module.namespaces.structid_max);
+        self.cur_module.namespaces.structid_from.insert(self.cur_module.namespaces.structid_max, instname.clone());
+
+        let mut idxmapping: std::collections::HashMap<String, i32> = std::collections::HashMap::new();
+        let mut idx = 0;
+        for member in &st.names {
+            idxmapping.insert(member.clone(), idx);
+            idx+=1;
+        }
+
+        self.cur_module.datatypes.insert(instname.clone(), tp.clone());
+        self.cur_module.namespaces.structs.insert(instname.clone(), (tp.clone(), Some(Self::build_struct_tp_from_types(self.context, &self.inkwell_types, &tp.types, &self.cur_module.datatypes)), idxmapping, ForwardDeclarationType::Real));
+        builtin_types::add_simple_type(self, std::collections::HashMap::new(), types::BasicDataType::Struct, &instname);
+        self.cur_module.namespaces.generic_structs.get_mut(name).unwrap().instances.insert(instname.clone(), generic_tps);
+
+        let prev_pending: Option<Vec<(String, String, parser::Node)>> = self.pending_impls.replace(Vec::new());
+        for implnode in &generic.impls {
+            self.build_generic_struct_node(name, &instname, implnode);
+        }
+        self.finish_pending_impls(prev_pending);
+
+        return self.cur_module.namespaces.structs.get(&instname).unwrap().0.clone();
+    }
+
+    //Register the instances of generic structs, and the tuple types, used by a type
+    fn build_generic_struct_types(&mut self, tp: &parser::Type, node: &parser::Node) {
+        let alias: Option<parser::Type> = Self::resolve_type_alias(&self.cur_module.namespaces, self.info, tp, node);
+        if alias.is_some() {
+            self.build_generic_struct_types(alias.as_ref().unwrap(), node);
+        }
+        else if tp.isfn {
+            for arg in &tp.args.as_ref().unwrap().args {
+                self.build_generic_struct_types(arg, node);
+            }
+            for rettp in &tp.args.as_ref().unwrap().rettp {
+                self.build_generic_struct_types(rettp, node);
+            }
+        }
+        else if tp.isarr || tp.isref {
+            self.build_generic_struct_types(tp.basetp.as_ref().unwrap(), node);
+        }
+        else if tp.istuple {
+            for elemtp in tp.generic_tps.as_ref().unwrap() {
+                self.build_generic_struct_types(elemtp, node);
+            }
+
+            let tupletp: types::DataType = Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, tp, node).0;
+            self.add_tuple_type(&tupletp);
+        }
+        else if tp.isgenum {
+            for generic_tp in tp.generic_tps.as_ref().unwrap() {
+                self.build_generic_struct_types(generic_tp, node);
+            }
+
+            let name: &String = tp.basetp.as_ref().unwrap().data.as_ref().unwrap();
+            if self.cur_module.namespaces.generic_structs.contains_key(name) {
+                let mut generic_tps: Vec<types::DataType> = Vec::new();
+                for generic_tp in tp.generic_tps.as_ref().unwrap() {
+                    generic_tps.push(Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, generic_tp, node).0);
+                }
+                self.build_generic_struct_instance(name, generic_tps, node);
+            }
+        }
+    }
+
+    //Check if a type refers to the type alias name, directly or through other type aliases
+    fn type_alias_references(&self, tp: &parser::Type, name: &String, visited: &mut Vec<String>) -> bool {
+        let mut tps: Vec<&parser::Type> = Vec::new();
+        if tp.basetp.is_some() {
+            tps.push(tp.basetp.as_ref().unwrap());
+        }
+        if tp.generic_tps.is_some() {
+            tps.extend(tp.generic_tps.as_ref().unwrap());
+        }
+        if tp.args.is_some() {
+            tps.extend(&tp.args.as_ref().unwrap().args);
+            tps.extend(&tp.args.as_ref().unwrap().rettp);
+        }
+
+        if !tp.isfn && !tp.isarr && !tp.istuple && !tp.isdyn && !tp.isgenum && !tp.isref {
+            let data: &String = tp.data.as_ref().unwrap();
+            if data == name {
+                return true;
+            }
+            if self.cur_module.namespaces.type_aliases.contains_key(data) && !visited.contains(data) {
+                visited.push(data.to_owned());
+                tps.push(&self.cur_module.namespaces.type_aliases.get(data).unwrap().1);
+            }
+        }
+
+        return tps.iter().any(|x| self.type_alias_references(x, name, visited));
+    }
+
+    fn add_tuple_type(&mut self, tp: &types::DataType<'ctx>) {
+        if !self.cur_module.types.contains_key(&tp.name) {
+            builtin_types::add_simple_type(self, std::collections::HashMap::new(), types::BasicDataType::Struct, &tp.name);
+        }
+    }
+
+    //Compile a method, namespace function, or impl of a generic struct for one of its instances
+    fn build_generic_struct_node(&mut self, name: &String, instname: &String, node: &parser::Node) {
+        let generic: GenericStruct = self.cur_module.namespaces.generic_structs.get(name).unwrap().clone();
+        let generic_tps: Vec<types::DataType> = generic.instances.get(instname).unwrap().clone();
+
+        let mut node: parser::Node = node.clone();
+        if node.tp == parser::NodeType::IMPL {
+            node.data.impln.as_mut().unwrap().structnm = instname.to_owned();
+        }
+        else {
+            node.data.func.as_mut().unwrap().name = instname.to_owned();
+        }
+
+        let current_block: Option<inkwell::basic_block::BasicBlock> = self.current_block;
+        let enclosing_block: Option<inkwell::basic_block::BasicBlock> = self.enclosing_block;
+        let alloc_head: Option<inkwell::values::InstructionValue> = self.alloc_head;
+        let expected_rettp: Option<types::DataType> = self.expected_rettp.clone();
+        let loops: Vec<LoopFrame> = std::mem::take(&mut self.loops);
+        self.alloc_head = None;
+
+        let prev_tps: Vec<Option<types::DataType>> = self.push_template_types(&generic.template_types, &generic_tps);
+        if node.tp == parser::NodeType::IMPL {
+            self.build_impl(&node);
+        }
+        else {
+            self.build_func(&node, None, None, None);
+        }
+        self.pop_template_types(&generic.template_types, prev_tps);
+
+        self.current_block = current_block;
+        self.enclosing_block = enclosing_block;
+        self.alloc_head = alloc_head;
+        self.expected_rettp = expected_rettp;
+        self.loops = loops;
+        if self.current_block.is_some() {
+            self.builder.position_at_end(self.current_block.unwrap());
+        }
+    }
+
+    //Get a method or namespace function of a generic struct instance, compiling it on first use
+    fn get_generic_struct_method(&mut self, tp: &types::DataType<'ctx>, attr: &String) -> Option<types::Method<'ctx>> {
+        if tp.tp != types::BasicDataType::Struct || !tp.name.contains("<") {
+            return None;
+        }
+
+        let name: String = tp.name.split_once("<").unwrap().0.to_string();
+        let generic: GenericStruct = match self.cur_module.namespaces.generic_structs.get(&name) {
+            Some(v) => {
+                v.clone()
+            }
+            None => {
+                return None;
+            }
+        };
+
+        if !generic.instances.contains_key(&tp.name) {
+            return None;
+        }
+
+        if self.cur_module.namespaces.structs.get(&tp.name).unwrap().0.methods.contains_key(attr) {
+            return self.cur_module.namespaces.structs.get(&tp.name).unwrap().0.methods.get(attr).cloned();
+        }
+
+        for func in &generic.functions {
+            let funcnm: &String = if func.data.func.as_ref().unwrap().methodname.is_some() { func.data.func.as_ref().unwrap().methodname.as_ref().unwrap() } else { func.data.func.as_ref().unwrap().namespacename.as_ref().unwrap() };
+            if funcnm == attr {
+                self.build_generic_struct_node(&name, &tp.name, func);
+                return self.cur_module.namespaces.structs.get(&tp.name).unwrap().0.methods.get(attr).cloned();
+            }
+        }
+
+        return None;
+    }
+
+    //Get a method of a type from its ToString, Clone or Hash trait, so that `x.clone()` calls the builtin or derived trait
+    fn get_trait_method(&self, tp: &types::DataType<'ctx>, attr: &String) -> Option<types::Method<'ctx>> {
+        let traits: &std::collections::HashMap<String, types::Trait> = match self.cur_module.types.get(&tp.name) {
+            Some(v) => &v.traits,
+            None => return None,
+        };
+
+        for traittp in [types::TraitType::ToString, types::TraitType::Clone, types::TraitType::Hash] {
+            if &self.traits.get(&traittp.to_string()).unwrap().name != attr {
+                continue;
+            }
+            let t: &types::Trait = match traits.get(&traittp.to_string()) {
+                Some(v) => v,
+                None => return None,
+            };
+
+            if t.function.is_some() {
+                let mut functp: types::DataType = Self::datatypes_get(self, &types::BasicDataType::WrapperFunc.to_string()).unwrap().clone();
+                functp.names = Some(vec![String::from("self")]);
+                functp.types = vec![tp.clone()];
+                functp.rettp = Some(Box::new(t.rettp.clone().unwrap()));
+                functp.wrapperfn = t.function;
+
+                return Some(types::Method {
+                    tp: types::MethodType::Builtin,
+                    builtin: t.function,
+                    func: None,
+                    functp,
+                    isinstance: true,
+                    isinstanceptr: false,
+                    ismutinstanceptr: false,
+                });
+            }
+            if t.inkfunc.is_some() {
+                let mut functp: types::DataType = Self::datatypes_get(self, &types::BasicDataType::Func.to_string()).unwrap().clone();
+                functp.names = Some(vec![String::from("self")]);
+                functp.types = vec![tp.clone()];
+                functp.rettp = Some(Box::new(t.rettp.clone().unwrap()));
+
+                return Some(types::Method {
+                    tp: types::MethodType::Fn,
+                    builtin: None,
+                    func: t.inkfunc,
+                    functp,
+                    isinstance: true,
+                    isinstanceptr: false,
+                    ismutinstanceptr: false,
+                });
+            }
+        }
+
+        return None;
+    }
+
+    //Make template types of a generic struct resolvable, returning the types they shadow
+    fn push_template_types(&mut self, templates: &Vec<String>, tps: &Vec<types::DataType<'ctx>>) -> Vec<Option<types::DataType<'ctx>>> {
+        let mut prev_tps: Vec<Option<types::DataType>> = Vec::new();
+        for (template, tp) in izip![templates, tps] {
+            prev_tps.push(self.cur_module.datatypes.insert(template.to_owned(), tp.clone()));
+        }
+        return prev_tps;
+    }
+
+    //Make the bounds of the template types of a template function resolvable, with their supertraits, returning the bounds they shadow
+    fn push_template_bounds(&mut self, templates: &Vec<String>, template_bounds: &std::collections::HashMap<String, Vec<String>>) -> std::collections::HashMap<String, Vec<String>> {
+        let prev_bounds: std::collections::HashMap<String, Vec<String>> = self.cur_module.namespaces.template_type_bounds.clone();
+        for template in templates {
+            let bounds: Vec<String> = self.get_bound_traits(&template_bounds.get(template).cloned().unwrap_or(Vec::new()));
+            self.cur_module.namespaces.template_type_bounds.insert(template.to_owned(), bounds);
+        }
+        return prev_bounds;
+    }
+
+    fn pop_template_bounds(&mut self, prev_bounds: std::collections::HashMap<String, Vec<String>>) {
+        self.cur_module.namespaces.template_type_bounds = prev_bounds;
+    }
+
+    fn pop_template_types(&mut self, templates: &Vec<String>, prev_tps: Vec<Option<types::DataType<'ctx>>>) {
+        for (template, tp) in izip![templates, prev_tps] {
+            if tp.is_some() {
+                self.cur_module.datatypes.insert(template.to_owned(), tp.unwrap());
+            }
+            else {
+                self.cur_module.datatypes.remove(template);
+            }
+        }
+    }
+
+    fn build_generic_struct_namespaceload(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        let name: &String = &node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name;
+        let attr: &String = &node.data.attr.as_ref().unwrap().attr;
+
+        let mut generic_tps: Vec<types::DataType> = Vec::new();
+        for tp in node.data.attr.as_ref().unwrap().template_types.as_ref().unwrap() {
+            self.build_generic_struct_types(tp, node);
+            generic_tps.push(Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, tp, node).0);
+        }
+
+        let tp: types::DataType = self.build_generic_struct_instance(name, generic_tps, node);
+        let method: Option<types::Method> = self.get_generic_struct_method(&tp, attr);
+
+        if method.is_none() || method.as_ref().unwrap().isinstance {
+            let fmt: String = format!("Type '{}' has no namespace attribute '{}'.", tp, attr);
+            errors::raise_error(&fmt, errors::ErrorType::NamespaceAttrNotFound, &node.pos, self.info);
+        }
+
+        let data: types::Data = types::Data {
+            data: Some(inkwell::values::BasicValueEnum::PointerValue(method.as_ref().unwrap().func.unwrap())),
+            tp: method.unwrap().functp.clone(),
+            owned: true,
+        };
+        return data;
+    }
+
+    fn build_attrload(&mut self, node: &parser::Node, borrow_options: BorrowOptions) -> types::Data<'ctx> {
+        if !borrow_options.give_ownership {
+            let fmt: String = format!("Cannot take reference of attribute.");
+            errors::raise_error(&fmt, errors::ErrorType::CannotTakeReferenceOfAttr, &node.pos, self.info);
+        }
+        let base: types::Data = self.compile_expr(&node.data.attr.as_ref().unwrap().name, BorrowOptions{ give_ownership: false, get_ptr: true, mut_borrow: false}, false, false);
+
+        if base.tp.tp != types::BasicDataType::Struct {
+            let fmt: String = format!("Expected struct, got '{}'.", base.tp.tp);
+            errors::raise_error(&fmt, errors::ErrorType::GetAttrOfNonStruct, &node.pos, self.info);
+        }
+
+        let attr: String = node.data.attr.as_ref().unwrap().attr.clone();
+
+        if !base.tp.names.as_ref().unwrap().contains(&attr) {
+            let fmt: String = format!("Type '{}' has no attribute '{}'.", base.tp, attr);
+            errors::raise_error(&fmt, errors::ErrorType::StructAttrNotFound, &node.pos, self.info);
+        }
+
+        let mut idx: u32 = 0;
+        for (attrn, tp) in izip![base.tp.names.as_ref().unwrap(), &base.tp.types] {
+            if tp.tp == types::BasicDataType::Void {
+                if attrn == &attr {
+                    let data: types::Data = types::Data {
+                        data: None,
+                        tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
+                        owned: true,
+                    };
+                    return data;
+                }
+                continue;
+            }
+            if attrn == &attr {
+                break;
+            }
+            idx+=1;
+        }
+
+        let mut ptr: inkwell::values::PointerValue;
+        if base.data.unwrap().is_pointer_value() {
+            ptr = base.data.unwrap().into_pointer_value();
+        }
+        else {
+            ptr = Self::alloca(self, base.data.unwrap().get_type(), "inplace_attr");
+            self.builder.build_store(ptr, base.data.unwrap());
+        }
+
+        //A name holding a reference stores the pointer to the struct, which is loaded through
+        if base.tp.is_ref && ptr.get_type().get_element_type().is_pointer_type() {
+            ptr = self.builder.build_load(ptr, "ref_attr").into_pointer_value();
+        }
+
+        let itmptr: inkwell::values::PointerValue = self.builder.build_struct_gep(ptr, idx, base.tp.name.as_str()).expect("GEP Error");
+        if borrow_options.get_ptr {
+            let data: types::Data = types::Data {
+                data: Some(inkwell::values::BasicValueEnum::PointerValue(itmptr)),
+                tp: base.tp.types.get(idx as usize).unwrap().clone(),
+                owned: true,
+            };
+            return data;
+        }
+        
+        let val = self.builder.build_load(itmptr, attr.as_str());
+
+        let data: types::Data = types::Data {
+            data: Some(val),
+            tp: base.tp.types.get(idx as usize).unwrap().clone(),
+            owned: true,
+        };
+        return data;
+    }
+
+    fn build_attrasssign(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        let attrassign: &parser::nodes::AttrAssignNode = node.data.attrassign.as_ref().unwrap();
+
+        let target: Option<(inkwell::values::PointerValue, types::DataType)> = self.build_attr_target(&attrassign.name, &attrassign.attr, node, true);
+
+        if target.is_some() {
+            let (itmptr, attrtp) = target.unwrap();
+
+            let expr: types::Data = self.compile_expr(&attrassign.expr, BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, false, false);
+
+            if expr.tp != attrtp {
+                let fmt: String = format!("Expected '{}' type, got '{}' type.", expr.tp, attrtp);
+                errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
+            }
+
+            if expr.data.is_some() {
+                self.builder.build_store(itmptr, expr.data.unwrap());
+            }
+        }
+
+        let data: types::Data = types::Data {
+            data: None,
+            tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
+            owned: true,
+        };
+        return data;
+    }
+
+    //Get a pointer to a struct attribute, or None for a void attribute. Nested attributes are resolved recursively,
+    //and if assign is set every attribute and reference along the way must be mutable.
+    fn build_attr_target(&mut self, name: &parser::Node, attr: &String, node: &parser::Node, assign: bool) -> Option<(inkwell::values::PointerValue<'ctx>, types::DataType<'ctx>)> {
+        let base: types::Data = if name.tp == parser::NodeType::ATTR {
+            let attrn: &parser::nodes::AttrNode = name.data.attr.as_ref().unwrap();
+            match self.build_attr_target(&attrn.name, &attrn.attr, name, assign) {
+                Some((ptr, tp)) => {
+                    types::Data {
+                        data: Some(inkwell::values::BasicValueEnum::PointerValue(ptr)),
+                        tp,
+                        owned: true,
+                    }
+                }
+                None => {
+                    types::Data {
+                        data: None,
+                        tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
+                        owned: true,
+                    }
+                }
+            }
+        }
+        else {
+            self.compile_expr(name, BorrowOptions{ give_ownership: false, get_ptr: true, mut_borrow: false}, false, false)
+        };
+
+        if base.tp.tp != types::BasicDataType::Struct {
+            let fmt: String = format!("Expected struct, got '{}'.", base.tp.tp);
+            errors::raise_error(&fmt, errors::ErrorType::GetAttrOfNonStruct, &node.pos, self.info);
+        }
+
+        if !base.tp.names.as_ref().unwrap().contains(attr) {
+            let fmt: String = format!("Struct '{}' has no attribute '{}'.", base.tp, attr);
+            errors::raise_error(&fmt, errors::ErrorType::StructAttrNotFound, &node.pos, self.info);
+        }
+
+        if assign && base.tp.is_ref && base.tp.mutability.first().unwrap() == &types::DataMutablility::Immutable{
+            let fmt: String = format!("Cannot assign attribute to immutable reference");
+            errors::raise_error(&fmt, errors::ErrorType::ImmutableRefAttr, &node.pos, self.info);
+        }
+
+        let mut idx: u32 = 0;
+        for (attrn, tp) in izip![base.tp.names.as_ref().unwrap(), &base.tp.types] {
+            if tp.tp == types::BasicDataType::Void {
+                if attrn == attr {
+                    return None;
+                }
+                continue;
+            }
+            if attrn == attr {
+                break;
+            }
+            idx+=1;
+        }
+
+        if assign && base.tp.mutability.get(idx as usize).unwrap() == &types::DataMutablility::Immutable{
+            let fmt: String = format!("Attribute '{}' is immutable.", base.tp);
+            errors::raise_error(&fmt, errors::ErrorType::ImmutableAttr, &node.pos, self.info);
+        }
+
+        let mut ptr: inkwell::values::PointerValue;
+        if base.data.unwrap().is_pointer_value() {
+            ptr = base.data.unwrap().into_pointer_value();
+        }
+        else {
+            ptr = Self::alloca(self, base.data.unwrap().get_type(), "inplace_attr");
+            self.builder.build_store(ptr, base.data.unwrap());
+        }
+
+        //A name holding a reference stores the pointer to the struct, which is loaded through
+        if base.tp.is_ref && ptr.get_type().get_element_type().is_pointer_type() {
+            ptr = self.builder.build_load(ptr, "ref_attr").into_pointer_value();
+        }
+
+        let itmptr: inkwell::values::PointerValue = self.builder.build_struct_gep(ptr, idx, base.tp.name.as_str()).expect("GEP Error");
+
+        return Some((itmptr, base.tp.types.get(idx as usize).unwrap().clone()));
+    }
+
+    fn build_index(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        self.check_template_bound(&node.data.index.as_ref().unwrap().name, &types::TraitType::Index.to_string(), node);
+
+        let (base, index) = self.build_index_target(node, false);
+
+        return self.build_index_load(base, index, node);
+    }
+
+    fn build_indexassign(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        self.check_template_bound(&node.data.index.as_ref().unwrap().name, &types::TraitType::IndexMut.to_string(), node);
+
+        let (base, index) = self.build_index_target(node, true);
+
+        //An untyped literal takes the element type of builtin indexable types
+        let elemtp: Option<types::DataType> = if base.tp.tp == types::BasicDataType::Array {
+            Some(base.tp.types.first().unwrap().clone())
+        }
+        else if base.tp.tp == types::BasicDataType::Struct && base.tp.name == "String" {
+            Some(Self::datatypes_get(self, &types::BasicDataType::U8.to_string()).unwrap().clone())
+        }
+        else {
+            None
+        };
+
+        let expr: types::Data = if elemtp.is_some() {
+            self.compile_expr_expecting(node.data.index.as_ref().unwrap().expr.as_ref().unwrap(), BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, elemtp.as_ref().unwrap())
+        }
+        else {
+            self.compile_expr(node.data.index.as_ref().unwrap().expr.as_ref().unwrap(), BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, false, false)
+        };
+
+        self.build_index_store(base, index, expr, node);
+
+        let data: types::Data = types::Data {
+            data: None,
+            tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
+            owned: true,
+        };
+        return data;
+    }
+
+    //Compile the indexed value (as a pointer) and the index
+    fn build_index_target(&mut self, node: &parser::Node, assign: bool) -> (types::Data<'ctx>, types::Data<'ctx>) {
+        let indexn: &parser::nodes::IndexNode = node.data.index.as_ref().unwrap();
+
+        if assign && indexn.name.tp == parser::NodeType::IDENTIFIER {
+            let name: &String = &indexn.name.data.identifier.as_ref().unwrap().name;
+            if  self.get_variable(name).0.is_some() &&
+                self.get_variable(name).0.unwrap().2 == types::DataMutablility::Immutable &&
+                !self.get_variable(name).0.unwrap().3.mut_borrowed {
+                let fmt: String = format!("Cannot assign to index of immutable variable.");
+                errors::raise_error(&fmt, errors::ErrorType::ImmutableAssign, &node.pos, self.info);
+            }
+        }
+
+        let base: types::Data = if indexn.name.tp == parser::NodeType::ATTR {
+            let attrn: &parser::nodes::AttrNode = indexn.name.data.attr.as_ref().unwrap();
+            match self.build_attr_target(&attrn.name, &attrn.attr, &indexn.name, assign) {
+                Some((ptr, tp)) => {
+                    types::Data {
+                        data: Some(inkwell::values::BasicValueEnum::PointerValue(ptr)),
+                        tp,
+                        owned: true,
+                    }
+                }
+                None => {
+                    types::Data {
+                        data: None,
+                        tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
+                        owned: true,
+                    }
+                }
+            }
+        }
+        else {
+            self.compile_expr(&indexn.name, BorrowOptions{ give_ownership: false, get_ptr: true, mut_borrow: false}, false, false)
+        };
+        let index: types::Data = self.compile_expr(&indexn.index, BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: false}, false, false);
+
+        return (base, index);
+    }
+
+    //Get a bounds-checked element pointer for builtin indexable types, or None if the Index traits should be used
+    fn build_index_ptr(&mut self, base: &types::Data<'ctx>, index: &types::Data<'ctx>, node: &parser::Node) -> Option<(inkwell::values::PointerValue<'ctx>, types::DataType<'ctx>)> {
+        let isstring: bool = base.tp.tp == types::BasicDataType::Struct && base.tp.name == "String";
+        let elemtp: types::DataType;
+
+        if base.tp.tp == types::BasicDataType::Array {
+            elemtp = base.tp.types.first().unwrap().clone();
+        }
+        else if isstring {
+            elemtp = Self::datatypes_get(self, &types::BasicDataType::U8.to_string()).unwrap().clone();
+        }
+        else {
+            return None;
+        }
+
+        match index.tp.tp {
+            types::BasicDataType::I8 |
+            types::BasicDataType::U8 |
+            types::BasicDataType::I16 |
+            types::BasicDataType::U16 |
+            types::BasicDataType::I32 |
+            types::BasicDataType::U32 |
+            types::BasicDataType::I64 |
+            types::BasicDataType::U64 |
+            types::BasicDataType::I128 |
+            types::BasicDataType::U128 => {}
+            _ => {
+                let fmt: String = format!("Expected integer index, got '{}'.", index.tp);
+                errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, &node.pos, self.info);
+            }
+        }
+
+        let mut idx: inkwell::values::IntValue = index.data.unwrap().into_int_value();
+
+        //Negative signed indices are sign extended, so they are caught by the unsigned bounds check
+        if idx.get_type().get_bit_width() < 64 {
+            if builtin_types::int_issigned(index.tp.clone()) {
+                idx = self.builder.build_int_s_extend(idx, *self.inkwell_types.i64tp, "index_ext");
+            }
+            else {
+                idx = self.builder.build_int_z_extend(idx, *self.inkwell_types.i64tp, "index_ext");
+            }
+        }
+
+        //The length of an array is part of its type, and that of a String is only known at runtime
+        let lenv: inkwell::values::IntValue = if isstring {
+            let lenptr: inkwell::values::PointerValue = self.builder.build_struct_gep(base.data.unwrap().into_pointer_value(), 1 as u32, "len_ptr").expect("GEP Error");
+            let len: inkwell::values::IntValue = self.builder.build_load(lenptr, "len").into_int_value();
+            self.builder.build_int_z_extend_or_bit_cast(len, idx.get_type(), "len")
+        }
+        else {
+            let len: u32 = base.data.unwrap().into_pointer_value().get_type().get_element_type().into_array_type().len();
+            if idx.is_const() && idx.get_zero_extended_constant().is_some() && idx.get_zero_extended_constant().unwrap() >= len as u64 {
+                let fmt: String = format!("Index out of range for length '{}'.", len);
+                errors::raise_error(&fmt, errors::ErrorType::IndexOutOfRange, &node.pos, self.info);
+            }
+            idx.get_type().const_int(len as u64, false)
+        };
+
+        let check: inkwell::values::IntValue = self.builder.build_int_compare(inkwell::IntPredicate::UGE, idx, lenv, "bounds_check");
+        let msg: String = format!("index out of bounds at {}:{}:{}", self.info.name, node.pos.line+1, node.pos.startcol+1);
+        Self::build_trap_if(self, check, "index_out_of_range", Some(msg));
+
+        let itmptr: inkwell::values::PointerValue = if isstring {
+            let dataptr: inkwell::values::PointerValue = self.builder.build_struct_gep(base.data.unwrap().into_pointer_value(), 0 as u32, "data_ptr").expect("GEP Error");
+            let data: inkwell::values::PointerValue = self.builder.build_load(dataptr, "data").into_pointer_value();
+            unsafe { self.builder.build_in_bounds_gep(data, &[idx], "itmptr") }
+        }
+        else {
+            unsafe { self.builder.build_in_bounds_gep(base.data.unwrap().into_pointer_value(), &[self.inkwell_types.i32tp.const_zero(), idx], "itmptr") }
+        };
+
+        return Some((itmptr, elemtp));
+    }
+
+    fn build_index_load(&mut self, base: types::Data<'ctx>, index: types::Data<'ctx>, node: &parser::Node) -> types::Data<'ctx> {
+        let res: Option<(inkwell::values::PointerValue, types::DataType)> = self.build_index_ptr(&base, &index, node);
+
+        if res.is_some() {
+            let (itmptr, elemtp) = res.unwrap();
+
+            let data: types::Data = types::Data {
+                data: Some(self.builder.build_load(itmptr, "item")),
+                tp: elemtp,
+                owned: true,
+            };
+            return data;
+        }
+
+        let tp: types::Type = Self::get_type_from_data(self.cur_module.types.clone(), &base);
+
+        let t: &types::Trait = match tp.traits.get(&types::TraitType::Index.to_string()) {
+            Some (v) => {
+                v
+            }
+            None => {
+                let fmt: String = format!("Type '{}' has no trait '{}'.", base.tp.name, &types::TraitType::Index.to_string());
+                errors::raise_error(&fmt, errors::ErrorType::MissingTrait, &node.pos, self.info);
+            }
+        };
+
+        let mut selfv: types::Data = base.clone();
+        selfv.tp.is_ref = true;
+
+        return self.call_trait(t, vec![selfv, index], node);
+    }
+
+    fn build_index_store(&mut self, base: types::Data<'ctx>, index: types::Data<'ctx>, expr: types::Data<'ctx>, node: &parser::Node) {
+        let res: Option<(inkwell::values::PointerValue, types::DataType)> = self.build_index_ptr(&base, &index, node);
+
+        if res.is_some() {
+            let (itmptr, elemtp) = res.unwrap();
+
+            if expr.tp != elemtp {
+                let fmt: String = format!("Expected '{}' type, got '{}' type.", elemtp, expr.tp);
+                errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
+            }
+
+            self.builder.build_store(itmptr, expr.data.unwrap());
+            return;
+        }
+
+        let tp: types::Type = Self::get_type_from_data(self.cur_module.types.clone(), &base);
+
+        let t: &types::Trait = match tp.traits.get(&types::TraitType::IndexMut.to_string()) {
+            Some (v) => {
+                v
+            }
+            None => {
+                let fmt: String = format!("Type '{}' has no trait '{}'.", base.tp.name, &types::TraitType::IndexMut.to_string());
+                errors::raise_error(&fmt, errors::ErrorType::MissingTrait, &node.pos, self.info);
+            }
+        };
+
+        let mut selfv: types::Data = base.clone();
+        selfv.tp.is_ref = true;
+
+        self.call_trait(t, vec![selfv, index, expr], node);
+    }
+
+    fn build_string(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        return self.build_string_data(&node.data.str.as_ref().unwrap().data);
+    }
+
+    fn build_string_data(&mut self, data: &String) -> types::Data<'ctx> {
+        let data: Vec<u8> = data.as_str().as_bytes().to_vec();
+        
+        let arraytp: inkwell::types::ArrayType = self.inkwell_types.i8tp.array_type(data.len() as u32);
+
+        let mut arrdata: Vec<inkwell::values::IntValue> = Vec::new();
+        for c in data {
+            arrdata.push(self.inkwell_types.i8tp.const_int(c as u64, false));
+        }
+        arrdata.push(self.inkwell_types.i8tp.const_zero());
+
+        let array: inkwell::values::ArrayValue = self.inkwell_types.i8tp.const_array(&arrdata[..]);
+
+        let types: Vec<types::DataType> = vec![Self::datatypes_get(self, &types::BasicDataType::I8.to_string()).unwrap().clone()];
+
+        let mut tp: types::DataType = Self::datatypes_get(self, &types::BasicDataType::Array.to_string()).unwrap().clone();
+        tp.name = Self::array_repr(arraytp);
+        tp.arrtp = Some(arraytp);
+        tp.types = types;
+
+        let data: types::Data = types::Data {
+            data: Some(inkwell::values::BasicValueEnum::ArrayValue(array)),
+            tp,
+            owned: true,
+        };
+        return data;
+    }
+
+    fn build_char(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        let mut data: std::str::Chars = node.data.num.as_ref().unwrap().left.chars();
+        
+        let selfv: inkwell::values::IntValue = self.inkwell_types.i32tp.const_int((data.next().unwrap()).into(), false);
+        return types::Data {data: Some(inkwell::values::BasicValueEnum::IntValue(selfv)), tp: Self::datatypes_get(self, &types::BasicDataType::Char.to_string()).unwrap().clone(), owned: true}
+    }
+
+    fn build_bool(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        let value: bool = node.data.num.as_ref().unwrap().left == "true";
+
+        let selfv: inkwell::values::IntValue = self.inkwell_types.booltp.const_int(value as u64, false);
+        return types::Data {data: Some(inkwell::values::BasicValueEnum::IntValue(selfv)), tp: Self::datatypes_get(self, &types::BasicDataType::Bool.to_string()).unwrap().clone(), owned: true}
+    }
+
+    fn build_array(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        let elements: &Vec<parser::Node> = &node.data.arr.as_ref().unwrap().elements;
+
+        let mut data_elem: Vec<types::Data> = Vec::new();
+
+        if elements.len() == 0 {
+            let fmt: String = format!("Cannot define zero-length array.");
+            errors::raise_error(&fmt, errors::ErrorType::ZeroLengthArray, &node.pos, self.info);
+        }
+
+        data_elem.push(self.compile_expr(elements.first().unwrap(), BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, false, false));
+        let firsttp_: Option<inkwell::types::AnyTypeEnum> = Self::get_anytp_from_tp(self.context, &self.inkwell_types, data_elem.first().unwrap().tp.clone(), &self.cur_module.datatypes);
+        if firsttp_.is_none() {
+            let fmt: String = format!("Cannot define array of 'void'.");
+            errors::raise_error(&fmt, errors::ErrorType::CannotDefineVoidArray, &node.pos, self.info);
+        }
+        if firsttp_.unwrap().is_function_type() {
+            let fmt: String = format!("Cannot define array of 'fn'.");
+            errors::raise_error(&fmt, errors::ErrorType::CannotDefineFnArray, &node.pos, self.info);
+        }
+        let firsttp: inkwell::types::BasicTypeEnum = Self::get_basic_from_any(firsttp_.unwrap()).unwrap();
+
+        for element in elements[1..].to_vec() {
+            data_elem.push(self.compile_expr(&element, BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, false, false));
+            let tp_: Option<inkwell::types::AnyTypeEnum> = Self::get_anytp_from_tp(self.context, &self.inkwell_types, data_elem.last().unwrap().tp.clone(), &self.cur_module.datatypes);
+            if tp_.is_none() {
+                let fmt: String = format!("Expected '{}' type, got 'void' type.", data_elem.first().unwrap().tp.to_string());
+                errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &element.pos, self.info);
+            }
+            let tp: inkwell::types::BasicTypeEnum = Self::get_basic_from_any(tp_.unwrap()).unwrap();
+            if tp != firsttp {
+                let fmt: String = format!("Expected '{}' type, got '{}' type.", data_elem.first().unwrap().tp.to_string(), data_elem.last().unwrap().tp.to_string());
+                errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &element.pos, self.info);
+            }
+        }
+
+        let firstdatatp: types::DataType = data_elem.first().unwrap().tp.clone();
+
+        let arraytp: inkwell::types::ArrayType = firsttp.array_type(elements.len() as u32);
+        let array: inkwell::values::PointerValue = Self::alloca(self, arraytp, "arr");
+
+        let mut idx: usize = 0;
+        for element in data_elem {
+            let ptr: inkwell::values::PointerValue = unsafe { self.builder.build_gep(array, &[self.inkwell_types.i8tp.const_int(0, false), self.inkwell_types.i8tp.const_int(idx as u64, false)], &element.tp.name) };
+            self.builder.build_store(ptr, element.data.unwrap());
+            idx+=1;
+        }        
+
+        let mut tp: types::DataType = Self::datatypes_get(self, &types::BasicDataType::Array.to_string()).unwrap().clone();
+        tp.name = Self::array_repr(arraytp);
+        tp.arrtp = Some(arraytp);
+        tp.types = vec![firstdatatp];
+
+        let data: types::Data = types::Data {
+            data: Some(inkwell::values::BasicValueEnum::PointerValue(array)),
+            tp,
+            owned: true,
+        };
+        return data;
+    }
+
+    fn build_tuple(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        let mut elements: Vec<types::Data> = Vec::new();
+
+        for element in &node.data.arr.as_ref().unwrap().elements {
+            let v: types::Data = self.compile_expr(element, BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, false, false);
+
+            if v.tp.tp == types::BasicDataType::Array && v.data.is_some() && v.data.unwrap().is_pointer_value() {
+                elements.push(types::Data {
+                    data: Some(self.builder.build_load(v.data.unwrap().into_pointer_value(), &v.tp.name)),
+                    tp: v.tp.clone(),
+                    owned: v.owned,
+                });
+            }
+            else {
+                elements.push(v);
+            }
+        }
+
+        let tp: types::DataType = types::new_tuple_datatype(elements.iter().map(|x| x.tp.clone()).collect());
+        self.add_tuple_type(&tp);
+
+        let structtp: inkwell::types::StructType = Self::build_struct_tp_from_types(self.context, &self.inkwell_types, &tp.types, &self.cur_module.datatypes).into_struct_type();
+        let ptr: inkwell::values::PointerValue = Self::alloca(self, structtp, "tuple");
+
+        let mut idx: u32 = 0;
+        for element in &elements {
+            if element.data.is_some() {
+                let itmptr: inkwell::values::PointerValue = self.builder.build_struct_gep(ptr, idx, &element.tp.name).expect("GEP Error");
+                self.builder.build_store(itmptr, element.data.unwrap());
+                idx += 1;
+            }
+        }
+
+        let data: types::Data = types::Data {
+            data: Some(self.builder.build_load(ptr, "tuple")),
+            tp,
+            owned: true,
+        };
+        return data;
+    }
+
+    fn build_impl(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        let traitnm: &String = &node.data.impln.as_ref().unwrap().traitnm;
+        let structnm: &String = &node.data.impln.as_ref().unwrap().structnm;
+
+        //Impls for generic structs are applied to each instance
+        if self.cur_module.namespaces.generic_structs.contains_key(structnm) {
+            self.cur_module.namespaces.generic_structs.get_mut(structnm).unwrap().impls.push(node.clone());
+            
+            let instances: Vec<String> = self.cur_module.namespaces.generic_structs.get(structnm).unwrap().instances.keys().cloned().collect();
+            for instname in &instances {
+                self.build_generic_struct_node(structnm, instname, node);
+            }
+
+            let data: types::Data = types::Data {
+                data: None,
+                tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
+                owned: true,
+            };
+            return data;
+        }
+
+        if !self.traits.contains_key(traitnm) {
+            let fmt: String = format!("Trait '{}' not found.", traitnm.to_string());
+            errors::raise_error(&fmt, errors::ErrorType::TraitNotFound, &node.pos, self.info);
+        }
+
+        let mut traitsig: types::TraitSignature = self.traits.get(traitnm).unwrap().clone();
+
+        let mut functions: std::collections::HashMap<String, inkwell::values::PointerValue> = std::collections::HashMap::new();
+
+        if traitsig.traittp == types::TraitMetatype::Builtin {
+            //A builtin trait has a single function, so any other function of the impl is not in the trait
+            for function in &node.data.impln.as_ref().unwrap().functions[..node.data.impln.as_ref().unwrap().functions.len().saturating_sub(1)] {
+                if function.data.func.as_ref().unwrap().name != traitsig.name {
+                    let fmt: String = format!("Function '{}' is not defined in trait '{}'.", function.data.func.as_ref().unwrap().name, traitnm.to_string());
+                    errors::raise_error(&fmt, errors::ErrorType::FunctionNotDefinedInTrait, &function.pos, self.info);
+                }
+
+                let fmt: String = format!("Function '{}' is redefined in impl for trait '{}' on struct '{}'.", traitsig.name, traitnm.to_string(), structnm.to_string());
+                errors::raise_error(&fmt, errors::ErrorType::FunctionRedefinedInImpl, &function.pos, self.info);
+            }
+
+            if traitsig.name != node.data.impln.as_ref().unwrap().functions.last().unwrap().data.func.as_ref().unwrap().name {
+                let fmt: String = format!("Trait '{}' expected function '{}'.", traitnm.to_string(), traitsig.name);
+                errors::raise_error(&fmt, errors::ErrorType::TraitExpectProperFunctionName, &node.pos, self.info);
+            }
+
+            let nargs: usize = traitsig.nargs.unwrap();
+
+            if nargs != node.data.impln.as_ref().unwrap().functions.last().unwrap().data.func.as_ref().unwrap().args.args.len() {
+                let fmt: String = format!("Trait '{}' expected function with '{}' arguments.", traitnm.to_string(), traitsig.nargs.unwrap());
+                errors::raise_error(&fmt, errors::ErrorType::ArgumentCountMismatch, &node.pos, self.info);
+            }
+            
+            let func: types::Data = self.build_func(&node.data.impln.as_ref().unwrap().functions.last().unwrap(), Some(structnm.to_owned() + "." + node.data.impln.as_ref().unwrap().functions.last().unwrap().data.func.as_ref().unwrap().name.as_str()), None, None);
+
+            if !self.cur_module.namespaces.structs.contains_key(structnm) {
+                let fmt: String = format!("Struct '{}' is not defined.", structnm);
+                errors::raise_error(&fmt, errors::ErrorType::StructNotDefined, &node.pos, self.info);
+            }
+            
+            let mut tp: types::Type = self.cur_module.types.get(structnm).unwrap().clone();
+
+            if tp.traits.contains_key(traitnm) {
+                let fmt: String = format!("Struct '{}' already implements trait '{}'.", structnm, traitnm);
+                errors::raise_error(&fmt, errors::ErrorType::StructAlreadyImplements, &node.pos, self.info);
+            }
+
+            let rettp: types::DataType = Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, node.data.impln.as_ref().unwrap().functions.last().unwrap().data.func.as_ref().unwrap().args.rettp.first().unwrap(), node).0;
+
+            let traittp: Option<types::TraitType> = types::get_traittp_from_str(traitnm.to_owned());
+            if traittp.as_ref().unwrap() == &types::TraitType::Call {
+                let fmt: String = format!("Cannot implement 'Call' trait.");
+                errors::raise_error(&fmt, errors::ErrorType::CannotImplementCallTrait, &node.pos, self.info);
+            }
+
+            tp.traits.insert(traitnm.to_owned(), builtin_types::create_trait_ink(func.data.unwrap().into_pointer_value(), nargs, traittp.unwrap(), rettp));
+            
+            self.cur_module.types.insert(structnm.to_owned(), tp);
+
+            functions.insert(traitsig.name, func.data.unwrap().into_pointer_value());
+        }
+        else {
+            if !self.cur_module.namespaces.structs.contains_key(structnm) {
+                let fmt: String = format!("Struct '{}' is not defined.", structnm);
+                errors::raise_error(&fmt, errors::ErrorType::StructNotDefined, &node.pos, self.info);
+            }
+
+            let traittp: Option<types::TraitType> = types::get_traittp_from_str(traitnm.to_owned());
+            if traittp.is_some() {
+                let fmt: String = format!("Cannot implement builtin trait '{}'.", traittp.unwrap().to_string());
+                errors::raise_error(&fmt, errors::ErrorType::CannotImplementBuiltinTrait, &node.pos, self.info);
+            }
+            
+            let mut tp: types::Type = self.cur_module.types.get(structnm).unwrap().clone();
+
+            if tp.traits.contains_key(traitnm) {
+                let fmt: String = format!("Struct '{}' already implements trait '{}'.", structnm, traitnm);
+                errors::raise_error(&fmt, errors::ErrorType::StructAlreadyImplements, &node.pos, self.info);
+            }
+            
+            tp.traits.insert(traitnm.to_owned(), builtin_types::create_empty_trait());  
+            self.cur_module.types.insert(structnm.to_owned(), tp);
+
+            for var in traitsig.vars.as_ref().unwrap() {
+                if !self.cur_module.namespaces.structs.get(structnm).unwrap().0.names.as_ref().unwrap().contains(var.0) {
+                    let fmt: String = format!("Struct '{}' does not implement required member '{}'.", structnm, var.0);
+                    errors::raise_error(&fmt, errors::ErrorType::CannotImplementBuiltinTrait, &node.pos, self.info);                    
+                }
+                let idx = self.cur_module.namespaces.structs.get(structnm).unwrap().0.names.as_ref().unwrap().iter().position(|x| x==var.0).unwrap();
+                if self.cur_module.namespaces.structs.get(structnm).unwrap().0.types.get(idx).unwrap() != &Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, var.1, node).0 {
+                    let fmt: String = format!("Struct '{}' does not implement required member '{}' of type '{}'.", structnm, var.0, self.cur_module.namespaces.structs.get(structnm).unwrap().0.types.get(idx).unwrap());
+                    errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);     
+                }
+            }
+            
+            for function in &node.data.impln.as_ref().unwrap().functions {
+                if function.data.func.as_ref().unwrap().template_types.len() > 0 {
+                    let fmt: String = format!("Implementation functions may not be templated.");
+                    errors::raise_error(&fmt, errors::ErrorType::ImplTemplatedFunction, &node.pos, self.info);
+                }
+
+                let mut found: bool = false;
+                for sig in traitsig.trait_sig.as_ref().unwrap() {
+                    if function.data.func.as_ref().unwrap().name == sig.name {
+                        found = true;
+                        break;
+                    }
+                }
+
+                if !found {
+                    let fmt: String = format!("Function '{}' is not defined in trait '{}'.", function.data.func.as_ref().unwrap().name, traitnm.to_string());
+                    errors::raise_error(&fmt, errors::ErrorType::FunctionNotDefinedInTrait, &node.pos, self.info);
+                }
+                
+                let mut redundant: bool = false;
+                for func in &node.data.impln.as_ref().unwrap().functions {
+                    if  (function.data.func.as_ref().unwrap().name == func.data.func.as_ref().unwrap().name) &&
+                        func != function {
+                        redundant = true;
+                        break;
+                    }
+                }
+
+                if redundant {
+                    let fmt: String = format!("Function '{}' is redefined in impl for trait '{}' on struct '{}'.", function.data.func.as_ref().unwrap().name, traitnm.to_string(), structnm.to_string());
+                    errors::raise_error(&fmt, errors::ErrorType::FunctionRedefinedInImpl, &node.pos, self.info);
+                }
+            }
+
+            //Associated types and constants are named under the struct and the trait, and set before the functions so they may use them
+            for alias in &node.data.impln.as_ref().unwrap().types {
+                let name: &String = &alias.data.alias.as_ref().unwrap().name;
+                if !traitsig.types.as_ref().unwrap().contains(name) {
+                    let fmt: String = format!("Type '{}' is not an associated type of trait '{}'.", name, traitnm);
+                    errors::raise_error(&fmt, errors::ErrorType::AssociatedItemNotInTrait, &alias.pos, self.info);
+                }
+
+                let fullname: String = structnm.to_owned() + "::" + traitnm.as_str() + "::" + name.as_str();
+                if self.cur_module.namespaces.type_aliases.contains_key(&fullname) {
+                    let fmt: String = format!("Type '{}::{}' is already defined.", structnm, name);
+                    errors::raise_error(&fmt, errors::ErrorType::TypeRedefinitionAttempt, &alias.pos, self.info);
+                }
+
+                self.cur_module.namespaces.type_aliases.insert(fullname, (alias.data.alias.as_ref().unwrap().template_types.to_owned(), alias.data.alias.as_ref().unwrap().tp.to_owned()));
+            }
+
+            for name in traitsig.types.as_ref().unwrap() {
+                if !self.cur_module.namespaces.type_aliases.contains_key(&(structnm.to_owned() + "::" + traitnm.as_str() + "::" + name.as_str())) {
+                    let fmt: String = format!("Struct '{}' does not set associated type '{}' of trait '{}'.", structnm, name, traitnm);
+                    errors::raise_error(&fmt, errors::ErrorType::MissingAssociatedItem, &node.pos, self.info);
+                }
+            }
+
+            for constant in &node.data.impln.as_ref().unwrap().consts {
+                let letn: &parser::nodes::LetNode = constant.data.letn.as_ref().unwrap();
+                if !traitsig.consts.as_ref().unwrap().contains_key(&letn.name) {
+                    let fmt: String = format!("Constant '{}' is not an associated constant of trait '{}'.", letn.name, traitnm);
+                    errors::raise_error(&fmt, errors::ErrorType::AssociatedItemNotInTrait, &constant.pos, self.info);
+                }
+
+                let fullname: String = structnm.to_owned() + "::" + traitnm.as_str() + "::" + letn.name.as_str();
+                if self.cur_module.namespaces.consts.contains_key(&fullname) {
+                    let fmt: String = format!("Name '{}::{}' is already defined.", structnm, letn.name);
+                    errors::raise_error(&fmt, errors::ErrorType::RedefinitionAttempt, &constant.pos, self.info);
+                }
+
+                let (tp, _) = Self::get_llvm_from_type(&self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, traitsig.consts.as_ref().unwrap().get(&letn.name).unwrap(), constant);
+                let (consttp, _) = Self::get_llvm_from_type(&self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, letn.tp.as_ref().unwrap(), constant);
+                if tp != consttp {
+                    let fmt: String = format!("Expected '{}' type, got '{}' type.", tp.to_string(), consttp.to_string());
+                    errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &constant.pos, self.info);
+                }
+
+                let value: consteval::ConstValue = consteval::ConstEvaluator::new(&self.cur_module.namespaces, &self.cur_module.datatypes, &self.datatypes, self.info).eval_expecting(letn.expr.as_ref().unwrap(), &tp.tp);
+
+                let right: types::Data = self.build_const_value(&value);
+                if tp != right.tp {
+                    let fmt: String = format!("Expected '{}' type, got '{}' type.", tp.to_string(), right.tp.to_string());
+                    errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &constant.pos, self.info);
+                }
+
+                self.cur_module.namespaces.consts.insert(fullname, (value, constant.pos.clone()));
+            }
+
+            for name in traitsig.consts.as_ref().unwrap().keys() {
+                if !self.cur_module.namespaces.consts.contains_key(&(structnm.to_owned() + "::" + traitnm.as_str() + "::" + name.as_str())) {
+                    let fmt: String = format!("Struct '{}' does not set associated constant '{}' of trait '{}'.", structnm, name, traitnm);
+                    errors::raise_error(&fmt, errors::ErrorType::MissingAssociatedItem, &node.pos, self.info);
+                }
+            }
+
+            let mut impl_functions: Vec<(types::TemplateTraitSignature, parser::Node, bool)> = Vec::new();
+            for sig in traitsig.trait_sig.as_ref().unwrap() {
+                let function: Option<&parser::Node> = node.data.impln.as_ref().unwrap().functions.iter().find(|x| x.data.func.as_ref().unwrap().name == sig.name);
+                if function.is_some() {
+                    impl_functions.push((sig.clone(), function.unwrap().clone(), false));
+                }
+            }
+
+            //Functions not defined by the impl use the default of the trait, compiled after the impl's functions so they may call them
+            for sig in traitsig.trait_sig.as_ref().unwrap() {
+                if node.data.impln.as_ref().unwrap().functions.iter().any(|x| x.data.func.as_ref().unwrap().name == sig.name) {
+                    continue;
+                }
+
+                if sig.default.is_none() {
+                    let fmt: String = format!("Trait '{}' expected function '{}'.", traitnm.to_string(), sig.name);
+                    errors::raise_error(&fmt, errors::ErrorType::ExpectedNFunctionsDefined, &node.pos, self.info);
+                }
+
+                impl_functions.push((sig.clone(), sig.default.as_ref().unwrap().clone(), true));
+            }
+            
+            for (sig, function, isdefault) in &impl_functions {
+                let nargs: usize = sig.args.args.len();
+
+                if nargs != function.data.func.as_ref().unwrap().args.args.len() {
+                    let fmt: String = format!("Trait '{}' expected function with '{}' arguments.", traitnm.to_string(), nargs);
+                    errors::raise_error(&fmt, errors::ErrorType::ArgumentCountMismatch, &node.pos, self.info);
+                }
+
+                let mut template_indices: std::collections::HashMap<String, Vec<usize>> = std::collections::HashMap::new();
+                let mut all_indices: Vec<usize> = Vec::new();
+                let mut standard_indices: Vec<usize> = Vec::new();
+                for template in &sig.template_types {
+                    let mut indices: Vec<usize> = Vec::new();
+                    let mut idx: usize = 0;
+                    for arg in &sig.args.args {
+                        if  !arg.isarr &&
+                            !arg.isfn &&
+                            !arg.isdyn &&
+                            !arg.isgenum &&
+                            !arg.isref &&
+                            !self.cur_module.datatypes.contains_key(arg.data.as_ref().unwrap()) &&
+                            sig.template_types.contains(arg.data.as_ref().unwrap()) &&
+                            arg.data.as_ref().unwrap() == template {
+                            indices.push(idx);
+                            all_indices.push(idx);
+                        }
+                        idx += 1;
+                    }
+                    template_indices.insert(template.to_owned(), indices);
+                }
+
+                for idx in 0..sig.args.args.len() {
+                    if all_indices.contains(&idx) {
+                        continue;
+                    }
+                    standard_indices.push(idx);
+                }
+                
+                if *isdefault {
+                    //A default is compiled for each struct, with the template type of its first argument being the struct
+                    for template in &sig.template_types {
+                        if !template_indices.get(template).unwrap().contains(&0) {
+                            let fmt: String = format!("Default function '{}' of trait '{}' may only be templated by the type of its first argument.", sig.name, traitnm.to_string());
+                            errors::raise_error(&fmt, errors::ErrorType::ImplTemplatedFunction, &function.pos, self.info);
+                        }
+                    }
+
+                    let mut default: parser::Node = function.clone();
+                    default.data.func.as_mut().unwrap().template_types = Vec::new();
+                    default.data.func.as_mut().unwrap().template_bounds = std::collections::HashMap::new();
+
+                    let structtp: types::DataType = self.cur_module.namespaces.structs.get(structnm).unwrap().0.clone();
+                    let prev_tps: Vec<Option<types::DataType>> = self.push_template_types(&sig.template_types, &vec![structtp; sig.template_types.len()]);
+                    let prev_bounds: std::collections::HashMap<String, Vec<String>> = self.push_template_bounds(&sig.template_types, &sig.template_types.iter().map(|x| (x.to_owned(), vec![traitnm.to_owned()])).collect());
+                    let _ = self.build_func(&default, Some(structnm.to_owned() + "." + sig.name.as_str()), None, None);
+                    self.pop_template_bounds(prev_bounds);
+                    self.pop_template_types(&sig.template_types, prev_tps);
+                }
+                else {
+                    let _ = self.build_func(&function, Some(structnm.to_owned() + "." + function.data.func.as_ref().unwrap().name.as_str()), None, None);
+                }
+                let functp: types::DataType = self.cur_module.namespaces.functions.get(&(structnm.to_owned() + "." + function.data.func.as_ref().unwrap().name.as_str())).unwrap().1.to_owned();
+                
+                for (template, indices) in &template_indices {
+                    let firsttp: types::DataType = functp.types.get(indices.get(0).unwrap().to_owned()).unwrap().to_owned();
+                    if indices.len() > 1 {
+                        for index in indices[1..].to_vec() {
+                            if functp.types.get(index).unwrap().to_owned() != firsttp {
+                                let fmt: String = format!("Expected '{}' type, got '{}' type for '{}' template type.", firsttp, functp.types.get(index).unwrap().to_owned(), template);
+                                errors::raise_error(&fmt, errors::ErrorType::ImplFunctionTemplateTypeMismatch, &node.pos, self.info);
+                            }
+                        }
+                    }
+                }
+
+                for idx in &standard_indices {
+                    let tp: types::DataType = functp.types.get(idx.to_owned()).unwrap().to_owned();
+
+                    if tp != Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, sig.args.args.get(idx.to_owned()).as_ref().unwrap(), function).0 {
+                        let fmt: String = format!("Expected '{}' type, got '{}' type.", tp, Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, sig.args.args.get(idx.to_owned()).as_ref().unwrap(), function).0);
+                        errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
+                    }
+                }
+
+                if  !sig.args.rettp.first().unwrap().isarr &&
+                    !sig.args.rettp.first().unwrap().isfn &&
+                    !sig.args.rettp.first().unwrap().isdyn &&
+                    !sig.args.rettp.first().unwrap().isgenum &&
+                    !sig.args.rettp.first().unwrap().isref &&
+                    !self.cur_module.datatypes.contains_key(sig.args.rettp.first().unwrap().data.as_ref().unwrap()) &&
+                    sig.template_types.contains(sig.args.rettp.first().unwrap().data.as_ref().unwrap()) {
+                    let tp: types::DataType = functp.types.get(template_indices.get(sig.args.rettp.first().unwrap().data.as_ref().unwrap()).unwrap().get(0).unwrap().to_owned()).unwrap().to_owned();
+
+                    if tp != *functp.rettp.as_ref().unwrap().to_owned() {
+                        let fmt: String = format!("Expected '{}' return  type, got '{}' return type.", tp, *functp.rettp.as_ref().unwrap().to_owned());
+                        errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
+                    }
+                }
+
+                //Add as method
+                let mut s: (types::DataType, Option<AnyTypeEnum>, std::collections::HashMap<String, i32>, ForwardDeclarationType) = self.cur_module.namespaces.structs.get(structnm).unwrap().clone();
+                
+                s.0.methods.insert(function.data.func.as_ref().unwrap().name.clone(), types::Method {
+                    tp: types::MethodType::Fn,
+                    builtin: None,
+                    func: Some(self.cur_module.namespaces.functions.get(&(structnm.to_owned() + "." + function.data.func.as_ref().unwrap().name.as_str())).unwrap().0.as_global_value().as_pointer_value()),
+                    functp: functp.clone(),
+                    isinstance: true,
+                    isinstanceptr: false,
+                    ismutinstanceptr: false,
+                });
+
+                self.cur_module.namespaces.structs.insert(structnm.to_owned(), (s.0, s.1, s.2, s.3));  
+
+                functions.insert(function.data.func.as_ref().unwrap().name.to_owned(), self.cur_module.namespaces.functions.get(&(structnm.to_owned() + "." + function.data.func.as_ref().unwrap().name.as_str())).unwrap().0.as_global_value().as_pointer_value());                  
+            }
+
+            traitsig.implementations.insert(structnm.to_owned(), functions);
+            
+            self.traits.insert(traitsig.name.to_owned(), traitsig);   
+
+            //Supertraits may be implemented after the trait, so the impl is finished once the impls around it are built
+            if self.pending_impls.is_some() {
+                self.pending_impls.as_mut().unwrap().push((structnm.to_owned(), traitnm.to_owned(), node.clone()));
+            }
+            else {
+                self.finish_impl(structnm, traitnm, node);
+            }
+        }
+
+        let data: types::Data = types::Data {
+            data: None,
+            tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
+            owned: true,
+        };
+        return data;
+    }
+
+    //Check that a struct implements the supertraits of a trait, and build its vtable
+    fn finish_impl(&mut self, structnm: &String, traitnm: &String, node: &parser::Node) {
+        let implemented: Vec<String> = self.cur_module.types.get(structnm).unwrap().traits.keys().cloned().collect();
+        for supertrait in self.traits.get(traitnm).unwrap().supertraits.clone().unwrap() {
+            if !Self::traits_provide(&implemented, &supertrait) {
+                let fmt: String = format!("Struct '{}' does not implement supertrait '{}' of trait '{}'.", structnm, supertrait, traitnm);
+                errors::raise_error(&fmt, errors::ErrorType::MissingTrait, &node.pos, self.info);
+            }
+        }
+
+        //The vtable holds the functions of the supertraits followed by those of the trait, each in the order of its trait
+        let mut ptrs: Vec<inkwell::values::PointerValue<'ctx>> = Vec::new();
+        for name in self.get_supertraits(traitnm).iter().chain(std::iter::once(traitnm)) {
+            let sig: types::TraitSignature = self.traits.get(name).unwrap().clone();
+            if sig.trait_sig.is_some() {
+                for function in sig.trait_sig.as_ref().unwrap() {
+                    ptrs.push(sig.implementations.get(structnm).unwrap().get(&function.name).unwrap().to_owned());
+                }
+            }
+            else if Self::is_dyn_builtin_trait(name) {
+                ptrs.push(self.build_dyn_trait_thunk(structnm, name, traitnm, node));
+            }
+        }
+
+        let idx: i32 = self.cur_module.namespaces.structid.get(structnm).unwrap().clone();
+
+        self.append_struct_to_vtables(ptrs, idx);
+    }
+
+    //Finish the impls deferred since prev_pending was replaced
+    fn finish_pending_impls(&mut self, prev_pending: Option<Vec<(String, String, parser::Node)>>) {
+        let pending: Vec<(String, String, parser::Node)> = std::mem::replace(&mut self.pending_impls, prev_pending).unwrap();
+        for (structnm, traitnm, node) in &pending {
+            self.finish_impl(structnm, traitnm, node);
+        }
+    }
+
+    //Build the vtable entry of a builtin supertrait, which takes 'dyn' values of the trait and calls the impl of the struct.
+    //Equality of values of different types is false, and ordering values of different types traps.
+    fn build_dyn_trait_thunk(&mut self, structnm: &String, supertrait: &String, traitnm: &String, node: &parser::Node) -> inkwell::values::PointerValue<'ctx> {
+        let fnname: String = structnm.to_owned() + "." + supertrait + ".dyn";
+        if self.module.get_function(&fnname).is_some() {
+            return self.module.get_function(&fnname).unwrap().as_global_value().as_pointer_value();
+        }
+
+        let traittp: types::TraitType = types::get_traittp_from_str(supertrait.to_owned()).unwrap();
+        let nargs: usize = self.traits.get(supertrait).unwrap().nargs.unwrap();
+
+        let implemented: &std::collections::HashMap<String, types::Trait> = &self.cur_module.types.get(structnm).unwrap().traits;
+        let (t, viacmp): (types::Trait, bool) = if implemented.contains_key(supertrait) {
+            (implemented.get(supertrait).unwrap().clone(), false)
+        }
+        else {
+            (implemented.get(&types::TraitType::Cmp.to_string()).unwrap().clone(), true)
+        };
+
+        let selftp: types::DataType = Self::datatypes_get(self, structnm).unwrap().clone();
+        let inktp: inkwell::types::BasicTypeEnum = Self::get_basic_from_any(Self::get_anytp_from_tp(self.context, &self.inkwell_types, selftp.clone(), &self.cur_module.datatypes).unwrap()).unwrap();
+
+        //The impl is called with the struct values, so it must take the struct for each argument
+        let paramtps: Vec<inkwell::types::BasicTypeEnum> = t.inkfunc.unwrap().get_type().get_element_type().into_function_type().get_param_types();
+        if paramtps.len() != nargs || paramtps.iter().any(|x| x != &inktp) || (!viacmp && t.rettp.as_ref().unwrap().tp != types::BasicDataType::Bool) {
+            let fmt: String = format!("Trait '{}' of struct '{}' cannot be called through 'dyn {}', as it must take '{}' arguments and return 'bool'.", supertrait, structnm, traitnm, structnm);
+            errors::raise_error(&fmt, errors::ErrorType::TraitIsNotTraitObjSafe, &node.pos, self.info);
+        }
+
+        let dyntp: inkwell::types::BasicMetadataTypeEnum = inkwell::types::BasicMetadataTypeEnum::StructType(*self.inkwell_types.dynptrtp);
+        let fn_type: inkwell::types::FunctionType = self.inkwell_types.booltp.fn_type(&vec![dyntp; nargs][..], false);
+        let func: inkwell::values::FunctionValue = self.module.add_function(&fnname, fn_type, None);
+
+        // Add debug information
+        let sub_type = self.dibuilder.create_subroutine_type(
+            self.dicompile_unit.get_file(),
+            None,
+            &[],
+            inkwell::debug_info::DIFlagsConstants::PUBLIC);
+
+        let func_scope: inkwell::debug_info::DISubprogram = self.dibuilder.create_function(
+            self.dicompile_unit.as_debug_info_scope(),
+            fnname.as_str(),
+            Some(fnname.as_str()),
+            self.dicompile_unit.get_file(),
+            node.pos.line as u32,
+            sub_type,
+            true,
+            true,
+            node.pos.line as u32,
+            inkwell::debug_info::DIFlagsConstants::PUBLIC,
+            true);
+
+        func.set_subprogram(func_scope);
+
+        let location = self.dibuilder.create_debug_location(
+            self.context,
+            node.pos.line as u32,
+            node.pos.startcol as u32,
+            func_scope.as_debug_info_scope(),
+            None);
+
+        let prev_location: Option<inkwell::debug_info::DILocation> = self.builder.get_current_debug_location();
+        let current_block: Option<inkwell::basic_block::BasicBlock> = self.current_block;
+        let enclosing_block: Option<inkwell::basic_block::BasicBlock> = self.enclosing_block;
+        let alloc_head: Option<inkwell::values::InstructionValue> = self.alloc_head;
+
+        let entry: inkwell::basic_block::BasicBlock = self.context.append_basic_block(func, "entry");
+        self.current_block = Some(entry);
+        self.enclosing_block = Some(entry);
+        self.alloc_head = None;
+        self.builder.position_at_end(entry);
+        self.builder.set_current_debug_location(self.context, location);
+
+        let id: inkwell::values::IntValue = self.inkwell_types.i32tp.const_int(*self.cur_module.namespaces.structid.get(structnm).unwrap() as u64, false);
+
+        let mut args: Vec<types::Data> = Vec::new();
+        for idx in 0..nargs {
+            let param: inkwell::values::StructValue = func.get_nth_param(idx as u32).unwrap().into_struct_value();
+
+            //The self argument is known to hold the struct, as the vtable of the struct was used
+            if idx > 0 {
+                let argid: inkwell::values::IntValue = self.builder.build_extract_value(param, 0, "arg_id").unwrap().into_int_value();
+                let differ: inkwell::values::IntValue = self.builder.build_int_compare(inkwell::IntPredicate::NE, argid, id, "id_differ");
+
+                if traittp == types::TraitType::Eq || traittp == types::TraitType::Ne {
+                    let differ_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(func, "differ");
+                    let same_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(func, "same");
+                    self.builder.build_conditional_branch(differ, differ_block, same_block);
+
+                    self.builder.position_at_end(differ_block);
+                    self.builder.build_return(Some(&self.inkwell_types.booltp.const_int((traittp == types::TraitType::Ne) as u64, false)));
+
+                    self.builder.position_at_end(same_block);
+                    self.current_block = Some(same_block);
+                }
+                else {
+                    let msg: String = format!("attempt to compare 'dyn {}' values of different types at {}:{}:{}", traitnm, self.info.name, node.pos.line+1, node.pos.startcol+1);
+                    Self::build_trap_if(self, differ, "dyn_type_mismatch", Some(msg));
+                }
+            }
+
+            let item: inkwell::values::PointerValue = self.builder.build_extract_value(param, 1, "item").unwrap().into_pointer_value();
+            let structptr: inkwell::values::PointerValue = self.builder.build_pointer_cast(item, inktp.ptr_type(inkwell::AddressSpace::from(0u16)), "st_bitcast");
+            args.push(types::Data {
+                data: Some(self.builder.build_load(structptr, "instance")),
+                tp: selftp.clone(),
+                owned: false,
+            });
+        }
+
+        let res: types::Data = if viacmp {
+            self.build_cmp_ordering(&t, args, traittp, node)
+        }
+        else {
+            self.call_trait(&t, args, node)
+        };
+        self.builder.build_return(Some(&res.data.unwrap()));
+
+        self.current_block = current_block;
+        self.enclosing_block = enclosing_block;
+        self.alloc_head = alloc_head;
+        if self.current_block.is_some() {
+            self.builder.position_at_end(self.current_block.unwrap());
+        }
+        if prev_location.is_some() {
+            self.builder.set_current_debug_location(self.context, prev_location.unwrap());
+        }
+
+        return func.as_global_value().as_pointer_value();
+    }
+
+    fn build_namespaceload(&mut self, node: &parser::Node, get_enum_id: bool, allow_enum_noinit: bool, alttp: Option<types::DataType<'ctx>>, borrow_options: BorrowOptions, multinamespace: bool) -> types::Data<'ctx> {
+        let attr: &String = &node.data.attr.as_ref().unwrap().attr;
+
+        //Associated constants are named under the struct and the trait, and may be given through a template type
+        let name: &String = &node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name;
+        let fullname: Option<String> = Self::get_associated_item(&self.cur_module.namespaces, &self.cur_module.datatypes, &self.traits, self.info, name, attr, false, node);
+        if fullname.is_some() {
+            let value: consteval::ConstValue = self.cur_module.namespaces.consts.get(fullname.as_ref().unwrap()).unwrap().0.clone();
+            return self.build_const_value(&value);
+        }
+
+        //Check for enums
+        if  Self::datatypes_get(self, &node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name).is_some() &&
+            Self::datatypes_get(self, &node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name).unwrap().tp == types::BasicDataType::Enum{
+            let mut tp: types::DataType = Self::datatypes_get(self, &node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name).unwrap().clone();
+
+            if alttp.is_some() {
+                tp = alttp.unwrap().to_owned();
+            }
+            let name: String = node.data.attr.as_ref().unwrap().attr.clone();
+            
+            if !tp.names.as_ref().unwrap().contains(&name) {
+                let default: Option<types::Data> = self.get_default_trait_load(&tp.name, attr);
+                if default.is_some() {
+                    return default.unwrap();
+                }
+                let fmt: String = format!("Type '{}' has no namespace attribute '{}'.", node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name, attr);
+                errors::raise_error(&fmt, errors::ErrorType::NamespaceAttrNotFound, &node.pos, self.info);
+            }
+            
+            let idx: usize = tp.names.as_ref().unwrap().iter().position(|x| x == &name).unwrap() as usize;
+            
+            if get_enum_id {
+                return types::Data {
+                    data: Some(inkwell::values::BasicValueEnum::IntValue(self.inkwell_types.i32tp.const_int(self.get_enum_discriminant(&tp.name, idx), false))),
+                    tp: tp.clone(),
+                    owned: true
+                };
+            }
+            
+            if self.cur_module.namespaces.generic_enums.contains_key(&tp.name) && !allow_enum_noinit && tp.mutability.get(idx).unwrap() != &types::DataMutablility::Immutable {
+                let fmt: String = format!("Enum '{}' is generic, use a generic load.", tp.name);
+                errors::raise_error(&fmt, errors::ErrorType::NamespaceLoadOfGenericEnum, &node.pos, self.info);
+            }
+            else if (self.cur_module.namespaces.generic_enums.contains_key(&tp.name) || allow_enum_noinit) &&
+                    tp.mutability.get(idx).unwrap() == &types::DataMutablility::Immutable {
+                if node.data.attr.as_ref().unwrap().expr.is_some() {
+                    let dat: types::Data = self.compile_expr(&node.data.attr.as_ref().unwrap().expr.as_ref().unwrap(), BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, false, false);    
+                    let fmt: String = format!("Expected 'i32' type, got '{}' type.", dat.tp);
+                    errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
+                }
+                tp.types = vec![Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(), Self::datatypes_get(self, &types::BasicDataType::I32.to_string()).unwrap().clone()];
+                return types::Data {
+                    data: Some(inkwell::values::BasicValueEnum::IntValue(self.inkwell_types.i32tp.const_int(self.get_enum_discriminant(&tp.name, idx), false))),
+                    tp: tp.clone(),
+                    owned: true
+                };
+            }
+            
+            let enum_tp: types::DataType = tp.types.get(idx).unwrap().clone();
+            
+            let data: Option<inkwell::values::BasicValueEnum>;
+            if  (node.data.attr.as_ref().unwrap().expr.is_none() &&
+                tp.mutability.get(idx).unwrap() == &types::DataMutablility::Immutable) || allow_enum_noinit  {
+                data = Some(inkwell::values::BasicValueEnum::IntValue(self.inkwell_types.i32tp.const_int(self.get_enum_discriminant(&tp.name, idx), false)));
+            }
+            else {
+                if node.data.attr.as_ref().unwrap().expr.is_none() && !allow_enum_noinit {
+                    let fmt: String = format!("Expected '{}' type, got 'i32' type.", enum_tp.clone());
+                    errors::raise_error_multi(errors::ErrorType::TypeMismatch, vec![String::from("Add <...>."), fmt], vec![&node.pos, &node.pos], self.info);
+                }
+                let dat: types::Data = self.compile_expr(&node.data.attr.as_ref().unwrap().expr.as_ref().unwrap(), BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, false, false);
+                if dat.tp != enum_tp.clone() {
+                    let fmt: String = format!("Expected '{}' type, got '{}' type.", enum_tp.clone(), dat.tp);
+                    errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
+                }
+                data = dat.data;
+            }
+
+            let mut types: Vec<types::DataType> = tp.types.to_owned();
+            types.insert(0, Self::datatypes_get(self, &types::BasicDataType::I32.to_string()).unwrap().clone());
+    
+            let st: inkwell::values::PointerValue = Self::alloca(self, Self::build_struct_tp_from_types(&self.context, &self.inkwell_types, &types, &self.cur_module.datatypes).into_struct_type(), "enum_st");
+    
+            let id: inkwell::values::PointerValue = self.builder.build_struct_gep(st, 0, "variant_id").expect("GEP Error");
+            self.builder.build_store(id, self.inkwell_types.i32tp.const_int(self.get_enum_discriminant(&tp.name, idx), false));
+            
+            if data.is_some() {
+                let variant_data: inkwell::values::PointerValue = self.builder.build_struct_gep(st, (idx+1) as u32, "variant_data").expect("GEP Error");
+                self.builder.build_store(variant_data, data.unwrap());
+            }
+
+            if borrow_options.get_ptr {
+                return types::Data {
+                    data: Some(inkwell::values::BasicValueEnum::PointerValue(st)),
+                    tp: tp.clone(),
+                    owned: true
+                };
+            }
+            return types::Data {
+                data: Some(self.builder.build_load(st, "load_variant")),
+                tp: tp.clone(),
+                owned: true
+            };
+        }
+        else if self.cur_module.modules.contains_key(&node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name) {
+            let module = self.cur_module.modules.get(&node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name).unwrap().clone();
+            let attr: String = node.data.attr.as_ref().unwrap().attr.clone();
+            
+            if module.namespaces.structs.contains_key(&attr) && multinamespace {
+                return types::Data {
+                    data: None,
+                    tp: module.namespaces.structs.get(&attr).unwrap().0.clone(),
+                    owned: true
+                };
+            }
+        }
+
+        if self.cur_module.namespaces.generic_structs.contains_key(&node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name) {
+            let fmt: String = format!("Expected generic types for '{}'.", &node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name);
+            errors::raise_error(&fmt, errors::ErrorType::ExpectedGenericTypes, &node.pos, self.info);
+        }
+
+        if self.cur_module.namespaces.structs.get(&node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name).is_none() {
+            let fmt: String = format!("Struct '{}' is not defined.", &node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name);
+            errors::raise_error(&fmt, errors::ErrorType::StructNotDefined, &node.pos, self.info);
+        }
+
+        let st = self.cur_module.namespaces.structs.get(&node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name).unwrap().clone();
+
+        //First check methods
+        let method_: Option<&types::Method> = st.0.methods.get(attr);
+        if method_.is_some() {
+            let method: &types::Method = method_.unwrap();
+            if !method.isinstance {
+                if method.tp == types::MethodType::Fn {
+                    let data: types::Data = types::Data {
+                        data: Some(inkwell::values::BasicValueEnum::PointerValue(method.func.unwrap())),
+                        tp: method.functp.clone(),
+                        owned: true,
+                    };
+
+                    return data;
+                }
+                else {
+                    let mut tp_: types::DataType = Self::datatypes_get(self, &types::BasicDataType::WrapperFunc.to_string()).unwrap().clone();
+                    tp_.wrapperfn = method.builtin;
+                    tp_.types = method.functp.types.clone();                    
+                    let data: types::Data = types::Data {
+                        data: None,
+                        tp: tp_,
+                        owned: true,
+                    };
+
+                    return data;
+                }
+            }
+        }
+
+        let default: Option<types::Data> = self.get_default_trait_load(&st.0.name, attr);
+        if default.is_some() {
+            return default.unwrap();
+        }
+
+        //Last case
+        let fmt: String = format!("Type '{}' has no namespace attribute '{}'.", node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name, attr);
+        errors::raise_error(&fmt, errors::ErrorType::NamespaceAttrNotFound, &node.pos, self.info);
+    }
+
+    //Load the function of the Default trait of a type, so that `S::default()` calls the builtin or derived trait
+    fn get_default_trait_load(&self, name: &String, attr: &String) -> Option<types::Data<'ctx>> {
+        if &self.traits.get(&types::TraitType::Default.to_string()).unwrap().name != attr {
+            return None;
+        }
+
+        let t: &types::Trait = match self.cur_module.types.get(name) {
+            Some(tp) => match tp.traits.get(&types::TraitType::Default.to_string()) {
+                Some(v) => v,
+                None => return None,
+            }
+            None => return None,
+        };
+
+        if t.inkfunc.is_some() {
+            let mut tp: types::DataType = Self::datatypes_get(self, &types::BasicDataType::Func.to_string()).unwrap().clone();
+            tp.names = Some(Vec::new());
+            tp.rettp = Some(Box::new(t.rettp.clone().unwrap()));
+
+            return Some(types::Data {
+                data: Some(inkwell::values::BasicValueEnum::PointerValue(t.inkfunc.unwrap())),
+                tp,
+                owned: true,
+            });
+        }
+
+        let mut tp: types::DataType = Self::datatypes_get(self, &types::BasicDataType::WrapperFunc.to_string()).unwrap().clone();
+        tp.wrapperfn = t.function;
+        tp.rettp = Some(Box::new(t.rettp.clone().unwrap()));
+        return Some(types::Data {
+            data: None,
+            tp,
+            owned: true,
+        });
+    }
+
+    //Compile the condition of an if or while, which must be a bool
+    fn build_condition(&mut self, cond: &parser::Node) -> inkwell::values::IntValue<'ctx> {
+        let data: types::Data = self.compile_expr(cond, BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: false}, false, false);
+
+        if data.tp != Self::datatypes_get(self, &types::BasicDataType::Bool.to_string()).unwrap().clone() {
+            let fmt: String = format!("Expected 'bool' type, got '{}' type.", data.tp);
+            errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &cond.pos, self.info);
+        }
+
+        return data.data.unwrap().into_int_value();
+    }
+
+    fn build_if(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        let end_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(self.current_block.unwrap().get_parent().unwrap(), "if_end");
+        let else_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(self.current_block.unwrap().get_parent().unwrap(), "else");
+
+        let mut current_block: inkwell::basic_block::BasicBlock = self.current_block.unwrap();
+
+        let mut collected_locals: Vec<std::collections::HashMap<String, usize>> = Vec::new();
+
+        let mut blocks: Vec<(Option<inkwell::values::BasicValueEnum>, inkwell::basic_block::BasicBlock)> = Vec::new();
+        let mut rettp: Option<types::DataType> = None;
+
+        let inexpr: bool = node.data.ifn.as_ref().unwrap().inexpr;
+
+        let mut idx: usize = 0;
+        for ifn in &node.data.ifn.as_ref().unwrap().ifs {
+            self.builder.position_at_end(current_block);  
+            self.current_block = Some(current_block);  
+            let cond: inkwell::values::IntValue = self.build_condition(&ifn.0);
+            
+            let then_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(self.current_block.unwrap().get_parent().unwrap(), "if");
+
+            let _ = then_block.move_after(self.current_block.unwrap());
+            
+            let elseif_block: inkwell::basic_block::BasicBlock;
+
+            if idx!=node.data.ifn.as_ref().unwrap().ifs.len()-1 {
+                elseif_block = self.context.append_basic_block(self.current_block.unwrap().get_parent().unwrap(), "else_if");
+
+                let _ = elseif_block.move_after(then_block);
+                
+                current_block = elseif_block;                
+            }
+            else {
+                elseif_block = else_block;
+            }
+
+            self.builder.build_conditional_branch(cond, then_block, elseif_block);
+
+            self.builder.position_at_end(then_block);
+            self.current_block = Some(then_block);
+            
+            self.cur_module.namespaces.locals.push(std::collections::HashMap::new());
+
+
+            let mut start_locals: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+            let mut end_locals: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+            
+            let mut lvl: usize = 0;
+            for local in &self.cur_module.namespaces.locals {
+                for item in local {
+                    if item.1.5 == InitializationStatus::Uninitialized {
+                        start_locals.insert(item.0.clone(), lvl);
+                    }
+                }
+                lvl += 1;
+            }
+            
+            let res: types::Data = self.compile(&ifn.1, true, false);
+
+            if rettp.is_none() {
+                rettp = Some(res.tp.clone());
             }
-        }
-        
-        let data: types::Data = types::Data {
-            data: Some(self.builder.build_load(ptr, name.as_str())),
-            tp: s.0.clone(),
-            owned: true,
-        };
-        return data;
-    }
 
-    fn build_attrload(&mut self, node: &parser::Node, borrow_options: BorrowOptions) -> types::Data<'ctx> {
-        if !borrow_options.give_ownership {
-            let fmt: String = format!("Cannot take reference of attribute.");
-            errors::raise_error(&fmt, errors::ErrorType::CannotTakeReferenceOfAttr, &node.pos, self.info);
-        }
-        let base: types::Data = self.compile_expr(&node.data.attr.as_ref().unwrap().name, BorrowOptions{ give_ownership: false, get_ptr: true, mut_borrow: false}, false, false);
+            blocks.push((res.data.clone(), then_block));
 
-        if base.tp.tp != types::BasicDataType::Struct {
-            let fmt: String = format!("Expected struct, got '{}'.", base.tp.tp);
-            errors::raise_error(&fmt, errors::ErrorType::GetAttrOfNonStruct, &node.pos, self.info);
-        }
+            if &res.tp != rettp.as_ref().unwrap() && inexpr {
+                let fmt: String = format!("Expected '{}' type, got '{}' type.", rettp.as_ref().unwrap(), res.tp);
+                errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);                
+            }
 
-        let attr: String = node.data.attr.as_ref().unwrap().attr.clone();
 
-        if !base.tp.names.as_ref().unwrap().contains(&attr) {
-            let fmt: String = format!("Type '{}' has no attribute '{}'.", base.tp, attr);
-            errors::raise_error(&fmt, errors::ErrorType::StructAttrNotFound, &node.pos, self.info);
-        }
+            let mut lvl: usize = 0;
+            for local in &self.cur_module.namespaces.locals {
+                for item in local {
+                    if  item.1.5 == InitializationStatus::Initialized && start_locals.get(item.0).is_some() &&
+                        start_locals.get(item.0).unwrap() == &lvl {
+                        
+                        end_locals.insert(item.0.clone(), lvl);
 
-        let mut idx: u32 = 0;
-        for (attrn, tp) in izip![base.tp.names.as_ref().unwrap(), &base.tp.types] {
-            if tp.tp == types::BasicDataType::Void {
-                if attrn == &attr {
-                    let data: types::Data = types::Data {
-                        data: None,
-                        tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
-                        owned: true,
-                    };
-                    return data;
+                    }
                 }
-                continue;
+                lvl += 1;
             }
-            if attrn == &attr {
-                break;
+
+            for var in &end_locals {
+                let mut var_val = self.cur_module.namespaces.locals.get_mut(var.1.to_owned()).unwrap().get(&var.0.to_owned()).unwrap().to_owned();
+                var_val.5 = InitializationStatus::Uninitialized;
+
+                self.cur_module.namespaces.locals.get_mut(var.1.to_owned()).unwrap().insert(var.0.to_owned(), var_val);
             }
-            idx+=1;
-        }
 
-        let ptr: inkwell::values::PointerValue;
-        if base.data.unwrap().is_pointer_value() {
-            ptr = base.data.unwrap().into_pointer_value();
-        }
-        else {
-            ptr = Self::alloca(self, base.data.unwrap().get_type(), "inplace_attr");
-            self.builder.build_store(ptr, base.data.unwrap());
-        }
+            collected_locals.push(end_locals);
 
-        let itmptr: inkwell::values::PointerValue = self.builder.build_struct_gep(ptr, idx, base.tp.name.as_str()).expect("GEP Error");
-        if borrow_options.get_ptr {
-            let data: types::Data = types::Data {
-                data: Some(inkwell::values::BasicValueEnum::PointerValue(itmptr)),
-                tp: base.tp.types.get(idx as usize).unwrap().clone(),
-                owned: true,
-            };
-            return data;
+
+            self.build_scope_drops(self.cur_module.namespaces.locals.len() - 1, ifn.1.last(), node);
+            self.cur_module.namespaces.locals.pop();
+
+            self.builder.build_unconditional_branch(end_block);
+
+            self.current_block = Some(then_block);
+
+            idx+=1;
         }
         
-        let val = self.builder.build_load(itmptr, attr.as_str());
+        
+        let _ = else_block.move_after(self.current_block.unwrap());
+        let _ = end_block.move_after(else_block); 
 
-        let data: types::Data = types::Data {
-            data: Some(val),
-            tp: base.tp.types.get(idx as usize).unwrap().clone(),
-            owned: true,
-        };
-        return data;
-    }
+        if node.data.ifn.as_ref().unwrap().else_opt.is_some() {
+            self.builder.position_at_end(else_block);
+            self.current_block = Some(else_block);
+            
+            self.cur_module.namespaces.locals.push(std::collections::HashMap::new());
 
-    fn build_attrasssign(&mut self, node: &parser::Node) -> types::Data<'ctx> {
-        let base: types::Data = self.compile_expr(&node.data.attrassign.as_ref().unwrap().name, BorrowOptions{ give_ownership: false, get_ptr: true, mut_borrow: false}, false, false);
 
-        if base.tp.tp != types::BasicDataType::Struct {
-            let fmt: String = format!("Expected struct, got '{}'.", base.tp.tp);
-            errors::raise_error(&fmt, errors::ErrorType::GetAttrOfNonStruct, &node.pos, self.info);
-        }
+            let mut start_locals: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+            let mut end_locals: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+            
+            let mut lvl: usize = 0;
+            for local in &self.cur_module.namespaces.locals {
+                for item in local {
+                    if item.1.5 == InitializationStatus::Uninitialized {
+                        start_locals.insert(item.0.clone(), lvl);
+                    }
+                }
+                lvl += 1;
+            }
 
-        let attr: String = node.data.attrassign.as_ref().unwrap().attr.clone();
+            let res: types::Data = self.compile(&node.data.ifn.as_ref().unwrap().else_opt.as_ref().unwrap(), true, false);
 
-        if !base.tp.names.as_ref().unwrap().contains(&attr) {
-            let fmt: String = format!("Struct '{}' has no attribute '{}'.", base.tp, attr);
-            errors::raise_error(&fmt, errors::ErrorType::StructAttrNotFound, &node.pos, self.info);
-        }
+            
+            if rettp.is_none() {
+                rettp = Some(res.tp.clone());
+            }
 
-        if base.tp.is_ref && base.tp.mutability.first().unwrap() == &types::DataMutablility::Immutable{
-            let fmt: String = format!("Cannot assign attribute to immutable reference");
-            errors::raise_error(&fmt, errors::ErrorType::ImmutableRefAttr, &node.pos, self.info);
-        }
+            blocks.push((res.data.clone(), else_block));
 
-        let mut idx: u32 = 0;
-        for (attrn, tp) in izip![base.tp.names.as_ref().unwrap(), &base.tp.types] {
-            if tp.tp == types::BasicDataType::Void {
-                if attrn == &attr {
-                    let data: types::Data = types::Data {
-                        data: None,
-                        tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
-                        owned: true,
-                    };
-                    return data;
+            if &res.tp != rettp.as_ref().unwrap() && inexpr{
+                let fmt: String = format!("Expected '{}' type, got '{}' type.", rettp.as_ref().unwrap(), res.tp);
+                errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);                
+            }
+
+            let mut lvl: usize = 0;
+            for local in &self.cur_module.namespaces.locals {
+                for item in local {
+                    if  item.1.5 == InitializationStatus::Initialized && start_locals.get(item.0).is_some() &&
+                        start_locals.get(item.0).unwrap() == &lvl {
+                        
+                        end_locals.insert(item.0.clone(), lvl);
+                    }
                 }
-                continue;
+                lvl += 1;
             }
-            if attrn == &attr {
-                break;
+
+            for var in &end_locals {
+                let mut var_val = self.cur_module.namespaces.locals.get_mut(var.1.to_owned()).unwrap().get(&var.0.to_owned()).unwrap().to_owned();
+                var_val.5 = InitializationStatus::Uninitialized;
+
+                self.cur_module.namespaces.locals.get_mut(var.1.to_owned()).unwrap().insert(var.0.to_owned(), var_val);
             }
-            idx+=1;
-        }
 
-        if base.tp.mutability.get(idx as usize).unwrap() == &types::DataMutablility::Immutable{
-            let fmt: String = format!("Attribute '{}' is immutable.", base.tp);
-            errors::raise_error(&fmt, errors::ErrorType::ImmutableAttr, &node.pos, self.info);
-        }
+            collected_locals.push(end_locals);
 
-        let ptr: inkwell::values::PointerValue;
-        if base.data.unwrap().is_pointer_value() {
-            ptr = base.data.unwrap().into_pointer_value();
+
+            self.build_scope_drops(self.cur_module.namespaces.locals.len() - 1, node.data.ifn.as_ref().unwrap().else_opt.as_ref().unwrap().last(), node);
+            self.builder.build_unconditional_branch(self.current_block.unwrap());
+
+            self.cur_module.namespaces.locals.pop();
         }
         else {
-            ptr = Self::alloca(self, base.data.unwrap().get_type(), "inplace_attr");
-            self.builder.build_store(ptr, base.data.unwrap());
+            self.builder.position_at_end(else_block);
+            self.current_block = Some(else_block);
+            self.builder.build_unconditional_branch(end_block);
         }
 
-        let itmptr: inkwell::values::PointerValue = self.builder.build_struct_gep(ptr, idx, base.tp.name.as_str()).expect("GEP Error");
-        
-        let expr: types::Data = self.compile_expr(&node.data.attrassign.as_ref().unwrap().expr, BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, false, false);
+        //Only if there is an else clause to catch all other cases
+        if node.data.ifn.as_ref().unwrap().else_opt.is_some() {
+            let mut common: Vec<(String, usize)> = Vec::new();
+            let mut common_init: Vec<(String, usize)> = Vec::new();
 
-        if &expr.tp != base.tp.types.get(idx as usize).unwrap() {
-            let fmt: String = format!("Expected '{}' type, got '{}' type.", expr.tp, base.tp.types.get(idx as usize).unwrap());
-            errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
-        }
+            //Get all of the initialized variables
+            for local_set in &collected_locals {
+                for item in local_set {
+                    if !common.contains(&(item.0.to_owned(), item.1.to_owned())) {
+                        common.push((item.0.to_owned(), item.1.to_owned()));
+                    }
+                }
+            }
 
-        if expr.data.is_some() {
-            self.builder.build_store(itmptr, expr.data.unwrap());
+            //Get all of the initialized variables that all have been commonly init
+            'outer: for var in &common {
+                for local_set in &collected_locals {
+                    if local_set.len() == 0 {
+                        continue 'outer;
+                    }
+                    for local in local_set {
+                        if local != (&var.0, &var.1) {
+                            continue 'outer;
+                        }
+                    }
+                }
+                common_init.push((var.0.to_owned(), var.1.to_owned()));
+            }
+
+            for var in common_init {
+                let mut var_val = self.cur_module.namespaces.locals.get_mut(var.1).unwrap().get(&var.0).unwrap().to_owned();
+                var_val.5 = InitializationStatus::Initialized;
+
+                self.cur_module.namespaces.locals.get_mut(var.1).unwrap().insert(var.0, var_val);
+            }
         }
 
-        let data: types::Data = types::Data {
-            data: None,
-            tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
-            owned: true,
-        };
-        return data;
-    }
+        self.current_block = Some(end_block);
 
-    fn build_string(&mut self, node: &parser::Node) -> types::Data<'ctx> {
-        let data: Vec<u8> = node.data.str.as_ref().unwrap().data.as_str().as_bytes().to_vec();
-        
-        let arraytp: inkwell::types::ArrayType = self.inkwell_types.i8tp.array_type(data.len() as u32);
+        self.builder.position_at_end(end_block);
+        self.current_block = Some(end_block);
 
-        let mut arrdata: Vec<inkwell::values::IntValue> = Vec::new();
-        for c in data {
-            arrdata.push(self.inkwell_types.i8tp.const_int(c as u64, false));
+        if rettp.as_ref().unwrap().tp == types::BasicDataType::Void {
+            let data: types::Data = types::Data {
+                data: None,
+                tp: rettp.unwrap().clone(),
+                owned: true,
+            };
+            return data;
         }
-        arrdata.push(self.inkwell_types.i8tp.const_zero());
-
-        let array: inkwell::values::ArrayValue = self.inkwell_types.i8tp.const_array(&arrdata[..]);
 
-        let types: Vec<types::DataType> = vec![Self::datatypes_get(self, &types::BasicDataType::I8.to_string()).unwrap().clone()];
+        let phi: inkwell::values::PhiValue = self.builder.build_phi(blocks.first().unwrap().0.unwrap().get_type(), "if_phi");
 
-        let mut tp: types::DataType = Self::datatypes_get(self, &types::BasicDataType::Array.to_string()).unwrap().clone();
-        tp.name = Self::array_repr(arraytp);
-        tp.arrtp = Some(arraytp);
-        tp.types = types;
+        for block in blocks {
+            phi.add_incoming(&[(&block.0.unwrap(), block.1)]);
+        }
 
-        let data: types::Data = types::Data {
-            data: Some(inkwell::values::BasicValueEnum::ArrayValue(array)),
-            tp,
+        let data: types::Data = types::Data {
+            data: Some(phi.as_basic_value()),
+            tp: rettp.unwrap().clone(),
             owned: true,
         };
         return data;
     }
 
-    fn build_char(&mut self, node: &parser::Node) -> types::Data<'ctx> {
-        let mut data: std::str::Chars = node.data.num.as_ref().unwrap().left.chars();
-        
-        let selfv: inkwell::values::IntValue = self.inkwell_types.i32tp.const_int((data.next().unwrap()).into(), false);
-        return types::Data {data: Some(inkwell::values::BasicValueEnum::IntValue(selfv)), tp: Self::datatypes_get(self, &types::BasicDataType::U32.to_string()).unwrap().clone(), owned: true}
-    }
+    fn build_loop(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        let loop_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(self.current_block.unwrap().get_parent().unwrap(), "loop");
+        let end_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(self.current_block.unwrap().get_parent().unwrap(), "loop_end");
 
-    fn build_array(&mut self, node: &parser::Node) -> types::Data<'ctx> {
-        let elements: &Vec<parser::Node> = &node.data.arr.as_ref().unwrap().elements;
+        self.loops.push(LoopFrame {
+            label: node.data.loopn.as_ref().unwrap().label.clone(),
+            start_block: loop_block,
+            end_block,
+            allow_value: true,
+            breaks: Vec::new(),
+            break_tp: None,
+            scopes: self.cur_module.namespaces.locals.len(),
+        });
 
-        let mut data_elem: Vec<types::Data> = Vec::new();
+        self.builder.build_unconditional_branch(loop_block);
 
-        if elements.len() == 0 {
-            let fmt: String = format!("Cannot define zero-length array.");
-            errors::raise_error(&fmt, errors::ErrorType::ZeroLengthArray, &node.pos, self.info);
-        }
+        self.builder.position_at_end(loop_block);
+        self.current_block = Some(loop_block);
 
-        data_elem.push(self.compile_expr(elements.first().unwrap(), BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, false, false));
-        let firsttp_: Option<inkwell::types::AnyTypeEnum> = Self::get_anytp_from_tp(self.context, &self.inkwell_types, data_elem.first().unwrap().tp.clone(), &self.cur_module.datatypes);
-        if firsttp_.is_none() {
-            let fmt: String = format!("Cannot define array of 'void'.");
-            errors::raise_error(&fmt, errors::ErrorType::CannotDefineVoidArray, &node.pos, self.info);
-        }
-        if firsttp_.unwrap().is_function_type() {
-            let fmt: String = format!("Cannot define array of 'fn'.");
-            errors::raise_error(&fmt, errors::ErrorType::CannotDefineFnArray, &node.pos, self.info);
-        }
-        let firsttp: inkwell::types::BasicTypeEnum = Self::get_basic_from_any(firsttp_.unwrap()).unwrap();
+        self.compile(&node.data.loopn.as_ref().unwrap().block, true, true);
 
-        for element in elements[1..].to_vec() {
-            data_elem.push(self.compile_expr(&element, BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, false, false));
-            let tp_: Option<inkwell::types::AnyTypeEnum> = Self::get_anytp_from_tp(self.context, &self.inkwell_types, data_elem.last().unwrap().tp.clone(), &self.cur_module.datatypes);
-            if tp_.is_none() {
-                let fmt: String = format!("Expected '{}' type, got 'void' type.", data_elem.first().unwrap().tp.to_string());
-                errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &element.pos, self.info);
-            }
-            let tp: inkwell::types::BasicTypeEnum = Self::get_basic_from_any(tp_.unwrap()).unwrap();
-            if tp != firsttp {
-                let fmt: String = format!("Expected '{}' type, got '{}' type.", data_elem.first().unwrap().tp.to_string(), data_elem.last().unwrap().tp.to_string());
-                errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &element.pos, self.info);
-            }
-        }
+        self.builder.build_unconditional_branch(loop_block);
 
-        let firstdatatp: types::DataType = data_elem.first().unwrap().tp.clone();
+        let _ = end_block.move_after(self.builder.get_insert_block().unwrap());
 
-        let arraytp: inkwell::types::ArrayType = firsttp.array_type(elements.len() as u32);
-        let array: inkwell::values::PointerValue = Self::alloca(self, arraytp, "arr");
+        self.builder.position_at_end(end_block);
+        self.current_block = Some(end_block);
 
-        let mut idx: usize = 0;
-        for element in data_elem {
-            let ptr: inkwell::values::PointerValue = unsafe { self.builder.build_gep(array, &[self.inkwell_types.i8tp.const_int(0, false), self.inkwell_types.i8tp.const_int(idx as u64, false)], &element.tp.name) };
-            self.builder.build_store(ptr, element.data.unwrap());
-            idx+=1;
-        }        
+        let frame: LoopFrame = self.loops.pop().unwrap();
 
-        let mut tp: types::DataType = Self::datatypes_get(self, &types::BasicDataType::Array.to_string()).unwrap().clone();
-        tp.name = Self::array_repr(arraytp);
-        tp.arrtp = Some(arraytp);
-        tp.types = vec![firstdatatp];
+        //The loop evaluates to the value given to break
+        if  frame.break_tp.is_some() &&
+            frame.break_tp.as_ref().unwrap().tp != types::BasicDataType::Void {
+            let phi: inkwell::values::PhiValue = self.builder.build_phi(frame.breaks.first().unwrap().0.unwrap().get_type(), "loop_value");
+            for (value, block) in &frame.breaks {
+                phi.add_incoming(&[(&value.unwrap(), *block)]);
+            }
 
+            let data: types::Data = types::Data {
+                data: Some(phi.as_basic_value()),
+                tp: frame.break_tp.unwrap(),
+                owned: true,
+            };
+            return data;
+        }
+        
         let data: types::Data = types::Data {
-            data: Some(inkwell::values::BasicValueEnum::PointerValue(array)),
-            tp,
+            data: None,
+            tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
             owned: true,
         };
         return data;
     }
 
-    fn build_impl(&mut self, node: &parser::Node) -> types::Data<'ctx> {
-        let traitnm: &String = &node.data.impln.as_ref().unwrap().traitnm;
-        let structnm: &String = &node.data.impln.as_ref().unwrap().structnm;
-
-        if !self.traits.contains_key(traitnm) {
-            let fmt: String = format!("Trait '{}' not found.", traitnm.to_string());
-            errors::raise_error(&fmt, errors::ErrorType::TraitNotFound, &node.pos, self.info);
+    //Get the index of the loop targeted by a break or continue
+    fn get_loop_frame(&mut self, node: &parser::Node, isbreak: bool) -> usize {
+        if self.loops.len() == 0 {
+            if isbreak {
+                let fmt: String = format!("Cannot break outside of loop.");
+                errors::raise_error(&fmt, errors::ErrorType::BreakOutsideOfLoop, &node.pos, self.info);
+            }
+            let fmt: String = format!("Cannot continue outside of loop.");
+            errors::raise_error(&fmt, errors::ErrorType::ContinueOutsideOfLoop, &node.pos, self.info);
         }
 
-        let mut traitsig: types::TraitSignature = self.traits.get(traitnm).unwrap().clone();
+        let label: &Option<String> = &node.data.breakn.as_ref().unwrap().label;
 
-        let mut functions: std::collections::HashMap<String, inkwell::values::PointerValue> = std::collections::HashMap::new();
+        if label.is_none() {
+            return self.loops.len()-1;
+        }
 
-        if traitsig.traittp == types::TraitMetatype::Builtin {
-            if traitsig.name != node.data.impln.as_ref().unwrap().functions.last().unwrap().data.func.as_ref().unwrap().name {
-                let fmt: String = format!("Trait '{}' expected function '{}'.", traitnm.to_string(), traitsig.name);
-                errors::raise_error(&fmt, errors::ErrorType::TraitExpectProperFunctionName, &node.pos, self.info);
+        match self.loops.iter().rposition(|frame| &frame.label == label) {
+            Some(idx) => {
+                idx
             }
-
-            let nargs: usize = traitsig.nargs.unwrap();
-
-            if nargs != node.data.impln.as_ref().unwrap().functions.last().unwrap().data.func.as_ref().unwrap().args.args.len() {
-                let fmt: String = format!("Trait '{}' expected function with '{}' arguments.", traitnm.to_string(), traitsig.nargs.unwrap());
-                errors::raise_error(&fmt, errors::ErrorType::ArgumentCountMismatch, &node.pos, self.info);
+            None => {
+                let fmt: String = format!("Label '{}' is not defined.", label.as_ref().unwrap());
+                errors::raise_error(&fmt, errors::ErrorType::LabelNotFound, &node.pos, self.info);
             }
-            
-            let func: types::Data = self.build_func(&node.data.impln.as_ref().unwrap().functions.last().unwrap(), Some(structnm.to_owned() + "." + node.data.impln.as_ref().unwrap().functions.last().unwrap().data.func.as_ref().unwrap().name.as_str()), None, None);
+        }
+    }
 
-            if !self.cur_module.namespaces.structs.contains_key(structnm) {
-                let fmt: String = format!("Struct '{}' is not defined.", structnm);
-                errors::raise_error(&fmt, errors::ErrorType::StructNotDefined, &node.pos, self.info);
-            }
-            
-            let mut tp: types::Type = self.cur_module.types.get(structnm).unwrap().clone();
+    //Code after a break or continue is unreachable, so continue building in a new block
+    fn build_loop_jump(&mut self, target: inkwell::basic_block::BasicBlock<'ctx>) {
+        self.builder.build_unconditional_branch(target);
 
-            if tp.traits.contains_key(structnm) {
-                let fmt: String = format!("Struct '{}' already implements trait '{}'.", structnm, traitnm);
-                errors::raise_error(&fmt, errors::ErrorType::StructAlreadyImplements, &node.pos, self.info);
-            }
+        let unreachable_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(self.current_block.unwrap().get_parent().unwrap(), "unreachable");
+        let _ = unreachable_block.move_after(self.builder.get_insert_block().unwrap());
 
-            let rettp: types::DataType = Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, node.data.impln.as_ref().unwrap().functions.last().unwrap().data.func.as_ref().unwrap().args.rettp.first().unwrap(), node).0;
+        self.builder.position_at_end(unreachable_block);
+        self.current_block = Some(unreachable_block);
+    }
 
-            let traittp: Option<types::TraitType> = types::get_traittp_from_str(traitnm.to_owned());
-            if traittp.as_ref().unwrap() == &types::TraitType::Call {
-                let fmt: String = format!("Cannot implement 'Call' trait.");
-                errors::raise_error(&fmt, errors::ErrorType::CannotImplementCallTrait, &node.pos, self.info);
-            }
+    fn build_break(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        let idx: usize = self.get_loop_frame(node, true);
 
-            tp.traits.insert(traitnm.to_owned(), builtin_types::create_trait_ink(func.data.unwrap().into_pointer_value(), nargs, traittp.unwrap(), rettp));
-            
-            self.cur_module.types.insert(structnm.to_owned(), tp);
+        let expr: &Option<parser::Node> = &node.data.breakn.as_ref().unwrap().expr;
 
-            functions.insert(traitsig.name, func.data.unwrap().into_pointer_value());
+        let value: types::Data = if expr.is_some() {
+            if !self.loops.get(idx).unwrap().allow_value {
+                let fmt: String = format!("Cannot break with a value from 'while' or 'for' loop.");
+                errors::raise_error(&fmt, errors::ErrorType::BreakWithValue, &node.pos, self.info);
+            }
+            self.compile_expr(expr.as_ref().unwrap(), BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, false, false)
         }
         else {
-            if !self.cur_module.namespaces.structs.contains_key(structnm) {
-                let fmt: String = format!("Struct '{}' is not defined.", structnm);
-                errors::raise_error(&fmt, errors::ErrorType::StructNotDefined, &node.pos, self.info);
-            }
-
-            let traittp: Option<types::TraitType> = types::get_traittp_from_str(traitnm.to_owned());
-            if traittp.is_some() {
-                let fmt: String = format!("Cannot implement builtin trait '{}'.", traittp.unwrap().to_string());
-                errors::raise_error(&fmt, errors::ErrorType::CannotImplementBuiltinTrait, &node.pos, self.info);
-            }
-            
-            let mut tp: types::Type = self.cur_module.types.get(structnm).unwrap().clone();
-
-            if tp.traits.contains_key(structnm) {
-                let fmt: String = format!("Struct '{}' already implements trait '{}'.", structnm, traitnm);
-                errors::raise_error(&fmt, errors::ErrorType::StructAlreadyImplements, &node.pos, self.info);
-            }
-            
-            tp.traits.insert(traitnm.to_owned(), builtin_types::create_empty_trait());  
-            self.cur_module.types.insert(structnm.to_owned(), tp);
-
-            for var in traitsig.vars.as_ref().unwrap() {
-                if !self.cur_module.namespaces.structs.get(structnm).unwrap().0.names.as_ref().unwrap().contains(var.0) {
-                    let fmt: String = format!("Struct '{}' does not implement required member '{}'.", structnm, var.0);
-                    errors::raise_error(&fmt, errors::ErrorType::CannotImplementBuiltinTrait, &node.pos, self.info);                    
-                }
-                let idx = self.cur_module.namespaces.structs.get(structnm).unwrap().0.names.as_ref().unwrap().iter().position(|x| x==var.0).unwrap();
-                if self.cur_module.namespaces.structs.get(structnm).unwrap().0.types.get(idx).unwrap() != &Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, var.1, node).0 {
-                    let fmt: String = format!("Struct '{}' does not implement required member '{}' of type '{}'.", structnm, var.0, self.cur_module.namespaces.structs.get(structnm).unwrap().0.types.get(idx).unwrap());
-                    errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);     
-                }
-            }
-            
-            if node.data.impln.as_ref().unwrap().functions.len() != traitsig.trait_sig.as_ref().unwrap().len() {
-                let fmt: String = format!("Trait '{}' expected {} functions.", traitnm.to_string(), traitsig.trait_sig.unwrap().len());
-                errors::raise_error(&fmt, errors::ErrorType::ExpectedNFunctionsDefined, &node.pos, self.info);
+            types::Data {
+                data: None,
+                tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
+                owned: true,
             }
+        };
 
-            for function in &node.data.impln.as_ref().unwrap().functions {
-                if function.data.func.as_ref().unwrap().template_types.len() > 0 {
-                    let fmt: String = format!("Implementation functions may not be templated.");
-                    errors::raise_error(&fmt, errors::ErrorType::ImplTemplatedFunction, &node.pos, self.info);
-                }
+        let scopes: usize = self.loops.get(idx).unwrap().scopes;
+        self.build_scope_drops(scopes, None, node);
 
-                let mut found: bool = false;
-                for sig in traitsig.trait_sig.as_ref().unwrap() {
-                    if function.data.func.as_ref().unwrap().name == sig.name {
-                        found = true;
-                        break;
-                    }
-                }
+        let frame: &mut LoopFrame = self.loops.get_mut(idx).unwrap();
 
-                if !found {
-                    let fmt: String = format!("Function '{}' is not defined in trait '{}'.", function.data.func.as_ref().unwrap().name, traitnm.to_string());
-                    errors::raise_error(&fmt, errors::ErrorType::FunctionNotDefinedInTrait, &node.pos, self.info);
-                }
-                
-                let mut redundant: bool = false;
-                for func in &node.data.impln.as_ref().unwrap().functions {
-                    if  (function.data.func.as_ref().unwrap().name == func.data.func.as_ref().unwrap().name) &&
-                        func != function {
-                        redundant = true;
-                        break;
-                    }
-                }
+        if frame.break_tp.is_some() && frame.break_tp.as_ref().unwrap() != &value.tp {
+            let fmt: String = format!("Expected '{}' type, got '{}' type.", frame.break_tp.as_ref().unwrap(), value.tp);
+            errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
+        }
 
-                if redundant {
-                    let fmt: String = format!("Function '{}' is redefined in impl for trait '{}' on struct '{}'.", function.data.func.as_ref().unwrap().name, traitnm.to_string(), structnm.to_string());
-                    errors::raise_error(&fmt, errors::ErrorType::FunctionRedefinedInImpl, &node.pos, self.info);
-                }
-            }
+        frame.break_tp = Some(value.tp.clone());
+        frame.breaks.push((value.data, self.builder.get_insert_block().unwrap()));
 
-            let mut ptrs: Vec<inkwell::values::PointerValue<'ctx>> = Vec::new();
-            
-            for (sig, function) in izip![traitsig.trait_sig.as_ref().unwrap(), &node.data.impln.as_ref().unwrap().functions] {
-                let nargs: usize = sig.args.args.len();
+        let end_block: inkwell::basic_block::BasicBlock = frame.end_block;
+        self.build_loop_jump(end_block);
+        
+        let data: types::Data = types::Data {
+            data: None,
+            tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
+            owned: true,
+        };
+        return data;
+    }
 
-                if nargs != function.data.func.as_ref().unwrap().args.args.len() {
-                    let fmt: String = format!("Trait '{}' expected function with '{}' arguments.", traitnm.to_string(), nargs);
-                    errors::raise_error(&fmt, errors::ErrorType::ArgumentCountMismatch, &node.pos, self.info);
-                }
+    fn build_continue(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        let idx: usize = self.get_loop_frame(node, false);
 
-                let mut template_indices: std::collections::HashMap<String, Vec<usize>> = std::collections::HashMap::new();
-                let mut all_indices: Vec<usize> = Vec::new();
-                let mut standard_indices: Vec<usize> = Vec::new();
-                for template in &sig.template_types {
-                    let mut indices: Vec<usize> = Vec::new();
-                    let mut idx: usize = 0;
-                    for arg in &sig.args.args {
-                        if  !arg.isarr &&
-                            !arg.isfn &&
-                            !arg.isdyn &&
-                            !arg.isgenum &&
-                            !arg.isref &&
-                            !self.cur_module.datatypes.contains_key(arg.data.as_ref().unwrap()) &&
-                            sig.template_types.contains(arg.data.as_ref().unwrap()) &&
-                            arg.data.as_ref().unwrap() == template {
-                            indices.push(idx);
-                            all_indices.push(idx);
-                        }
-                        idx += 1;
-                    }
-                    template_indices.insert(template.to_owned(), indices);
-                }
+        let scopes: usize = self.loops.get(idx).unwrap().scopes;
+        self.build_scope_drops(scopes, None, node);
 
-                for idx in 0..sig.args.args.len() {
-                    if all_indices.contains(&idx) {
-                        continue;
-                    }
-                    standard_indices.push(idx);
-                }
-                
-                let _ = self.build_func(&function, Some(structnm.to_owned() + "." + function.data.func.as_ref().unwrap().name.as_str()), None, None);
-                let functp: types::DataType = self.cur_module.namespaces.functions.get(&(structnm.to_owned() + "." + function.data.func.as_ref().unwrap().name.as_str())).unwrap().1.to_owned();
-                
-                for (template, indices) in &template_indices {
-                    let firsttp: types::DataType = functp.types.get(indices.get(0).unwrap().to_owned()).unwrap().to_owned();
-                    if indices.len() > 1 {
-                        for index in indices[1..].to_vec() {
-                            if functp.types.get(index).unwrap().to_owned() != firsttp {
-                                let fmt: String = format!("Expected '{}' type, got '{}' type for '{}' template type.", firsttp, functp.types.get(index).unwrap().to_owned(), template);
-                                errors::raise_error(&fmt, errors::ErrorType::ImplFunctionTemplateTypeMismatch, &node.pos, self.info);
-                            }
-                        }
-                    }
-                }
+        let start_block: inkwell::basic_block::BasicBlock = self.loops.get(idx).unwrap().start_block;
+        self.build_loop_jump(start_block);
+        
+        let data: types::Data = types::Data {
+            data: None,
+            tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
+            owned: true,
+        };
+        return data;
+    }
 
-                for idx in &standard_indices {
-                    let tp: types::DataType = functp.types.get(idx.to_owned()).unwrap().to_owned();
+    fn build_while(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        let loop_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(self.current_block.unwrap().get_parent().unwrap(), "loop_head");
+        let loop_then_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(self.current_block.unwrap().get_parent().unwrap(), "loop_then");
+        let end_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(self.current_block.unwrap().get_parent().unwrap(), "loop_end");
 
-                    if tp != Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, sig.args.args.get(idx.to_owned()).as_ref().unwrap(), function).0 {
-                        let fmt: String = format!("Expected '{}' type, got '{}' type.", tp, Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, sig.args.args.get(idx.to_owned()).as_ref().unwrap(), function).0);
-                        errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
-                    }
-                }
+        self.loops.push(LoopFrame {
+            label: node.data.loopn.as_ref().unwrap().label.clone(),
+            start_block: loop_block,
+            end_block,
+            allow_value: false,
+            breaks: Vec::new(),
+            break_tp: None,
+            scopes: self.cur_module.namespaces.locals.len(),
+        });
 
-                if  !sig.args.rettp.first().unwrap().isarr &&
-                    !sig.args.rettp.first().unwrap().isfn &&
-                    !sig.args.rettp.first().unwrap().isdyn &&
-                    !sig.args.rettp.first().unwrap().isgenum &&
-                    !sig.args.rettp.first().unwrap().isref &&
-                    !self.cur_module.datatypes.contains_key(sig.args.rettp.first().unwrap().data.as_ref().unwrap()) &&
-                    sig.template_types.contains(sig.args.rettp.first().unwrap().data.as_ref().unwrap()) {
-                    let tp: types::DataType = functp.types.get(template_indices.get(sig.args.rettp.first().unwrap().data.as_ref().unwrap()).unwrap().get(0).unwrap().to_owned()).unwrap().to_owned();
+        self.builder.build_unconditional_branch(loop_block);
 
-                    if tp != *functp.rettp.as_ref().unwrap().to_owned() {
-                        let fmt: String = format!("Expected '{}' return  type, got '{}' return type.", tp, *functp.rettp.as_ref().unwrap().to_owned());
-                        errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
-                    }
-                }
+        self.builder.position_at_end(loop_block);     
+        self.current_block = Some(loop_block);       
 
-                //Add as method
-                let mut s: (types::DataType, Option<AnyTypeEnum>, std::collections::HashMap<String, i32>, ForwardDeclarationType) = self.cur_module.namespaces.structs.get(structnm).unwrap().clone();
-                
-                s.0.methods.insert(function.data.func.as_ref().unwrap().name.clone(), types::Method {
-                    tp: types::MethodType::Fn,
-                    builtin: None,
-                    func: Some(self.cur_module.namespaces.functions.get(&(structnm.to_owned() + "." + function.data.func.as_ref().unwrap().name.as_str())).unwrap().0.as_global_value().as_pointer_value()),
-                    functp: functp.clone(),
-                    isinstance: true,
-                    isinstanceptr: false,
-                    ismutinstanceptr: false,
-                });
+        let cond: inkwell::values::IntValue = self.build_condition(&node.data.loopn.as_ref().unwrap().expr.as_ref().unwrap());
 
-                self.cur_module.namespaces.structs.insert(structnm.to_owned(), (s.0, s.1, s.2, s.3));  
+        self.builder.build_conditional_branch(cond, loop_then_block, end_block);
 
-                ptrs.push(self.cur_module.namespaces.functions.get(&(structnm.to_owned() + "." + function.data.func.as_ref().unwrap().name.as_str())).unwrap().0.as_global_value().as_pointer_value());
-                functions.insert(function.data.func.as_ref().unwrap().name.to_owned(), self.cur_module.namespaces.functions.get(&(structnm.to_owned() + "." + function.data.func.as_ref().unwrap().name.as_str())).unwrap().0.as_global_value().as_pointer_value());                  
-            }
+        self.builder.position_at_end(loop_then_block);
+        self.current_block = Some(loop_then_block);
 
-            traitsig.implementations.insert(structnm.to_owned(), functions);
-            
-            self.traits.insert(traitsig.name.to_owned(), traitsig);   
+        self.compile(&node.data.loopn.as_ref().unwrap().block, true, true);
 
-            let idx: i32 = self.cur_module.namespaces.structid.get(structnm).unwrap().clone();
+        self.builder.build_unconditional_branch(loop_block);
 
-            self.append_struct_to_vtables(ptrs, idx);
-        }
+        self.builder.position_at_end(end_block);
+        self.current_block = Some(end_block);
 
+        self.loops.pop();
+        
         let data: types::Data = types::Data {
             data: None,
             tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
@@ -2418,640 +5975,749 @@ impl<'ctx> CodeGen<'ctx> {
         return data;
     }
 
-    fn build_namespaceload(&mut self, node: &parser::Node, get_enum_id: bool, allow_enum_noinit: bool, alttp: Option<types::DataType<'ctx>>, borrow_options: BorrowOptions, multinamespace: bool) -> types::Data<'ctx> {
-        let attr: &String = &node.data.attr.as_ref().unwrap().attr;
+    fn build_for(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        let forn: &parser::nodes::ForNode = node.data.forn.as_ref().unwrap();
 
-        //Check for enums
-        if  Self::datatypes_get(self, &node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name).is_some() &&
-            Self::datatypes_get(self, &node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name).unwrap().tp == types::BasicDataType::Enum{
-            let mut tp: types::DataType = Self::datatypes_get(self, &node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name).unwrap().clone();
+        //The loop counts from start up to end, and for arrays the counter is the index of the element
+        let counter_tp: types::DataType;
+        let startv: inkwell::values::IntValue;
+        let endv: inkwell::values::IntValue;
+        let inclusive: bool;
+        let mut arr: Option<(inkwell::values::PointerValue, types::DataType)> = None;
 
-            if alttp.is_some() {
-                tp = alttp.unwrap().to_owned();
-            }
-            let name: String = node.data.attr.as_ref().unwrap().attr.clone();
-            
-            if !tp.names.as_ref().unwrap().contains(&name) {
-                let fmt: String = format!("Type '{}' has no namespace attribute '{}'.", node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name, attr);
-                errors::raise_error(&fmt, errors::ErrorType::NamespaceAttrNotFound, &node.pos, self.info);
-            }
-            
-            let idx: usize = tp.names.as_ref().unwrap().iter().position(|x| x == &name).unwrap() as usize;
-            
-            if get_enum_id {
-                return types::Data {
-                    data: Some(inkwell::values::BasicValueEnum::IntValue(self.inkwell_types.i32tp.const_int(idx as u64, false))),
-                    tp: tp.clone(),
-                    owned: true
-                };
-            }
-            
-            if self.cur_module.namespaces.generic_enums.contains_key(&tp.name) && !allow_enum_noinit && tp.mutability.get(idx).unwrap() != &types::DataMutablility::Immutable {
-                let fmt: String = format!("Enum '{}' is generic, use a generic load.", tp.name);
-                errors::raise_error(&fmt, errors::ErrorType::NamespaceLoadOfGenericEnum, &node.pos, self.info);
-            }
-            else if (self.cur_module.namespaces.generic_enums.contains_key(&tp.name) || allow_enum_noinit) &&
-                    tp.mutability.get(idx).unwrap() == &types::DataMutablility::Immutable {
-                if node.data.attr.as_ref().unwrap().expr.is_some() {
-                    let dat: types::Data = self.compile_expr(&node.data.attr.as_ref().unwrap().expr.as_ref().unwrap(), BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, false, false);    
-                    let fmt: String = format!("Expected 'i32' type, got '{}' type.", dat.tp);
-                    errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
-                }
-                tp.types = vec![Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(), Self::datatypes_get(self, &types::BasicDataType::I32.to_string()).unwrap().clone()];
-                return types::Data {
-                    data: Some(inkwell::values::BasicValueEnum::IntValue(self.inkwell_types.i32tp.const_int(idx as u64, false))),
-                    tp: tp.clone(),
-                    owned: true
-                };
-            }
-            
-            let enum_tp: types::DataType = tp.types.get(idx).unwrap().clone();
-            
-            let data: Option<inkwell::values::BasicValueEnum>;
-            if  (node.data.attr.as_ref().unwrap().expr.is_none() &&
-                tp.mutability.get(idx).unwrap() == &types::DataMutablility::Immutable) || allow_enum_noinit  {
-                data = Some(inkwell::values::BasicValueEnum::IntValue(self.inkwell_types.i32tp.const_int(idx as u64, false)));
-            }
-            else {
-                if node.data.attr.as_ref().unwrap().expr.is_none() && !allow_enum_noinit {
-                    let fmt: String = format!("Expected '{}' type, got 'i32' type.", enum_tp.clone());
-                    errors::raise_error_multi(errors::ErrorType::TypeMismatch, vec![String::from("Add <...>."), fmt], vec![&node.pos, &node.pos], self.info);
-                }
-                let dat: types::Data = self.compile_expr(&node.data.attr.as_ref().unwrap().expr.as_ref().unwrap(), BorrowOptions{ give_ownership: true, get_ptr: false, mut_borrow: false}, false, false);
-                if dat.tp != enum_tp.clone() {
-                    let fmt: String = format!("Expected '{}' type, got '{}' type.", enum_tp.clone(), dat.tp);
-                    errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
-                }
-                data = dat.data;
-            }
+        if forn.expr.tp == parser::NodeType::RANGE {
+            let range: &parser::nodes::RangeNode = forn.expr.data.range.as_ref().unwrap();
 
-            let mut types: Vec<types::DataType> = tp.types.to_owned();
-            types.insert(0, Self::datatypes_get(self, &types::BasicDataType::I32.to_string()).unwrap().clone());
-    
-            let st: inkwell::values::PointerValue = Self::alloca(self, Self::build_struct_tp_from_types(&self.context, &self.inkwell_types, &types, &self.cur_module.datatypes).into_struct_type(), "enum_st");
-    
-            let id: inkwell::values::PointerValue = self.builder.build_struct_gep(st, 0, "variant_id").expect("GEP Error");
-            self.builder.build_store(id, self.inkwell_types.i32tp.const_int(idx as u64, false));
-            
-            if data.is_some() {
-                let variant_data: inkwell::values::PointerValue = self.builder.build_struct_gep(st, (idx+1) as u32, "variant_data").expect("GEP Error");
-                self.builder.build_store(variant_data, data.unwrap());
+            //An untyped literal bound takes the type of the other bound
+            let end_first: Option<types::Data> = if Self::is_untyped_literal(&range.start) && !Self::is_untyped_literal(&range.end) {
+                Some(self.compile_expr(&range.end, BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: false}, false, false))
             }
+            else {
+                None
+            };
 
-            if borrow_options.get_ptr {
-                return types::Data {
-                    data: Some(inkwell::values::BasicValueEnum::PointerValue(st)),
-                    tp: tp.clone(),
-                    owned: true
-                };
+            let start: types::Data = if end_first.is_some() {
+                self.compile_expr_expecting(&range.start, BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: false}, &end_first.as_ref().unwrap().tp.clone())
             }
-            return types::Data {
-                data: Some(self.builder.build_load(st, "load_variant")),
-                tp: tp.clone(),
-                owned: true
+            else {
+                self.compile_expr(&range.start, BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: false}, false, false)
             };
-        }
-        else if self.cur_module.modules.contains_key(&node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name) {
-            let module = self.cur_module.modules.get(&node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name).unwrap().clone();
-            let attr: String = node.data.attr.as_ref().unwrap().attr.clone();
-            
-            if module.namespaces.structs.contains_key(&attr) && multinamespace {
-                return types::Data {
-                    data: None,
-                    tp: module.namespaces.structs.get(&attr).unwrap().0.clone(),
-                    owned: true
-                };
+            let end: types::Data = if end_first.is_some() {
+                end_first.unwrap()
             }
-        }
-
-        if self.cur_module.namespaces.structs.get(&node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name).is_none() {
-            let fmt: String = format!("Struct '{}' is not defined.", &node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name);
-            errors::raise_error(&fmt, errors::ErrorType::StructNotDefined, &node.pos, self.info);
-        }
-
-        let st = self.cur_module.namespaces.structs.get(&node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name).unwrap().clone();
-
-        //First check methods
-        let method_: Option<&types::Method> = st.0.methods.get(attr);
-        if method_.is_some() {
-            let method: &types::Method = method_.unwrap();
-            if !method.isinstance {
-                if method.tp == types::MethodType::Fn {
-                    let data: types::Data = types::Data {
-                        data: Some(inkwell::values::BasicValueEnum::PointerValue(method.func.unwrap())),
-                        tp: method.functp.clone(),
-                        owned: true,
-                    };
-
-                    return data;
-                }
-                else {
-                    let mut tp_: types::DataType = Self::datatypes_get(self, &types::BasicDataType::WrapperFunc.to_string()).unwrap().clone();
-                    tp_.wrapperfn = method.builtin;
-                    tp_.types = method.functp.types.clone();                    
-                    let data: types::Data = types::Data {
-                        data: None,
-                        tp: tp_,
-                        owned: true,
-                    };
+            else {
+                self.compile_expr_expecting(&range.end, BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: false}, &start.tp.clone())
+            };
 
-                    return data;
+            match start.tp.tp {
+                types::BasicDataType::I8 |
+                types::BasicDataType::U8 |
+                types::BasicDataType::I16 |
+                types::BasicDataType::U16 |
+                types::BasicDataType::I32 |
+                types::BasicDataType::U32 |
+                types::BasicDataType::I64 |
+                types::BasicDataType::U64 |
+                types::BasicDataType::I128 |
+                types::BasicDataType::U128 => {}
+                _ => {
+                    let fmt: String = format!("Expected integer range, got '{}'.", start.tp);
+                    errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, &forn.expr.pos, self.info);
                 }
             }
-        }
-
-        //Last case
-        let fmt: String = format!("Type '{}' has no namespace attribute '{}'.", node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name, attr);
-        errors::raise_error(&fmt, errors::ErrorType::NamespaceAttrNotFound, &node.pos, self.info);
-    }
-
-    fn build_if(&mut self, node: &parser::Node) -> types::Data<'ctx> {
-        let end_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(self.current_block.unwrap().get_parent().unwrap(), "if_end");
-        let else_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(self.current_block.unwrap().get_parent().unwrap(), "else");
-
-        let mut current_block: inkwell::basic_block::BasicBlock = self.current_block.unwrap();
 
-        let mut collected_locals: Vec<std::collections::HashMap<String, usize>> = Vec::new();
+            if start.tp != end.tp {
+                let fmt: String = format!("Expected '{}' type, got '{}' type.", start.tp, end.tp);
+                errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &forn.expr.pos, self.info);
+            }
 
-        let mut blocks: Vec<(Option<inkwell::values::BasicValueEnum>, inkwell::basic_block::BasicBlock)> = Vec::new();
-        let mut rettp: Option<types::DataType> = None;
+            counter_tp = start.tp.clone();
+            startv = start.data.unwrap().into_int_value();
+            endv = end.data.unwrap().into_int_value();
+            inclusive = range.inclusive;
+        }
+        else {
+            let base: types::Data = self.compile_expr(&forn.expr, BorrowOptions{ give_ownership: false, get_ptr: true, mut_borrow: false}, false, false);
 
-        let inexpr: bool = node.data.ifn.as_ref().unwrap().inexpr;
+            if base.tp.tp != types::BasicDataType::Array {
+                let fmt: String = format!("Type '{}' is not iterable.", base.tp);
+                errors::raise_error(&fmt, errors::ErrorType::NotIterable, &forn.expr.pos, self.info);
+            }
 
-        let mut idx: usize = 0;
-        for ifn in &node.data.ifn.as_ref().unwrap().ifs {
-            self.builder.position_at_end(current_block);  
-            self.current_block = Some(current_block);  
-            let right: types::Data = self.compile_expr(&ifn.0, BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: false}, false, false);
-            
-            let mut args: Vec<types::Data> = Vec::new();
+            let arrptr: inkwell::values::PointerValue = base.data.unwrap().into_pointer_value();
+            let len: u32 = arrptr.get_type().get_element_type().into_array_type().len();
 
-            let tp: types::Type = Self::get_type_from_data(self.cur_module.types.clone(), &right);
+            counter_tp = Self::datatypes_get(self, &types::BasicDataType::U64.to_string()).unwrap().clone();
+            startv = self.inkwell_types.i64tp.const_zero();
+            endv = self.inkwell_types.i64tp.const_int(len as u64, false);
+            inclusive = false;
+            arr = Some((arrptr, base.tp.types.first().unwrap().clone()));
+        }
 
-            let tp_str: &String = &right.tp.name.clone();
+        let signed: bool = builtin_types::int_issigned(counter_tp.clone());
 
-            args.push(right);
+        let counter: inkwell::values::PointerValue = Self::alloca(self, startv.get_type(), "for_counter");
+        self.builder.build_store(counter, startv);
 
-            let traittp: types::TraitType = types::TraitType::Bool;
+        let loop_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(self.current_block.unwrap().get_parent().unwrap(), "for_head");
+        let loop_then_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(self.current_block.unwrap().get_parent().unwrap(), "for_then");
+        let step_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(self.current_block.unwrap().get_parent().unwrap(), "for_step");
+        let end_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(self.current_block.unwrap().get_parent().unwrap(), "for_end");
 
-            let t: &types::Trait = match tp.traits.get(&traittp.to_string()) {
-                Some (v) => {
-                    v
-                }
-                None => {
-                    let fmt: String = format!("Type '{}' has no trait '{}'.", tp_str, &traittp.to_string());
-                    errors::raise_error(&fmt, errors::ErrorType::MissingTrait, &node.pos, self.info);
-                }
-            };
+        //Continue goes to the step block so the counter is still incremented
+        self.loops.push(LoopFrame {
+            label: forn.label.clone(),
+            start_block: step_block,
+            end_block,
+            allow_value: false,
+            breaks: Vec::new(),
+            break_tp: None,
+            scopes: self.cur_module.namespaces.locals.len(),
+        });
 
-            let data: types::Data = self.call_trait(t, args, node);
+        self.builder.build_unconditional_branch(loop_block);
 
-            if data.tp != Self::datatypes_get(self, &types::BasicDataType::Bool.to_string()).unwrap().clone() {
-                let fmt: String = format!("Expected 'bool' type, got '{}' type.", data.tp);
-                errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
-            }
-            
-            let then_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(self.current_block.unwrap().get_parent().unwrap(), "if");
+        self.builder.position_at_end(loop_block);     
+        self.current_block = Some(loop_block);
 
-            let _ = then_block.move_after(self.current_block.unwrap());
-            
-            let elseif_block: inkwell::basic_block::BasicBlock;
+        let cur: inkwell::values::IntValue = self.builder.build_load(counter, "for_cur").into_int_value();
 
-            if idx!=node.data.ifn.as_ref().unwrap().ifs.len()-1 {
-                elseif_block = self.context.append_basic_block(self.current_block.unwrap().get_parent().unwrap(), "else_if");
+        let predicate: inkwell::IntPredicate = match (inclusive, signed) {
+            (false, false) => inkwell::IntPredicate::ULT,
+            (false, true) => inkwell::IntPredicate::SLT,
+            (true, false) => inkwell::IntPredicate::ULE,
+            (true, true) => inkwell::IntPredicate::SLE,
+        };
+        let cond: inkwell::values::IntValue = self.builder.build_int_compare(predicate, cur, endv, "for_cond");
 
-                let _ = elseif_block.move_after(then_block);
-                
-                current_block = elseif_block;                
-            }
-            else {
-                elseif_block = else_block;
-            }
+        self.builder.build_conditional_branch(cond, loop_then_block, end_block);
 
-            self.builder.build_conditional_branch(data.data.unwrap().into_int_value(), then_block, elseif_block);
+        self.builder.position_at_end(loop_then_block);
+        self.current_block = Some(loop_then_block);
 
-            self.builder.position_at_end(then_block);
-            self.current_block = Some(then_block);
-            
-            self.cur_module.namespaces.locals.push(std::collections::HashMap::new());
+        self.cur_module.namespaces.locals.push(std::collections::HashMap::new());
 
+        let (varptr, vartp) = if arr.is_some() {
+            let (arrptr, elemtp) = arr.unwrap();
+            let itmptr: inkwell::values::PointerValue = unsafe { self.builder.build_in_bounds_gep(arrptr, &[self.inkwell_types.i32tp.const_zero(), cur], "itmptr") };
+            let item: inkwell::values::BasicValueEnum = self.builder.build_load(itmptr, "item");
 
-            let mut start_locals: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
-            let mut end_locals: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
-            
-            let mut lvl: usize = 0;
-            for local in &self.cur_module.namespaces.locals {
-                for item in local {
-                    if item.1.5 == InitializationStatus::Uninitialized {
-                        start_locals.insert(item.0.clone(), lvl);
-                    }
-                }
-                lvl += 1;
-            }
-            
-            let loop_flow_broken_old = self.loop_flow_broken;
+            let ptr: inkwell::values::PointerValue = Self::alloca(self, item.get_type(), forn.name.as_str());
+            self.builder.build_store(ptr, item);
+            (ptr, elemtp)
+        }
+        else {
+            let ptr: inkwell::values::PointerValue = Self::alloca(self, cur.get_type(), forn.name.as_str());
+            self.builder.build_store(ptr, cur);
+            (ptr, counter_tp)
+        };
 
-            let res: types::Data = self.compile(&ifn.1, true, false);
+        self.cur_module.namespaces.locals.last_mut().unwrap().insert(forn.name.clone(), (Some(varptr), vartp, types::DataMutablility::Immutable, types::DataOwnership {owned: true, transferred: None, mut_borrowed: false}, node.pos.clone(), InitializationStatus::Initialized));
 
-            if rettp.is_none() {
-                rettp = Some(res.tp.clone());
-            }
+        self.compile(&forn.block, true, true);
 
-            blocks.push((res.data.clone(), then_block));
+        self.build_scope_drops(self.cur_module.namespaces.locals.len() - 1, None, node);
+        self.cur_module.namespaces.locals.pop();
 
-            if &res.tp != rettp.as_ref().unwrap() && inexpr {
-                let fmt: String = format!("Expected '{}' type, got '{}' type.", rettp.as_ref().unwrap(), res.tp);
-                errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);                
-            }
+        self.builder.build_unconditional_branch(step_block);
 
-            self.loop_flow_broken = loop_flow_broken_old;
+        self.builder.position_at_end(step_block);
+        self.current_block = Some(step_block);
 
-            let mut lvl: usize = 0;
-            for local in &self.cur_module.namespaces.locals {
-                for item in local {
-                    if  item.1.5 == InitializationStatus::Initialized && start_locals.get(item.0).is_some() &&
-                        start_locals.get(item.0).unwrap() == &lvl {
-                        
-                        end_locals.insert(item.0.clone(), lvl);
+        let cur: inkwell::values::IntValue = self.builder.build_load(counter, "for_cur").into_int_value();
 
-                    }
-                }
-                lvl += 1;
-            }
+        //Stop before incrementing past the end, so that ranges up to the maximum value do not overflow
+        if inclusive {
+            let inc_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(self.current_block.unwrap().get_parent().unwrap(), "for_inc");
+            let _ = inc_block.move_after(step_block);
 
-            for var in &end_locals {
-                let mut var_val = self.cur_module.namespaces.locals.get_mut(var.1.to_owned()).unwrap().get(&var.0.to_owned()).unwrap().to_owned();
-                var_val.5 = InitializationStatus::Uninitialized;
+            let last: inkwell::values::IntValue = self.builder.build_int_compare(inkwell::IntPredicate::EQ, cur, endv, "for_last");
+            self.builder.build_conditional_branch(last, end_block, inc_block);
 
-                self.cur_module.namespaces.locals.get_mut(var.1.to_owned()).unwrap().insert(var.0.to_owned(), var_val);
-            }
+            self.builder.position_at_end(inc_block);
+            self.current_block = Some(inc_block);
+        }
 
-            collected_locals.push(end_locals);
+        let next: inkwell::values::IntValue = self.builder.build_int_add(cur, cur.get_type().const_int(1, false), "for_next");
+        self.builder.build_store(counter, next);
 
+        self.builder.build_unconditional_branch(loop_block);
 
-            self.cur_module.namespaces.locals.pop();
+        self.builder.position_at_end(end_block);
+        self.current_block = Some(end_block);
 
-            self.builder.build_unconditional_branch(end_block);
+        self.loops.pop();
+        
+        let data: types::Data = types::Data {
+            data: None,
+            tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
+            owned: true,
+        };
+        return data;
+    }
 
-            self.current_block = Some(then_block);
+    fn build_enum(&mut self, node: &parser::Node) -> types::Data<'ctx> {        
+        if !node.data.enumn.as_ref().unwrap().name.is_camel_case() {
+            errors::show_warning(errors::WarningType::ExpectedCamelCase, vec![String::from(""), node.data.st.as_ref().unwrap().name.to_camel_case()], vec![String::from("Expected camel case"), String::from("Convert to this: ")], &node.pos, self.info)
+        }
 
-            idx+=1;
+        if Self::datatypes_get(self, &node.data.enumn.as_ref().unwrap().name.clone()).is_some() && self.cur_module.namespaces.structs.get(&node.data.enumn.as_ref().unwrap().name.clone()).unwrap().3 != ForwardDeclarationType::Forward {
+            let fmt: String = format!("Type '{}' is already defined.", node.data.enumn.as_ref().unwrap().name.clone());
+            errors::raise_error(&fmt, errors::ErrorType::TypeRedefinitionAttempt, &node.pos, self.info);
         }
+
+        let mut names: Vec<String> = Vec::new();
+        let mut mutabilities: Vec<types::DataMutablility> = Vec::new();
         
-        
-        let _ = else_block.move_after(self.current_block.unwrap());
-        let _ = end_block.move_after(else_block); 
+        for member in &node.data.enumn.as_ref().unwrap().variants {
+            if !member.is_camel_case() {
+                errors::show_warning(errors::WarningType::ExpectedCamelCase, vec![String::from(""), member.to_camel_case()], vec![String::from("Expected camel case"), String::from("Convert to this: ")], &node.pos, self.info)
+            }
+            if names.contains(&member.clone()) {
+                let fmt: String = format!("Variant '{}' is already declared.", member.clone());
+                errors::raise_error(&fmt, errors::ErrorType::VariantRedeclaration, &node.pos, self.info);
+            }
+            names.push(member.clone());
+        }
 
-        if node.data.ifn.as_ref().unwrap().else_opt.is_some() {
-            self.builder.position_at_end(else_block);
-            self.current_block = Some(else_block);
+        let mut discriminants: Vec<i32> = Vec::new();
+        let mut next: i128 = 0;
+
+        for (member, discriminant) in std::iter::zip(&node.data.enumn.as_ref().unwrap().variants, &node.data.enumn.as_ref().unwrap().discriminants) {
+            if discriminant.is_some() {
+                let value: consteval::ConstValue = consteval::ConstEvaluator::new(&self.cur_module.namespaces, &self.cur_module.datatypes, &self.datatypes, self.info).eval(discriminant.as_ref().unwrap());
+                next = match value {
+                    consteval::ConstValue::Int(v, _) => v,
+                    consteval::ConstValue::UInt(v, _) if v <= i128::MAX as u128 => v as i128,
+                    _ => {
+                        let fmt: String = format!("Discriminant '{}' of variant '{}' is not a valid 'i32'.", value, member);
+                        errors::raise_error(&fmt, errors::ErrorType::DiscriminantOutOfRange, &discriminant.as_ref().unwrap().pos, self.info);
+                    }
+                };
+            }
             
-            self.cur_module.namespaces.locals.push(std::collections::HashMap::new());
+            let pos: &parser::Position = if discriminant.is_some() { &discriminant.as_ref().unwrap().pos } else { &node.pos };
+            let value: i32 = match i32::try_from(next) {
+                Ok(v) => v,
+                Err(_) => {
+                    let fmt: String = format!("Discriminant '{}' of variant '{}' is not a valid 'i32'.", next, member);
+                    errors::raise_error(&fmt, errors::ErrorType::DiscriminantOutOfRange, pos, self.info);
+                }
+            };
+            if discriminants.contains(&value) {
+                let fmt: String = format!("Discriminant '{}' of variant '{}' is already used.", value, member);
+                errors::raise_error(&fmt, errors::ErrorType::DuplicateDiscriminant, pos, self.info);
+            }
+            discriminants.push(value);
+            next += 1;
+        }
 
+        self.cur_module.namespaces.enum_discriminants.insert(node.data.enumn.as_ref().unwrap().name.clone(), discriminants);
 
-            let mut start_locals: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
-            let mut end_locals: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
-            
-            let mut lvl: usize = 0;
-            for local in &self.cur_module.namespaces.locals {
-                for item in local {
-                    if item.1.5 == InitializationStatus::Uninitialized {
-                        start_locals.insert(item.0.clone(), lvl);
-                    }
+        let mut types: Vec<types::DataType> = Vec::new();
+        
+        if node.data.enumn.as_ref().unwrap().template_types.len() == 0 {
+            for tp in &node.data.enumn.as_ref().unwrap().tps {
+                if tp.is_some() {
+                    types.push(Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, tp.as_ref().unwrap(), node).0);
+                    mutabilities.push(types::DataMutablility::Mutable);
+                }
+                else {
+                    types.push(Self::datatypes_get(self, &types::BasicDataType::I32.to_string()).unwrap().clone());
+                    mutabilities.push(types::DataMutablility::Immutable);
+                }
+                if types.last().unwrap().is_ref {
+                    let fmt: String = format!("Enums may not contain references.");
+                    errors::raise_error(&fmt, errors::ErrorType::ReferenceVariantEnum, &node.pos, self.info);
                 }
-                lvl += 1;
             }
+        }   
 
-            let loop_flow_broken_old = self.loop_flow_broken;
+        let mut tp: types::DataType = Self::datatypes_get(self, &types::BasicDataType::Enum.to_string()).unwrap().clone();
+        tp.name = node.data.enumn.as_ref().unwrap().name.clone();
+        tp.names = Some(names);
+        tp.types = types;
+        tp.mutability = mutabilities;
 
-            let res: types::Data = self.compile(&node.data.ifn.as_ref().unwrap().else_opt.as_ref().unwrap(), true, false);
+        self.cur_module.datatypes.insert(node.data.enumn.as_ref().unwrap().name.clone(), tp.clone());
 
-            self.loop_flow_broken = loop_flow_broken_old;
-            
-            if rettp.is_none() {
-                rettp = Some(res.tp.clone());
-            }
+        if node.data.enumn.as_ref().unwrap().template_types.len() > 0 {
+            self.cur_module.namespaces.generic_enums.insert(node.data.enumn.as_ref().unwrap().name.clone(), (node.data.enumn.as_ref().unwrap().template_types.to_owned(), node.data.enumn.as_ref().unwrap().tps.to_owned()));
+        }
 
-            blocks.push((res.data.clone(), else_block));
+        builtin_types::add_simple_type(self, std::collections::HashMap::new(), types::BasicDataType::Enum, &node.data.enumn.as_ref().unwrap().name.clone());
 
-            if &res.tp != rettp.as_ref().unwrap() && inexpr{
-                let fmt: String = format!("Expected '{}' type, got '{}' type.", rettp.as_ref().unwrap(), res.tp);
-                errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);                
-            }
+        if node.data.enumn.as_ref().unwrap().derives.len() > 0 {
+            self.build_derives(&node.data.enumn.as_ref().unwrap().name, &node.data.enumn.as_ref().unwrap().derives, node.data.enumn.as_ref().unwrap().template_types.len() > 0, node);
+        }
 
-            let mut lvl: usize = 0;
-            for local in &self.cur_module.namespaces.locals {
-                for item in local {
-                    if  item.1.5 == InitializationStatus::Initialized && start_locals.get(item.0).is_some() &&
-                        start_locals.get(item.0).unwrap() == &lvl {
-                        
-                        end_locals.insert(item.0.clone(), lvl);
-                    }
-                }
-                lvl += 1;
-            }
+        let data: types::Data = types::Data {
+            data: None,
+            tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
+            owned: true,
+        };
+        return data;
+    }
 
-            for var in &end_locals {
-                let mut var_val = self.cur_module.namespaces.locals.get_mut(var.1.to_owned()).unwrap().get(&var.0.to_owned()).unwrap().to_owned();
-                var_val.5 = InitializationStatus::Uninitialized;
+    //Declare the functions of the derived traits of a struct or enum, and add the traits to its type
+    fn declare_derives(&mut self, name: &String, derives: &Vec<String>, is_generic: bool, node: &parser::Node) -> Vec<(inkwell::values::FunctionValue<'ctx>, types::TraitType)> {
+        if is_generic {
+            let fmt: String = format!("Cannot derive traits for generic type '{}'.", name);
+            errors::raise_error(&fmt, errors::ErrorType::CannotDerive, &node.pos, self.info);
+        }
 
-                self.cur_module.namespaces.locals.get_mut(var.1.to_owned()).unwrap().insert(var.0.to_owned(), var_val);
+        let selftp: types::DataType = Self::datatypes_get(self, name).unwrap().clone();
+        let booltp: types::DataType = Self::datatypes_get(self, &types::BasicDataType::Bool.to_string()).unwrap().clone();
+        let stringtp: types::DataType = Self::datatypes_get(self, &String::from("String")).unwrap().clone();
+        let u64tp: types::DataType = Self::datatypes_get(self, &types::BasicDataType::U64.to_string()).unwrap().clone();
+        let inktp: inkwell::types::BasicTypeEnum = Self::get_basic_from_any(Self::get_anytp_from_tp(self.context, &self.inkwell_types, selftp.clone(), &self.cur_module.datatypes).unwrap()).unwrap();
+
+        let derivable: Vec<types::TraitType> = vec![types::TraitType::Eq, types::TraitType::Ne, types::TraitType::ToString, types::TraitType::Clone, types::TraitType::Default, types::TraitType::Hash];
+
+        let mut funcs: Vec<(inkwell::values::FunctionValue, types::TraitType)> = Vec::new();
+        for derive in derives {
+            let traittp: Option<types::TraitType> = types::get_traittp_from_str(derive.to_owned());
+            if traittp.is_none() || !derivable.contains(traittp.as_ref().unwrap()) {
+                let fmt: String = format!("Trait '{}' cannot be derived.", derive);
+                errors::raise_error(&fmt, errors::ErrorType::CannotDerive, &node.pos, self.info);
             }
 
-            collected_locals.push(end_locals);
+            let mut tp: types::Type = self.cur_module.types.get(name).unwrap().clone();
 
+            if tp.traits.contains_key(derive) {
+                let fmt: String = format!("Type '{}' already implements trait '{}'.", name, derive);
+                errors::raise_error(&fmt, errors::ErrorType::StructAlreadyImplements, &node.pos, self.info);
+            }
 
-            self.builder.build_unconditional_branch(self.current_block.unwrap());
+            let (fn_type, nargs, rettp): (inkwell::types::FunctionType, usize, types::DataType) = match traittp.as_ref().unwrap() {
+                types::TraitType::Eq | types::TraitType::Ne => (self.inkwell_types.booltp.fn_type(&[inktp.into(), inktp.into()], false), 2, booltp.clone()),
+                types::TraitType::ToString => (self.inkwell_types.stringtp.fn_type(&[inktp.into()], false), 1, stringtp.clone()),
+                types::TraitType::Clone => (inktp.fn_type(&[inktp.into()], false), 1, selftp.clone()),
+                types::TraitType::Default => (inktp.fn_type(&[], false), 0, selftp.clone()),
+                types::TraitType::Hash => (self.inkwell_types.i64tp.fn_type(&[inktp.into()], false), 1, u64tp.clone()),
+                _ => unreachable!(),
+            };
+            let func: inkwell::values::FunctionValue = self.module.add_function(&(name.to_owned() + "." + self.traits.get(derive).unwrap().name.as_str()), fn_type, None);
 
-            self.cur_module.namespaces.locals.pop();
-        }
-        else {
-            self.builder.position_at_end(else_block);
-            self.current_block = Some(else_block);
-            self.builder.build_unconditional_branch(end_block);
+            tp.traits.insert(derive.to_owned(), builtin_types::create_trait_ink(func.as_global_value().as_pointer_value(), nargs, traittp.unwrap(), rettp));
+            self.cur_module.types.insert(name.to_owned(), tp);
+
+            funcs.push((func, traittp.unwrap()));
         }
 
-        //Only if there is an else clause to catch all other cases
-        if node.data.ifn.as_ref().unwrap().else_opt.is_some() {
-            let mut common: Vec<(String, usize)> = Vec::new();
-            let mut common_init: Vec<(String, usize)> = Vec::new();
+        return funcs;
+    }
 
-            //Get all of the initialized variables
-            for local_set in &collected_locals {
-                for item in local_set {
-                    if !common.contains(&(item.0.to_owned(), item.1.to_owned())) {
-                        common.push((item.0.to_owned(), item.1.to_owned()));
-                    }
-                }
+    //Build the derived traits of a struct or enum. While forward declaring, the bodies are built once all types are declared,
+    //so fields may use the derived traits of types declared later
+    fn build_derives(&mut self, name: &String, derives: &Vec<String>, is_generic: bool, node: &parser::Node) {
+        for (func, traittp) in self.declare_derives(name, derives, is_generic, node) {
+            if self.pending_derives.is_some() {
+                self.pending_derives.as_mut().unwrap().push((name.to_owned(), func, traittp, node.clone()));
             }
-
-            //Get all of the initialized variables that all have been commonly init
-            'outer: for var in &common {
-                for local_set in &collected_locals {
-                    if local_set.len() == 0 {
-                        continue 'outer;
-                    }
-                    for local in local_set {
-                        if local != (&var.0, &var.1) {
-                            continue 'outer;
-                        }
-                    }
-                }
-                common_init.push((var.0.to_owned(), var.1.to_owned()));
+            else {
+                self.build_derive(name, func, traittp, node);
             }
+        }
+    }
 
-            for var in common_init {
-                let mut var_val = self.cur_module.namespaces.locals.get_mut(var.1).unwrap().get(&var.0).unwrap().to_owned();
-                var_val.5 = InitializationStatus::Initialized;
+    //Get the trait of a field (or variant data) of a type with a derived trait
+    fn get_derive_field_trait(&self, name: &String, field: &String, fieldtp: &types::DataType<'ctx>, traittp: &types::TraitType, node: &parser::Node) -> types::Trait<'ctx> {
+        let t: Option<&types::Trait> = match self.cur_module.types.get(&fieldtp.name) {
+            Some(tp) => tp.traits.get(&traittp.to_string()),
+            None => None,
+        };
 
-                self.cur_module.namespaces.locals.get_mut(var.1).unwrap().insert(var.0, var_val);
-            }
+        if t.is_none() || (t.unwrap().function.is_none() && t.unwrap().inkfunc.is_none()) {
+            let fmt: String = format!("Cannot derive '{}' for '{}', as field '{}' of type '{}' does not implement '{}'.", traittp, name, field, fieldtp, traittp);
+            errors::raise_error(&fmt, errors::ErrorType::CannotDerive, &node.pos, self.info);
         }
 
-        self.current_block = Some(end_block);
+        return t.unwrap().clone();
+    }
 
-        self.builder.position_at_end(end_block);
-        self.current_block = Some(end_block);
+    //Build the function of a derived trait, with the body built from the trait of each field (or variant data)
+    fn build_derive(&mut self, name: &String, func: inkwell::values::FunctionValue<'ctx>, traittp: types::TraitType, node: &parser::Node) {
+        let selftp: types::DataType = Self::datatypes_get(self, name).unwrap().clone();
 
-        if rettp.as_ref().unwrap().tp == types::BasicDataType::Void {
-            let data: types::Data = types::Data {
-                data: None,
-                tp: rettp.unwrap().clone(),
-                owned: true,
-            };
-            return data;
-        }
+        // Add debug information
+        let sub_type = self.dibuilder.create_subroutine_type(
+            self.dicompile_unit.get_file(),
+            None,
+            &[],
+            inkwell::debug_info::DIFlagsConstants::PUBLIC);
 
-        let phi: inkwell::values::PhiValue = self.builder.build_phi(blocks.first().unwrap().0.unwrap().get_type(), "if_phi");
+        let func_scope: inkwell::debug_info::DISubprogram = self.dibuilder.create_function(
+            self.dicompile_unit.as_debug_info_scope(),
+            func.get_name().to_str().unwrap(),
+            Some(func.get_name().to_str().unwrap()),
+            self.dicompile_unit.get_file(),
+            node.pos.line as u32,
+            sub_type,
+            true,
+            true,
+            node.pos.line as u32,
+            inkwell::debug_info::DIFlagsConstants::PUBLIC,
+            true);
 
-        for block in blocks {
-            phi.add_incoming(&[(&block.0.unwrap(), block.1)]);
+        func.set_subprogram(func_scope);
+
+        let location = self.dibuilder.create_debug_location(
+            self.context,
+            node.pos.line as u32,
+            node.pos.startcol as u32,
+            func_scope.as_debug_info_scope(),
+            None);
+
+        let prev_location: Option<inkwell::debug_info::DILocation> = self.builder.get_current_debug_location();
+        let current_block: Option<inkwell::basic_block::BasicBlock> = self.current_block;
+        let enclosing_block: Option<inkwell::basic_block::BasicBlock> = self.enclosing_block;
+        let alloc_head: Option<inkwell::values::InstructionValue> = self.alloc_head;
+
+        let entry: inkwell::basic_block::BasicBlock = self.context.append_basic_block(func, "entry");
+        self.current_block = Some(entry);
+        self.enclosing_block = Some(entry);
+        self.alloc_head = None;
+        self.builder.position_at_end(entry);
+        self.builder.set_current_debug_location(self.context, location);
+
+        match traittp {
+            types::TraitType::Eq | types::TraitType::Ne => self.build_derive_eq(name, &selftp, func, entry, &traittp, node),
+            types::TraitType::ToString => self.build_derive_to_string(name, &selftp, func, node),
+            types::TraitType::Clone => self.build_derive_clone(name, &selftp, func, node),
+            types::TraitType::Default => self.build_derive_default(name, &selftp, func, node),
+            types::TraitType::Hash => self.build_derive_hash(name, &selftp, func, node),
+            _ => unreachable!(),
         }
 
-        let data: types::Data = types::Data {
-            data: Some(phi.as_basic_value()),
-            tp: rettp.unwrap().clone(),
-            owned: true,
-        };
-        return data;
+        self.current_block = current_block;
+        self.enclosing_block = enclosing_block;
+        self.alloc_head = alloc_head;
+        if self.current_block.is_some() {
+            self.builder.position_at_end(self.current_block.unwrap());
+        }
+        if prev_location.is_some() {
+            self.builder.set_current_debug_location(self.context, prev_location.unwrap());
+        }
     }
 
-    fn build_loop(&mut self, node: &parser::Node) -> types::Data<'ctx> {
-        let loop_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(self.current_block.unwrap().get_parent().unwrap(), "loop");
-        let end_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(self.current_block.unwrap().get_parent().unwrap(), "loop_end");
+    //Build a derived Eq or Ne. Structs compare each field in order, and enums compare the ids and then the data of the variant
+    fn build_derive_eq(&mut self, name: &String, selftp: &types::DataType<'ctx>, func: inkwell::values::FunctionValue<'ctx>, entry: inkwell::basic_block::BasicBlock<'ctx>, traittp: &types::TraitType, node: &parser::Node) {
+        let iseq: bool = traittp == &types::TraitType::Eq;
 
-        let start_block_old = self.start_block;
-        let end_block_old = self.end_block;
-        let loop_flow_broken_old = self.loop_flow_broken;
+        let left: inkwell::values::StructValue = func.get_nth_param(0).unwrap().into_struct_value();
+        let right: inkwell::values::StructValue = func.get_nth_param(1).unwrap().into_struct_value();
 
-        self.start_block = Some(loop_block);
-        self.end_block = Some(end_block);
+        //The result of comparing equal values, and of comparing values which differ
+        let same: inkwell::values::IntValue = self.inkwell_types.booltp.const_int(iseq as u64, false);
+        let differ: inkwell::values::IntValue = self.inkwell_types.booltp.const_int(!iseq as u64, false);
 
-        self.builder.build_unconditional_branch(loop_block);
+        if selftp.tp == types::BasicDataType::Struct {
+            let mut res: inkwell::values::IntValue = same;
+            for (idx, (field, fieldtp)) in std::iter::zip(selftp.names.as_ref().unwrap(), &selftp.types).enumerate() {
+                let t: types::Trait = self.get_derive_field_trait(name, field, fieldtp, traittp, node);
 
-        self.builder.position_at_end(loop_block);
-        self.current_block = Some(loop_block);
+                let a: inkwell::values::BasicValueEnum = self.builder.build_extract_value(left, idx as u32, "left_field").unwrap();
+                let b: inkwell::values::BasicValueEnum = self.builder.build_extract_value(right, idx as u32, "right_field").unwrap();
+                let args: Vec<types::Data> = vec![types::Data {data: Some(a), tp: fieldtp.clone(), owned: false}, types::Data {data: Some(b), tp: fieldtp.clone(), owned: false}];
+                let v: inkwell::values::IntValue = self.call_trait(&t, args, node).data.unwrap().into_int_value();
+
+                res = if iseq { self.builder.build_and(res, v, "derive_and") } else { self.builder.build_or(res, v, "derive_or") };
+            }
+            self.builder.build_return(Some(&res));
+        }
+        else {
+            let compare_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(func, "compare");
+            let end_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(func, "end");
+
+            let left_id: inkwell::values::IntValue = self.builder.build_extract_value(left, 0, "left_id").unwrap().into_int_value();
+            let right_id: inkwell::values::IntValue = self.builder.build_extract_value(right, 0, "right_id").unwrap().into_int_value();
+            self.builder.build_conditional_branch(self.builder.build_int_compare(inkwell::IntPredicate::EQ, left_id, right_id, "id_compare"), compare_block, end_block);
+
+            //Only typed variants hold data to compare
+            let mut cases: Vec<(inkwell::values::IntValue, inkwell::basic_block::BasicBlock)> = Vec::new();
+            let mut results: Vec<(inkwell::values::IntValue, inkwell::basic_block::BasicBlock)> = Vec::new();
+            for (idx, (variant, varianttp)) in std::iter::zip(selftp.names.as_ref().unwrap(), &selftp.types).enumerate() {
+                if selftp.mutability.get(idx).unwrap() == &types::DataMutablility::Immutable {
+                    continue;
+                }
+                let t: types::Trait = self.get_derive_field_trait(name, variant, varianttp, traittp, node);
 
-        self.compile(&node.data.loopn.as_ref().unwrap().block, true, true);
+                let variant_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(func, "variant");
+                let _ = variant_block.move_before(end_block);
+                cases.push((self.inkwell_types.i32tp.const_int(self.get_enum_discriminant(name, idx), false), variant_block));
 
-        self.builder.build_unconditional_branch(loop_block);
+                self.builder.position_at_end(variant_block);
+                self.current_block = Some(variant_block);
 
-        self.builder.position_at_end(end_block);
-        self.current_block = Some(end_block);
+                let a: inkwell::values::BasicValueEnum = self.builder.build_extract_value(left, (idx+1) as u32, "left_data").unwrap();
+                let b: inkwell::values::BasicValueEnum = self.builder.build_extract_value(right, (idx+1) as u32, "right_data").unwrap();
+                let args: Vec<types::Data> = vec![types::Data {data: Some(a), tp: varianttp.clone(), owned: false}, types::Data {data: Some(b), tp: varianttp.clone(), owned: false}];
+                let v: inkwell::values::IntValue = self.call_trait(&t, args, node).data.unwrap().into_int_value();
 
-        self.end_block = end_block_old;
-        self.start_block = start_block_old;
-        self.loop_flow_broken = loop_flow_broken_old;
-        
-        let data: types::Data = types::Data {
-            data: None,
-            tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
-            owned: true,
-        };
-        return data;
-    }
+                results.push((v, self.builder.get_insert_block().unwrap()));
+                self.builder.build_unconditional_branch(end_block);
+            }
 
-    fn build_break(&mut self, node: &parser::Node) -> types::Data<'ctx> {
-        if self.end_block.is_none() {
-            let fmt: String = format!("Cannot break outside of loop.");
-            errors::raise_error(&fmt, errors::ErrorType::BreakOutsideOfLoop, &node.pos, self.info);         
-        }
+            self.builder.position_at_end(compare_block);
+            self.builder.build_switch(left_id, end_block, &cases[..]);
 
-        if self.loop_flow_broken {
-            self.builder.build_unconditional_branch(self.end_block.unwrap());
-            self.loop_flow_broken = true;
+            self.builder.position_at_end(end_block);
+            self.current_block = Some(end_block);
+            let phi: inkwell::values::PhiValue = self.builder.build_phi(*self.inkwell_types.booltp, "derive_phi");
+            phi.add_incoming(&[(&differ, entry), (&same, compare_block)]);
+            for (v, block) in results {
+                phi.add_incoming(&[(&v, block)]);
+            }
+            self.builder.build_return(Some(&phi.as_basic_value()));
         }
-        
-        let data: types::Data = types::Data {
-            data: None,
-            tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
-            owned: true,
-        };
-        return data;
     }
 
-    fn build_continue(&mut self, node: &parser::Node) -> types::Data<'ctx> {
-        if self.start_block.is_none() {
-            let fmt: String = format!("Cannot continue outside of loop.");
-            errors::raise_error(&fmt, errors::ErrorType::ContinueOutsideOfLoop, &node.pos, self.info);         
-        }
+    //Branch on the id of an enum to a block for each variant, returning the blocks and the block where they join
+    fn build_derive_switch(&mut self, name: &String, selftp: &types::DataType<'ctx>, func: inkwell::values::FunctionValue<'ctx>, id: inkwell::values::IntValue<'ctx>) -> (Vec<inkwell::basic_block::BasicBlock<'ctx>>, inkwell::basic_block::BasicBlock<'ctx>) {
+        let unknown_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(func, "unknown");
 
-        if self.loop_flow_broken {
-            self.builder.build_unconditional_branch(self.start_block.unwrap());
-            self.loop_flow_broken = true;
+        let mut cases: Vec<(inkwell::values::IntValue, inkwell::basic_block::BasicBlock)> = Vec::new();
+        let mut blocks: Vec<inkwell::basic_block::BasicBlock> = Vec::new();
+        for idx in 0..selftp.names.as_ref().unwrap().len() {
+            let variant_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(func, "variant");
+            cases.push((self.inkwell_types.i32tp.const_int(self.get_enum_discriminant(name, idx), false), variant_block));
+            blocks.push(variant_block);
         }
-        
-        let data: types::Data = types::Data {
-            data: None,
-            tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
-            owned: true,
-        };
-        return data;
-    }
+        let end_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(func, "end");
 
-    fn build_while(&mut self, node: &parser::Node) -> types::Data<'ctx> {
-        let loop_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(self.current_block.unwrap().get_parent().unwrap(), "loop_head");
-        let loop_then_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(self.current_block.unwrap().get_parent().unwrap(), "loop_then");
-        let end_block: inkwell::basic_block::BasicBlock = self.context.append_basic_block(self.current_block.unwrap().get_parent().unwrap(), "loop_end");
+        self.builder.build_switch(id, unknown_block, &cases[..]);
 
-        let start_block_old = self.start_block;
-        let end_block_old = self.end_block;
-        let loop_flow_broken_old = self.loop_flow_broken;
+        self.builder.position_at_end(unknown_block);
+        self.builder.build_unreachable();
 
-        self.start_block = Some(loop_block);
-        self.end_block = Some(end_block);
+        return (blocks, end_block);
+    }
 
-        self.builder.build_unconditional_branch(loop_block);
+    //A String of constant text, for the parts of a derived ToString
+    fn build_derive_text(&mut self, text: &str) -> inkwell::values::StructValue<'ctx> {
+        let ptr: inkwell::values::PointerValue = self.builder.build_global_string_ptr(text, "str").as_pointer_value();
+        let len: inkwell::values::IntValue = self.inkwell_types.i64tp.const_int(text.len() as u64, false);
+        return builtin_types::structs::stringtype::string_from_parts(self, ptr, len).data.unwrap().into_struct_value();
+    }
 
-        self.builder.position_at_end(loop_block);     
-        self.current_block = Some(loop_block);       
+    //Build a derived ToString. Structs are formatted as `S{x = 1, y = 2}`, and enums as `E::Variant` or `E::Variant(data)`
+    fn build_derive_to_string(&mut self, name: &String, selftp: &types::DataType<'ctx>, func: inkwell::values::FunctionValue<'ctx>, node: &parser::Node) {
+        let selfv: inkwell::values::StructValue = func.get_nth_param(0).unwrap().into_struct_value();
 
-        let right: types::Data = self.compile_expr(&node.data.loopn.as_ref().unwrap().expr.as_ref().unwrap(), BorrowOptions{ give_ownership: false, get_ptr: false, mut_borrow: false}, false, false);
-      
-        
-        let mut args: Vec<types::Data> = Vec::new();
+        if selftp.tp == types::BasicDataType::Struct {
+            let mut parts: Vec<inkwell::values::StructValue> = vec![self.build_derive_text(&format!("{}{{", name))];
+            for (idx, (field, fieldtp)) in std::iter::zip(selftp.names.as_ref().unwrap(), &selftp.types).enumerate() {
+                let t: types::Trait = self.get_derive_field_trait(name, field, fieldtp, &types::TraitType::ToString, node);
 
-        let tp: types::Type = Self::get_type_from_data(self.cur_module.types.clone(), &right);
+                let sep: String = if idx == 0 { format!("{} = ", field) } else { format!(", {} = ", field) };
+                parts.push(self.build_derive_text(&sep));
 
-        let tp_str: &String = &right.tp.name.clone();
+                let v: inkwell::values::BasicValueEnum = self.builder.build_extract_value(selfv, idx as u32, "field").unwrap();
+                let args: Vec<types::Data> = vec![types::Data {data: Some(v), tp: fieldtp.clone(), owned: false}];
+                parts.push(self.call_trait(&t, args, node).data.unwrap().into_struct_value());
+            }
+            parts.push(self.build_derive_text("}"));
 
-        args.push(right);
+            let res: types::Data = builtin_types::structs::stringtype::string_concat(self, &parts);
+            self.builder.build_return(Some(&res.data.unwrap()));
+            return;
+        }
 
-        let traittp: types::TraitType = types::TraitType::Bool;
+        let id: inkwell::values::IntValue = self.builder.build_extract_value(selfv, 0, "id").unwrap().into_int_value();
+        let (blocks, end_block) = self.build_derive_switch(name, selftp, func, id);
 
-        let t: &types::Trait = match tp.traits.get(&traittp.to_string()) {
-            Some (v) => {
-                v
+        let mut results: Vec<(inkwell::values::BasicValueEnum, inkwell::basic_block::BasicBlock)> = Vec::new();
+        for (idx, ((variant, varianttp), block)) in std::iter::zip(std::iter::zip(selftp.names.as_ref().unwrap(), &selftp.types), blocks).enumerate() {
+            self.builder.position_at_end(block);
+            self.current_block = Some(block);
+
+            let mut parts: Vec<inkwell::values::StructValue> = Vec::new();
+            if selftp.mutability.get(idx).unwrap() == &types::DataMutablility::Immutable {
+                parts.push(self.build_derive_text(&format!("{}::{}", name, variant)));
             }
-            None => {
-                let fmt: String = format!("Type '{}' has no trait '{}'.", tp_str, &traittp.to_string());
-                errors::raise_error(&fmt, errors::ErrorType::MissingTrait, &node.pos, self.info);
+            else {
+                let t: types::Trait = self.get_derive_field_trait(name, variant, varianttp, &types::TraitType::ToString, node);
+
+                parts.push(self.build_derive_text(&format!("{}::{}(", name, variant)));
+                let v: inkwell::values::BasicValueEnum = self.builder.build_extract_value(selfv, (idx+1) as u32, "data").unwrap();
+                let args: Vec<types::Data> = vec![types::Data {data: Some(v), tp: varianttp.clone(), owned: false}];
+                parts.push(self.call_trait(&t, args, node).data.unwrap().into_struct_value());
+                parts.push(self.build_derive_text(")"));
             }
-        };
 
-        let data: types::Data = self.call_trait(t, args, node);
+            let res: types::Data = builtin_types::structs::stringtype::string_concat(self, &parts);
+            results.push((res.data.unwrap(), self.builder.get_insert_block().unwrap()));
+            self.builder.build_unconditional_branch(end_block);
+        }
 
-        if data.tp != Self::datatypes_get(self, &types::BasicDataType::Bool.to_string()).unwrap().clone() {
-            let fmt: String = format!("Expected 'bool' type, got '{}' type.", data.tp);
-            errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
+        self.builder.position_at_end(end_block);
+        self.current_block = Some(end_block);
+        let phi: inkwell::values::PhiValue = self.builder.build_phi(*self.inkwell_types.stringtp, "derive_phi");
+        for (v, block) in results {
+            phi.add_incoming(&[(&v, block)]);
         }
+        self.builder.build_return(Some(&phi.as_basic_value()));
+    }
 
-        self.builder.build_conditional_branch(data.data.unwrap().into_int_value(), loop_then_block, end_block);
+    //Build a derived Clone. Structs clone each field, and enums clone the data of the variant
+    fn build_derive_clone(&mut self, name: &String, selftp: &types::DataType<'ctx>, func: inkwell::values::FunctionValue<'ctx>, node: &parser::Node) {
+        let selfv: inkwell::values::StructValue = func.get_nth_param(0).unwrap().into_struct_value();
 
-        self.builder.position_at_end(loop_then_block);
-        self.current_block = Some(loop_then_block);
+        if selftp.tp == types::BasicDataType::Struct {
+            let mut res: inkwell::values::StructValue = selfv;
+            for (idx, (field, fieldtp)) in std::iter::zip(selftp.names.as_ref().unwrap(), &selftp.types).enumerate() {
+                let t: types::Trait = self.get_derive_field_trait(name, field, fieldtp, &types::TraitType::Clone, node);
 
-        self.compile(&node.data.loopn.as_ref().unwrap().block, true, true);
+                let v: inkwell::values::BasicValueEnum = self.builder.build_extract_value(selfv, idx as u32, "field").unwrap();
+                let args: Vec<types::Data> = vec![types::Data {data: Some(v), tp: fieldtp.clone(), owned: false}];
+                let cloned: inkwell::values::BasicValueEnum = self.call_trait(&t, args, node).data.unwrap();
+                res = self.builder.build_insert_value(res, cloned, idx as u32, "clone").unwrap().into_struct_value();
+            }
+            self.builder.build_return(Some(&res));
+            return;
+        }
 
-        self.builder.build_unconditional_branch(loop_block);
+        let id: inkwell::values::IntValue = self.builder.build_extract_value(selfv, 0, "id").unwrap().into_int_value();
+        let (blocks, end_block) = self.build_derive_switch(name, selftp, func, id);
 
-        self.builder.position_at_end(end_block);
-        self.current_block = Some(end_block);
+        let mut results: Vec<(inkwell::values::StructValue, inkwell::basic_block::BasicBlock)> = Vec::new();
+        for (idx, ((variant, varianttp), block)) in std::iter::zip(std::iter::zip(selftp.names.as_ref().unwrap(), &selftp.types), blocks).enumerate() {
+            self.builder.position_at_end(block);
+            self.current_block = Some(block);
 
-        self.end_block = end_block_old;
-        self.start_block = start_block_old;
-        self.loop_flow_broken = loop_flow_broken_old;
-        
-        let data: types::Data = types::Data {
-            data: None,
-            tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
-            owned: true,
-        };
-        return data;
-    }
+            //Unit variants hold no data, so they are copied whole
+            let mut res: inkwell::values::StructValue = selfv;
+            if selftp.mutability.get(idx).unwrap() != &types::DataMutablility::Immutable {
+                let t: types::Trait = self.get_derive_field_trait(name, variant, varianttp, &types::TraitType::Clone, node);
 
-    fn build_enum(&mut self, node: &parser::Node) -> types::Data<'ctx> {        
-        if !node.data.enumn.as_ref().unwrap().name.is_camel_case() {
-            errors::show_warning(errors::WarningType::ExpectedCamelCase, vec![String::from(""), node.data.st.as_ref().unwrap().name.to_camel_case()], vec![String::from("Expected camel case"), String::from("Convert to this: ")], &node.pos, self.info)
+                let v: inkwell::values::BasicValueEnum = self.builder.build_extract_value(selfv, (idx+1) as u32, "data").unwrap();
+                let args: Vec<types::Data> = vec![types::Data {data: Some(v), tp: varianttp.clone(), owned: false}];
+                let cloned: inkwell::values::BasicValueEnum = self.call_trait(&t, args, node).data.unwrap();
+                res = self.builder.build_insert_value(res, cloned, (idx+1) as u32, "clone").unwrap().into_struct_value();
+            }
+
+            results.push((res, self.builder.get_insert_block().unwrap()));
+            self.builder.build_unconditional_branch(end_block);
         }
 
-        if Self::datatypes_get(self, &node.data.enumn.as_ref().unwrap().name.clone()).is_some() && self.cur_module.namespaces.structs.get(&node.data.enumn.as_ref().unwrap().name.clone()).unwrap().3 != ForwardDeclarationType::Forward {
-            let fmt: String = format!("Type '{}' is already defined.", node.data.enumn.as_ref().unwrap().name.clone());
-            errors::raise_error(&fmt, errors::ErrorType::TypeRedefinitionAttempt, &node.pos, self.info);
+        self.builder.position_at_end(end_block);
+        self.current_block = Some(end_block);
+        let phi: inkwell::values::PhiValue = self.builder.build_phi(selfv.get_type(), "derive_phi");
+        for (v, block) in results {
+            phi.add_incoming(&[(&v, block)]);
         }
+        self.builder.build_return(Some(&phi.as_basic_value()));
+    }
 
-        let mut names: Vec<String> = Vec::new();
-        let mut mutabilities: Vec<types::DataMutablility> = Vec::new();
-        
-        for member in &node.data.enumn.as_ref().unwrap().variants {
-            if !member.is_camel_case() {
-                errors::show_warning(errors::WarningType::ExpectedCamelCase, vec![String::from(""), member.to_camel_case()], vec![String::from("Expected camel case"), String::from("Convert to this: ")], &node.pos, self.info)
-            }
-            if names.contains(&member.clone()) {
-                let fmt: String = format!("Variant '{}' is already declared.", member.clone());
-                errors::raise_error(&fmt, errors::ErrorType::VariantRedeclaration, &node.pos, self.info);
+    //Build a derived Default. Structs default each field, and enums are the first variant, with its data defaulted
+    fn build_derive_default(&mut self, name: &String, selftp: &types::DataType<'ctx>, func: inkwell::values::FunctionValue<'ctx>, node: &parser::Node) {
+        let mut res: inkwell::values::StructValue = func.get_type().get_return_type().unwrap().into_struct_type().const_zero();
+
+        if selftp.tp == types::BasicDataType::Struct {
+            for (idx, (field, fieldtp)) in std::iter::zip(selftp.names.as_ref().unwrap(), &selftp.types).enumerate() {
+                let t: types::Trait = self.get_derive_field_trait(name, field, fieldtp, &types::TraitType::Default, node);
+
+                let v: inkwell::values::BasicValueEnum = self.call_trait(&t, Vec::new(), node).data.unwrap();
+                res = self.builder.build_insert_value(res, v, idx as u32, "default").unwrap().into_struct_value();
             }
-            names.push(member.clone());
         }
+        else {
+            res = self.builder.build_insert_value(res, self.inkwell_types.i32tp.const_int(self.get_enum_discriminant(name, 0), false), 0, "id").unwrap().into_struct_value();
 
-        let mut types: Vec<types::DataType> = Vec::new();
-        
-        if node.data.enumn.as_ref().unwrap().template_types.len() == 0 {
-            for tp in &node.data.enumn.as_ref().unwrap().tps {
-                if tp.is_some() {
-                    types.push(Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, tp.as_ref().unwrap(), node).0);
-                    mutabilities.push(types::DataMutablility::Mutable);
-                }
-                else {
-                    types.push(Self::datatypes_get(self, &types::BasicDataType::I32.to_string()).unwrap().clone());
-                    mutabilities.push(types::DataMutablility::Immutable);
-                }
-                if types.last().unwrap().is_ref {
-                    let fmt: String = format!("Enums may not contain references.");
-                    errors::raise_error(&fmt, errors::ErrorType::ReferenceVariantEnum, &node.pos, self.info);
-                }
+            if selftp.mutability.first().unwrap() != &types::DataMutablility::Immutable {
+                let t: types::Trait = self.get_derive_field_trait(name, selftp.names.as_ref().unwrap().first().unwrap(), selftp.types.first().unwrap(), &types::TraitType::Default, node);
+
+                let v: inkwell::values::BasicValueEnum = self.call_trait(&t, Vec::new(), node).data.unwrap();
+                res = self.builder.build_insert_value(res, v, 1, "default").unwrap().into_struct_value();
             }
-        }   
+        }
 
-        let mut tp: types::DataType = Self::datatypes_get(self, &types::BasicDataType::Enum.to_string()).unwrap().clone();
-        tp.name = node.data.enumn.as_ref().unwrap().name.clone();
-        tp.names = Some(names);
-        tp.types = types;
-        tp.mutability = mutabilities;
+        self.builder.build_return(Some(&res));
+    }
 
-        self.cur_module.datatypes.insert(node.data.enumn.as_ref().unwrap().name.clone(), tp.clone());
+    //Build a derived Hash. The hashes of the fields (or of the id and the variant data) are combined in order as `h*31 + field`
+    fn build_derive_hash(&mut self, name: &String, selftp: &types::DataType<'ctx>, func: inkwell::values::FunctionValue<'ctx>, node: &parser::Node) {
+        let selfv: inkwell::values::StructValue = func.get_nth_param(0).unwrap().into_struct_value();
+        let i64tp: inkwell::types::IntType = *self.inkwell_types.i64tp;
 
-        if node.data.enumn.as_ref().unwrap().template_types.len() > 0 {
-            self.cur_module.namespaces.generic_enums.insert(node.data.enumn.as_ref().unwrap().name.clone(), (node.data.enumn.as_ref().unwrap().template_types.to_owned(), node.data.enumn.as_ref().unwrap().tps.to_owned()));
+        if selftp.tp == types::BasicDataType::Struct {
+            let mut res: inkwell::values::IntValue = i64tp.const_zero();
+            for (idx, (field, fieldtp)) in std::iter::zip(selftp.names.as_ref().unwrap(), &selftp.types).enumerate() {
+                let t: types::Trait = self.get_derive_field_trait(name, field, fieldtp, &types::TraitType::Hash, node);
+
+                let v: inkwell::values::BasicValueEnum = self.builder.build_extract_value(selfv, idx as u32, "field").unwrap();
+                let args: Vec<types::Data> = vec![types::Data {data: Some(v), tp: fieldtp.clone(), owned: false}];
+                let hash: inkwell::values::IntValue = self.call_trait(&t, args, node).data.unwrap().into_int_value();
+
+                let scaled: inkwell::values::IntValue = self.builder.build_int_mul(res, i64tp.const_int(31, false), "hash_mul");
+                res = self.builder.build_int_add(scaled, hash, "hash_add");
+            }
+            self.builder.build_return(Some(&res));
+            return;
         }
 
-        builtin_types::add_simple_type(self, std::collections::HashMap::new(), types::BasicDataType::Enum, &node.data.enumn.as_ref().unwrap().name.clone());
+        let id: inkwell::values::IntValue = self.builder.build_extract_value(selfv, 0, "id").unwrap().into_int_value();
+        let idhash: inkwell::values::IntValue = self.builder.build_int_z_extend(id, i64tp, "id_hash");
+        let (blocks, end_block) = self.build_derive_switch(name, selftp, func, id);
 
-        let data: types::Data = types::Data {
-            data: None,
-            tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
-            owned: true,
-        };
-        return data;
+        let mut results: Vec<(inkwell::values::IntValue, inkwell::basic_block::BasicBlock)> = Vec::new();
+        for (idx, ((variant, varianttp), block)) in std::iter::zip(std::iter::zip(selftp.names.as_ref().unwrap(), &selftp.types), blocks).enumerate() {
+            self.builder.position_at_end(block);
+            self.current_block = Some(block);
+
+            let mut res: inkwell::values::IntValue = idhash;
+            if selftp.mutability.get(idx).unwrap() != &types::DataMutablility::Immutable {
+                let t: types::Trait = self.get_derive_field_trait(name, variant, varianttp, &types::TraitType::Hash, node);
+
+                let v: inkwell::values::BasicValueEnum = self.builder.build_extract_value(selfv, (idx+1) as u32, "data").unwrap();
+                let args: Vec<types::Data> = vec![types::Data {data: Some(v), tp: varianttp.clone(), owned: false}];
+                let hash: inkwell::values::IntValue = self.call_trait(&t, args, node).data.unwrap().into_int_value();
+
+                let scaled: inkwell::values::IntValue = self.builder.build_int_mul(res, i64tp.const_int(31, false), "hash_mul");
+                res = self.builder.build_int_add(scaled, hash, "hash_add");
+            }
+
+            results.push((res, self.builder.get_insert_block().unwrap()));
+            self.builder.build_unconditional_branch(end_block);
+        }
+
+        self.builder.position_at_end(end_block);
+        self.current_block = Some(end_block);
+        let phi: inkwell::values::PhiValue = self.builder.build_phi(i64tp, "derive_phi");
+        for (v, block) in results {
+            phi.add_incoming(&[(&v, block)]);
+        }
+        self.builder.build_return(Some(&phi.as_basic_value()));
     }
 
     fn build_trait(&mut self, node: &parser::Node) -> types::Data<'ctx> {
+        for supertrait in &node.data.traitn.as_ref().unwrap().supertraits {
+            if !self.traits.contains_key(supertrait) {
+                let fmt: String = format!("Trait '{}' not found.", supertrait);
+                errors::raise_error(&fmt, errors::ErrorType::TraitNotFound, &node.pos, self.info);
+            }
+        }
+
         self.traits.insert(node.data.traitn.as_ref().unwrap().traitname.clone(), types::TraitSignature {
                 nargs: None, trait_sig: Some(node.data.traitn.as_ref().unwrap().functions.clone()), name: node.data.traitn.as_ref().unwrap().traitname.clone(), traittp: types::TraitMetatype::User,
                 vars: Some(node.data.traitn.as_ref().unwrap().vars.clone()),
+                supertraits: Some(node.data.traitn.as_ref().unwrap().supertraits.clone()),
+                consts: Some(node.data.traitn.as_ref().unwrap().consts.clone()),
+                types: Some(node.data.traitn.as_ref().unwrap().types.clone()),
                 implementations: std::collections::HashMap::new(),
             });
 
+        //The functions of the trait and its supertraits share a vtable, so their names must be distinct
+        let mut names: Vec<String> = Vec::new();
+        for sig in self.get_trait_functions(&node.data.traitn.as_ref().unwrap().traitname) {
+            if names.contains(&sig.name) {
+                let fmt: String = format!("Function '{}' is defined more than once in trait '{}' and its supertraits.", sig.name, node.data.traitn.as_ref().unwrap().traitname);
+                errors::raise_error(&fmt, errors::ErrorType::TraitFunctionRedefined, &node.pos, self.info);
+            }
+            names.push(sig.name);
+        }
+
         let data: types::Data = types::Data {
             data: None,
             tp: Self::datatypes_get(self, &types::BasicDataType::Void.to_string()).unwrap().clone(),
@@ -3185,8 +6851,6 @@ impl<'ctx> CodeGen<'ctx> {
                     lvl += 1;
                 }
                 
-                let loop_flow_broken_old = self.loop_flow_broken;
-
                 //Store optional data
                 let dtp: types::DataType = pattern_v.tp.types.get(pattern_v.tp.names.as_ref().unwrap().iter().position(|x| x == &pattern.as_ref().unwrap().data.attr.as_ref().unwrap().attr).unwrap() as usize).unwrap().clone();
                 if dtp.tp != types::BasicDataType::Void && name.is_some(){
@@ -3204,7 +6868,6 @@ impl<'ctx> CodeGen<'ctx> {
                     errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
                 }
                 
-                self.loop_flow_broken = loop_flow_broken_old;
 
                 let mut lvl: usize = 0;
                 for local in &self.cur_module.namespaces.locals {
@@ -3226,6 +6889,7 @@ impl<'ctx> CodeGen<'ctx> {
                     self.cur_module.namespaces.locals.get_mut(var.1.to_owned()).unwrap().insert(var.0.to_owned(), var_val);
                 }
 
+                self.build_scope_drops(self.cur_module.namespaces.locals.len() - 1, block.last(), node);
                 self.cur_module.namespaces.locals.pop();
 
                 collected_locals.push(end_locals);
@@ -3266,8 +6930,6 @@ impl<'ctx> CodeGen<'ctx> {
                     lvl += 1;
                 }
                 
-                let loop_flow_broken_old = self.loop_flow_broken;
-
                 let data: types::Data = self.compile(block, true, false);
 
                 if tp.is_none() {
@@ -3279,7 +6941,6 @@ impl<'ctx> CodeGen<'ctx> {
                     errors::raise_error(&fmt, errors::ErrorType::TypeMismatch, &node.pos, self.info);
                 }
                 
-                self.loop_flow_broken = loop_flow_broken_old;
 
                 let mut lvl: usize = 0;
                 for local in &self.cur_module.namespaces.locals {
@@ -3301,6 +6962,7 @@ impl<'ctx> CodeGen<'ctx> {
                     self.cur_module.namespaces.locals.get_mut(var.1.to_owned()).unwrap().insert(var.0.to_owned(), var_val);
                 }
 
+                self.build_scope_drops(self.cur_module.namespaces.locals.len() - 1, block.last(), node);
                 self.cur_module.namespaces.locals.pop();
 
                 collected_locals.push(end_locals);
@@ -3378,6 +7040,10 @@ impl<'ctx> CodeGen<'ctx> {
     }
 
     fn build_genericenum(&mut self, node: &parser::Node, get_enum_id: bool, borrow_options: BorrowOptions) -> types::Data<'ctx> {
+        if self.cur_module.namespaces.generic_structs.contains_key(&node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name) {
+            return self.build_generic_struct_namespaceload(node);
+        }
+
         let mut tp: types::DataType = Self::datatypes_get(self, &node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name).unwrap().clone();
         if tp.tp != types::BasicDataType::Enum {
             let fmt: String = format!("Expected 'enum', got '{}'.", tp);
@@ -3388,6 +7054,10 @@ impl<'ctx> CodeGen<'ctx> {
             errors::raise_error(&fmt, errors::ErrorType::EnumNotGeneric, &node.pos, self.info);
         }
 
+        for generic_tp in node.data.attr.as_ref().unwrap().template_types.as_ref().unwrap() {
+            self.build_generic_struct_types(generic_tp, node);
+        }
+
         let (generics, tps) = self.cur_module.namespaces.generic_enums.get(&node.data.attr.as_ref().unwrap().name.data.identifier.as_ref().unwrap().name).unwrap();
         let generic_tps = node.data.attr.as_ref().unwrap().template_types.as_ref().unwrap();
 
@@ -3427,7 +7097,7 @@ impl<'ctx> CodeGen<'ctx> {
         let idx: usize = tp.names.as_ref().unwrap().iter().position(|x| x == &name).unwrap() as usize;
         if get_enum_id {
             return types::Data {
-                data: Some(inkwell::values::BasicValueEnum::IntValue(self.inkwell_types.i32tp.const_int(idx as u64, false))),
+                data: Some(inkwell::values::BasicValueEnum::IntValue(self.inkwell_types.i32tp.const_int(self.get_enum_discriminant(&tp.name, idx), false))),
                 tp: tp.clone(),
                 owned: true
             };
@@ -3437,7 +7107,7 @@ impl<'ctx> CodeGen<'ctx> {
         let data: Option<inkwell::values::BasicValueEnum>;
         if  node.data.attr.as_ref().unwrap().expr.is_none() &&
             tp.mutability.get(idx).unwrap() == &types::DataMutablility::Immutable  {
-            data = Some(inkwell::values::BasicValueEnum::IntValue(self.inkwell_types.i32tp.const_int(idx as u64, false)));
+            data = Some(inkwell::values::BasicValueEnum::IntValue(self.inkwell_types.i32tp.const_int(self.get_enum_discriminant(&tp.name, idx), false)));
         }
         else {
             if node.data.attr.as_ref().unwrap().expr.is_none() {
@@ -3461,7 +7131,7 @@ impl<'ctx> CodeGen<'ctx> {
         let st: inkwell::values::PointerValue = Self::alloca(self, Self::build_struct_tp_from_types(&self.context, &self.inkwell_types, &types, &self.cur_module.datatypes).into_struct_type(), "enum_st");
 
         let id: inkwell::values::PointerValue = self.builder.build_struct_gep(st, 0, "variant_id").expect("GEP Error");
-        self.builder.build_store(id, self.inkwell_types.i32tp.const_int(idx as u64, false));
+        self.builder.build_store(id, self.inkwell_types.i32tp.const_int(self.get_enum_discriminant(&tp.name, idx), false));
         
         if data.is_some() {
             let variant_data: inkwell::values::PointerValue = self.builder.build_struct_gep(st, (idx+1) as u32, "variant_data").expect("GEP Error");
@@ -3561,6 +7231,67 @@ impl<'ctx> CodeGen<'ctx> {
         return data;
     }
 
+    //Whether the node is a numeric literal without a type suffix, optionally negated
+    pub fn is_untyped_literal(node: &parser::Node) -> bool {
+        let literal: &parser::Node = if node.tp == parser::NodeType::UNARY && node.data.unary.as_ref().unwrap().op == parser::nodes::UnaryOpType::NEG { &node.data.unary.as_ref().unwrap().right } else { node };
+        return (literal.tp == parser::NodeType::I32 || literal.tp == parser::NodeType::F32) && literal.data.num.as_ref().unwrap().inferred;
+    }
+
+    //Give an untyped literal the expected type, or None if the literal cannot have that type
+    pub fn type_literal(node: &parser::Node, tp: &types::BasicDataType) -> Option<parser::Node> {
+        if !Self::is_untyped_literal(node) {
+            return None;
+        }
+
+        let negative: bool = node.tp == parser::NodeType::UNARY;
+        let mut literal: parser::Node = if negative { node.data.unary.as_ref().unwrap().right.clone() } else { node.clone() };
+
+        let isfloat: bool = literal.tp == parser::NodeType::F32;
+        literal.tp = match tp {
+            types::BasicDataType::I8 if !isfloat => parser::NodeType::I8,
+            types::BasicDataType::U8 if !isfloat => parser::NodeType::U8,
+            types::BasicDataType::I16 if !isfloat => parser::NodeType::I16,
+            types::BasicDataType::U16 if !isfloat => parser::NodeType::U16,
+            types::BasicDataType::I32 if !isfloat => parser::NodeType::I32,
+            types::BasicDataType::U32 if !isfloat => parser::NodeType::U32,
+            types::BasicDataType::I64 if !isfloat => parser::NodeType::I64,
+            types::BasicDataType::U64 if !isfloat => parser::NodeType::U64,
+            types::BasicDataType::I128 if !isfloat => parser::NodeType::I128,
+            types::BasicDataType::U128 if !isfloat => parser::NodeType::U128,
+            types::BasicDataType::F32 if isfloat => parser::NodeType::F32,
+            types::BasicDataType::F64 if isfloat => parser::NodeType::F64,
+            _ => {
+                return None;
+            }
+        };
+
+        //The sign is part of the literal, so it is range checked with it
+        if negative {
+            let num: &mut parser::nodes::NumNode = literal.data.num.as_mut().unwrap();
+            num.left = String::from("-") + num.left.as_str();
+            literal.pos = node.pos.clone();
+        }
+
+        return Some(literal);
+    }
+
+    //Compile an expression of an expected type, so that an untyped literal takes that type
+    fn compile_expr_expecting(&mut self, node: &parser::Node, borrow_options: BorrowOptions, tp: &types::DataType<'ctx>) -> types::Data<'ctx> {
+        if !tp.is_ref {
+            let literal: Option<parser::Node> = Self::type_literal(node, &tp.tp);
+            if literal.is_some() {
+                return self.compile_expr(literal.as_ref().unwrap(), borrow_options, false, false);
+            }
+        }
+
+        //A closure may be compiled to a 'fn'
+        if node.tp == parser::NodeType::CLOSURE {
+            self.expected_tp = Some(tp.clone());
+        }
+
+        return self.compile_expr(node, borrow_options, false, false);
+    }
+
     fn compile_expr(&mut self, node: &parser::Node, borrow_options: BorrowOptions, get_enum_id: bool, allow_enum_noinit: bool) -> types::Data<'ctx> {
         let raw: types::Data = match node.tp {
             parser::NodeType::I32 => {
@@ -3783,9 +7514,19 @@ impl<'ctx> CodeGen<'ctx> {
             parser::NodeType::CHAR => {
                 self.build_char(node)
             }
+            parser::NodeType::BOOL => {
+                self.build_bool(node)
+            }
+            parser::NodeType::CLOSURE => {
+                let expected: Option<types::DataType> = self.expected_tp.take();
+                self.build_closure(node, expected)
+            }
             parser::NodeType::ARRAY => {
                 self.build_array(node)
             }
+            parser::NodeType::TUPLE => {
+                self.build_tuple(node)
+            }
             parser::NodeType::IMPL => {
                 types::Data {
                     data: None,
@@ -3811,8 +7552,18 @@ impl<'ctx> CodeGen<'ctx> {
             parser::NodeType::WHILE => {
                 self.build_while(node)
             }
+            parser::NodeType::FOR => {
+                self.build_for(node)
+            }
+            parser::NodeType::RANGE => {
+                let fmt: String = format!("Range expressions are only allowed in 'for' loops.");
+                errors::raise_error(&fmt, errors::ErrorType::RangeOutsideOfFor, &node.pos, self.info);
+            }
             parser::NodeType::ENUM |
             parser::NodeType::TRAIT |
+            parser::NodeType::TYPEALIAS |
+            parser::NodeType::CONST |
+            parser::NodeType::STATIC |
             parser::NodeType::VOID => {
                 types::Data {
                     data: None,
@@ -3838,6 +7589,12 @@ impl<'ctx> CodeGen<'ctx> {
             parser::NodeType::MULTINAMESPACE => {
                 self.build_multinamespace(node)
             }
+            parser::NodeType::INDEX => {
+                self.build_index(node)
+            }
+            parser::NodeType::INDEXASSIGN => {
+                self.build_indexassign(node)
+            }
         };
         
         let res: types::Data = if raw.data.is_some() && !raw.data.unwrap().is_pointer_value() && (borrow_options.get_ptr || get_enum_id) {
@@ -3879,7 +7636,6 @@ impl<'ctx> CodeGen<'ctx> {
         let enclosing = self.enclosing_block;
         let expected = self.expected_rettp.clone();
         let alloc = self.alloc_head;
-        let end = self.end_block;
         let mut idx: usize = 0;
         for node in nodes {
             if infn && node.tp == parser::NodeType::FUNC {
@@ -3891,7 +7647,10 @@ impl<'ctx> CodeGen<'ctx> {
                 node.tp != parser::NodeType::STRUCT &&
                 node.tp != parser::NodeType::IMPL &&
                 node.tp != parser::NodeType::ENUM &&
-                node.tp != parser::NodeType::TRAIT {
+                node.tp != parser::NodeType::TRAIT &&
+                node.tp != parser::NodeType::TYPEALIAS &&
+                node.tp != parser::NodeType::CONST &&
+                node.tp != parser::NodeType::STATIC {
                 let fmt: String = format!("Invalid global scope statement.");
                 errors::raise_error(&fmt, errors::ErrorType::GlobalScopeStmt, &node.pos, self.info);
             }
@@ -3900,7 +7659,10 @@ impl<'ctx> CodeGen<'ctx> {
                 node.tp == parser::NodeType::STRUCT ||
                 node.tp == parser::NodeType::IMPL ||
                 node.tp == parser::NodeType::ENUM ||
-                node.tp == parser::NodeType::TRAIT) {
+                node.tp == parser::NodeType::TRAIT ||
+                node.tp == parser::NodeType::TYPEALIAS ||
+                node.tp == parser::NodeType::CONST ||
+                node.tp == parser::NodeType::STATIC) {
                 let fmt: String = format!("Invalid local scope statement.");
                 errors::raise_error(&fmt, errors::ErrorType::LocalScopeStmt, &node.pos, self.info);
             }
@@ -3922,14 +7684,107 @@ impl<'ctx> CodeGen<'ctx> {
         self.current_block = current;
         self.expected_rettp = expected;
         self.alloc_head = alloc;
-        self.end_block = end;
         self.enclosing_block = enclosing;
         return retv;
     }
 
     fn forward_declare(&mut self, nodes: &Vec<parser::Node>){
+        let prev_pending: Option<Vec<(String, String, parser::Node)>> = self.pending_impls.replace(Vec::new());
+        let prev_derives: Option<Vec<(String, inkwell::values::FunctionValue, types::TraitType, parser::Node)>> = self.pending_derives.replace(Vec::new());
+
+        //Generic structs are registered first, so their functions and impls may be declared in any order
+        for node in nodes {
+            if node.tp == parser::NodeType::STRUCT && node.data.st.as_ref().unwrap().template_types.len() > 0 {
+                let name: &String = &node.data.st.as_ref().unwrap().name;
+
+                if !name.is_camel_case() {
+                    errors::show_warning(errors::WarningType::ExpectedCamelCase, vec![String::from(""), name.to_camel_case()], vec![String::from("Expected camel case"), String::from("Convert to this: ")], &node.pos, self.info)
+                }
+
+                if self.cur_module.namespaces.generic_structs.contains_key(name) {
+                    let fmt: String = format!("Struct '{}' is already defined.", name);
+                    errors::raise_error(&fmt, errors::ErrorType::RedefinitionAttempt, &node.pos, self.info);
+                }
+                if Self::datatypes_get(self, name).is_some() {
+                    let fmt: String = format!("Type '{}' is already defined.", name);
+                    errors::raise_error(&fmt, errors::ErrorType::TypeRedefinitionAttempt, &node.pos, self.info);
+                }
+
+                self.cur_module.namespaces.generic_structs.insert(name.to_owned(), GenericStruct {
+                    template_types: node.data.st.as_ref().unwrap().template_types.to_owned(),
+                    node: node.clone(),
+                    functions: Vec::new(),
+                    impls: Vec::new(),
+                    instances: std::collections::HashMap::new(),
+                });
+
+                if node.data.st.as_ref().unwrap().derives.len() > 0 {
+                    self.build_derives(name, &node.data.st.as_ref().unwrap().derives, true, node);
+                }
+            }
+            else if node.tp == parser::NodeType::TYPEALIAS {
+                let name: &String = &node.data.alias.as_ref().unwrap().name;
+
+                if !name.is_camel_case() {
+                    errors::show_warning(errors::WarningType::ExpectedCamelCase, vec![String::from(""), name.to_camel_case()], vec![String::from("Expected camel case"), String::from("Convert to this: ")], &node.pos, self.info)
+                }
+
+                let defined: bool = nodes.iter().any(|x|
+                    (x.tp == parser::NodeType::STRUCT && x.data.st.as_ref().unwrap().name == *name) ||
+                    (x.tp == parser::NodeType::ENUM && x.data.enumn.as_ref().unwrap().name == *name));
+                if  defined || Self::datatypes_get(self, name).is_some() ||
+                    self.cur_module.namespaces.type_aliases.contains_key(name) {
+                    let fmt: String = format!("Type '{}' is already defined.", name);
+                    errors::raise_error(&fmt, errors::ErrorType::TypeRedefinitionAttempt, &node.pos, self.info);
+                }
+
+                self.cur_module.namespaces.type_aliases.insert(name.to_owned(), (node.data.alias.as_ref().unwrap().template_types.to_owned(), node.data.alias.as_ref().unwrap().tp.to_owned()));
+            }
+            else if node.tp == parser::NodeType::FUNC && node.data.func.as_ref().unwrap().isconst {
+                let func: &parser::nodes::FuncNode = node.data.func.as_ref().unwrap();
+                if func.methodname.is_some() || func.namespacename.is_some() || func.template_types.len() > 0 {
+                    let fmt: String = format!("Const function '{}' may not be a method, namespace function or template function.", func.name);
+                    errors::raise_error(&fmt, errors::ErrorType::InvalidConstFunction, &node.pos, self.info);
+                }
+
+                self.cur_module.namespaces.const_functions.insert(func.name.to_owned(), node.clone());
+            }
+        }
+
+        for node in nodes {
+            if node.tp == parser::NodeType::TYPEALIAS {
+                let name: &String = &node.data.alias.as_ref().unwrap().name;
+                if self.type_alias_references(&node.data.alias.as_ref().unwrap().tp, name, &mut Vec::new()) {
+                    let fmt: String = format!("Type alias '{}' refers to itself.", name);
+                    errors::raise_error(&fmt, errors::ErrorType::RecursiveTypeAlias, &node.pos, self.info);
+                }
+            }
+        }
+
+        //Constants and statics are evaluated before types are defined, so they may be used in array lengths and enum discriminants.
+        //The functions of this file are not declared yet, so they are checked here, and functions already declared by modules in build_global
+        for node in nodes {
+            if node.tp == parser::NodeType::CONST || node.tp == parser::NodeType::STATIC {
+                let name: &String = &node.data.letn.as_ref().unwrap().name;
+                if nodes.iter().any(|x| x.tp == parser::NodeType::FUNC && x.data.func.as_ref().unwrap().name == *name &&
+                                        x.data.func.as_ref().unwrap().methodname.is_none() && x.data.func.as_ref().unwrap().namespacename.is_none()) {
+                    let fmt: String = format!("Name '{}' is already defined.", name);
+                    errors::raise_error(&fmt, errors::ErrorType::RedefinitionAttempt, &node.pos, self.info);
+                }
+
+                self.build_global(node);
+            }
+        }
+
         for node in nodes {
             if node.tp == parser::NodeType::FUNC {
+                if  (node.data.func.as_ref().unwrap().methodname.is_some() ||
+                    node.data.func.as_ref().unwrap().namespacename.is_some()) &&
+                    self.cur_module.namespaces.generic_structs.contains_key(&node.data.func.as_ref().unwrap().name) {
+                    self.cur_module.namespaces.generic_structs.get_mut(&node.data.func.as_ref().unwrap().name).unwrap().functions.push(node.clone());
+                    continue;
+                }
+
                 if  (node.data.func.as_ref().unwrap().methodname.is_some() ||
                     node.data.func.as_ref().unwrap().namespacename.is_some()) &&
                     node.data.func.as_ref().unwrap().template_types.len() == 0 {
@@ -3973,6 +7828,7 @@ impl<'ctx> CodeGen<'ctx> {
                 let mut inktypes: Vec<inkwell::types::BasicMetadataTypeEnum> = Vec::new();
 
                 for arg in &args.args {
+                    self.build_generic_struct_types(arg, node);
                     let (data, tp) = Self::get_llvm_from_type(&self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, &arg, node);
                     datatypes.push(data);
                     mutability.push(arg.mutability);
@@ -3985,6 +7841,7 @@ impl<'ctx> CodeGen<'ctx> {
                     }
                 }
                 
+                self.build_generic_struct_types(args.rettp.last().unwrap(), node);
                 let rettp_full: (types::DataType, inkwell::types::AnyTypeEnum) = Self::get_llvm_from_type(&self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, &args.rettp.last().unwrap(), node);
 
                 self.expected_rettp = Some(rettp_full.0.clone());
@@ -4044,6 +7901,10 @@ impl<'ctx> CodeGen<'ctx> {
                 self.cur_module.namespaces.functions.insert(name.clone(), (func, tp, ForwardDeclarationType::Forward));
             }
             else if node.tp == parser::NodeType::STRUCT {
+                if node.data.st.as_ref().unwrap().template_types.len() > 0 {
+                    continue;
+                }
+
                 if !node.data.st.as_ref().unwrap().name.is_camel_case() {
                     errors::show_warning(errors::WarningType::ExpectedCamelCase, vec![String::from(""), node.data.st.as_ref().unwrap().name.to_camel_case()], vec![String::from("Expected camel case"), String::from("Convert to this: ")], &node.pos, self.info)
                 }
@@ -4068,6 +7929,7 @@ impl<'ctx> CodeGen<'ctx> {
                         errors::raise_error(&fmt, errors::ErrorType::FieldRedeclaration, &node.pos, self.info);
                     }
                     names.push(member.0.clone());
+                    self.build_generic_struct_types(member.1, node);
                     types.push(Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, member.1, node));
                     simpletypes.push(Self::get_llvm_from_type(self.context, &self.cur_module.namespaces, &self.inkwell_types, &self.cur_module.datatypes, &self.datatypes, &self.traits, self.info, member.1, node).0);
                     mutabilitites.push(types::DataMutablility::Mutable);
@@ -4084,6 +7946,11 @@ impl<'ctx> CodeGen<'ctx> {
                 self.cur_module.datatypes.insert(node.data.st.as_ref().unwrap().name.clone(), tp.clone());
                 self.cur_module.namespaces.structs.insert(node.data.st.as_ref().unwrap().name.clone(), (tp, Some(Self::build_struct_tp_from_types(self.context, &self.inkwell_types, &simpletypes, &self.cur_module.datatypes)), idxmapping, ForwardDeclarationType::Forward));
                 builtin_types::add_simple_type(self, std::collections::HashMap::new(), types::BasicDataType::Struct, &node.data.st.as_ref().unwrap().name.clone());
+
+                //Structs are defined while forward declaring, so their derives are built with the definition, as those of enums are in build_enum
+                if node.data.st.as_ref().unwrap().derives.len() > 0 {
+                    self.build_derives(&node.data.st.as_ref().unwrap().name, &node.data.st.as_ref().unwrap().derives, false, node);
+                }
             }
             else if node.tp == parser::NodeType::ENUM {
                 self.build_enum(node);    
@@ -4095,10 +7962,17 @@ impl<'ctx> CodeGen<'ctx> {
                 self.build_trait(node);
             }
         }
+
+        let pending_derives: Vec<(String, inkwell::values::FunctionValue, types::TraitType, parser::Node)> = std::mem::replace(&mut self.pending_derives, prev_derives).unwrap();
+        for (name, func, traittp, node) in &pending_derives {
+            self.build_derive(name, *func, traittp.clone(), node);
+        }
+
+        self.finish_pending_impls(prev_pending);
     }
 }
 
-pub fn generate_code(module_name: &str, source_name: &str, nodes: Vec<parser::Node>, info: &crate::fileinfo::FileInfo) -> Result<(), Box<dyn Error>> {
+pub fn generate_code(module_name: &str, source_name: &str, nodes: Vec<parser::Node>, info: &crate::fileinfo::FileInfo, overflow_checks: bool) -> Result<(), Box<dyn Error>> {
     let context: inkwell::context::Context = Context::create();
     let module: inkwell::module::Module = context.create_module(module_name);
     
@@ -4114,6 +7988,12 @@ pub fn generate_code(module_name: &str, source_name: &str, nodes: Vec<parser::No
 
     let dynptrtp: inkwell::types::StructType = context.struct_type(&[inkwell::types::BasicTypeEnum::IntType(context.i32_type()), inkwell::types::BasicTypeEnum::PointerType(st_data_tp.ptr_type(inkwell::AddressSpace::from(0u16)))], false);
 
+    //Function pointer and environment pointer
+    //The function pointer, the environment pointer, and the function dropping the environment, which is null if it is not owned
+    let closuretp: inkwell::types::StructType = context.struct_type(&[inkwell::types::BasicTypeEnum::PointerType(context.i8_type().ptr_type(inkwell::AddressSpace::from(0u16))), inkwell::types::BasicTypeEnum::PointerType(context.i8_type().ptr_type(inkwell::AddressSpace::from(0u16))), inkwell::types::BasicTypeEnum::PointerType(context.i8_type().ptr_type(inkwell::AddressSpace::from(0u16)))], false);
+
+    //Pointer to the NUL terminated data, and the length in bytes
+    let stringtp: inkwell::types::StructType = context.struct_type(&[inkwell::types::BasicTypeEnum::PointerType(context.i8_type().ptr_type(inkwell::AddressSpace::from(0u16))), inkwell::types::BasicTypeEnum::IntType(context.i64_type())], false);
 
     let inkwelltypes = InkwellTypes {
         i8tp: &context.i8_type(),
@@ -4127,6 +8007,8 @@ pub fn generate_code(module_name: &str, source_name: &str, nodes: Vec<parser::No
         booltp: &context.bool_type(),
         dynptrtp: &dynptrtp,
         st_data_tp: &st_data_tp,
+        closuretp: &closuretp,
+        stringtp: &stringtp,
     };
 
     let namespaces: Namespaces = Namespaces {
@@ -4139,6 +8021,13 @@ pub fn generate_code(module_name: &str, source_name: &str, nodes: Vec<parser::No
         structid_from: std::collections::HashMap::new(),
         structid_max: -1,
         generic_enums: std::collections::HashMap::new(),
+        generic_structs: std::collections::HashMap::new(),
+        type_aliases: std::collections::HashMap::new(),
+        consts: std::collections::HashMap::new(),
+        statics: std::collections::HashMap::new(),
+        const_functions: std::collections::HashMap::new(),
+        enum_discriminants: std::collections::HashMap::new(),
+        template_type_bounds: std::collections::HashMap::new(),
     };
 
     
@@ -4181,12 +8070,17 @@ pub fn generate_code(module_name: &str, source_name: &str, nodes: Vec<parser::No
         traits: std::collections::HashMap::new(),
         current_block: None,
         enclosing_block: None,
-        start_block: None,
-        end_block: None,
-        loop_flow_broken: false,
+        loops: Vec::new(),
+        template_bounds: Vec::new(),
+        expected_tp: None,
         cur_module,
         datatypes: std::collections::HashMap::new(),
         alloc_head: None,
+        overflow_checks,
+        pending_impls: None,
+        pending_derives: None,
+        closure_owners: Vec::new(),
+        drop_scope: 1,
     };
     
     //Pass manager (optimizer)
@@ -4203,6 +8097,7 @@ pub fn generate_code(module_name: &str, source_name: &str, nodes: Vec<parser::No
     codegen.cur_module.datatypes = prev_tps;
     builtin_types::init_traits(&mut codegen);
     builtin_types::init_structs(&mut codegen);
+    builtin_types::init_value_traits(&mut codegen);
     builtin_types::init_enums(&mut codegen);
     modules::builtin_modules::init_builtin_modules(&mut codegen);
 
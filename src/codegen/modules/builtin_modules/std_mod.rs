@@ -15,10 +15,15 @@ fn std_print<'a>(codegen: &mut CodeGen<'a>, args: Vec<Data<'a>>, pos: &crate::pa
         errors::raise_error(&fmt, errors::ErrorType::InvalidDataTypes, pos, codegen.info);
     }
     
-    let ptr: inkwell::values::PointerValue = codegen.builder.build_struct_gep(args.get(0).unwrap().data.unwrap().into_pointer_value(), 0, "data").expect("GEP Error");
-    let data_ptr: inkwell::values::PointerValue = unsafe { codegen.builder.build_in_bounds_gep(ptr, &[codegen.inkwell_types.i32tp.const_zero(), codegen.inkwell_types.i32tp.const_zero()], "data_ptr") };
+    //A reference is passed as a pointer, and a temporary as the value
+    let st: inkwell::values::StructValue = match args.get(0).unwrap().data.unwrap() {
+        inkwell::values::BasicValueEnum::PointerValue(ptr) => codegen.builder.build_load(ptr, "str").into_struct_value(),
+        v => v.into_struct_value(),
+    };
+    let data_ptr: inkwell::values::PointerValue = codegen.builder.build_extract_value(st, 0, "data_ptr").unwrap().into_pointer_value();
+    let format_ptr: inkwell::values::PointerValue = codegen.builder.build_global_string_ptr("%s", "format").as_pointer_value();
 
-    let res: inkwell::values::CallSiteValue = codegen.builder.build_call(inkwell::values::CallableValue::try_from(codegen.cur_module.modules.get("std").unwrap().namespaces.functions.get("printf").unwrap().0.as_global_value().as_pointer_value()).unwrap(), &[data_ptr.into()], "printf_call");
+    let res: inkwell::values::CallSiteValue = codegen.builder.build_call(inkwell::values::CallableValue::try_from(codegen.cur_module.modules.get("std").unwrap().namespaces.functions.get("printf").unwrap().0.as_global_value().as_pointer_value()).unwrap(), &[format_ptr.into(), data_ptr.into()], "printf_call");
 
     let data: Data = Data {
         data: Some(res.try_as_basic_value().left().unwrap()),
@@ -40,6 +45,12 @@ pub fn init_std(codegen: &mut CodeGen) {
         structid_from: std::collections::HashMap::new(),
         structid_max: -1,
         generic_enums: std::collections::HashMap::new(),
+        generic_structs: std::collections::HashMap::new(),
+        type_aliases: std::collections::HashMap::new(),
+        consts: std::collections::HashMap::new(),
+        statics: std::collections::HashMap::new(),
+        const_functions: std::collections::HashMap::new(),
+        enum_discriminants: std::collections::HashMap::new(),
     };
 
     let mut module: Module = Module { 
@@ -24,6 +24,8 @@ pub enum BasicDataType {
     Bool,
     Enum,
     Dyn,
+    Char,
+    Closure,
 }
 
 #[derive(Clone)]
@@ -40,6 +42,7 @@ pub struct DataType<'a> {
     pub wrapperfn: Option<fn(&mut codegen::CodeGen<'a>, Vec<Data<'a>>, &crate::parser::Position) -> Data<'a>>,
     pub methods: std::collections::HashMap<String, Method<'a>>,
     pub lifetime: Option<DataLifetime>,
+    pub alias: Option<String>, //The type alias the type was written as, shown in diagnostics
 }
 
 impl<'a> std::fmt::Debug for DataType<'a> {
@@ -64,6 +67,20 @@ pub enum TraitType {
     Lt,
     Ge,
     Le,
+    Not,
+    BitAnd,
+    BitOr,
+    BitXor,
+    Shl,
+    Shr,
+    BitNot,
+    Index,
+    IndexMut,
+    Cmp,
+    ToString,
+    Clone,
+    Default,
+    Hash,
     User,
 }
 
@@ -98,6 +115,8 @@ impl std::fmt::Display for BasicDataType {
             BasicDataType::Bool => write!(f, "bool"),
             BasicDataType::Enum => write!(f, "enum"),
             BasicDataType::Dyn => write!(f, "dyn"),
+            BasicDataType::Char => write!(f, "char"),
+            BasicDataType::Closure => write!(f, "closure"),
         }
     }    
 }
@@ -119,6 +138,9 @@ impl<'a> std::fmt::Display for DataType<'a> {
         else {
             prefix = String::from("");
         }
+        if self.alias.is_some() {
+            return write!(f, "{}{} = {}", prefix, self.alias.as_ref().unwrap(), self.name);
+        }
         write!(f, "{}{}", prefix, self.name)
     }    
 }
@@ -152,6 +174,10 @@ impl<'a> PartialEq for DataType<'a> {
             return true;
         }
 
+        if self.tp==BasicDataType::Closure && other.tp==BasicDataType::Closure {
+            return self.types == other.types && self.rettp == other.rettp;
+        }
+
         if self.tp==BasicDataType::Struct && other.tp==BasicDataType::Struct {
             if  self.types != other.types ||
                 self.names.as_ref().unwrap() != other.names.as_ref().unwrap() ||
@@ -203,6 +229,10 @@ impl<'a> PartialEq for DataType<'a> {
             return false;
         }
 
+        if self.tp==BasicDataType::Closure && other.tp==BasicDataType::Closure {
+            return self.types != other.types || self.rettp != other.rettp;
+        }
+
         if self.tp==BasicDataType::Struct && other.tp==BasicDataType::Struct {
             if  self.types == other.types &&
                 self.names.as_ref().unwrap() == other.names.as_ref().unwrap() &&
@@ -256,6 +286,20 @@ impl std::fmt::Display for TraitType {
             TraitType::Le => write!(f, "Le"),
             TraitType::Ge => write!(f, "Ge"),
             TraitType::Ne => write!(f, "Ne"),
+            TraitType::Not => write!(f, "Not"),
+            TraitType::BitAnd => write!(f, "BitAnd"),
+            TraitType::BitOr => write!(f, "BitOr"),
+            TraitType::BitXor => write!(f, "BitXor"),
+            TraitType::Shl => write!(f, "Shl"),
+            TraitType::Shr => write!(f, "Shr"),
+            TraitType::BitNot => write!(f, "BitNot"),
+            TraitType::Index => write!(f, "Index"),
+            TraitType::IndexMut => write!(f, "IndexMut"),
+            TraitType::Cmp => write!(f, "Cmp"),
+            TraitType::ToString => write!(f, "ToString"),
+            TraitType::Clone => write!(f, "Clone"),
+            TraitType::Default => write!(f, "Default"),
+            TraitType::Hash => write!(f, "Hash"),
             TraitType::User => write!(f, "User"),
         }
     }    
@@ -301,6 +345,9 @@ pub struct TraitSignature<'a> {
     pub nargs: Option<usize>,
     pub trait_sig: Option<Vec<TemplateTraitSignature>>,
     pub vars: Option<std::collections::HashMap<String, crate::parser::Type>>,
+    pub supertraits: Option<Vec<String>>,
+    pub consts: Option<std::collections::HashMap<String, crate::parser::Type>>,
+    pub types: Option<Vec<String>>,
     pub implementations: std::collections::HashMap<String, std::collections::HashMap<String, inkwell::values::PointerValue<'a>>>,
 }
 
@@ -311,6 +358,7 @@ pub struct TemplateTraitSignature {
     pub namespacename: Option<String>,
     pub template_types: Vec<String>,
     pub args: crate::parser::Args,
+    pub default: Option<crate::parser::Node>, //The function with the default body, if the trait gives one
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -354,6 +402,7 @@ pub fn new_datatype<'a>(tp: BasicDataType, name: String, names: Option<Vec<Strin
         wrapperfn: None,
         methods,
         lifetime: None,
+        alias: None,
     };
 }
 
@@ -371,9 +420,41 @@ pub fn new_dyn_datatype<'a>(traitnm: String, mutability: DataMutablility) -> Dat
         wrapperfn: None,
         methods: std::collections::HashMap::new(),
         lifetime: None,
+        alias: None,
     };
 }
 
+//Tuples are anonymous structs, with the element indices as member names
+pub fn new_tuple_datatype<'a>(types: Vec<DataType<'a>>) -> DataType<'a> {
+    let name: String = String::from("(") + &types.iter().map(|x| datatype_repr(x)).collect::<Vec<String>>().join(", ") + ")";
+    return DataType {
+        tp: BasicDataType::Struct,
+        names: Some((0..types.len()).map(|x| x.to_string()).collect()),
+        mutability: vec![DataMutablility::Mutable; types.len()],
+        types,
+        name,
+        rettp: None,
+        is_ref: false,
+        is_dyn: false,
+        arrtp: None,
+        wrapperfn: None,
+        methods: std::collections::HashMap::new(),
+        lifetime: None,
+        alias: None,
+    };
+}
+
+//The type as it is named where the name identifies it, without any type alias
+pub fn datatype_repr(tp: &DataType) -> String {
+    let mut tp: DataType = tp.clone();
+    tp.alias = None;
+    return tp.to_string();
+}
+
+pub fn is_tuple_datatype(tp: &DataType) -> bool {
+    return tp.tp == BasicDataType::Struct && tp.name.starts_with('(');
+}
+
 pub fn basic_to_metadata(basic: inkwell::values::BasicValueEnum) -> inkwell::values::BasicMetadataValueEnum{
     if basic.is_int_value() {
         return inkwell::values::BasicMetadataValueEnum::IntValue(basic.into_int_value());
@@ -439,6 +520,66 @@ pub fn get_traittp_from_str(tp: String) -> Option<TraitType> {
     else if tp == TraitType::Call.to_string() {
         return Some(TraitType::Call);
     }
+    else if tp == TraitType::Eq.to_string() {
+        return Some(TraitType::Eq);
+    }
+    else if tp == TraitType::Ne.to_string() {
+        return Some(TraitType::Ne);
+    }
+    else if tp == TraitType::Gt.to_string() {
+        return Some(TraitType::Gt);
+    }
+    else if tp == TraitType::Lt.to_string() {
+        return Some(TraitType::Lt);
+    }
+    else if tp == TraitType::Ge.to_string() {
+        return Some(TraitType::Ge);
+    }
+    else if tp == TraitType::Le.to_string() {
+        return Some(TraitType::Le);
+    }
+    else if tp == TraitType::Not.to_string() {
+        return Some(TraitType::Not);
+    }
+    else if tp == TraitType::BitAnd.to_string() {
+        return Some(TraitType::BitAnd);
+    }
+    else if tp == TraitType::BitOr.to_string() {
+        return Some(TraitType::BitOr);
+    }
+    else if tp == TraitType::BitXor.to_string() {
+        return Some(TraitType::BitXor);
+    }
+    else if tp == TraitType::Shl.to_string() {
+        return Some(TraitType::Shl);
+    }
+    else if tp == TraitType::Shr.to_string() {
+        return Some(TraitType::Shr);
+    }
+    else if tp == TraitType::BitNot.to_string() {
+        return Some(TraitType::BitNot);
+    }
+    else if tp == TraitType::Index.to_string() {
+        return Some(TraitType::Index);
+    }
+    else if tp == TraitType::IndexMut.to_string() {
+        return Some(TraitType::IndexMut);
+    }
+    else if tp == TraitType::Cmp.to_string() {
+        return Some(TraitType::Cmp);
+    }
+    else if tp == TraitType::ToString.to_string() {
+        return Some(TraitType::ToString);
+    }
+    else if tp == TraitType::Clone.to_string() {
+        return Some(TraitType::Clone);
+    }
+    else if tp == TraitType::Default.to_string() {
+        return Some(TraitType::Default);
+    }
+    else if tp == TraitType::Hash.to_string() {
+        return Some(TraitType::Hash);
+    }
     
     return None;
 }
\ No newline at end of file
@@ -70,6 +70,39 @@ pub enum ErrorType {
     MutableRefFromImmutable,
     ExpectedStruct,
     UnexpectedVoid,
+    IndexOutOfRange,
+    RangeOutsideOfFor,
+    NotIterable,
+    LabelNotFound,
+    BreakWithValue,
+    TemplateTypeCountMismatch,
+    TemplateBoundNotSatisfied,
+    MissingTemplateBound,
+    AmbiguousTemplateType,
+    FunctionNotGeneric,
+    ExpectedTuple,
+    TupleLengthMismatch,
+    RecursiveTypeAlias,
+    NonConstantExpression,
+    ConstEvalOverflow,
+    ConstEvalDivisionByZero,
+    ConstEvalLimit,
+    InvalidConstFunction,
+    DuplicateDiscriminant,
+    DiscriminantOutOfRange,
+    DivisionByZero,
+    InvalidCharLiteral,
+    InvalidCodePoint,
+    CapturingClosureAsFn,
+    ExpectedClosureReturnType,
+    TraitFunctionRedefined,
+    MissingAssociatedItem,
+    AssociatedItemNotInTrait,
+    CannotDerive,
+    ShiftOverflow,
+    AmbiguousAssociatedItem,
+    MoveOutOfCapture,
+    InvalidClampRange,
 }
 
 impl std::fmt::Display for ErrorType {
@@ -137,7 +170,7 @@ pub fn repr_err(tp: ErrorType) -> &'static str {
         ErrorType::StructAlreadyImplements => "struct already implements trait",
         ErrorType::EnumNotGeneric => "enum is not generic",
         ErrorType::NamespaceLoadOfGenericEnum => "namespace load of generic enum is invalid",
-        ErrorType::ExpectedGenericTypes => "expected generic types for generic enum",
+        ErrorType::ExpectedGenericTypes => "expected generic types for generic enum or struct",
         ErrorType::CannotTakeReferenceOfAttr => "cannot take reference of attribute",
         ErrorType::NameMutableBorrowed => "name is already mutable borrowed",
         ErrorType::ReferenceMemberStruct => "reference member in struct is not allowed",
@@ -148,6 +181,39 @@ pub fn repr_err(tp: ErrorType) -> &'static str {
         ErrorType::MutableRefFromImmutable => "cannot take mutable reference from immutable name",
         ErrorType::ExpectedStruct => "expected struct",
         ErrorType::UnexpectedVoid => "unexpected void",
+        ErrorType::IndexOutOfRange => "index out of range",
+        ErrorType::RangeOutsideOfFor => "range outside of for loop",
+        ErrorType::NotIterable => "type is not iterable",
+        ErrorType::LabelNotFound => "label not found",
+        ErrorType::BreakWithValue => "break with value outside of loop",
+        ErrorType::TemplateTypeCountMismatch => "invalid number of template types",
+        ErrorType::TemplateBoundNotSatisfied => "template type does not satisfy bound",
+        ErrorType::MissingTemplateBound => "template type has no bound providing trait",
+        ErrorType::AmbiguousTemplateType => "ambiguous template type",
+        ErrorType::FunctionNotGeneric => "function is not generic",
+        ErrorType::ExpectedTuple => "expected tuple",
+        ErrorType::TupleLengthMismatch => "tuple length mismatch",
+        ErrorType::RecursiveTypeAlias => "type alias is recursive",
+        ErrorType::NonConstantExpression => "expression is not constant",
+        ErrorType::ConstEvalOverflow => "overflow in constant expression",
+        ErrorType::ConstEvalDivisionByZero => "division by zero in constant expression",
+        ErrorType::ConstEvalLimit => "constant evaluation limit exceeded",
+        ErrorType::InvalidConstFunction => "invalid const function",
+        ErrorType::DuplicateDiscriminant => "duplicate enum discriminant",
+        ErrorType::DiscriminantOutOfRange => "enum discriminant out of range",
+        ErrorType::DivisionByZero => "division by zero",
+        ErrorType::InvalidCharLiteral => "invalid 'char' literal",
+        ErrorType::InvalidCodePoint => "invalid Unicode code point",
+        ErrorType::CapturingClosureAsFn => "capturing closure used as 'fn'",
+        ErrorType::ExpectedClosureReturnType => "expected closure return type",
+        ErrorType::TraitFunctionRedefined => "trait function redefined",
+        ErrorType::MissingAssociatedItem => "missing associated item",
+        ErrorType::AssociatedItemNotInTrait => "associated item not in trait",
+        ErrorType::CannotDerive => "cannot derive",
+        ErrorType::ShiftOverflow => "shift amount out of range",
+        ErrorType::AmbiguousAssociatedItem => "ambiguous associated item",
+        ErrorType::MoveOutOfCapture => "move out of capture",
+        ErrorType::InvalidClampRange => "invalid clamp range",
     }
 }
 
@@ -157,6 +223,7 @@ pub enum WarningType {
     ExpectedCamelCase,
     ExpectedSnakeCase,
     UnreachableCode,
+    ExpectedScreamingSnakeCase,
 }
 
 impl std::fmt::Display for WarningType {
@@ -170,6 +237,7 @@ pub fn repr_warn(tp: WarningType) -> &'static str {
         WarningType::ExpectedCamelCase => "expected camel case",
         WarningType::ExpectedSnakeCase => "expected snake case",
         WarningType::UnreachableCode => "unreachable code",
+        WarningType::ExpectedScreamingSnakeCase => "expected screaming snake case",
     }
 }
 
@@ -47,6 +47,18 @@ pub enum TokenType {
     NE,
     FATARROW,
     SEMICOLON,
+    DOUBLEAMPERSAND,
+    DOUBLEPIPE,
+    EXCLAMATION,
+    PIPE,
+    CARET,
+    TILDE,
+    LSHIFT,
+    DOTDOT,
+    DOTDOTEQ,
+    LABEL,
+    BOOL,
+    HASH,
 }
 
 macro_rules! hashmap {
@@ -128,6 +140,18 @@ impl std::fmt::Display for TokenType {
            TokenType::NE => write!(f, "NE"),
            TokenType::FATARROW => write!(f, "FATARROW"),
            TokenType::SEMICOLON => write!(f, "SEMICOLON"),
+           TokenType::DOUBLEAMPERSAND => write!(f, "DOUBLEAMPERSAND"),
+           TokenType::DOUBLEPIPE => write!(f, "DOUBLEPIPE"),
+           TokenType::EXCLAMATION => write!(f, "EXCLAMATION"),
+           TokenType::PIPE => write!(f, "PIPE"),
+           TokenType::CARET => write!(f, "CARET"),
+           TokenType::TILDE => write!(f, "TILDE"),
+           TokenType::LSHIFT => write!(f, "LSHIFT"),
+           TokenType::DOTDOT => write!(f, "DOTDOT"),
+           TokenType::DOTDOTEQ => write!(f, "DOTDOTEQ"),
+           TokenType::LABEL => write!(f, "LABEL"),
+           TokenType::BOOL => write!(f, "bool"),
+           TokenType::HASH => write!(f, "HASH"),
        }
     }
 }
@@ -190,6 +214,9 @@ pub fn generate_tokens(lexer: &mut Lexer, kwds: &Vec<String>) -> (usize, Vec<Tok
         else if cur=='"'{
             tokens.push(make_string(lexer));
         }
+        else if cur=='\'' && is_label(lexer) {
+            tokens.push(make_label(lexer));
+        }
         else if cur=='\''{
             tokens.push(make_char(lexer));
         }
@@ -373,6 +400,38 @@ pub fn generate_tokens(lexer: &mut Lexer, kwds: &Vec<String>) -> (usize, Vec<Tok
                 endcol: lexer.col+1,
             });
             advance(lexer);
+            if lexer.current == b'&' {     
+                let popped: Token = tokens.pop().unwrap();           
+                tokens.push(Token {
+                    data: String::from("&&"),
+                    tp: TokenType::DOUBLEAMPERSAND,
+                    line: popped.line,
+                    startcol: popped.startcol,
+                    endcol: popped.endcol+1,
+                });
+                advance(lexer);
+            }
+        }
+        else if cur == '|' {
+            tokens.push(Token {
+                data: String::from("|"),
+                tp: TokenType::PIPE,
+                line: lexer.line,
+                startcol: lexer.col,
+                endcol: lexer.col+1,
+            });
+            advance(lexer);
+            if lexer.current == b'|' {     
+                let popped: Token = tokens.pop().unwrap();           
+                tokens.push(Token {
+                    data: String::from("||"),
+                    tp: TokenType::DOUBLEPIPE,
+                    line: popped.line,
+                    startcol: popped.startcol,
+                    endcol: popped.endcol+1,
+                });
+                advance(lexer);
+            }
         }
         else if cur == '.' {
             tokens.push(Token {
@@ -383,6 +442,28 @@ pub fn generate_tokens(lexer: &mut Lexer, kwds: &Vec<String>) -> (usize, Vec<Tok
                 endcol: lexer.col+1,
             });
             advance(lexer);
+            if lexer.current == b'.' {
+                let popped: Token = tokens.pop().unwrap();
+                tokens.push(Token {
+                    data: String::from(".."),
+                    tp: TokenType::DOTDOT,
+                    line: popped.line,
+                    startcol: popped.startcol,
+                    endcol: popped.endcol+1,
+                });
+                advance(lexer);
+                if lexer.current == b'=' {
+                    let popped: Token = tokens.pop().unwrap();
+                    tokens.push(Token {
+                        data: String::from("..="),
+                        tp: TokenType::DOTDOTEQ,
+                        line: popped.line,
+                        startcol: popped.startcol,
+                        endcol: popped.endcol+1,
+                    });
+                    advance(lexer);
+                }
+            }
         }
         else if cur == '[' {
             tokens.push(Token {
@@ -434,7 +515,18 @@ pub fn generate_tokens(lexer: &mut Lexer, kwds: &Vec<String>) -> (usize, Vec<Tok
                 endcol: lexer.col+1,
             });
             advance(lexer);
-            if lexer.current == b'=' {     
+            if lexer.current == b'<' {     
+                let popped: Token = tokens.pop().unwrap();           
+                tokens.push(Token {
+                    data: String::from("<<"),
+                    tp: TokenType::LSHIFT,
+                    line: popped.line,
+                    startcol: popped.startcol,
+                    endcol: popped.endcol+1,
+                });
+                advance(lexer);
+            }
+            else if lexer.current == b'=' {     
                 let popped: Token = tokens.pop().unwrap();           
                 tokens.push(Token {
                     data: String::from("<="),
@@ -447,18 +539,46 @@ pub fn generate_tokens(lexer: &mut Lexer, kwds: &Vec<String>) -> (usize, Vec<Tok
             }
         }
         else if cur == '!' {
+            tokens.push(Token {
+                data: String::from("!"),
+                tp: TokenType::EXCLAMATION,
+                line: lexer.line,
+                startcol: lexer.col,
+                endcol: lexer.col+1,
+            });
             advance(lexer);
-            if lexer.current == b'=' {           
+            if lexer.current == b'=' {     
+                let popped: Token = tokens.pop().unwrap();           
                 tokens.push(Token {
                     data: String::from("!="),
                     tp: TokenType::NE,
-                    line: lexer.line,
-                    startcol: lexer.col-1,
-                    endcol: lexer.col+1,
+                    line: popped.line,
+                    startcol: popped.startcol,
+                    endcol: popped.endcol+1,
                 });
                 advance(lexer);
             }
         }
+        else if cur == '^' {
+            tokens.push(Token {
+                data: String::from("^"),
+                tp: TokenType::CARET,
+                line: lexer.line,
+                startcol: lexer.col,
+                endcol: lexer.col+1,
+            });
+            advance(lexer);
+        }
+        else if cur == '~' {
+            tokens.push(Token {
+                data: String::from("~"),
+                tp: TokenType::TILDE,
+                line: lexer.line,
+                startcol: lexer.col,
+                endcol: lexer.col+1,
+            });
+            advance(lexer);
+        }
         else if cur == ';' {
             tokens.push(Token {
                 data: String::from(";"),
@@ -469,6 +589,16 @@ pub fn generate_tokens(lexer: &mut Lexer, kwds: &Vec<String>) -> (usize, Vec<Tok
             });
             advance(lexer);
         }
+        else if cur == '#' {
+            tokens.push(Token {
+                data: String::from("#"),
+                tp: TokenType::HASH,
+                line: lexer.line,
+                startcol: lexer.col,
+                endcol: lexer.col+1,
+            });
+            advance(lexer);
+        }
         else if cur.is_whitespace() {
             advance(lexer);
         }
@@ -505,12 +635,16 @@ fn make_number(lexer: &mut Lexer) -> Token {
 
     let mut tp: TokenType = TokenType::I32;
 
+    //A number after '.' is a tuple field, so 't.0.1' is not a float
+    let tuple_field: bool = lexer.idx >= 2 && lexer.data[lexer.idx-1] == b'.' && lexer.data[lexer.idx-2] != b'.';
+
     while (lexer.current as char).is_numeric() || lexer.current==b'_' {
         data.push(lexer.current as char);
         end=lexer.col;
         line=lexer.line;
         advance(lexer);
-        if lexer.current == b'.' {
+        //'..' after a number is a range, not a decimal point
+        if !tuple_field && lexer.current == b'.' && (lexer.idx+1 >= lexer.len || lexer.data[lexer.idx+1] != b'.') {
             tp=TokenType::F32;
             data.push(lexer.current as char);
             advance(lexer);
@@ -648,23 +782,9 @@ fn make_identifier(lexer: &mut Lexer, kwds: &Vec<String>) -> Token {
 
     if kwds.iter().find(|x| **x==tok.data)!=None {
         tok.tp = TokenType::KEYWORD;
-        if  tok.data == String::from("true") {
-            tok = Token {
-                data: String::from("1"),
-                tp: TokenType::I8,
-                line,
-                startcol: start,
-                endcol: end+1,
-            };                
-        }
-        else if  tok.data == String::from("false") {
-            tok = Token {
-                data: String::from("0"),
-                tp: TokenType::I8,
-                line,
-                startcol: start,
-                endcol: end+1,
-            };                
+        if  tok.data == String::from("true") ||
+            tok.data == String::from("false") {
+            tok.tp = TokenType::BOOL;
         }
     }
     return tok;
@@ -717,6 +837,41 @@ fn make_string(lexer: &mut Lexer) -> Token {
     return tok;
 }
 
+//A quote followed by a name without a closing quote is a label, not a char
+fn is_label(lexer: &Lexer) -> bool {
+    let mut idx: usize = lexer.idx+1;
+
+    while idx < lexer.len && (lexer.data[idx].is_ascii_alphanumeric() || lexer.data[idx] == b'_') {
+        idx+=1;
+    }
+
+    return idx > lexer.idx+1 && (idx >= lexer.len || lexer.data[idx] != b'\'');
+}
+
+fn make_label(lexer: &mut Lexer) -> Token {
+    let mut data: String = String::from("");
+    let start: usize = lexer.col;
+    let line: usize = lexer.line;
+
+    advance(lexer);
+
+    let mut end: usize = lexer.col;
+
+    while lexer.current.is_ascii_alphanumeric() || lexer.current == b'_' {
+        data.push(lexer.current as char);
+        end=lexer.col;
+        advance(lexer);
+    }
+
+    return Token {
+        data,
+        tp: TokenType::LABEL,
+        line,
+        startcol: start,
+        endcol: end+1,
+    };
+}
+
 fn make_char(lexer: &mut Lexer) -> Token {
     let mut data: Vec<u8> = Vec::new();
     let start: usize = lexer.col;
@@ -726,6 +881,11 @@ fn make_char(lexer: &mut Lexer) -> Token {
     advance(lexer);
 
     while lexer.current!=b'\''{
+        //An escaped quote or backslash does not end the literal
+        if lexer.current==b'\\' {
+            data.push(lexer.current);
+            advance(lexer);
+        }
         data.push(lexer.current);
         line=lexer.line;
         advance(lexer);
@@ -15,12 +15,13 @@ fn main() {
     let args: Vec<String> = std::env::args().collect();
 
     if args.last().unwrap() == &String::from("--help") && args.len() == 2 {
-        println!("usage: kestrel [--version | --help] [--err <error> | --warn <warning>] [<program> [--run]] ");
+        println!("usage: kestrel [--version | --help] [--err <error> | --warn <warning>] [<program> [--run] [--release]] ");
         println!("--version => view Kestrel version");
         println!("--help => view this information");
         println!("--err => get the textual representation of an error code");
         println!("--warn => get the textual representation of an warning code");
         println!("--run => run the program automatically after compilation");
+        println!("--release => compile without runtime overflow checks, integer arithmetic wraps on overflow");
         return;
     }
 
@@ -70,13 +71,23 @@ fn main() {
     }
 
     let mut inplace_run: bool = false;
-    if args.len() == 3 && args.last().unwrap() == &String::from("--run") {
-        inplace_run = true;
-    }
-    else if args.len() != 2 {
-        println!("Invalid number of command line arguments. Expected 2, got {}.", args.len());
+    let mut release: bool = false;
+    if args.len() < 2 || args.len() > 4 {
+        println!("Invalid number of command line arguments. Expected 2 to 4, got {}.", args.len());
         return;
     }
+    for arg in &args[2..] {
+        if arg == &String::from("--run") {
+            inplace_run = true;
+        }
+        else if arg == &String::from("--release") {
+            release = true;
+        }
+        else {
+            println!("Invalid command line argument '{}'.", arg);
+            return;
+        }
+    }
 
     let filename: &String = &args[1];
     let file_data: String;
@@ -125,6 +136,11 @@ fn main() {
                                             String::from("void"),
                                             String::from("is"),
                                             String::from("match"),
+                                            String::from("in"),
+                                            String::from("type"),
+                                            String::from("const"),
+                                            String::from("static"),
+                                            String::from("move"),
                                             ];
 
     let mut lexer: lexer::Lexer = lexer::new(file_data_bytes, &file_info);
@@ -137,7 +153,7 @@ fn main() {
 
     let nodes: Vec<parser::Node> = parser.generate_ast();
 
-    let res: Result<(), Box<dyn std::error::Error>> = codegen::generate_code("module", filename.as_str(), nodes, &file_info);
+    let res: Result<(), Box<dyn std::error::Error>> = codegen::generate_code("module", filename.as_str(), nodes, &file_info, !release);
 
     match res {
         Ok(_) => {}
@@ -70,6 +70,16 @@ pub enum NodeType {
     MUTREF,
     STMT,
     MULTINAMESPACE,
+    INDEX,
+    INDEXASSIGN,
+    RANGE,
+    FOR,
+    TUPLE,
+    TYPEALIAS,
+    CONST,
+    STATIC,
+    BOOL,
+    CLOSURE,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -79,7 +89,7 @@ pub struct Node{
     pub pos: Position,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Position{
     pub line: usize,
     pub startcol: usize,
@@ -89,12 +99,14 @@ pub struct Position{
 #[derive(Clone, Debug, PartialEq)]
 pub struct Type {
     pub isfn: bool,
+    pub isclosure: bool, //A fn type with an environment, so isfn is also set
     pub isarr: bool,
     pub isdyn: bool,
     pub isgenum: bool,
     pub isref: bool,
+    pub istuple: bool,
     pub basetp: Option<Box<Type>>,
-    pub arrlen: Option<Vec<String>>,
+    pub arrlen: Option<Vec<Node>>,
     pub data: Option<String>,
     pub args: Option<Args>,
     pub mutability: types::DataMutablility,
@@ -112,7 +124,9 @@ impl std::fmt::Display for Node {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.tp {
             NodeType::BINARY => write!(f, "{}", self.data.binary.as_ref().unwrap() ),
-            NodeType::LET => write!(f, "{}", self.data.letn.as_ref().unwrap() ),
+            NodeType::LET |
+            NodeType::CONST |
+            NodeType::STATIC => write!(f, "{}", self.data.letn.as_ref().unwrap() ),
             NodeType::IDENTIFIER => write!(f, "{}", self.data.identifier.as_ref().unwrap() ),
             NodeType::FUNC => write!(f, "{}", self.data.func.as_ref().unwrap() ),
             NodeType::ASSIGN => write!(f, "{}", self.data.assign.as_ref().unwrap() ),
@@ -130,7 +144,8 @@ impl std::fmt::Display for Node {
             NodeType::F32 |
             NodeType::F64 |
             NodeType::U128 |
-            NodeType::CHAR => write!(f, "{}", self.data.num.as_ref().unwrap() ),
+            NodeType::CHAR |
+            NodeType::BOOL => write!(f, "{}", self.data.num.as_ref().unwrap() ),
             NodeType::AS => write!(f, "{}", self.data.to.as_ref().unwrap() ),
             NodeType::UNARY |
             NodeType::REF |
@@ -143,19 +158,26 @@ impl std::fmt::Display for Node {
             NodeType::GENERICENUM  => write!(f, "{}", self.data.attr.as_ref().unwrap() ),
             NodeType::ATTRASSIGN => write!(f, "{}", self.data.attrassign.as_ref().unwrap() ),
             NodeType::STRING => write!(f, "{}", self.data.str.as_ref().unwrap() ),
-            NodeType::ARRAY => write!(f, "{}", self.data.arr.as_ref().unwrap() ),
+            NodeType::ARRAY |
+            NodeType::TUPLE => write!(f, "{}", self.data.arr.as_ref().unwrap() ),
             NodeType::IMPL => write!(f, "{}", self.data.impln.as_ref().unwrap() ),
             NodeType::IF => write!(f, "{}", self.data.ifn.as_ref().unwrap() ),
             NodeType::LOOP |
             NodeType::WHILE => write!(f, "{}", self.data.loopn.as_ref().unwrap() ),
             NodeType::BREAK |
-            NodeType::CONTINUE => Ok(()),
+            NodeType::CONTINUE => write!(f, "{}", self.data.breakn.as_ref().unwrap() ),
             NodeType::ENUM => write!(f, "{}", self.data.enumn.as_ref().unwrap() ),
             NodeType::TRAIT => write!(f, "{}", self.data.traitn.as_ref().unwrap() ),
             NodeType::VOID => write!(f, "void"),
             NodeType::IS => write!(f, "{}", self.data.is.as_ref().unwrap() ),
             NodeType::MATCH => write!(f, "{}", self.data.matchn.as_ref().unwrap() ),
             NodeType::MULTINAMESPACE => write!(f, "{}", self.data.nameattr.as_ref().unwrap() ),
+            NodeType::INDEX |
+            NodeType::INDEXASSIGN => write!(f, "{}", self.data.index.as_ref().unwrap() ),
+            NodeType::RANGE => write!(f, "{}", self.data.range.as_ref().unwrap() ),
+            NodeType::FOR => write!(f, "{}", self.data.forn.as_ref().unwrap() ),
+            NodeType::TYPEALIAS => write!(f, "{}", self.data.alias.as_ref().unwrap() ),
+            NodeType::CLOSURE => write!(f, "{}", self.data.closure.as_ref().unwrap() ),
         }
     }    
 }
@@ -271,6 +293,48 @@ impl<'a> Parser<'a> {
         return res;
     }
 
+    //Right shift is lexed as two adjacent '>' (or '>' followed by '>=' for an inplace shift),
+    //so that closing nested template brackets still works.
+    fn current_is_rshift(&self) -> bool {
+        if self.current.tp != TokenType::GT {
+            return false;
+        }
+
+        let next: &lexer::Token = match self.tokens.get(self.idx) {
+            Some(v) => v,
+            None => return false,
+        };
+
+        if next.line != self.current.line || next.startcol != self.current.endcol {
+            return false;
+        }
+
+        match next.tp {
+            TokenType::GTE => true,
+            TokenType::GT => {
+                match self.tokens.get(self.idx+1) {
+                    Some(v) => {
+                        match v.tp {
+                            TokenType::NEWLINE |
+                            TokenType::EOF |
+                            TokenType::GT |
+                            TokenType::COMMA |
+                            TokenType::SEMICOLON |
+                            TokenType::RPAREN |
+                            TokenType::RSQUARE |
+                            TokenType::LCURLY |
+                            TokenType::RCURLY |
+                            TokenType::DOUBLECOLON => false,
+                            _ => true,
+                        }
+                    }
+                    None => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
     fn get_precedence(&self) -> Precedence {
         match self.current.tp {
             TokenType::EQUALS => {
@@ -291,6 +355,13 @@ impl<'a> Parser<'a> {
             TokenType::LPAREN => {
                 Precedence::Call
             }
+            TokenType::LSQUARE => {
+                Precedence::Index
+            }
+            TokenType::DOTDOT |
+            TokenType::DOTDOTEQ => {
+                Precedence::Range
+            }
             TokenType::KEYWORD => {
                 if self.current.data == "to" {
                     Precedence::To
@@ -305,7 +376,14 @@ impl<'a> Parser<'a> {
                     Precedence::Lowest
                 }
             }
-            TokenType::GT |
+            TokenType::GT => {
+                if self.current_is_rshift() {
+                    Precedence::BitwiseShift
+                }
+                else {
+                    Precedence::Comparison
+                }
+            }
             TokenType::GTE |
             TokenType::LT |
             TokenType::LTE  => {
@@ -315,6 +393,24 @@ impl<'a> Parser<'a> {
             TokenType::NE => {
                 Precedence::Equals
             }
+            TokenType::DOUBLEAMPERSAND => {
+                Precedence::LogicalAnd
+            }
+            TokenType::DOUBLEPIPE => {
+                Precedence::LogicalOr
+            }
+            TokenType::AMPERSAND => {
+                Precedence::BitwiseAnd
+            }
+            TokenType::PIPE => {
+                Precedence::BitwiseOr
+            }
+            TokenType::CARET => {
+                Precedence::BitwiseXor
+            }
+            TokenType::LSHIFT => {
+                Precedence::BitwiseShift
+            }
             TokenType::DOT |
             TokenType::DOUBLECOLON => {
                 Precedence::Attr
@@ -352,6 +448,12 @@ impl<'a> Parser<'a> {
             lexer::TokenType::KEYWORD => {
                 self.keyword()
             }
+            lexer::TokenType::LABEL => {
+                self.parse_label()
+            }
+            lexer::TokenType::HASH => {
+                self.parse_attribute()
+            }
             _ => {
                 self.expr(Precedence::Lowest)
             }
@@ -377,10 +479,13 @@ impl<'a> Parser<'a> {
             TokenType::U64 |
             TokenType::I128 |
             TokenType::U128 |
+            TokenType::BOOL |
             TokenType::IDENTIFIER |
             TokenType::AMPERSAND |
             TokenType::PLUS |
             TokenType::HYPHEN |
+            TokenType::EXCLAMATION |
+            TokenType::TILDE |
             TokenType::LPAREN |
             TokenType::STRING => return true,
             _ => return false,
@@ -404,16 +509,27 @@ impl<'a> Parser<'a> {
             TokenType::F32 => Some(self.generate_f32(self.current.data.clone())),
             TokenType::F64 => Some(self.generate_f64(self.current.data.clone())),
             TokenType::PLUS |
-            TokenType::HYPHEN => Some(self.generate_unary()),
+            TokenType::HYPHEN |
+            TokenType::EXCLAMATION |
+            TokenType::TILDE => Some(self.generate_unary()),
             TokenType::AMPERSAND => Some(self.generate_ref()),
             TokenType::STRING => Some(self.generate_str()),
             TokenType::CHAR => Some(self.generate_char(self.current.data.clone())),
+            TokenType::BOOL => Some(self.generate_bool(self.current.data.clone())),
             TokenType::LSQUARE => Some(self.generate_array()),
-            TokenType::KEYWORD => if self.current.data == "void" { Some(self.generate_void()) } else if self.current.data == "if" { let v: Option<Node> = Some(self.parse_if(true)); self.backadvance(); v } else if self.current.data == "match" { let v: Option<Node> = Some(self.parse_match(true)); self.backadvance(); v } else { None },
+            TokenType::PIPE |
+            TokenType::DOUBLEPIPE => Some(self.generate_closure(false)),
+            TokenType::KEYWORD => if self.current.data == "void" { Some(self.generate_void()) } else if self.current.data == "if" { let v: Option<Node> = Some(self.parse_if(true)); self.backadvance(); v } else if self.current.data == "match" { let v: Option<Node> = Some(self.parse_match(true)); self.backadvance(); v } else if self.current.data == "loop" { let v: Option<Node> = Some(self.parse_loop()); self.backadvance(); v } else if self.current.data == "move" { Some(self.generate_closure(true)) } else { None },
+            TokenType::LABEL => { let v: Option<Node> = Some(self.parse_label()); self.backadvance(); v },
             _ => None,
         }
     }
 
+    //A type suffix makes the token span longer than the literal itself
+    fn is_unsuffixed_literal(&self) -> bool {
+        return self.current.endcol - self.current.startcol <= self.current.data.len();
+    }
+
     fn skip_newline(&mut self) {
         while self.current_is_type(TokenType::NEWLINE) {
             self.advance();
@@ -451,12 +567,34 @@ impl<'a> Parser<'a> {
         else if self.current.data == String::from("while") {
             return self.parse_while();
         }
+        else if self.current.data == String::from("for") {
+            return self.parse_for();
+        }
         else if self.current.data == String::from("enum") {
             return self.parse_enum();
         }
         else if self.current.data == String::from("trait") {
             return self.parse_trait();
         }
+        else if self.current.data == String::from("type") {
+            return self.parse_type_alias();
+        }
+        else if self.current.data == String::from("const") {
+            self.advance();
+            let isfn: bool = self.current_is_type(TokenType::KEYWORD) && self.current.data == "fn";
+            self.backadvance();
+            
+            if isfn {
+                self.advance();
+                let mut func: Node = self.parse_fn();
+                func.data.func.as_mut().unwrap().isconst = true;
+                return func;
+            }
+            return self.parse_global(NodeType::CONST);
+        }
+        else if self.current.data == String::from("static") {
+            return self.parse_global(NodeType::STATIC);
+        }
         else if self.current.data == String::from("match") {
             return self.parse_match(false);
         }
@@ -491,7 +629,13 @@ impl<'a> Parser<'a> {
                 TokenType::LT |
                 TokenType::LTE |
                 TokenType::EQ |
-                TokenType::NE => {
+                TokenType::NE |
+                TokenType::DOUBLEAMPERSAND |
+                TokenType::DOUBLEPIPE |
+                TokenType::AMPERSAND |
+                TokenType::PIPE |
+                TokenType::CARET |
+                TokenType::LSHIFT => {
                     left = self.generate_binary(left, self.get_precedence());
                 }
 
@@ -503,6 +647,15 @@ impl<'a> Parser<'a> {
                     left = self.generate_call(left);
                 }
 
+                TokenType::LSQUARE => {
+                    left = self.generate_index(left);
+                }
+
+                TokenType::DOTDOT |
+                TokenType::DOTDOTEQ => {
+                    left = self.generate_range(left);
+                }
+
                 TokenType::DOT => {
                     self.advance();
                     let attr: String = self.current.data.clone();
@@ -538,7 +691,7 @@ impl<'a> Parser<'a> {
                 }
             }
         }
-        if self.is_atomic() {
+        if self.is_atomic() && self.get_precedence() == Precedence::Lowest {
             self.raise_error("Unexpected token.", ErrorType::InvalidTok);
         }
         return left;
@@ -548,7 +701,8 @@ impl<'a> Parser<'a> {
     
     fn generate_i32(&mut self, data: String) -> Node{
         let int: nodes::NumNode = nodes::NumNode{
-            left: data.clone()
+            left: data.clone(),
+            inferred: self.is_unsuffixed_literal(),
         };
     
         let nodedat: nodes::NodeData = nodes::NodeData {
@@ -576,6 +730,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
 
         let pos = Position {
@@ -596,31 +756,50 @@ impl<'a> Parser<'a> {
             endcol: 0,
         };
 
+        let isshr: bool = self.current_is_rshift();
 
         let op: nodes::BinaryOpType = match self.current.tp {
             TokenType::PLUS => nodes::BinaryOpType::ADD,
             TokenType::HYPHEN => nodes::BinaryOpType::SUB,
             TokenType::ASTERISK => nodes::BinaryOpType::MUL,
             TokenType::FWSLASH => nodes::BinaryOpType::DIV,
-            TokenType::GT => nodes::BinaryOpType::GT,
+            TokenType::GT => if isshr { nodes::BinaryOpType::SHR } else { nodes::BinaryOpType::GT },
             TokenType::GTE => nodes::BinaryOpType::GTE,
             TokenType::LT => nodes::BinaryOpType::LT,
             TokenType::LTE => nodes::BinaryOpType::LTE,
             TokenType::EQ => nodes::BinaryOpType::EQ,
             TokenType::NE => nodes::BinaryOpType::NE,
+            TokenType::DOUBLEAMPERSAND => nodes::BinaryOpType::AND,
+            TokenType::DOUBLEPIPE => nodes::BinaryOpType::OR,
+            TokenType::AMPERSAND => nodes::BinaryOpType::BITAND,
+            TokenType::PIPE => nodes::BinaryOpType::BITOR,
+            TokenType::CARET => nodes::BinaryOpType::BITXOR,
+            TokenType::LSHIFT => nodes::BinaryOpType::SHL,
             _ => self.raise_error("Invalid token.", ErrorType::InvalidTok),
         };
 
+        let mut shrassign: bool = false;
+        if isshr {
+            self.advance();
+            //'>>=' is lexed as '>' followed by '>='
+            shrassign = self.current_is_type(TokenType::GTE);
+        }
+
         self.advance();
 
         let mut isassign: bool = false;
 
-        if self.current_is_type(TokenType::EQUALS) {
+        if shrassign || self.current_is_type(TokenType::EQUALS) {
             isassign = true;
-            if left.tp != NodeType::IDENTIFIER {
-                self.raise_error("Expected identifier.", ErrorType::InvalidTok);
+            if left.tp != NodeType::IDENTIFIER && left.tp != NodeType::ATTR && left.tp != NodeType::INDEX {
+                self.raise_error("Expected identifier, attribute, or index.", ErrorType::InvalidTok);
+            }
+            if op == nodes::BinaryOpType::AND || op == nodes::BinaryOpType::OR {
+                self.raise_error("Logical operators cannot be used inplace.", ErrorType::InvalidTok);
+            }
+            if !shrassign {
+                self.advance();
             }
-            self.advance();
         }
         
         let bin: nodes::BinaryNode = nodes::BinaryNode{
@@ -657,6 +836,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
     
         let n: Node = self.create_node(NodeType::BINARY, nodedat, pos);
@@ -694,6 +879,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
 
         let pos = Position {
@@ -786,6 +977,12 @@ impl<'a> Parser<'a> {
                 is: None,
                 matchn: None,
                 nameattr: None,
+                index: None,
+                range: None,
+                forn: None,
+                breakn: None,
+                alias: None,
+                closure: None,
             };
         
             n = self.create_node(NodeType::INITSTRUCT, nodedat, pos);
@@ -860,6 +1057,12 @@ impl<'a> Parser<'a> {
                 is: None,
                 matchn: None,
                 nameattr: None,
+                index: None,
+                range: None,
+                forn: None,
+                breakn: None,
+                alias: None,
+                closure: None,
             };
         
             n = self.create_node(NodeType::NAMESPACE, nodedat, pos.clone());
@@ -890,28 +1093,39 @@ impl<'a> Parser<'a> {
                 }
             }
 
-            if !self.current_is_type(TokenType::DOUBLECOLON) {
-                self.raise_error("Expected doublecolon.", ErrorType::InvalidTok);
-            }
-            self.advance();
-
-            if !self.current_is_type(TokenType::IDENTIFIER) {
-                self.raise_error("Expected identifier.", ErrorType::InvalidTok);
+            let attr: String;
+            let expr: Option<Node>;
+            //Explicit template types of a template function call
+            if self.current_is_type(TokenType::LPAREN) {
+                self.backadvance();
+                pos.endcol = self.current.endcol;
+                attr = String::new();
+                expr = None;
             }
-            let attr: String = self.current.data.clone();
-            self.advance();
-            pos.endcol = self.current.endcol;
+            else {
+                if !self.current_is_type(TokenType::DOUBLECOLON) {
+                    self.raise_error("Expected doublecolon.", ErrorType::InvalidTok);
+                }
+                self.advance();
 
-            let expr: Option<Node>;
-            if self.current_is_type(TokenType::LT) {
+                if !self.current_is_type(TokenType::IDENTIFIER) {
+                    self.raise_error("Expected identifier.", ErrorType::InvalidTok);
+                }
+                attr = self.current.data.clone();
                 self.advance();
-                expr = Some(self.expr(Precedence::Comparison));
-                if !self.current_is_type(TokenType::GT) {
-                    self.raise_error("Expected right angle bracket.", ErrorType::InvalidTok);
+                pos.endcol = self.current.endcol;
+
+                if self.current_is_type(TokenType::LT) {
+                    self.advance();
+                    expr = Some(self.expr(Precedence::Comparison));
+                    if !self.current_is_type(TokenType::GT) {
+                        self.raise_error("Expected right angle bracket.", ErrorType::InvalidTok);
+                    }
+                }
+                else {
+                    self.backadvance();
+                    expr = None;
                 }
-            }
-            else {
-                expr = None;
             }
             
 
@@ -947,6 +1161,12 @@ impl<'a> Parser<'a> {
                 is: None,
                 matchn: None,
                 nameattr: None,
+                index: None,
+                range: None,
+                forn: None,
+                breakn: None,
+                alias: None,
+                closure: None,
             };
         
             n = self.create_node(NodeType::GENERICENUM, nodedat, pos.clone());
@@ -994,6 +1214,12 @@ impl<'a> Parser<'a> {
                 is: None,
                 matchn: None,
                 nameattr: None,
+                index: None,
+                range: None,
+                forn: None,
+                breakn: None,
+                alias: None,
+                closure: None,
             };
         
             return self.create_node(NodeType::ATTRASSIGN, nodedat, pos.clone());
@@ -1033,6 +1259,12 @@ impl<'a> Parser<'a> {
                 is: None,
                 matchn: None,
                 nameattr: None,
+                index: None,
+                range: None,
+                forn: None,
+                breakn: None,
+                alias: None,
+                closure: None,
             };
         
             return self.create_node(NodeType::ATTR, nodedat, pos.clone());
@@ -1110,6 +1342,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: Some(attr),
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
     
         return self.create_node(NodeType::MULTINAMESPACE, nodedat, pos.clone());
@@ -1160,6 +1398,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
     
         let n: Node = self.create_node(NodeType::ASSIGN, nodedat, pos);
@@ -1168,9 +1412,19 @@ impl<'a> Parser<'a> {
     }
 
     fn generate_grouped(&mut self) -> Node {
+        let pos = Position {
+            line: self.current.line,
+            startcol: self.current.startcol,
+            endcol: 0,
+        };
+
         self.advance();
         let node: Node = self.expr(Precedence::Lowest);
 
+        if self.current_is_type(TokenType::COMMA) {
+            return self.generate_tuple(node, pos);
+        }
+
         if !self.current_is_type(TokenType::RPAREN) {
             self.raise_error("Expected right parenthesis.", ErrorType::InvalidTok);
         }
@@ -1178,6 +1432,72 @@ impl<'a> Parser<'a> {
         return node;
     }
 
+    fn generate_tuple(&mut self, first: Node, mut pos: Position) -> Node {
+        let mut elements: Vec<Node> = vec![first];
+
+        self.advance();
+        self.skip_newline();
+
+        while !self.current_is_type(TokenType::RPAREN) && !self.current_is_type(TokenType::EOF) {
+            elements.push(self.expr(Precedence::Lowest));
+            self.skip_newline();
+            if !self.current_is_type(TokenType::COMMA) && !self.current_is_type(TokenType::RPAREN) {
+                self.raise_error("Expected comma.", ErrorType::InvalidTok);
+            }
+            if self.current_is_type(TokenType::RPAREN) {
+                break;
+            }
+            self.advance();
+            self.skip_newline();
+        }
+        if !self.current_is_type(TokenType::RPAREN) {
+            self.raise_error("Expected right parenthesis.", ErrorType::InvalidTok);
+        }
+
+        pos.endcol = self.current.endcol;
+
+        let arr: nodes::ArrayNode = nodes::ArrayNode{
+            elements,
+        };
+    
+        let nodedat: nodes::NodeData = nodes::NodeData {
+            binary: None,
+            num: None,
+            letn: None,
+            identifier: None,
+            func: None,
+            assign: None,
+            call: None,
+            ret: None,
+            to: None,
+            unary: None,
+            st: None,
+            initst: None,
+            attr: None,
+            attrassign: None,
+            str: None,
+            arr: Some(arr),
+            impln: None,
+            ifn: None,
+            loopn: None,
+            enumn: None,
+            traitn: None,
+            is: None,
+            matchn: None,
+            nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
+        };
+    
+        let n: Node = self.create_node(NodeType::TUPLE, nodedat, pos);
+    
+        return n;
+    }
+
     fn generate_call(&mut self, left: Node) -> Node{
         let mut pos = Position {
             line: self.current.line,
@@ -1188,7 +1508,8 @@ impl<'a> Parser<'a> {
         if  left.tp != NodeType::IDENTIFIER &&
             left.tp != NodeType::ATTR &&
             left.tp != NodeType::NAMESPACE &&
-            left.tp != NodeType::MULTINAMESPACE {
+            left.tp != NodeType::MULTINAMESPACE &&
+            left.tp != NodeType::GENERICENUM {
             self.raise_error_pos("Expected name", ErrorType::InvalidTok, left);
         }
 
@@ -1245,20 +1566,40 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
     
         let n: Node = self.create_node(NodeType::CALL, nodedat, pos);
         return n;
     }
-    
-    fn generate_u32(&mut self, data: String) -> Node{
-        let int: nodes::NumNode = nodes::NumNode{
-            left: data.clone()
+
+    fn generate_range(&mut self, left: Node) -> Node{
+        let mut pos = Position {
+            line: left.pos.line,
+            startcol: left.pos.startcol,
+            endcol: 0,
         };
-    
+
+        let inclusive: bool = self.current_is_type(TokenType::DOTDOTEQ);
+
+        self.advance();
+
+        let range: nodes::RangeNode = nodes::RangeNode{
+            start: left,
+            end: self.expr(Precedence::Range),
+            inclusive,
+        };
+
+        pos.endcol = range.end.pos.endcol;
+
         let nodedat: nodes::NodeData = nodes::NodeData {
             binary: None,
-            num: Some(int),
+            num: None,
             letn: None,
             identifier: None,
             func: None,
@@ -1281,27 +1622,58 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: Some(range),
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
 
-        let pos = Position {
-            line: self.current.line,
-            startcol: self.current.startcol,
-            endcol: self.current.endcol,
-        };
-    
-        let n: Node = self.create_node(NodeType::U32, nodedat, pos);
-    
+        let n: Node = self.create_node(NodeType::RANGE, nodedat, pos);
+
         return n;
     }
-    
-    fn generate_i8(&mut self, data: String) -> Node{
-        let int: nodes::NumNode = nodes::NumNode{
-            left: data.clone()
+
+    fn generate_index(&mut self, left: Node) -> Node{
+        let mut pos = Position {
+            line: self.current.line,
+            startcol: left.pos.startcol,
+            endcol: 0,
+        };
+
+        self.advance();
+
+        let index: Node = self.expr(Precedence::Lowest);
+
+        if !self.current_is_type(TokenType::RSQUARE) {
+            self.raise_error("Expected right square bracket.", ErrorType::InvalidTok);
+        }
+
+        pos.endcol = self.current.endcol;
+
+        self.advance();
+
+        let mut tp: NodeType = NodeType::INDEX;
+        let mut expr: Option<Node> = None;
+
+        if self.current_is_type(TokenType::EQUALS) {
+            self.advance();
+            let value: Node = self.expr(Precedence::Lowest);
+            pos.endcol = value.pos.endcol;
+            expr = Some(value);
+            tp = NodeType::INDEXASSIGN;
+        }
+
+        let idx: nodes::IndexNode = nodes::IndexNode{
+            name: left,
+            index,
+            expr,
         };
     
         let nodedat: nodes::NodeData = nodes::NodeData {
             binary: None,
-            num: Some(int),
+            num: None,
             letn: None,
             identifier: None,
             func: None,
@@ -1324,22 +1696,22 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: Some(idx),
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
-
-        let pos = Position {
-            line: self.current.line,
-            startcol: self.current.startcol,
-            endcol: self.current.endcol,
-        };
-    
-        let n: Node = self.create_node(NodeType::I8, nodedat, pos);
     
+        let n: Node = self.create_node(tp, nodedat, pos);
         return n;
     }
     
-    fn generate_u8(&mut self, data: String) -> Node{
+    fn generate_u32(&mut self, data: String) -> Node{
         let int: nodes::NumNode = nodes::NumNode{
-            left: data.clone()
+            left: data.clone(),
+            inferred: false,
         };
     
         let nodedat: nodes::NodeData = nodes::NodeData {
@@ -1367,6 +1739,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
 
         let pos = Position {
@@ -1375,14 +1753,15 @@ impl<'a> Parser<'a> {
             endcol: self.current.endcol,
         };
     
-        let n: Node = self.create_node(NodeType::U8, nodedat, pos);
+        let n: Node = self.create_node(NodeType::U32, nodedat, pos);
     
         return n;
     }
     
-    fn generate_i16(&mut self, data: String) -> Node{
+    fn generate_i8(&mut self, data: String) -> Node{
         let int: nodes::NumNode = nodes::NumNode{
-            left: data.clone()
+            left: data.clone(),
+            inferred: false,
         };
     
         let nodedat: nodes::NodeData = nodes::NodeData {
@@ -1410,6 +1789,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
 
         let pos = Position {
@@ -1418,14 +1803,15 @@ impl<'a> Parser<'a> {
             endcol: self.current.endcol,
         };
     
-        let n: Node = self.create_node(NodeType::I16, nodedat, pos);
+        let n: Node = self.create_node(NodeType::I8, nodedat, pos);
     
         return n;
     }
     
-    fn generate_u16(&mut self, data: String) -> Node{
+    fn generate_u8(&mut self, data: String) -> Node{
         let int: nodes::NumNode = nodes::NumNode{
-            left: data.clone()
+            left: data.clone(),
+            inferred: false,
         };
     
         let nodedat: nodes::NodeData = nodes::NodeData {
@@ -1453,6 +1839,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
 
         let pos = Position {
@@ -1461,14 +1853,15 @@ impl<'a> Parser<'a> {
             endcol: self.current.endcol,
         };
     
-        let n: Node = self.create_node(NodeType::U16, nodedat, pos);
+        let n: Node = self.create_node(NodeType::U8, nodedat, pos);
     
         return n;
     }
     
-    fn generate_i64(&mut self, data: String) -> Node{
+    fn generate_i16(&mut self, data: String) -> Node{
         let int: nodes::NumNode = nodes::NumNode{
-            left: data.clone()
+            left: data.clone(),
+            inferred: false,
         };
     
         let nodedat: nodes::NodeData = nodes::NodeData {
@@ -1496,6 +1889,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
 
         let pos = Position {
@@ -1504,14 +1903,15 @@ impl<'a> Parser<'a> {
             endcol: self.current.endcol,
         };
     
-        let n: Node = self.create_node(NodeType::I64, nodedat, pos);
+        let n: Node = self.create_node(NodeType::I16, nodedat, pos);
     
         return n;
     }
     
-    fn generate_u64(&mut self, data: String) -> Node{
+    fn generate_u16(&mut self, data: String) -> Node{
         let int: nodes::NumNode = nodes::NumNode{
-            left: data.clone()
+            left: data.clone(),
+            inferred: false,
         };
     
         let nodedat: nodes::NodeData = nodes::NodeData {
@@ -1539,6 +1939,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
 
         let pos = Position {
@@ -1547,14 +1953,15 @@ impl<'a> Parser<'a> {
             endcol: self.current.endcol,
         };
     
-        let n: Node = self.create_node(NodeType::U64, nodedat, pos);
+        let n: Node = self.create_node(NodeType::U16, nodedat, pos);
     
         return n;
     }
     
-    fn generate_i128(&mut self, data: String) -> Node{
+    fn generate_i64(&mut self, data: String) -> Node{
         let int: nodes::NumNode = nodes::NumNode{
-            left: data.clone()
+            left: data.clone(),
+            inferred: false,
         };
     
         let nodedat: nodes::NodeData = nodes::NodeData {
@@ -1582,6 +1989,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
 
         let pos = Position {
@@ -1590,14 +2003,15 @@ impl<'a> Parser<'a> {
             endcol: self.current.endcol,
         };
     
-        let n: Node = self.create_node(NodeType::I128, nodedat, pos);
+        let n: Node = self.create_node(NodeType::I64, nodedat, pos);
     
         return n;
     }
     
-    fn generate_u128(&mut self, data: String) -> Node{
+    fn generate_u64(&mut self, data: String) -> Node{
         let int: nodes::NumNode = nodes::NumNode{
-            left: data.clone()
+            left: data.clone(),
+            inferred: false,
         };
     
         let nodedat: nodes::NodeData = nodes::NodeData {
@@ -1625,6 +2039,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
 
         let pos = Position {
@@ -1633,17 +2053,117 @@ impl<'a> Parser<'a> {
             endcol: self.current.endcol,
         };
     
-        let n: Node = self.create_node(NodeType::U128, nodedat, pos);
+        let n: Node = self.create_node(NodeType::U64, nodedat, pos);
     
         return n;
     }
     
-    fn generate_as(&mut self, left: Node) -> Node{
-        let mut pos = Position {
-            line: left.pos.line,
-            startcol: left.pos.startcol,
-            endcol: 0,
-        };
+    fn generate_i128(&mut self, data: String) -> Node{
+        let int: nodes::NumNode = nodes::NumNode{
+            left: data.clone(),
+            inferred: false,
+        };
+    
+        let nodedat: nodes::NodeData = nodes::NodeData {
+            binary: None,
+            num: Some(int),
+            letn: None,
+            identifier: None,
+            func: None,
+            assign: None,
+            call: None,
+            ret: None,
+            to: None,
+            unary: None,
+            st: None,
+            initst: None,
+            attr: None,
+            attrassign: None,
+            str: None,
+            arr: None,
+            impln: None,
+            ifn: None,
+            loopn: None,
+            enumn: None,
+            traitn: None,
+            is: None,
+            matchn: None,
+            nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
+        };
+
+        let pos = Position {
+            line: self.current.line,
+            startcol: self.current.startcol,
+            endcol: self.current.endcol,
+        };
+    
+        let n: Node = self.create_node(NodeType::I128, nodedat, pos);
+    
+        return n;
+    }
+    
+    fn generate_u128(&mut self, data: String) -> Node{
+        let int: nodes::NumNode = nodes::NumNode{
+            left: data.clone(),
+            inferred: false,
+        };
+    
+        let nodedat: nodes::NodeData = nodes::NodeData {
+            binary: None,
+            num: Some(int),
+            letn: None,
+            identifier: None,
+            func: None,
+            assign: None,
+            call: None,
+            ret: None,
+            to: None,
+            unary: None,
+            st: None,
+            initst: None,
+            attr: None,
+            attrassign: None,
+            str: None,
+            arr: None,
+            impln: None,
+            ifn: None,
+            loopn: None,
+            enumn: None,
+            traitn: None,
+            is: None,
+            matchn: None,
+            nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
+        };
+
+        let pos = Position {
+            line: self.current.line,
+            startcol: self.current.startcol,
+            endcol: self.current.endcol,
+        };
+    
+        let n: Node = self.create_node(NodeType::U128, nodedat, pos);
+    
+        return n;
+    }
+    
+    fn generate_as(&mut self, left: Node) -> Node{
+        let mut pos = Position {
+            line: left.pos.line,
+            startcol: left.pos.startcol,
+            endcol: 0,
+        };
 
         self.advance();
         
@@ -1681,6 +2201,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
     
         let n: Node = self.create_node(NodeType::AS, nodedat, pos);
@@ -1690,7 +2216,8 @@ impl<'a> Parser<'a> {
     
     fn generate_f32(&mut self, data: String) -> Node{
         let int: nodes::NumNode = nodes::NumNode{
-            left: data.clone()
+            left: data.clone(),
+            inferred: self.is_unsuffixed_literal(),
         };
     
         let nodedat: nodes::NodeData = nodes::NodeData {
@@ -1718,6 +2245,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
 
         let pos = Position {
@@ -1733,7 +2266,8 @@ impl<'a> Parser<'a> {
     
     fn generate_f64(&mut self, data: String) -> Node{
         let int: nodes::NumNode = nodes::NumNode{
-            left: data.clone()
+            left: data.clone(),
+            inferred: false,
         };
     
         let nodedat: nodes::NodeData = nodes::NodeData {
@@ -1761,6 +2295,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
 
         let pos = Position {
@@ -1815,6 +2355,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
 
         self.backadvance();
@@ -1834,9 +2380,11 @@ impl<'a> Parser<'a> {
         };
 
 
-        let op: nodes::UnaryOpType = match self.current.tp {
-            TokenType::HYPHEN => nodes::UnaryOpType::NEG,
-            TokenType::PLUS => nodes::UnaryOpType::POS,
+        let (op, prec): (nodes::UnaryOpType, Precedence) = match self.current.tp {
+            TokenType::HYPHEN => (nodes::UnaryOpType::NEG, Precedence::BitwiseNot),
+            TokenType::PLUS => (nodes::UnaryOpType::POS, Precedence::BitwiseNot),
+            TokenType::EXCLAMATION => (nodes::UnaryOpType::NOT, Precedence::LogicalNot),
+            TokenType::TILDE => (nodes::UnaryOpType::BITNOT, Precedence::BitwiseNot),
             _ => self.raise_error("Invalid token.", ErrorType::InvalidTok),
         };
 
@@ -1844,9 +2392,11 @@ impl<'a> Parser<'a> {
         
         let un: nodes::UnaryNode = nodes::UnaryNode{
             op,
-            right: self.expr(Precedence::Lowest),
+            right: self.expr(prec),
         };
 
+        self.backadvance();
+
         pos.endcol = un.right.pos.endcol;
 
         let nodedat: nodes::NodeData = nodes::NodeData {
@@ -1859,7 +2409,7 @@ impl<'a> Parser<'a> {
             call: None,
             ret: None,
             to: None,
-            unary: None,
+            unary: Some(un),
             st: None,
             initst: None,
             attr: None,
@@ -1874,6 +2424,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
     
         let n: Node = self.create_node(NodeType::UNARY, nodedat, pos);
@@ -1917,6 +2473,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
     
         let n: Node = self.create_node(NodeType::STRING, nodedat, pos);
@@ -1929,12 +2491,11 @@ impl<'a> Parser<'a> {
             self.raise_error("Char literal cannot be empty.", ErrorType::EmptyCharLiteral);
         }
 
-        if data.len() > 4 {
-            self.raise_error("Invalid multibyte sequence (>4 bytes).", ErrorType::UnexpectedMultibyte);
-        }
+        let value: char = self.decode_char_literal(&data);
 
         let int: nodes::NumNode = nodes::NumNode{
-            left: data.clone(),
+            left: value.to_string(),
+            inferred: false,
         };
     
         let nodedat: nodes::NodeData = nodes::NodeData {
@@ -1962,6 +2523,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
 
         let pos = Position {
@@ -1975,42 +2542,66 @@ impl<'a> Parser<'a> {
         return n;
     }
     
-    fn generate_array(&mut self) -> Node{
-        let mut pos = Position {
-            line: self.current.line,
-            startcol: self.current.startcol,
-            endcol: 0,
-        };
-
-        let mut elements: Vec<Node> = Vec::new();
+    //Resolve the escape sequence of a char literal, which must be a single Unicode scalar value
+    fn decode_char_literal(&mut self, data: &String) -> char {
+        let mut chars: std::str::Chars = data.chars();
+        let first: char = chars.next().unwrap();
 
-        self.advance();
+        let value: char = if first != '\\' {
+            first
+        }
+        else {
+            match chars.next() {
+                Some('n') => '\n',
+                Some('t') => '\t',
+                Some('0') => '\0',
+                Some('\\') => '\\',
+                Some('\'') => '\'',
+                Some('u') => {
+                    let rest: String = chars.by_ref().collect();
+                    if !rest.starts_with('{') || !rest.ends_with('}') || rest.len() < 3 || rest.len() > 8 {
+                        self.raise_error("Expected unicode escape of the form '\\u{XXXX}'.", ErrorType::InvalidCharLiteral);
+                    }
 
-        while !self.current_is_type(TokenType::RSQUARE) && !self.current_is_type(TokenType::EOF) {
-            elements.push(self.expr(Precedence::Lowest));
-            self.skip_newline();
-            if !self.current_is_type(TokenType::COMMA) && !self.current_is_type(TokenType::RSQUARE) {
-                self.raise_error("Expected comma.", ErrorType::InvalidTok);
-            }
-            if self.current_is_type(TokenType::RSQUARE) {
-                break;
+                    let code: u32 = match u32::from_str_radix(&rest[1..rest.len()-1], 16) {
+                        Ok(v) => v,
+                        Err(_) => {
+                            self.raise_error("Expected unicode escape of the form '\\u{XXXX}'.", ErrorType::InvalidCharLiteral);
+                        }
+                    };
+
+                    match char::from_u32(code) {
+                        Some(v) => v,
+                        None => {
+                            let fmt: String = format!("'{:#X}' is not a valid Unicode scalar value.", code);
+                            self.raise_error(&fmt, ErrorType::InvalidCodePoint);
+                        }
+                    }
+                }
+                _ => {
+                    let fmt: String = format!("Unknown escape sequence in char literal '{}'.", data);
+                    self.raise_error(&fmt, ErrorType::InvalidCharLiteral);
+                }
             }
-            self.advance();
-            self.skip_newline();
-        }
-        if !self.current_is_type(TokenType::RSQUARE) {
-            self.raise_error("Expected right square bracket.", ErrorType::InvalidTok);
+        };
+
+        if chars.next().is_some() {
+            let fmt: String = format!("Char literal '{}' must contain exactly one character.", data);
+            self.raise_error(&fmt, ErrorType::InvalidCharLiteral);
         }
-        
-        pos.endcol = self.current.endcol;
 
-        let arr: nodes::ArrayNode = nodes::ArrayNode{
-            elements,
+        return value;
+    }
+
+    fn generate_bool(&mut self, data: String) -> Node{
+        let int: nodes::NumNode = nodes::NumNode{
+            left: data.clone(),
+            inferred: false,
         };
     
         let nodedat: nodes::NodeData = nodes::NodeData {
             binary: None,
-            num: None,
+            num: Some(int),
             letn: None,
             identifier: None,
             func: None,
@@ -2024,7 +2615,7 @@ impl<'a> Parser<'a> {
             attr: None,
             attrassign: None,
             str: None,
-            arr: Some(arr),
+            arr: None,
             impln: None,
             ifn: None,
             loopn: None,
@@ -2033,14 +2624,121 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
+        };
+
+        let pos = Position {
+            line: self.current.line,
+            startcol: self.current.startcol,
+            endcol: self.current.endcol,
         };
     
-        let n: Node = self.create_node(NodeType::ARRAY, nodedat, pos);
+        let n: Node = self.create_node(NodeType::BOOL, nodedat, pos);
     
         return n;
     }
+    
+    fn generate_closure(&mut self, ismove: bool) -> Node{
+        let mut pos = Position {
+            line: self.current.line,
+            startcol: self.current.startcol,
+            endcol: 0,
+        };
+
+        if ismove {
+            self.advance();
+            if !self.current_is_type(TokenType::PIPE) && !self.current_is_type(TokenType::DOUBLEPIPE) {
+                self.raise_error("Expected closure.", ErrorType::InvalidTok);
+            }
+        }
+
+        let mut args: Args = Args {
+            name: Vec::new(),
+            args: Vec::new(),
+            rettp: Vec::new(),
+        };
+
+        //No arguments are lexed as '||'
+        if self.current_is_type(TokenType::PIPE) {
+            self.advance();
+            while !self.current_is_type(TokenType::PIPE) && !self.current_is_type(TokenType::EOF) {
+                let mut mutability: DataMutablility = DataMutablility::Immutable;
+                if self.current_is_type(TokenType::KEYWORD) && self.current.data == "mut" {
+                    self.advance();
+                    mutability = DataMutablility::Mutable;
+                }
+
+                if !self.current_is_type(TokenType::IDENTIFIER) {
+                    self.raise_error("Expected identifier.", ErrorType::InvalidTok);
+                }
+                let name: String = self.current.data.clone();
+                self.advance();
+
+                if !self.current_is_type(TokenType::COLON) {
+                    self.raise_error("Expected colon.", ErrorType::InvalidTok);
+                }
+                self.advance();
+
+                args.args.push(self.parse_type(mutability).1);
+                if !self.current_is_type(TokenType::COMMA) && !self.current_is_type(TokenType::PIPE) {
+                    self.raise_error("Expected comma.", ErrorType::InvalidTok);
+                }
+
+                args.name.push(name);
+
+                if self.current_is_type(TokenType::PIPE) {
+                    break;
+                }
+
+                self.advance();
+            }
+
+            if !self.current_is_type(TokenType::PIPE) {
+                self.raise_error("Expected pipe.", ErrorType::InvalidTok);
+            }
+        }
+
+        self.advance();
+
+        //A closure with a return type must have a block body. Otherwise, the return type is inferred from the body.
+        let blocks: Vec<Node> = if self.current_is_type(TokenType::SMALLARROW) || self.current_is_type(TokenType::LCURLY) {
+            if self.current_is_type(TokenType::SMALLARROW) {
+                self.advance();
+                args.rettp.push(self.parse_type(DataMutablility::Immutable).1);
+                self.skip_newline();
+            }
+
+            if !self.current_is_type(TokenType::LCURLY) {
+                self.raise_error("Expected left curly bracket.", ErrorType::InvalidTok);
+            }
+
+            self.advance();
+
+            let blocks: Vec<Node> = self.block();
+            if !self.current_is_type(TokenType::RCURLY) {
+                self.raise_error("Expected right curly bracket.", ErrorType::InvalidTok);
+            }
+            blocks
+        }
+        else {
+            let expr: Node = self.expr(Precedence::Lowest);
+            self.backadvance();
+            vec![expr]
+        };
+
+        pos.endcol = self.current.endcol;
+
+        let closure: nodes::ClosureNode = nodes::ClosureNode{
+            args,
+            blocks,
+            ismove,
+        };
 
-    fn generate_void(&mut self) -> Node {
         let nodedat: nodes::NodeData = nodes::NodeData {
             binary: None,
             num: None,
@@ -2066,29 +2764,145 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: Some(closure),
         };
 
+        let n: Node = self.create_node(NodeType::CLOSURE, nodedat, pos);
     
-        let n: Node = self.create_node(NodeType::VOID, nodedat, Position {startcol: self.current.startcol, endcol: self.current.endcol, line: self.current.line } );
-
-        return n;   
+        return n;
     }
     
-    fn generate_is(&mut self, left: Node) -> Node{
+    fn generate_array(&mut self) -> Node{
         let mut pos = Position {
-            line: left.pos.line,
-            startcol: left.pos.startcol,
+            line: self.current.line,
+            startcol: self.current.startcol,
             endcol: 0,
         };
 
+        let mut elements: Vec<Node> = Vec::new();
+
         self.advance();
-        
-        let expr: Node = self.expr(Precedence::Lowest);
 
-        if expr.tp != NodeType::NAMESPACE && expr.tp != NodeType::GENERICENUM {
-            self.raise_error("Expected namespace or enum attribute access.", ErrorType::InvalidTok);
+        while !self.current_is_type(TokenType::RSQUARE) && !self.current_is_type(TokenType::EOF) {
+            elements.push(self.expr(Precedence::Lowest));
+            self.skip_newline();
+            if !self.current_is_type(TokenType::COMMA) && !self.current_is_type(TokenType::RSQUARE) {
+                self.raise_error("Expected comma.", ErrorType::InvalidTok);
+            }
+            if self.current_is_type(TokenType::RSQUARE) {
+                break;
+            }
+            self.advance();
+            self.skip_newline();
         }
-
+        if !self.current_is_type(TokenType::RSQUARE) {
+            self.raise_error("Expected right square bracket.", ErrorType::InvalidTok);
+        }
+        
+        pos.endcol = self.current.endcol;
+
+        let arr: nodes::ArrayNode = nodes::ArrayNode{
+            elements,
+        };
+    
+        let nodedat: nodes::NodeData = nodes::NodeData {
+            binary: None,
+            num: None,
+            letn: None,
+            identifier: None,
+            func: None,
+            assign: None,
+            call: None,
+            ret: None,
+            to: None,
+            unary: None,
+            st: None,
+            initst: None,
+            attr: None,
+            attrassign: None,
+            str: None,
+            arr: Some(arr),
+            impln: None,
+            ifn: None,
+            loopn: None,
+            enumn: None,
+            traitn: None,
+            is: None,
+            matchn: None,
+            nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
+        };
+    
+        let n: Node = self.create_node(NodeType::ARRAY, nodedat, pos);
+    
+        return n;
+    }
+
+    fn generate_void(&mut self) -> Node {
+        let nodedat: nodes::NodeData = nodes::NodeData {
+            binary: None,
+            num: None,
+            letn: None,
+            identifier: None,
+            func: None,
+            assign: None,
+            call: None,
+            ret: None,
+            to: None,
+            unary: None,
+            st: None,
+            initst: None,
+            attr: None,
+            attrassign: None,
+            str: None,
+            arr: None,
+            impln: None,
+            ifn: None,
+            loopn: None,
+            enumn: None,
+            traitn: None,
+            is: None,
+            matchn: None,
+            nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
+        };
+
+    
+        let n: Node = self.create_node(NodeType::VOID, nodedat, Position {startcol: self.current.startcol, endcol: self.current.endcol, line: self.current.line } );
+
+        return n;   
+    }
+    
+    fn generate_is(&mut self, left: Node) -> Node{
+        let mut pos = Position {
+            line: left.pos.line,
+            startcol: left.pos.startcol,
+            endcol: 0,
+        };
+
+        self.advance();
+        
+        let expr: Node = self.expr(Precedence::Lowest);
+
+        if expr.tp != NodeType::NAMESPACE && expr.tp != NodeType::GENERICENUM {
+            self.raise_error("Expected namespace or enum attribute access.", ErrorType::InvalidTok);
+        }
+
         pos.endcol = expr.pos.endcol;
 
         let is: nodes::IsNode = nodes::IsNode{
@@ -2121,6 +2935,12 @@ impl<'a> Parser<'a> {
             is: Some(is),
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
     
         let n: Node = self.create_node(NodeType::IS, nodedat, pos);
@@ -2146,13 +2966,23 @@ impl<'a> Parser<'a> {
             mutability = DataMutablility::Mutable;
         }
 
-        if !self.current_is_type(TokenType::IDENTIFIER) {
-            self.raise_error("Expected identifier.", ErrorType::InvalidTok);
+        let name: String;
+        let mut destructure: Option<Vec<(String, DataMutablility)>> = None;
+
+        if self.current_is_type(TokenType::LPAREN) && mutability == DataMutablility::Immutable {
+            let names: Vec<(String, DataMutablility)> = self.parse_tuple_pattern();
+            name = Self::tuple_pattern_repr(&names);
+            destructure = Some(names);
         }
+        else {
+            if !self.current_is_type(TokenType::IDENTIFIER) {
+                self.raise_error("Expected identifier.", ErrorType::InvalidTok);
+            }
 
-        let name: String = self.current.data.clone();
+            name = self.current.data.clone();
 
-        self.advance();
+            self.advance();
+        }
         
         if self.current_is_type(TokenType::COLON) {
             self.advance();
@@ -2160,6 +2990,10 @@ impl<'a> Parser<'a> {
             tp=Some(self.parse_type(mutability).1);
         }
 
+        if destructure.is_some() && !self.current_is_type(TokenType::EQUALS) {
+            self.raise_error("Expected equals.", ErrorType::InvalidTok);
+        }
+
         let mut expr: Option<Node> = None;
 
         if self.current_is_type(TokenType::EQUALS) {
@@ -2178,6 +3012,7 @@ impl<'a> Parser<'a> {
             expr,
             mutability,
             tp,
+            destructure,
         };
     
         let nodedat: nodes::NodeData = nodes::NodeData {
@@ -2205,6 +3040,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
         
         if nodedat.letn.as_ref().unwrap().expr.is_some() {
@@ -2216,6 +3057,132 @@ impl<'a> Parser<'a> {
         return n;        
     }
 
+    //Parse a 'const' or 'static' item, which always has a type and a value
+    fn parse_global(&mut self, tp: NodeType) -> Node{
+        let mut pos = Position {
+            line: self.current.line,
+            startcol: self.current.startcol,
+            endcol: 0,
+        };
+
+        self.advance();
+        
+        let mut mutability: DataMutablility = DataMutablility::Immutable;
+        if tp == NodeType::STATIC && self.current_is_type(TokenType::KEYWORD) && self.current.data == "mut" {
+            self.advance();
+            mutability = DataMutablility::Mutable;
+        }
+
+        if !self.current_is_type(TokenType::IDENTIFIER) {
+            self.raise_error("Expected identifier.", ErrorType::InvalidTok);
+        }
+
+        let name: String = self.current.data.clone();
+
+        self.advance();
+        
+        if !self.current_is_type(TokenType::COLON) {
+            self.raise_error("Expected colon.", ErrorType::InvalidTok);
+        }
+
+        self.advance();
+
+        let globaltp: Type = self.parse_type(mutability).1;
+
+        if !self.current_is_type(TokenType::EQUALS) {
+            self.raise_error("Expected equals.", ErrorType::InvalidTok);
+        }
+
+        self.advance();
+
+        let expr: Node = self.expr(Precedence::Lowest);
+        pos.endcol = expr.pos.endcol;
+
+        let letn: nodes::LetNode = nodes::LetNode{
+            name,
+            expr: Some(expr),
+            mutability,
+            tp: Some(globaltp),
+            destructure: None,
+        };
+    
+        let nodedat: nodes::NodeData = nodes::NodeData {
+            binary: None,
+            num: None,
+            letn: Some(letn),
+            identifier: None,
+            func: None,
+            assign: None,
+            call: None,
+            ret: None,
+            to: None,
+            unary: None,
+            st: None,
+            initst: None,
+            attr: None,
+            attrassign: None,
+            str: None,
+            arr: None,
+            impln: None,
+            ifn: None,
+            loopn: None,
+            enumn: None,
+            traitn: None,
+            is: None,
+            matchn: None,
+            nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
+        };
+    
+        let n: Node = self.create_node(tp, nodedat, pos);
+
+        return n;        
+    }
+
+    //Parse the names of a tuple destructuring, like '(mut a, b)'
+    fn parse_tuple_pattern(&mut self) -> Vec<(String, DataMutablility)> {
+        self.advance();
+
+        let mut names: Vec<(String, DataMutablility)> = Vec::new();
+
+        while !self.current_is_type(TokenType::RPAREN) && !self.current_is_type(TokenType::EOF) {
+            let mut mutability: DataMutablility = DataMutablility::Immutable;
+            if self.current_is_type(TokenType::KEYWORD) && self.current.data == "mut" {
+                self.advance();
+                mutability = DataMutablility::Mutable;
+            }
+
+            if !self.current_is_type(TokenType::IDENTIFIER) {
+                self.raise_error("Expected identifier.", ErrorType::InvalidTok);
+            }
+            names.push((self.current.data.clone(), mutability));
+            self.advance();
+
+            if self.current_is_type(TokenType::RPAREN) {
+                break;
+            }
+            if !self.current_is_type(TokenType::COMMA) {
+                self.raise_error("Expected comma.", ErrorType::InvalidTok);
+            }
+            self.advance();
+        }
+        if !self.current_is_type(TokenType::RPAREN) {
+            self.raise_error("Expected right parenthesis.", ErrorType::InvalidTok);
+        }
+        self.advance();
+
+        return names;
+    }
+
+    fn tuple_pattern_repr(names: &Vec<(String, DataMutablility)>) -> String {
+        return String::from("(") + &names.iter().map(|x| x.0.clone()).collect::<Vec<String>>().join(", ") + ")";
+    }
+
     fn parse_type(&mut self, mutability: DataMutablility) -> (usize, Type){
         if self.current_is_type(TokenType::KEYWORD) && self.current.data == "dyn" {
             self.advance();
@@ -2227,10 +3194,12 @@ impl<'a> Parser<'a> {
             self.advance();
             return (end, Type {
                 isfn: false,
+                isclosure: false,
                 isarr: false,
                 isdyn: true,
                 isgenum: false,
                 isref: false,
+                istuple: false,
                 basetp: None,
                 arrlen: None,
                 data: Some(name),
@@ -2249,16 +3218,132 @@ impl<'a> Parser<'a> {
             
             return (end, Type {
                 isfn: false,
+                isclosure: false,
                 isarr: false,
                 isdyn: false,
                 isgenum: false,
                 isref: true,
+                istuple: false,
                 basetp: Some(Box::new(tp)),
                 arrlen: None,
                 data: None,
-                args: None,
-                mutability: DataMutablility::Immutable,
-                refmutability: Some(mutability),
+                args: None,
+                mutability: DataMutablility::Immutable,
+                refmutability: Some(mutability),
+                generic_tps: None,
+            });
+        }
+        else if self.current_is_type(TokenType::LPAREN) {
+            self.advance();
+
+            let mut tps: Vec<Type> = Vec::new();
+
+            while !self.current_is_type(TokenType::RPAREN) && !self.current_is_type(TokenType::EOF) {
+                tps.push(self.parse_type(DataMutablility::Immutable).1);
+                if self.current_is_type(TokenType::RPAREN) {
+                    break;
+                }
+                if !self.current_is_type(TokenType::COMMA) {
+                    self.raise_error("Expected comma.", ErrorType::InvalidTok);
+                }
+                self.advance();
+            }
+            if !self.current_is_type(TokenType::RPAREN) {
+                self.raise_error("Expected right parenthesis.", ErrorType::InvalidTok);
+            }
+            let end: usize = self.current.endcol;
+            self.advance();
+
+            //The element types of a tuple are stored as its generic types
+            return (end, Type {
+                isfn: false,
+                isclosure: false,
+                isarr: false,
+                isdyn: false,
+                isgenum: false,
+                isref: false,
+                istuple: true,
+                basetp: None,
+                arrlen: None,
+                data: Some(String::from("tuple")),
+                args: None,
+                mutability,
+                refmutability: None,
+                generic_tps: Some(tps),
+            });
+        }
+        else if self.current_is_type(TokenType::PIPE) || self.current_is_type(TokenType::DOUBLEPIPE) {
+            let mut args_: Args = Args {
+                name: Vec::new(),
+                args: Vec::new(),
+                rettp: Vec::new(),
+            };
+
+            //No arguments are lexed as '||'
+            if self.current_is_type(TokenType::PIPE) {
+                self.advance();
+                while !self.current_is_type(TokenType::PIPE) && !self.current_is_type(TokenType::EOF) {
+                    let mut mutability: DataMutablility = DataMutablility::Immutable;
+                    if self.current_is_type(TokenType::KEYWORD) && self.current.data == "mut" {
+                        self.advance();
+                        mutability = DataMutablility::Mutable;
+                    }
+                    args_.args.push(self.parse_type(mutability).1);
+                    if self.current_is_type(TokenType::PIPE) {
+                        break;
+                    }
+                    if !self.current_is_type(TokenType::COMMA) {
+                        self.raise_error("Expected comma.", ErrorType::InvalidTok);
+                    }
+                    self.advance();
+                }
+                if !self.current_is_type(TokenType::PIPE) {
+                    self.raise_error("Expected pipe.", ErrorType::InvalidTok);
+                }
+            }
+
+            let mut end: usize = self.current.endcol;
+
+            self.advance();
+
+            if self.current_is_type(TokenType::SMALLARROW) {
+                self.advance();
+                end = self.current.endcol;
+                args_.rettp.push(self.parse_type(DataMutablility::Immutable).1);
+            }
+            else {
+                args_.rettp.push(Type {
+                    isfn: false,
+                    isclosure: false,
+                    isarr: false,
+                    isdyn: false,
+                    isgenum: false,
+                    isref: false,
+                    istuple: false,
+                    basetp: None,
+                    arrlen: None,
+                    data: Some(String::from("void")),
+                    args: None,
+                    mutability: DataMutablility::Immutable,
+                    refmutability: None,
+                    generic_tps: None,
+                });
+            }
+
+            return (end, Type {
+                isfn: true,
+                isclosure: true,
+                isarr: false,
+                isdyn: false,
+                isgenum: false,
+                isref: false,
+                istuple: false,
+                basetp: None,
+                arrlen: None,
+                data: None,
+                args: Some(args_),
+                mutability,
+                refmutability: None,
                 generic_tps: None,
             });
         }
@@ -2310,10 +3395,12 @@ impl<'a> Parser<'a> {
             else {
                 args_.rettp.push(Type {
                     isfn: false,
+                    isclosure: false,
                     isarr: false,
                     isdyn: false,
                     isgenum: false,
                     isref: false,
+                    istuple: false,
                     basetp: None,
                     arrlen: None,
                     data: Some(String::from("void")),
@@ -2326,10 +3413,12 @@ impl<'a> Parser<'a> {
 
             return (end, Type {
                 isfn: true,
+                isclosure: false,
                 isarr: false,
                 isdyn: false,
                 isgenum: false,
                 isref: false,
+                istuple: false,
                 basetp: None,
                 arrlen: None,
                 data: None,
@@ -2342,10 +3431,12 @@ impl<'a> Parser<'a> {
         else if self.next_is_type(TokenType::LSQUARE) {
             let basetp: Type = Type {
                 isfn: false,
+                isclosure: false,
                 isarr: false,
                 isdyn: false,
                 isgenum: false,
                 isref: false,
+                istuple: false,
                 basetp: None,
                 arrlen: None,
                 data: Some(tp),
@@ -2357,16 +3448,11 @@ impl<'a> Parser<'a> {
             
             self.advance();
 
-            let mut len: Vec<String> = Vec::new();
+            let mut len: Vec<Node> = Vec::new();
 
             while self.current_is_type(TokenType::LSQUARE) {
                 self.advance();
-                if  !(self.current_is_type(TokenType::I32) &&
-                    self.current.data.chars().nth(0).unwrap() != '-') {
-                    self.raise_error("Expected positive integer.", ErrorType::InvalidTok);
-                }
-                len.push(self.current.data.clone());
-                self.advance();
+                len.push(self.expr(Precedence::Lowest));
                 if !self.current_is_type(TokenType::RSQUARE) {
                     self.raise_error("Expected right square bracket.", ErrorType::InvalidTok);
                 }
@@ -2377,10 +3463,12 @@ impl<'a> Parser<'a> {
 
             return (end, Type {
                 isfn: false,
+                isclosure: false,
                 isarr: true,
                 isdyn: false,
                 isgenum: false,
                 isref: false,
+                istuple: false,
                 basetp: Some(Box::new(basetp)),
                 arrlen: Some(len),
                 data: None,
@@ -2393,10 +3481,12 @@ impl<'a> Parser<'a> {
         else if self.next_is_type(TokenType::LT) {
             let basetp: Type = Type {
                 isfn: false,
+                isclosure: false,
                 isarr: false,
                 isdyn: false,
                 isgenum: false,
                 isref: false,
+                istuple: false,
                 basetp: None,
                 arrlen: None,
                 data: Some(tp),
@@ -2430,10 +3520,12 @@ impl<'a> Parser<'a> {
 
             return (end, Type {
                 isfn: false,
+                isclosure: false,
                 isarr: false,
                 isdyn: false,
                 isgenum: true,
                 isref: false,
+                istuple: false,
                 basetp: Some(Box::new(basetp)),
                 arrlen: None,
                 data: None,
@@ -2444,14 +3536,29 @@ impl<'a> Parser<'a> {
             });
         }
         else {
-            let end: usize = self.current.endcol;
+            let mut tp: String = tp;
+            let mut end: usize = self.current.endcol;
             self.advance();
+
+            //An associated type of a struct or template type
+            if self.current_is_type(TokenType::DOUBLECOLON) {
+                self.advance();
+                if !self.current_is_type(TokenType::IDENTIFIER) {
+                    self.raise_error("Expected identifier.", ErrorType::InvalidTok);
+                }
+                tp = tp + "::" + self.current.data.as_str();
+                end = self.current.endcol;
+                self.advance();
+            }
+
             return (end, Type {
                 isfn: false,
+                isclosure: false,
                 isarr: false,
                 isdyn: false,
                 isgenum: false,
                 isref: false,
+                istuple: false,
                 basetp: None,
                 arrlen: None,
                 data: Some(tp),
@@ -2480,6 +3587,7 @@ impl<'a> Parser<'a> {
         let mut methodname: Option<String> = None;
         let mut namespacename: Option<String> = None;
         let mut template_types: Vec<String> = Vec::new();
+        let mut template_bounds: std::collections::HashMap<String, Vec<String>> = std::collections::HashMap::new();
 
         self.advance();
 
@@ -2504,10 +3612,31 @@ impl<'a> Parser<'a> {
         if self.current_is_type(TokenType::LT) {
             self.advance();
             while self.current_is_type(TokenType::IDENTIFIER) {
-                template_types.push(self.current.data.clone());
+                let template: String = self.current.data.clone();
+                template_types.push(template.clone());
 
                 self.advance();
 
+                if self.current_is_type(TokenType::COLON) {
+                    let mut bounds: Vec<String> = Vec::new();
+                    self.advance();
+                    
+                    while self.current_is_type(TokenType::IDENTIFIER) {
+                        bounds.push(self.current.data.clone());
+                        self.advance();
+
+                        if !self.current_is_type(TokenType::PLUS) {
+                            break;
+                        }
+                        self.advance();
+                    }
+
+                    if bounds.len() == 0 {
+                        self.raise_error("Expected trait.", ErrorType::InvalidTok);
+                    }
+                    template_bounds.insert(template, bounds);
+                }
+
                 if !self.current_is_type(TokenType::COMMA) && !self.current_is_type(TokenType::GT) {
                     self.raise_error("Expected comma.", ErrorType::InvalidTok);
                 }
@@ -2532,6 +3661,7 @@ impl<'a> Parser<'a> {
             args: Vec::new(),
             rettp: Vec::new(),
         };
+        let mut destructured: Vec<(String, Vec<(String, DataMutablility)>, Position)> = Vec::new();
         while !self.current_is_type(TokenType::RPAREN) && !self.current_is_type(TokenType::EOF) {
             let mut mutability: DataMutablility = DataMutablility::Immutable;
             if self.current_is_type(TokenType::KEYWORD) && self.current.data == "mut" {
@@ -2539,13 +3669,30 @@ impl<'a> Parser<'a> {
                 mutability = DataMutablility::Mutable;
             }
 
-            if !self.current_is_type(TokenType::IDENTIFIER) {
-                self.raise_error("Expected identifier.", ErrorType::InvalidTok);
+            let name: String;
+            //Destructured arguments are passed under the name of their pattern, and destructured at the start of the body
+            if self.current_is_type(TokenType::LPAREN) && mutability == DataMutablility::Immutable {
+                let mut argpos: Position = Position {
+                    line: self.current.line,
+                    startcol: self.current.startcol,
+                    endcol: 0,
+                };
+                let names: Vec<(String, DataMutablility)> = self.parse_tuple_pattern();
+                self.backadvance();
+                argpos.endcol = self.current.endcol;
+                self.advance();
+                name = Self::tuple_pattern_repr(&names);
+                destructured.push((name.clone(), names, argpos));
+            }
+            else {
+                if !self.current_is_type(TokenType::IDENTIFIER) {
+                    self.raise_error("Expected identifier.", ErrorType::InvalidTok);
+                }
+        
+                name = self.current.data.clone();
+                
+                self.advance();
             }
-    
-            let name: String = self.current.data.clone();
-            
-            self.advance();
 
             if !self.current_is_type(TokenType::COLON) {
                 self.raise_error("Expected colon.", ErrorType::InvalidTok);
@@ -2594,10 +3741,12 @@ impl<'a> Parser<'a> {
         else {
             args.rettp.push(Type {
                 isfn: false,
+                isclosure: false,
                 isarr: false,
                 isdyn: false,
                 isgenum: false,
                 isref: false,
+                istuple: false,
                 basetp: None,
                 arrlen: None,
                 data: Some(String::from("void")),
@@ -2618,13 +3767,17 @@ impl<'a> Parser<'a> {
 
         self.skip_newline();
 
-        let blocks: Vec<Node> = self.block();
+        let mut blocks: Vec<Node> = self.block();
         if !self.current_is_type(TokenType::RCURLY) {
             self.raise_error("Expected right curly bracket.", ErrorType::InvalidTok);
         }
         
         self.advance();
 
+        for (name, names, argpos) in destructured.into_iter().rev() {
+            blocks.insert(0, self.generate_destructure_arg(name, names, argpos));
+        }
+
         let func: nodes::FuncNode = nodes::FuncNode{
             name,
             blocks,
@@ -2632,6 +3785,8 @@ impl<'a> Parser<'a> {
             methodname,
             namespacename,
             template_types,
+            template_bounds,
+            isconst: false,
         };
 
         let nodedat: nodes::NodeData = nodes::NodeData {
@@ -2659,6 +3814,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
 
         let n: Node = self.create_node(NodeType::FUNC, nodedat, pos);
@@ -2666,6 +3827,91 @@ impl<'a> Parser<'a> {
         return n; 
     }
 
+    //Generate the let statement destructuring a tuple argument
+    fn generate_destructure_arg(&self, name: String, names: Vec<(String, DataMutablility)>, pos: Position) -> Node {
+        let identifier: nodes::IdentifierNode = nodes::IdentifierNode {
+            name: name.clone(),
+        };
+
+        let nodedat: nodes::NodeData = nodes::NodeData {
+            binary: None,
+            num: None,
+            letn: None,
+            identifier: Some(identifier),
+            func: None,
+            assign: None,
+            call: None,
+            ret: None,
+            to: None,
+            unary: None,
+            st: None,
+            initst: None,
+            attr: None,
+            attrassign: None,
+            str: None,
+            arr: None,
+            impln: None,
+            ifn: None,
+            loopn: None,
+            enumn: None,
+            traitn: None,
+            is: None,
+            matchn: None,
+            nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
+        };
+
+        let expr: Node = self.create_node(NodeType::IDENTIFIER, nodedat, pos.clone());
+
+        let letn: nodes::LetNode = nodes::LetNode{
+            name,
+            expr: Some(expr),
+            mutability: DataMutablility::Immutable,
+            tp: None,
+            destructure: Some(names),
+        };
+
+        let nodedat: nodes::NodeData = nodes::NodeData {
+            binary: None,
+            num: None,
+            letn: Some(letn),
+            identifier: None,
+            func: None,
+            assign: None,
+            call: None,
+            ret: None,
+            to: None,
+            unary: None,
+            st: None,
+            initst: None,
+            attr: None,
+            attrassign: None,
+            str: None,
+            arr: None,
+            impln: None,
+            ifn: None,
+            loopn: None,
+            enumn: None,
+            traitn: None,
+            is: None,
+            matchn: None,
+            nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
+        };
+
+        return self.create_node(NodeType::LET, nodedat, pos);
+    }
+
     fn parse_return(&mut self) -> Node{
         let mut pos = Position {
             line: self.current.line,
@@ -2713,6 +3959,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
 
         if nodedat.ret.as_ref().unwrap().expr.is_some() {
@@ -2746,6 +3998,25 @@ impl<'a> Parser<'a> {
 
         self.advance();
 
+        let mut template_types: Vec<String> = Vec::new();
+        if self.current_is_type(TokenType::LT) {
+            self.advance();
+            while self.current_is_type(TokenType::IDENTIFIER) {
+                template_types.push(self.current.data.clone());
+
+                self.advance();
+
+                if !self.current_is_type(TokenType::COMMA) && !self.current_is_type(TokenType::GT) {
+                    self.raise_error("Expected comma.", ErrorType::InvalidTok);
+                }
+                self.advance();
+
+                if self.current_is_type(TokenType::GT) {
+                    self.advance();
+                    break;
+                }
+            }
+        }
         
         self.skip_newline();
 
@@ -2814,6 +4085,8 @@ impl<'a> Parser<'a> {
             name,
             names,
             members,
+            template_types,
+            derives: Vec::new(),
         };
     
         let nodedat: nodes::NodeData = nodes::NodeData {
@@ -2841,6 +4114,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
 
     
@@ -2893,6 +4172,8 @@ impl<'a> Parser<'a> {
         self.skip_newline();
 
         let mut functions: Vec<Node> = Vec::new();
+        let mut consts: Vec<Node> = Vec::new();
+        let mut types: Vec<Node> = Vec::new();
 
         while !self.current_is_type(TokenType::RCURLY) && !self.current_is_type(TokenType::EOF) {
             if !self.current_is_type(TokenType::KEYWORD) && self.current.data == "fn" {
@@ -2903,7 +4184,15 @@ impl<'a> Parser<'a> {
 
             self.skip_newline();
 
-            functions.push(stmt);            
+            if stmt.tp == NodeType::CONST {
+                consts.push(stmt);
+            }
+            else if stmt.tp == NodeType::TYPEALIAS {
+                types.push(stmt);
+            }
+            else {
+                functions.push(stmt);
+            }
         
             if self.current_is_type(TokenType::RCURLY) {
                 break;
@@ -2920,6 +4209,8 @@ impl<'a> Parser<'a> {
 
         let impln: nodes::ImplNode = nodes::ImplNode{
             functions,
+            consts,
+            types,
             traitnm,
             structnm,
         };
@@ -2949,6 +4240,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
 
     
@@ -3090,6 +4387,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
 
     
@@ -3130,6 +4433,7 @@ impl<'a> Parser<'a> {
         let loopn: nodes::LoopNode = nodes::LoopNode{
             block,
             expr: None,
+            label: None,
         };
     
         let nodedat: nodes::NodeData = nodes::NodeData {
@@ -3157,6 +4461,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
 
     
@@ -3166,13 +4476,39 @@ impl<'a> Parser<'a> {
     }
     
     fn parse_break(&mut self) -> Node{
-        let int: nodes::NumNode = nodes::NumNode{
-            left: String::from("")
+        let mut pos = Position {
+            line: self.current.line,
+            startcol: self.current.startcol,
+            endcol: self.current.endcol,
+        };
+
+        self.advance();
+
+        let mut label: Option<String> = None;
+        if self.current_is_type(TokenType::LABEL) {
+            label = Some(self.current.data.clone());
+            pos.endcol = self.current.endcol;
+            self.advance();
+        }
+
+        let mut expr: Option<Node> = None;
+        if  !self.current_is_type(TokenType::NEWLINE) &&
+            !self.current_is_type(TokenType::SEMICOLON) &&
+            !self.current_is_type(TokenType::RCURLY) &&
+            !self.current_is_type(TokenType::EOF) {
+            let value: Node = self.expr(Precedence::Lowest);
+            pos.endcol = value.pos.endcol;
+            expr = Some(value);
+        }
+
+        let breakn: nodes::BreakNode = nodes::BreakNode{
+            label,
+            expr,
         };
     
         let nodedat: nodes::NodeData = nodes::NodeData {
             binary: None,
-            num: Some(int),
+            num: None,
             letn: None,
             identifier: None,
             func: None,
@@ -3195,29 +4531,158 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: Some(breakn),
+            alias: None,
+            closure: None,
         };
-
-        let pos = Position {
+    
+        let n: Node = self.create_node(NodeType::BREAK, nodedat, pos);
+    
+        return n;
+    }
+    
+    fn parse_continue(&mut self) -> Node{
+        let mut pos = Position {
             line: self.current.line,
             startcol: self.current.startcol,
             endcol: self.current.endcol,
         };
+
+        self.advance();
+
+        let mut label: Option<String> = None;
+        if self.current_is_type(TokenType::LABEL) {
+            label = Some(self.current.data.clone());
+            pos.endcol = self.current.endcol;
+            self.advance();
+        }
+
+        let breakn: nodes::BreakNode = nodes::BreakNode{
+            label,
+            expr: None,
+        };
     
-        let n: Node = self.create_node(NodeType::BREAK, nodedat, pos);
+        let nodedat: nodes::NodeData = nodes::NodeData {
+            binary: None,
+            num: None,
+            letn: None,
+            identifier: None,
+            func: None,
+            assign: None,
+            call: None,
+            ret: None,
+            to: None,
+            unary: None,
+            st: None,
+            initst: None,
+            attr: None,
+            attrassign: None,
+            str: None,
+            arr: None,
+            impln: None,
+            ifn: None,
+            loopn: None,
+            enumn: None,
+            traitn: None,
+            is: None,
+            matchn: None,
+            nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: Some(breakn),
+            alias: None,
+            closure: None,
+        };
+    
+        let n: Node = self.create_node(NodeType::CONTINUE, nodedat, pos);
+    
+        return n;
+    }
+
+    fn parse_label(&mut self) -> Node{
+        let label: String = self.current.data.clone();
+
+        self.advance();
+
+        if !self.current_is_type(TokenType::COLON) {
+            self.raise_error("Expected colon.", ErrorType::InvalidTok);
+        }
+
+        self.advance();
+
+        if !self.current_is_type(TokenType::KEYWORD) {
+            self.raise_error("Expected 'loop', 'while', or 'for'.", ErrorType::InvalidTok);
+        }
+
+        let mut n: Node = if self.current.data == "loop" {
+            self.parse_loop()
+        }
+        else if self.current.data == "while" {
+            self.parse_while()
+        }
+        else if self.current.data == "for" {
+            self.parse_for()
+        }
+        else {
+            self.raise_error("Expected 'loop', 'while', or 'for'.", ErrorType::InvalidTok);
+        };
+
+        if n.tp == NodeType::FOR {
+            n.data.forn.as_mut().unwrap().label = Some(label);
+        }
+        else {
+            n.data.loopn.as_mut().unwrap().label = Some(label);
+        }
+
+        return n;
+    }
+
+    fn parse_while(&mut self) -> Node{
+        let mut pos = Position {
+            line: self.current.line,
+            startcol: self.current.startcol,
+            endcol: 0,
+        };
+
+        self.advance();
+
+        let expr: Node = self.expr(Precedence::Lowest);
+        
+        pos.endcol = expr.pos.endcol;
+        
+        self.skip_newline();
+    
+        if !self.current_is_type(TokenType::LCURLY) {
+            self.raise_error("Expected left curly bracket.", ErrorType::InvalidTok);
+        }
+        
+        self.advance();
+
+        self.skip_newline();
+        
+        let block: Vec<Node> = self.block();
+
+        self.skip_newline();
+        
+        if !self.current_is_type(TokenType::RCURLY) {
+            self.raise_error("Expected right curly bracket.", ErrorType::InvalidTok);
+        }
 
         self.advance();
-    
-        return n;
-    }
-    
-    fn parse_continue(&mut self) -> Node{
-        let int: nodes::NumNode = nodes::NumNode{
-            left: String::from("")
+
+        let loopn: nodes::LoopNode = nodes::LoopNode{
+            block,
+            expr: Some(expr),
+            label: None,
         };
     
         let nodedat: nodes::NodeData = nodes::NodeData {
             binary: None,
-            num: Some(int),
+            num: None,
             letn: None,
             identifier: None,
             func: None,
@@ -3234,28 +4699,27 @@ impl<'a> Parser<'a> {
             arr: None,
             impln: None,
             ifn: None,
-            loopn: None,
+            loopn: Some(loopn),
             enumn: None,
             traitn: None,
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
 
-        let pos = Position {
-            line: self.current.line,
-            startcol: self.current.startcol,
-            endcol: self.current.endcol,
-        };
     
-        let n: Node = self.create_node(NodeType::CONTINUE, nodedat, pos);
+        let n: Node = self.create_node(NodeType::WHILE, nodedat, pos);
 
-        self.advance();
-    
-        return n;
+        return n;        
     }
 
-    fn parse_while(&mut self) -> Node{
+    fn parse_for(&mut self) -> Node{
         let mut pos = Position {
             line: self.current.line,
             startcol: self.current.startcol,
@@ -3264,7 +4728,23 @@ impl<'a> Parser<'a> {
 
         self.advance();
 
+        if !self.current_is_type(TokenType::IDENTIFIER) {
+            self.raise_error("Expected identifier.", ErrorType::InvalidTok);
+        }
+
+        let name: String = self.current.data.clone();
+
+        self.advance();
+
+        if !self.current_is_type(TokenType::KEYWORD) || self.current.data != "in" {
+            self.raise_error("Expected 'in'.", ErrorType::InvalidTok);
+        }
+
+        self.advance();
+
+        self.allow_init.set_structinit_disallow();
         let expr: Node = self.expr(Precedence::Lowest);
+        self.allow_init.restore();
         
         pos.endcol = expr.pos.endcol;
         
@@ -3288,9 +4768,11 @@ impl<'a> Parser<'a> {
 
         self.advance();
 
-        let loopn: nodes::LoopNode = nodes::LoopNode{
+        let forn: nodes::ForNode = nodes::ForNode{
+            name,
+            expr,
             block,
-            expr: Some(expr),
+            label: None,
         };
     
         let nodedat: nodes::NodeData = nodes::NodeData {
@@ -3312,16 +4794,22 @@ impl<'a> Parser<'a> {
             arr: None,
             impln: None,
             ifn: None,
-            loopn: Some(loopn),
+            loopn: None,
             enumn: None,
             traitn: None,
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: Some(forn),
+            breakn: None,
+            alias: None,
+            closure: None,
         };
 
     
-        let n: Node = self.create_node(NodeType::WHILE, nodedat, pos);
+        let n: Node = self.create_node(NodeType::FOR, nodedat, pos);
 
         return n;        
     }
@@ -3375,6 +4863,7 @@ impl<'a> Parser<'a> {
 
         let mut variants: Vec<String> = Vec::new();
         let mut tps: Vec<Option<Type>> = Vec::new();
+        let mut discriminants: Vec<Option<Node>> = Vec::new();
 
         while self.current_is_type(TokenType::IDENTIFIER) {
             let name: String = self.current.data.clone();
@@ -3395,6 +4884,15 @@ impl<'a> Parser<'a> {
             else {
                 tps.push(None);
             }
+
+            if self.current_is_type(TokenType::EQUALS) {
+                self.advance();
+
+                discriminants.push(Some(self.expr(Precedence::Lowest)));
+            }
+            else {
+                discriminants.push(None);
+            }
         
             if !self.current_is_type(TokenType::COMMA) {
                 self.raise_error("Expected comma.", ErrorType::InvalidTok);
@@ -3418,6 +4916,8 @@ impl<'a> Parser<'a> {
             variants,
             tps,
             template_types,
+            discriminants,
+            derives: Vec::new(),
         };
     
         let nodedat: nodes::NodeData = nodes::NodeData {
@@ -3445,6 +4945,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
 
     
@@ -3453,6 +4959,159 @@ impl<'a> Parser<'a> {
         return n;        
     }
 
+    fn parse_type_alias(&mut self) -> Node {
+        let mut pos = Position {
+            line: self.current.line,
+            startcol: self.current.startcol,
+            endcol: 0,
+        };
+
+        self.advance();
+    
+        if !self.current_is_type(TokenType::IDENTIFIER) {
+            self.raise_error("Expected name.", ErrorType::InvalidTok);
+        }
+
+        let name = self.current.data.clone();
+
+        self.advance();
+
+        let mut template_types: Vec<String> = Vec::new();
+        if self.current_is_type(TokenType::LT) {
+            self.advance();
+            while self.current_is_type(TokenType::IDENTIFIER) {
+                template_types.push(self.current.data.clone());
+
+                self.advance();
+
+                if !self.current_is_type(TokenType::COMMA) && !self.current_is_type(TokenType::GT) {
+                    self.raise_error("Expected comma.", ErrorType::InvalidTok);
+                }
+                if self.current_is_type(TokenType::GT) {
+                    self.advance();
+                    break;
+                }
+                self.advance();
+            }
+        }
+
+        if !self.current_is_type(TokenType::EQUALS) {
+            self.raise_error("Expected equals.", ErrorType::InvalidTok);
+        }
+
+        self.advance();
+
+        let (end, tp) = self.parse_type(DataMutablility::Immutable);
+        pos.endcol = end;
+
+        let alias: nodes::TypeAliasNode = nodes::TypeAliasNode{
+            name,
+            template_types,
+            tp,
+        };
+
+        let nodedat: nodes::NodeData = nodes::NodeData {
+            binary: None,
+            num: None,
+            letn: None,
+            identifier: None,
+            func: None,
+            assign: None,
+            call: None,
+            ret: None,
+            to: None,
+            unary: None,
+            st: None,
+            initst: None,
+            attr: None,
+            attrassign: None,
+            str: None,
+            arr: None,
+            impln: None,
+            ifn: None,
+            loopn: None,
+            enumn: None,
+            traitn: None,
+            is: None,
+            matchn: None,
+            nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: Some(alias),
+            closure: None,
+        };
+
+        let n: Node = self.create_node(NodeType::TYPEALIAS, nodedat, pos);
+
+        return n;
+    }
+
+    //An attribute applies to the struct or enum following it
+    fn parse_attribute(&mut self) -> Node {
+        self.advance();
+
+        if !self.current_is_type(TokenType::LSQUARE) {
+            self.raise_error("Expected left square bracket.", ErrorType::InvalidTok);
+        }
+
+        self.advance();
+
+        if !self.current_is_type(TokenType::IDENTIFIER) || self.current.data != "derive" {
+            self.raise_error("Expected derive.", ErrorType::InvalidTok);
+        }
+
+        self.advance();
+
+        if !self.current_is_type(TokenType::LPAREN) {
+            self.raise_error("Expected left parenthesis.", ErrorType::InvalidTok);
+        }
+
+        self.advance();
+
+        let mut derives: Vec<String> = Vec::new();
+        while self.current_is_type(TokenType::IDENTIFIER) {
+            derives.push(self.current.data.clone());
+
+            self.advance();
+
+            if !self.current_is_type(TokenType::COMMA) && !self.current_is_type(TokenType::RPAREN) {
+                self.raise_error("Expected comma.", ErrorType::InvalidTok);
+            }
+            if self.current_is_type(TokenType::COMMA) {
+                self.advance();
+            }
+        }
+
+        if !self.current_is_type(TokenType::RPAREN) {
+            self.raise_error("Expected right parenthesis.", ErrorType::InvalidTok);
+        }
+
+        self.advance();
+
+        if !self.current_is_type(TokenType::RSQUARE) {
+            self.raise_error("Expected right square bracket.", ErrorType::InvalidTok);
+        }
+
+        self.advance();
+
+        self.skip_newline();
+
+        if !self.current_is_type(TokenType::KEYWORD) || (self.current.data != "struct" && self.current.data != "enum") {
+            self.raise_error("Expected struct or enum.", ErrorType::InvalidStatement);
+        }
+
+        let mut node: Node = self.keyword();
+        if node.tp == NodeType::STRUCT {
+            node.data.st.as_mut().unwrap().derives = derives;
+        }
+        else {
+            node.data.enumn.as_mut().unwrap().derives = derives;
+        }
+        return node;
+    }
+
     fn parse_trait(&mut self) -> Node {
         let mut pos = Position {
             line: self.current.line,
@@ -3472,6 +5131,25 @@ impl<'a> Parser<'a> {
 
         self.advance();
 
+        let mut supertraits: Vec<String> = Vec::new();
+        if self.current_is_type(TokenType::COLON) {
+            self.advance();
+
+            while self.current_is_type(TokenType::IDENTIFIER) {
+                supertraits.push(self.current.data.clone());
+                self.advance();
+
+                if !self.current_is_type(TokenType::PLUS) {
+                    break;
+                }
+                self.advance();
+            }
+
+            if supertraits.len() == 0 {
+                self.raise_error("Expected trait.", ErrorType::InvalidTok);
+            }
+        }
+
         self.skip_newline();
     
         if !self.current_is_type(TokenType::LCURLY) {
@@ -3484,8 +5162,59 @@ impl<'a> Parser<'a> {
 
         let mut functions: Vec<types::TemplateTraitSignature> = Vec::new();
         let mut vars: std::collections::HashMap<String, Type> = std::collections::HashMap::new();
+        let mut consts: std::collections::HashMap<String, Type> = std::collections::HashMap::new();
+        let mut types: Vec<String> = Vec::new();
 
         while !self.current_is_type(TokenType::RCURLY) && !self.current_is_type(TokenType::EOF) {
+            //Associated constants and types are set by each impl
+            if self.current_is_type(TokenType::KEYWORD) && self.current.data == "const" {
+                self.advance();
+
+                if !self.current_is_type(TokenType::IDENTIFIER) {
+                    self.raise_error("Expected identifier.", ErrorType::InvalidTok);
+                }
+
+                let name: String = self.current.data.clone();
+
+                self.advance();
+    
+                if !self.current_is_type(TokenType::COLON) {
+                    self.raise_error("Expected colon.", ErrorType::InvalidTok);
+                }
+
+                self.advance();
+
+                let tp: Type = self.parse_type(DataMutablility::Immutable).1;
+
+                consts.insert(name, tp);
+
+                self.skip_newline();
+                
+                if self.current_is_type(TokenType::RCURLY) {
+                    break;
+                }
+                continue;
+            }
+
+            if self.current_is_type(TokenType::KEYWORD) && self.current.data == "type" {
+                self.advance();
+
+                if !self.current_is_type(TokenType::IDENTIFIER) {
+                    self.raise_error("Expected identifier.", ErrorType::InvalidTok);
+                }
+
+                types.push(self.current.data.clone());
+
+                self.advance();
+
+                self.skip_newline();
+                
+                if self.current_is_type(TokenType::RCURLY) {
+                    break;
+                }
+                continue;
+            }
+
             if self.current_is_type(TokenType::IDENTIFIER) {
                 let name: String = self.current.data.clone();
 
@@ -3513,6 +5242,8 @@ impl<'a> Parser<'a> {
                 self.raise_error("Expected fn.", ErrorType::InvalidStatement);
             }
 
+            let fnidx: usize = self.idx;
+
             self.advance();
 
             if !self.current_is_type(TokenType::IDENTIFIER) {
@@ -3558,6 +5289,15 @@ impl<'a> Parser<'a> {
                 rettp: Vec::new(),
             };
             while !self.current_is_type(TokenType::RPAREN) && !self.current_is_type(TokenType::EOF) {
+                //Functions with a default body name their arguments, which the signature does not keep
+                if self.current_is_type(TokenType::KEYWORD) && self.current.data == "mut" {
+                    self.advance();
+                }
+                if self.current_is_type(TokenType::IDENTIFIER) && self.tokens.get(self.idx).is_some() && self.tokens.get(self.idx).unwrap().tp == TokenType::COLON {
+                    self.advance();
+                    self.advance();
+                }
+
                 args.args.push(self.parse_type(DataMutablility::Immutable).1);
                 if !self.current_is_type(TokenType::COMMA) && !self.current_is_type(TokenType::RPAREN) {
                     self.raise_error("Expected comma.", ErrorType::InvalidTok);
@@ -3593,10 +5333,12 @@ impl<'a> Parser<'a> {
             else {
                 args.rettp.push(Type {
                     isfn: false,
+                    isclosure: false,
                     isarr: false,
                     isdyn: false,
                     isgenum: false,
                     isref: false,
+                    istuple: false,
                     basetp: None,
                     arrlen: None,
                     data: Some(String::from("void")),
@@ -3607,15 +5349,25 @@ impl<'a> Parser<'a> {
                 });
             }
 
+            self.skip_newline();
+
+            //A body is the default implementation, so the function is parsed again from its fn keyword
+            let mut default: Option<Node> = None;
+            if self.current_is_type(TokenType::LCURLY) {
+                self.idx = fnidx - 1;
+                self.advance();
+                default = Some(self.parse_fn());
+                self.skip_newline();
+            }
+
             functions.push(types::TemplateTraitSignature {
                 name,
                 namespacename,
                 methodname,
                 template_types,
                 args,
+                default,
             });
-
-            self.skip_newline();
             
         
             if self.current_is_type(TokenType::RCURLY) {
@@ -3633,8 +5385,11 @@ impl<'a> Parser<'a> {
 
         let traitn: nodes::TraitNode = nodes::TraitNode{
             traitname,
+            supertraits,
             functions,
             vars,
+            consts,
+            types,
         };
     
         let nodedat: nodes::NodeData = nodes::NodeData {
@@ -3662,6 +5417,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
 
     
@@ -3804,6 +5565,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: Some(matchn),
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
 
     
@@ -3844,6 +5611,12 @@ impl<'a> Parser<'a> {
             is: None,
             matchn: None,
             nameattr: None,
+            index: None,
+            range: None,
+            forn: None,
+            breakn: None,
+            alias: None,
+            closure: None,
         };
     
         let n: Node = self.create_node(NodeType::STMT, nodedat, left.pos);
@@ -10,6 +10,13 @@ pub enum BinaryOpType {
     LTE,
     EQ,
     NE,
+    AND,
+    OR,
+    BITAND,
+    BITOR,
+    BITXOR,
+    SHL,
+    SHR,
 }
 
 impl std::fmt::Display for BinaryOpType {
@@ -25,6 +32,13 @@ impl std::fmt::Display for BinaryOpType {
             BinaryOpType::LTE => write!(f, "<="),
             BinaryOpType::EQ => write!(f, "=="),
             BinaryOpType::NE => write!(f, "!="),
+            BinaryOpType::AND => write!(f, "&&"),
+            BinaryOpType::OR => write!(f, "||"),
+            BinaryOpType::BITAND => write!(f, "&"),
+            BinaryOpType::BITOR => write!(f, "|"),
+            BinaryOpType::BITXOR => write!(f, "^"),
+            BinaryOpType::SHL => write!(f, "<<"),
+            BinaryOpType::SHR => write!(f, ">>"),
         }
     }    
 }
@@ -35,6 +49,8 @@ pub enum UnaryOpType {
     NEG,
     REF,
     STMT,
+    NOT,
+    BITNOT,
 }
 
 impl std::fmt::Display for UnaryOpType {
@@ -44,6 +60,8 @@ impl std::fmt::Display for UnaryOpType {
             UnaryOpType::REF => write!(f, "&"),
             UnaryOpType::POS => write!(f, "+"),
             UnaryOpType::STMT => write!(f, ";"),
+            UnaryOpType::NOT => write!(f, "!"),
+            UnaryOpType::BITNOT => write!(f, "~"),
         }
     }    
 }
@@ -65,6 +83,7 @@ impl std::fmt::Display for BinaryNode {
 #[derive(Clone, Debug, PartialEq)]
 pub struct NumNode {
     pub left: String,
+    pub inferred: bool, //No type suffix, so the type may be inferred from context
 }
 
 impl std::fmt::Display for NumNode {
@@ -79,6 +98,7 @@ pub struct LetNode {
     pub expr: Option<crate::parser::Node>,
     pub mutability: crate::codegen::types::DataMutablility,
     pub tp: Option<crate::parser::Type>,
+    pub destructure: Option<Vec<(String, crate::codegen::types::DataMutablility)>>,
 }
 
 impl std::fmt::Display for LetNode {
@@ -106,6 +126,8 @@ pub struct FuncNode {
     pub methodname: Option<String>,
     pub namespacename: Option<String>,
     pub template_types: Vec<String>,
+    pub template_bounds: std::collections::HashMap<String, Vec<String>>,
+    pub isconst: bool,
 }
 
 impl std::fmt::Display for FuncNode {
@@ -194,6 +216,8 @@ pub struct StructNode{
     pub name: String,
     pub names: Vec<String>,
     pub members: std::collections::HashMap<String, crate::parser::Type>,
+    pub template_types: Vec<String>,
+    pub derives: Vec<String>, //Traits to generate implementations of
 }
 
 impl std::fmt::Display for StructNode {
@@ -267,6 +291,8 @@ impl std::fmt::Display for ArrayNode {
 #[derive(Clone, Debug, PartialEq)]
 pub struct ImplNode{
     pub functions: Vec<crate::parser::Node>,
+    pub consts: Vec<crate::parser::Node>,
+    pub types: Vec<crate::parser::Node>,
     pub traitnm: String,
     pub structnm: String,
 }
@@ -294,11 +320,12 @@ impl std::fmt::Display for IfNode {
 pub struct LoopNode{
     pub block: Vec<crate::parser::Node>,
     pub expr: Option<crate::parser::Node>,
+    pub label: Option<String>,
 }
 
 impl std::fmt::Display for LoopNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "Loop (expr={:?}) {{", self.expr)?;
+        writeln!(f, "Loop (label={:?}, expr={:?}) {{", self.label, self.expr)?;
         for node in self.block.clone() {
             writeln!(f, "    {}", node)?;
         }
@@ -312,6 +339,8 @@ pub struct EnumNode{
     pub name: String,
     pub tps: Vec<Option<crate::parser::Type>>,
     pub template_types: Vec<String>,
+    pub discriminants: Vec<Option<crate::parser::Node>>,
+    pub derives: Vec<String>, //Traits to generate implementations of
 }
 
 impl std::fmt::Display for EnumNode {
@@ -320,11 +349,27 @@ impl std::fmt::Display for EnumNode {
     }    
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypeAliasNode{
+    pub name: String,
+    pub template_types: Vec<String>,
+    pub tp: crate::parser::Type,
+}
+
+impl std::fmt::Display for TypeAliasNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Type alias '{}'", self.name)
+    }    
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct TraitNode{
     pub traitname: String,
+    pub supertraits: Vec<String>,
     pub functions: Vec<crate::codegen::types::TemplateTraitSignature>,
     pub vars: std::collections::HashMap<String, crate::parser::Type>,
+    pub consts: std::collections::HashMap<String, crate::parser::Type>,
+    pub types: Vec<String>,
 }
 
 impl std::fmt::Display for TraitNode {
@@ -373,6 +418,89 @@ impl std::fmt::Display for NamespaceAttrNode {
     }    
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct IndexNode{
+    pub name: crate::parser::Node,
+    pub index: crate::parser::Node,
+    pub expr: Option<crate::parser::Node>,
+}
+
+impl std::fmt::Display for IndexNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.expr.is_some() {
+            write!(f, "{}[{}] = {}", self.name, self.index, self.expr.as_ref().unwrap())
+        }
+        else {
+            write!(f, "{}[{}]", self.name, self.index)
+        }
+    }    
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RangeNode{
+    pub start: crate::parser::Node,
+    pub end: crate::parser::Node,
+    pub inclusive: bool,
+}
+
+impl std::fmt::Display for RangeNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.inclusive {
+            write!(f, "{}..={}", self.start, self.end)
+        }
+        else {
+            write!(f, "{}..{}", self.start, self.end)
+        }
+    }    
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ForNode{
+    pub name: String,
+    pub expr: crate::parser::Node,
+    pub block: Vec<crate::parser::Node>,
+    pub label: Option<String>,
+}
+
+impl std::fmt::Display for ForNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "For (label={:?}) '{}' in {} {{", self.label, self.name, self.expr)?;
+        for node in self.block.clone() {
+            writeln!(f, "    {}", node)?;
+        }
+        write!(f, "    }}")
+    }    
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BreakNode{
+    pub label: Option<String>,
+    pub expr: Option<crate::parser::Node>,
+}
+
+impl std::fmt::Display for BreakNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(label={:?}, expr={:?})", self.label, self.expr)
+    }    
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClosureNode{
+    pub args: crate::parser::Args, //No return type if it is inferred from the body
+    pub blocks: Vec<crate::parser::Node>,
+    pub ismove: bool,
+}
+
+impl std::fmt::Display for ClosureNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Closure ({}) (move={}) {{", self.args.name.join(", "), self.ismove)?;
+        for node in self.blocks.clone() {
+            writeln!(f, "    {}", node)?;
+        }
+        write!(f, "    }}")
+    }    
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct NodeData {
     pub binary: Option<BinaryNode>,
@@ -399,4 +527,10 @@ pub struct NodeData {
     pub is: Option<IsNode>,
     pub matchn: Option<MatchNode>,
     pub nameattr: Option<NamespaceAttrNode>,
+    pub index: Option<IndexNode>,
+    pub range: Option<RangeNode>,
+    pub forn: Option<ForNode>,
+    pub breakn: Option<BreakNode>,
+    pub alias: Option<TypeAliasNode>,
+    pub closure: Option<ClosureNode>,
 }
\ No newline at end of file
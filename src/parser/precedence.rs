@@ -5,6 +5,7 @@ pub enum Precedence {
     Attr,
     To,
     Assign,
+    Range,
     LogicalOr,   
     LogicalAnd,
     LogicalNot, 
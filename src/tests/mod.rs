@@ -85,4 +85,689 @@ fn test_parser() {
     assert_eq!(nodes.first().unwrap().data.func.as_ref().unwrap().blocks.get(0).unwrap().tp, crate::parser::NodeType::IDENTIFIER);
     assert_eq!(nodes.first().unwrap().data.func.as_ref().unwrap().blocks.get(1).unwrap().tp, crate::parser::NodeType::I32);
     assert_eq!(nodes.first().unwrap().data.func.as_ref().unwrap().blocks.get(2).unwrap().tp, crate::parser::NodeType::STRING);
+}
+
+#[test]
+fn test_lexer_logical_operators() {
+    let file_data: String = String::from("a && !b || c != d");
+    let filename: &String = &String::from("file.ke");
+
+    let file_data_bytes: &[u8] = file_data.as_bytes();
+
+    let file_info: crate::fileinfo::FileInfo = crate::fileinfo::FileInfo {
+        data: file_data_bytes.clone(),
+        name: filename.clone(),
+        dir: String::from("."),
+    };
+
+    let mut keywords: Vec<String> = vec![   String::from("let"),
+                                            String::from("fn"),
+                                            ];
+
+    let mut lexer: crate::lexer::Lexer = crate::lexer::new(file_data_bytes, &file_info);
+
+    let (_, tokens) = crate::lexer::generate_tokens(&mut lexer, &mut keywords);
+    
+    assert_eq!(tokens.len(), 9);
+    assert_eq!(tokens.get(0).unwrap().tp, crate::lexer::TokenType::IDENTIFIER);
+    assert_eq!(tokens.get(1).unwrap().tp, crate::lexer::TokenType::DOUBLEAMPERSAND);
+    assert_eq!(tokens.get(2).unwrap().tp, crate::lexer::TokenType::EXCLAMATION);
+    assert_eq!(tokens.get(3).unwrap().tp, crate::lexer::TokenType::IDENTIFIER);
+    assert_eq!(tokens.get(4).unwrap().tp, crate::lexer::TokenType::DOUBLEPIPE);
+    assert_eq!(tokens.get(5).unwrap().tp, crate::lexer::TokenType::IDENTIFIER);
+    assert_eq!(tokens.get(6).unwrap().tp, crate::lexer::TokenType::NE);
+    assert_eq!(tokens.get(7).unwrap().tp, crate::lexer::TokenType::IDENTIFIER);
+    assert_eq!(tokens.get(8).unwrap().tp, crate::lexer::TokenType::EOF);
+}
+
+#[test]
+fn test_lexer_bitwise_operators() {
+    let file_data: String = String::from("a & b | ~c ^ d << e >> f");
+    let filename: &String = &String::from("file.ke");
+
+    let file_data_bytes: &[u8] = file_data.as_bytes();
+
+    let file_info: crate::fileinfo::FileInfo = crate::fileinfo::FileInfo {
+        data: file_data_bytes.clone(),
+        name: filename.clone(),
+        dir: String::from("."),
+    };
+
+    let mut keywords: Vec<String> = vec![   String::from("let"),
+                                            String::from("fn"),
+                                            ];
+
+    let mut lexer: crate::lexer::Lexer = crate::lexer::new(file_data_bytes, &file_info);
+
+    let (_, tokens) = crate::lexer::generate_tokens(&mut lexer, &mut keywords);
+    
+    assert_eq!(tokens.len(), 14);
+    assert_eq!(tokens.get(0).unwrap().tp, crate::lexer::TokenType::IDENTIFIER);
+    assert_eq!(tokens.get(1).unwrap().tp, crate::lexer::TokenType::AMPERSAND);
+    assert_eq!(tokens.get(2).unwrap().tp, crate::lexer::TokenType::IDENTIFIER);
+    assert_eq!(tokens.get(3).unwrap().tp, crate::lexer::TokenType::PIPE);
+    assert_eq!(tokens.get(4).unwrap().tp, crate::lexer::TokenType::TILDE);
+    assert_eq!(tokens.get(5).unwrap().tp, crate::lexer::TokenType::IDENTIFIER);
+    assert_eq!(tokens.get(6).unwrap().tp, crate::lexer::TokenType::CARET);
+    assert_eq!(tokens.get(7).unwrap().tp, crate::lexer::TokenType::IDENTIFIER);
+    assert_eq!(tokens.get(8).unwrap().tp, crate::lexer::TokenType::LSHIFT);
+    assert_eq!(tokens.get(9).unwrap().tp, crate::lexer::TokenType::IDENTIFIER);
+    assert_eq!(tokens.get(10).unwrap().tp, crate::lexer::TokenType::GT);
+    assert_eq!(tokens.get(11).unwrap().tp, crate::lexer::TokenType::GT);
+    assert_eq!(tokens.get(12).unwrap().tp, crate::lexer::TokenType::IDENTIFIER);
+    assert_eq!(tokens.get(13).unwrap().tp, crate::lexer::TokenType::EOF);
+}
+
+#[test]
+fn test_lexer_range() {
+    let file_data: String = String::from("0..n 1..=2 1.5");
+    let filename: &String = &String::from("file.ke");
+
+    let file_data_bytes: &[u8] = file_data.as_bytes();
+
+    let file_info: crate::fileinfo::FileInfo = crate::fileinfo::FileInfo {
+        data: file_data_bytes.clone(),
+        name: filename.clone(),
+        dir: String::from("."),
+    };
+
+    let mut keywords: Vec<String> = vec![   String::from("let"),
+                                            String::from("fn"),
+                                            ];
+
+    let mut lexer: crate::lexer::Lexer = crate::lexer::new(file_data_bytes, &file_info);
+
+    let (_, tokens) = crate::lexer::generate_tokens(&mut lexer, &mut keywords);
+    
+    assert_eq!(tokens.len(), 8);
+    assert_eq!(tokens.get(0).unwrap().tp, crate::lexer::TokenType::I32);
+    assert_eq!(tokens.get(1).unwrap().tp, crate::lexer::TokenType::DOTDOT);
+    assert_eq!(tokens.get(2).unwrap().tp, crate::lexer::TokenType::IDENTIFIER);
+    assert_eq!(tokens.get(3).unwrap().tp, crate::lexer::TokenType::I32);
+    assert_eq!(tokens.get(4).unwrap().tp, crate::lexer::TokenType::DOTDOTEQ);
+    assert_eq!(tokens.get(5).unwrap().tp, crate::lexer::TokenType::I32);
+    assert_eq!(tokens.get(6).unwrap().tp, crate::lexer::TokenType::F32);
+    assert_eq!(tokens.get(7).unwrap().tp, crate::lexer::TokenType::EOF);
+}
+
+#[test]
+fn test_lexer_label() {
+    let file_data: String = String::from("'outer 'a'");
+    let filename: &String = &String::from("file.ke");
+
+    let file_data_bytes: &[u8] = file_data.as_bytes();
+
+    let file_info: crate::fileinfo::FileInfo = crate::fileinfo::FileInfo {
+        data: file_data_bytes.clone(),
+        name: filename.clone(),
+        dir: String::from("."),
+    };
+
+    let mut keywords: Vec<String> = vec![   String::from("let"),
+                                            String::from("fn"),
+                                            ];
+
+    let mut lexer: crate::lexer::Lexer = crate::lexer::new(file_data_bytes, &file_info);
+
+    let (_, tokens) = crate::lexer::generate_tokens(&mut lexer, &mut keywords);
+    
+    assert_eq!(tokens.len(), 3);
+    assert_eq!(tokens.get(0).unwrap().tp, crate::lexer::TokenType::LABEL);
+    assert_eq!(tokens.get(0).unwrap().data, String::from("outer"));
+    assert_eq!(tokens.get(1).unwrap().tp, crate::lexer::TokenType::CHAR);
+    assert_eq!(tokens.get(2).unwrap().tp, crate::lexer::TokenType::EOF);
+}
+
+#[test]
+fn test_lexer_tuple_field() {
+    let file_data: String = String::from("t.0.1 1.5");
+    let filename: &String = &String::from("file.ke");
+
+    let file_data_bytes: &[u8] = file_data.as_bytes();
+
+    let file_info: crate::fileinfo::FileInfo = crate::fileinfo::FileInfo {
+        data: file_data_bytes.clone(),
+        name: filename.clone(),
+        dir: String::from("."),
+    };
+
+    let mut keywords: Vec<String> = vec![   String::from("let"),
+                                            String::from("fn"),
+                                            ];
+
+    let mut lexer: crate::lexer::Lexer = crate::lexer::new(file_data_bytes, &file_info);
+
+    let (_, tokens) = crate::lexer::generate_tokens(&mut lexer, &mut keywords);
+    
+    assert_eq!(tokens.len(), 7);
+    assert_eq!(tokens.get(0).unwrap().tp, crate::lexer::TokenType::IDENTIFIER);
+    assert_eq!(tokens.get(1).unwrap().tp, crate::lexer::TokenType::DOT);
+    assert_eq!(tokens.get(2).unwrap().tp, crate::lexer::TokenType::I32);
+    assert_eq!(tokens.get(3).unwrap().tp, crate::lexer::TokenType::DOT);
+    assert_eq!(tokens.get(4).unwrap().tp, crate::lexer::TokenType::I32);
+    assert_eq!(tokens.get(4).unwrap().data, String::from("1"));
+    assert_eq!(tokens.get(5).unwrap().tp, crate::lexer::TokenType::F32);
+    assert_eq!(tokens.get(6).unwrap().tp, crate::lexer::TokenType::EOF);
+}
+
+#[test]
+fn test_lexer_bool() {
+    let file_data: String = String::from("true != false");
+    let filename: &String = &String::from("file.ke");
+
+    let file_data_bytes: &[u8] = file_data.as_bytes();
+
+    let file_info: crate::fileinfo::FileInfo = crate::fileinfo::FileInfo {
+        data: file_data_bytes.clone(),
+        name: filename.clone(),
+        dir: String::from("."),
+    };
+
+    let mut keywords: Vec<String> = vec![   String::from("let"),
+                                            String::from("fn"),
+                                            String::from("true"),
+                                            String::from("false"),
+                                            ];
+
+    let mut lexer: crate::lexer::Lexer = crate::lexer::new(file_data_bytes, &file_info);
+
+    let (_, tokens) = crate::lexer::generate_tokens(&mut lexer, &mut keywords);
+    
+    assert_eq!(tokens.len(), 4);
+    assert_eq!(tokens.get(0).unwrap().tp, crate::lexer::TokenType::BOOL);
+    assert_eq!(tokens.get(0).unwrap().data, String::from("true"));
+    assert_eq!(tokens.get(1).unwrap().tp, crate::lexer::TokenType::NE);
+    assert_eq!(tokens.get(2).unwrap().tp, crate::lexer::TokenType::BOOL);
+    assert_eq!(tokens.get(2).unwrap().data, String::from("false"));
+    assert_eq!(tokens.get(3).unwrap().tp, crate::lexer::TokenType::EOF);
+}
+
+#[test]
+fn test_lexer_char_escape() {
+    let file_data: String = String::from("'\\'' '\\u{1F600}'");
+    let filename: &String = &String::from("file.ke");
+
+    let file_data_bytes: &[u8] = file_data.as_bytes();
+
+    let file_info: crate::fileinfo::FileInfo = crate::fileinfo::FileInfo {
+        data: file_data_bytes.clone(),
+        name: filename.clone(),
+        dir: String::from("."),
+    };
+
+    let mut keywords: Vec<String> = vec![   String::from("let"),
+                                            String::from("fn"),
+                                            ];
+
+    let mut lexer: crate::lexer::Lexer = crate::lexer::new(file_data_bytes, &file_info);
+
+    let (_, tokens) = crate::lexer::generate_tokens(&mut lexer, &mut keywords);
+    
+    assert_eq!(tokens.len(), 3);
+    assert_eq!(tokens.get(0).unwrap().tp, crate::lexer::TokenType::CHAR);
+    assert_eq!(tokens.get(0).unwrap().data, String::from("\\'"));
+    assert_eq!(tokens.get(1).unwrap().tp, crate::lexer::TokenType::CHAR);
+    assert_eq!(tokens.get(1).unwrap().data, String::from("\\u{1F600}"));
+    assert_eq!(tokens.get(2).unwrap().tp, crate::lexer::TokenType::EOF);
+}
+
+#[test]
+fn test_parser_closure() {
+    let file_data: String = String::from("fn main(){let f = move |x: i32| x + 1\na | b}");
+    let filename: &String = &String::from("file.ke");
+
+    let file_data_bytes: &[u8] = file_data.as_bytes();
+
+    let file_info: crate::fileinfo::FileInfo = crate::fileinfo::FileInfo {
+        data: file_data_bytes.clone(),
+        name: filename.clone(),
+        dir: String::from("."),
+    };
+
+    let mut keywords: Vec<String> = vec![   String::from("let"),
+                                            String::from("fn"),
+                                            String::from("mut"),
+                                            String::from("move"),
+                                            ];
+
+    let mut lexer: crate::lexer::Lexer = crate::lexer::new(file_data_bytes, &file_info);
+
+    let (_, tokens) = crate::lexer::generate_tokens(&mut lexer, &mut keywords);
+
+    let mut parser: crate::parser::Parser = crate::parser::Parser::new(tokens, &file_info);
+
+    let nodes: Vec<crate::parser::Node> = parser.generate_ast();
+
+    let blocks: &Vec<crate::parser::Node> = &nodes.first().unwrap().data.func.as_ref().unwrap().blocks;
+    let closure: &crate::parser::Node = blocks.get(0).unwrap().data.letn.as_ref().unwrap().expr.as_ref().unwrap();
+    assert_eq!(closure.tp, crate::parser::NodeType::CLOSURE);
+    assert!(closure.data.closure.as_ref().unwrap().ismove);
+    assert_eq!(closure.data.closure.as_ref().unwrap().args.name, vec![String::from("x")]);
+    assert_eq!(closure.data.closure.as_ref().unwrap().blocks.get(0).unwrap().tp, crate::parser::NodeType::BINARY);
+    assert_eq!(blocks.get(1).unwrap().tp, crate::parser::NodeType::BINARY);
+}
+
+#[test]
+fn test_parser_trait_default() {
+    let file_data: String = String::from("trait t{\nfn name<T>(T) -> i32\nfn other<T>(self: T) -> i32 {\nself.name()\n}\n}");
+    let filename: &String = &String::from("file.ke");
+
+    let file_data_bytes: &[u8] = file_data.as_bytes();
+
+    let file_info: crate::fileinfo::FileInfo = crate::fileinfo::FileInfo {
+        data: file_data_bytes.clone(),
+        name: filename.clone(),
+        dir: String::from("."),
+    };
+
+    let mut keywords: Vec<String> = vec![   String::from("fn"),
+                                            String::from("trait"),
+                                            ];
+
+    let mut lexer: crate::lexer::Lexer = crate::lexer::new(file_data_bytes, &file_info);
+
+    let (_, tokens) = crate::lexer::generate_tokens(&mut lexer, &mut keywords);
+
+    let mut parser: crate::parser::Parser = crate::parser::Parser::new(tokens, &file_info);
+
+    let nodes: Vec<crate::parser::Node> = parser.generate_ast();
+
+    let functions: &Vec<crate::codegen::types::TemplateTraitSignature> = &nodes.first().unwrap().data.traitn.as_ref().unwrap().functions;
+    assert!(functions.get(0).unwrap().default.is_none());
+    assert_eq!(functions.get(1).unwrap().name, String::from("other"));
+    assert_eq!(functions.get(1).unwrap().args.args.len(), 1);
+    let default: &crate::parser::Node = functions.get(1).unwrap().default.as_ref().unwrap();
+    assert_eq!(default.tp, crate::parser::NodeType::FUNC);
+    assert_eq!(default.data.func.as_ref().unwrap().args.name, vec![String::from("self")]);
+}
+
+#[test]
+fn test_parser_derive() {
+    let file_data: String = String::from("#[derive(Eq, Ne)]\nstruct s {\nx: i32\n}");
+    let filename: &String = &String::from("file.ke");
+
+    let file_data_bytes: &[u8] = file_data.as_bytes();
+
+    let file_info: crate::fileinfo::FileInfo = crate::fileinfo::FileInfo {
+        data: file_data_bytes.clone(),
+        name: filename.clone(),
+        dir: String::from("."),
+    };
+
+    let mut keywords: Vec<String> = vec![   String::from("struct"),
+                                            ];
+
+    let mut lexer: crate::lexer::Lexer = crate::lexer::new(file_data_bytes, &file_info);
+
+    let (_, tokens) = crate::lexer::generate_tokens(&mut lexer, &mut keywords);
+
+    let mut parser: crate::parser::Parser = crate::parser::Parser::new(tokens, &file_info);
+
+    let nodes: Vec<crate::parser::Node> = parser.generate_ast();
+
+    assert_eq!(nodes.first().unwrap().tp, crate::parser::NodeType::STRUCT);
+    assert_eq!(nodes.first().unwrap().data.st.as_ref().unwrap().derives, vec![String::from("Eq"), String::from("Ne")]);
+}
+
+#[test]
+fn test_parser_index() {
+    let file_data: String = String::from("fn main(){arr[1]\narr[0] = 2\n}");
+    let filename: &String = &String::from("file.ke");
+
+    let file_data_bytes: &[u8] = file_data.as_bytes();
+
+    let file_info: crate::fileinfo::FileInfo = crate::fileinfo::FileInfo {
+        data: file_data_bytes.clone(),
+        name: filename.clone(),
+        dir: String::from("."),
+    };
+
+    let mut keywords: Vec<String> = vec![   String::from("fn"),
+                                            ];
+
+    let mut lexer: crate::lexer::Lexer = crate::lexer::new(file_data_bytes, &file_info);
+
+    let (_, tokens) = crate::lexer::generate_tokens(&mut lexer, &mut keywords);
+
+    let mut parser: crate::parser::Parser = crate::parser::Parser::new(tokens, &file_info);
+
+    let nodes: Vec<crate::parser::Node> = parser.generate_ast();
+
+    let blocks: &Vec<crate::parser::Node> = &nodes.first().unwrap().data.func.as_ref().unwrap().blocks;
+    assert_eq!(blocks.get(0).unwrap().tp, crate::parser::NodeType::INDEX);
+    assert_eq!(blocks.get(0).unwrap().data.index.as_ref().unwrap().name.tp, crate::parser::NodeType::IDENTIFIER);
+    assert_eq!(blocks.get(0).unwrap().data.index.as_ref().unwrap().index.tp, crate::parser::NodeType::I32);
+    assert!(blocks.get(0).unwrap().data.index.as_ref().unwrap().expr.is_none());
+    assert_eq!(blocks.get(1).unwrap().tp, crate::parser::NodeType::INDEXASSIGN);
+    assert!(blocks.get(1).unwrap().data.index.as_ref().unwrap().expr.is_some());
+}
+
+#[test]
+fn test_parser_inplace_attr() {
+    let file_data: String = String::from("fn main(){a.b.x += 1\n}");
+    let filename: &String = &String::from("file.ke");
+
+    let file_data_bytes: &[u8] = file_data.as_bytes();
+
+    let file_info: crate::fileinfo::FileInfo = crate::fileinfo::FileInfo {
+        data: file_data_bytes.clone(),
+        name: filename.clone(),
+        dir: String::from("."),
+    };
+
+    let mut keywords: Vec<String> = vec![   String::from("fn"),
+                                            ];
+
+    let mut lexer: crate::lexer::Lexer = crate::lexer::new(file_data_bytes, &file_info);
+
+    let (_, tokens) = crate::lexer::generate_tokens(&mut lexer, &mut keywords);
+
+    let mut parser: crate::parser::Parser = crate::parser::Parser::new(tokens, &file_info);
+
+    let nodes: Vec<crate::parser::Node> = parser.generate_ast();
+
+    let binary: &crate::parser::nodes::BinaryNode = nodes.first().unwrap().data.func.as_ref().unwrap().blocks.first().unwrap().data.binary.as_ref().unwrap();
+    assert!(binary.isassign);
+    assert_eq!(binary.op, crate::parser::nodes::BinaryOpType::ADD);
+    assert_eq!(binary.left.tp, crate::parser::NodeType::ATTR);
+    assert_eq!(binary.left.data.attr.as_ref().unwrap().attr, String::from("x"));
+    assert_eq!(binary.left.data.attr.as_ref().unwrap().name.tp, crate::parser::NodeType::ATTR);
+}
+
+#[test]
+fn test_parser_associated_items() {
+    let file_data: String = String::from("trait t{\nconst ZERO: i32\ntype Item\nfn get<T>(T) -> T::Item\n}\nimpl t for s{\nconst ZERO: i32 = 0\ntype Item = i32\nfn get(self: s) -> s::Item {\ns::ZERO\n}\n}");
+    let filename: &String = &String::from("file.ke");
+
+    let file_data_bytes: &[u8] = file_data.as_bytes();
+
+    let file_info: crate::fileinfo::FileInfo = crate::fileinfo::FileInfo {
+        data: file_data_bytes.clone(),
+        name: filename.clone(),
+        dir: String::from("."),
+    };
+
+    let mut keywords: Vec<String> = vec![   String::from("fn"),
+                                            String::from("trait"),
+                                            String::from("impl"),
+                                            String::from("for"),
+                                            String::from("const"),
+                                            String::from("type"),
+                                            ];
+
+    let mut lexer: crate::lexer::Lexer = crate::lexer::new(file_data_bytes, &file_info);
+
+    let (_, tokens) = crate::lexer::generate_tokens(&mut lexer, &mut keywords);
+
+    let mut parser: crate::parser::Parser = crate::parser::Parser::new(tokens, &file_info);
+
+    let nodes: Vec<crate::parser::Node> = parser.generate_ast();
+
+    let traitn: &crate::parser::nodes::TraitNode = nodes.get(0).unwrap().data.traitn.as_ref().unwrap();
+    assert_eq!(traitn.consts.get("ZERO").unwrap().data, Some(String::from("i32")));
+    assert_eq!(traitn.types, vec![String::from("Item")]);
+    assert_eq!(traitn.functions.get(0).unwrap().args.rettp.last().unwrap().data, Some(String::from("T::Item")));
+
+    let impln: &crate::parser::nodes::ImplNode = nodes.get(1).unwrap().data.impln.as_ref().unwrap();
+    assert_eq!(impln.consts.get(0).unwrap().tp, crate::parser::NodeType::CONST);
+    assert_eq!(impln.consts.get(0).unwrap().data.letn.as_ref().unwrap().name, String::from("ZERO"));
+    assert_eq!(impln.types.get(0).unwrap().tp, crate::parser::NodeType::TYPEALIAS);
+    assert_eq!(impln.types.get(0).unwrap().data.alias.as_ref().unwrap().name, String::from("Item"));
+    assert_eq!(impln.functions.len(), 1);
+    assert_eq!(impln.functions.get(0).unwrap().data.func.as_ref().unwrap().args.rettp.last().unwrap().data, Some(String::from("s::Item")));
+}
+
+#[test]
+fn test_parser_supertraits() {
+    let file_data: String = String::from("trait t: Eq + Ne {\n}");
+    let filename: &String = &String::from("file.ke");
+
+    let file_data_bytes: &[u8] = file_data.as_bytes();
+
+    let file_info: crate::fileinfo::FileInfo = crate::fileinfo::FileInfo {
+        data: file_data_bytes.clone(),
+        name: filename.clone(),
+        dir: String::from("."),
+    };
+
+    let mut keywords: Vec<String> = vec![   String::from("trait"),
+                                            ];
+
+    let mut lexer: crate::lexer::Lexer = crate::lexer::new(file_data_bytes, &file_info);
+
+    let (_, tokens) = crate::lexer::generate_tokens(&mut lexer, &mut keywords);
+
+    let mut parser: crate::parser::Parser = crate::parser::Parser::new(tokens, &file_info);
+
+    let nodes: Vec<crate::parser::Node> = parser.generate_ast();
+
+    let traitn: &crate::parser::nodes::TraitNode = nodes.get(0).unwrap().data.traitn.as_ref().unwrap();
+    assert_eq!(traitn.supertraits, vec![String::from("Eq"), String::from("Ne")]);
+    assert_eq!(traitn.functions.len(), 0);
+}
+
+#[test]
+fn test_parser_generic_struct() {
+    let file_data: String = String::from("struct s<T> {\na: T,\nb: i32\n}");
+    let filename: &String = &String::from("file.ke");
+
+    let file_data_bytes: &[u8] = file_data.as_bytes();
+
+    let file_info: crate::fileinfo::FileInfo = crate::fileinfo::FileInfo {
+        data: file_data_bytes.clone(),
+        name: filename.clone(),
+        dir: String::from("."),
+    };
+
+    let mut keywords: Vec<String> = vec![   String::from("struct"),
+                                            ];
+
+    let mut lexer: crate::lexer::Lexer = crate::lexer::new(file_data_bytes, &file_info);
+
+    let (_, tokens) = crate::lexer::generate_tokens(&mut lexer, &mut keywords);
+
+    let mut parser: crate::parser::Parser = crate::parser::Parser::new(tokens, &file_info);
+
+    let nodes: Vec<crate::parser::Node> = parser.generate_ast();
+
+    let st: &crate::parser::nodes::StructNode = nodes.first().unwrap().data.st.as_ref().unwrap();
+    assert_eq!(st.template_types, vec![String::from("T")]);
+    assert_eq!(st.names, vec![String::from("a"), String::from("b")]);
+    assert_eq!(st.members.get("a").unwrap().data, Some(String::from("T")));
+    assert_eq!(st.members.get("b").unwrap().data, Some(String::from("i32")));
+}
+
+#[test]
+fn test_parser_explicit_template_types() {
+    let file_data: String = String::from("fn main() {\nf<i32, u8>(1)\n}");
+    let filename: &String = &String::from("file.ke");
+
+    let file_data_bytes: &[u8] = file_data.as_bytes();
+
+    let file_info: crate::fileinfo::FileInfo = crate::fileinfo::FileInfo {
+        data: file_data_bytes.clone(),
+        name: filename.clone(),
+        dir: String::from("."),
+    };
+
+    let mut keywords: Vec<String> = vec![   String::from("fn"),
+                                            ];
+
+    let mut lexer: crate::lexer::Lexer = crate::lexer::new(file_data_bytes, &file_info);
+
+    let (_, tokens) = crate::lexer::generate_tokens(&mut lexer, &mut keywords);
+
+    let mut parser: crate::parser::Parser = crate::parser::Parser::new(tokens, &file_info);
+
+    let nodes: Vec<crate::parser::Node> = parser.generate_ast();
+
+    let call: &crate::parser::nodes::CallNode = nodes.first().unwrap().data.func.as_ref().unwrap().blocks.first().unwrap().data.call.as_ref().unwrap();
+    assert_eq!(call.args.len(), 1);
+
+    let attr: &crate::parser::nodes::AttrNode = call.name.data.attr.as_ref().unwrap();
+    assert_eq!(attr.name.data.identifier.as_ref().unwrap().name, String::from("f"));
+    assert_eq!(attr.attr, String::new());
+    assert_eq!(attr.expr, None);
+    assert_eq!(attr.template_types.as_ref().unwrap().iter().map(|x| x.data.clone().unwrap()).collect::<Vec<String>>(), vec![String::from("i32"), String::from("u8")]);
+}
+
+#[test]
+fn test_parser_type_alias() {
+    let file_data: String = String::from("type Res<T> = Result<T, i32>\ntype Callback = fn(i32) -> i32");
+    let filename: &String = &String::from("file.ke");
+
+    let file_data_bytes: &[u8] = file_data.as_bytes();
+
+    let file_info: crate::fileinfo::FileInfo = crate::fileinfo::FileInfo {
+        data: file_data_bytes.clone(),
+        name: filename.clone(),
+        dir: String::from("."),
+    };
+
+    let mut keywords: Vec<String> = vec![   String::from("type"),
+                                            String::from("fn"),
+                                            ];
+
+    let mut lexer: crate::lexer::Lexer = crate::lexer::new(file_data_bytes, &file_info);
+
+    let (_, tokens) = crate::lexer::generate_tokens(&mut lexer, &mut keywords);
+
+    let mut parser: crate::parser::Parser = crate::parser::Parser::new(tokens, &file_info);
+
+    let nodes: Vec<crate::parser::Node> = parser.generate_ast();
+
+    assert_eq!(nodes.len(), 2);
+    assert_eq!(nodes.get(0).unwrap().tp, crate::parser::NodeType::TYPEALIAS);
+
+    let res: &crate::parser::nodes::TypeAliasNode = nodes.get(0).unwrap().data.alias.as_ref().unwrap();
+    assert_eq!(res.name, String::from("Res"));
+    assert_eq!(res.template_types, vec![String::from("T")]);
+    assert!(res.tp.isgenum);
+    assert_eq!(res.tp.basetp.as_ref().unwrap().data, Some(String::from("Result")));
+    assert_eq!(res.tp.generic_tps.as_ref().unwrap().iter().map(|x| x.data.clone().unwrap()).collect::<Vec<String>>(), vec![String::from("T"), String::from("i32")]);
+
+    let callback: &crate::parser::nodes::TypeAliasNode = nodes.get(1).unwrap().data.alias.as_ref().unwrap();
+    assert_eq!(callback.name, String::from("Callback"));
+    assert_eq!(callback.template_types.len(), 0);
+    assert!(callback.tp.isfn);
+}
+
+#[test]
+fn test_parser_const_static() {
+    let file_data: String = String::from("const fn square(x: i32) -> i32 {\nx * x\n}\nconst AREA: i32 = square(4)\nstatic mut COUNT: u8 = 1\nstatic LIMIT: i32 = AREA");
+    let filename: &String = &String::from("file.ke");
+
+    let file_data_bytes: &[u8] = file_data.as_bytes();
+
+    let file_info: crate::fileinfo::FileInfo = crate::fileinfo::FileInfo {
+        data: file_data_bytes.clone(),
+        name: filename.clone(),
+        dir: String::from("."),
+    };
+
+    let mut keywords: Vec<String> = vec![   String::from("fn"),
+                                            String::from("mut"),
+                                            String::from("const"),
+                                            String::from("static"),
+                                            ];
+
+    let mut lexer: crate::lexer::Lexer = crate::lexer::new(file_data_bytes, &file_info);
+
+    let (_, tokens) = crate::lexer::generate_tokens(&mut lexer, &mut keywords);
+
+    let mut parser: crate::parser::Parser = crate::parser::Parser::new(tokens, &file_info);
+
+    let nodes: Vec<crate::parser::Node> = parser.generate_ast();
+
+    assert_eq!(nodes.len(), 4);
+    assert_eq!(nodes.get(0).unwrap().tp, crate::parser::NodeType::FUNC);
+    assert!(nodes.get(0).unwrap().data.func.as_ref().unwrap().isconst);
+
+    assert_eq!(nodes.get(1).unwrap().tp, crate::parser::NodeType::CONST);
+    let area: &crate::parser::nodes::LetNode = nodes.get(1).unwrap().data.letn.as_ref().unwrap();
+    assert_eq!(area.name, String::from("AREA"));
+    assert_eq!(area.tp.as_ref().unwrap().data, Some(String::from("i32")));
+    assert_eq!(area.expr.as_ref().unwrap().tp, crate::parser::NodeType::CALL);
+
+    assert_eq!(nodes.get(2).unwrap().tp, crate::parser::NodeType::STATIC);
+    let count: &crate::parser::nodes::LetNode = nodes.get(2).unwrap().data.letn.as_ref().unwrap();
+    assert_eq!(count.name, String::from("COUNT"));
+    assert_eq!(count.mutability, crate::codegen::types::DataMutablility::Mutable);
+
+    assert_eq!(nodes.get(3).unwrap().tp, crate::parser::NodeType::STATIC);
+    let limit: &crate::parser::nodes::LetNode = nodes.get(3).unwrap().data.letn.as_ref().unwrap();
+    assert_eq!(limit.mutability, crate::codegen::types::DataMutablility::Immutable);
+    assert_eq!(limit.expr.as_ref().unwrap().tp, crate::parser::NodeType::IDENTIFIER);
+}
+
+#[test]
+fn test_parser_enum_discriminants() {
+    let file_data: String = String::from("enum e {\na = 2,\nb,\nc = 1 + 1 * 8,\n}");
+    let filename: &String = &String::from("file.ke");
+
+    let file_data_bytes: &[u8] = file_data.as_bytes();
+
+    let file_info: crate::fileinfo::FileInfo = crate::fileinfo::FileInfo {
+        data: file_data_bytes.clone(),
+        name: filename.clone(),
+        dir: String::from("."),
+    };
+
+    let mut keywords: Vec<String> = vec![   String::from("enum"),
+                                            ];
+
+    let mut lexer: crate::lexer::Lexer = crate::lexer::new(file_data_bytes, &file_info);
+
+    let (_, tokens) = crate::lexer::generate_tokens(&mut lexer, &mut keywords);
+
+    let mut parser: crate::parser::Parser = crate::parser::Parser::new(tokens, &file_info);
+
+    let nodes: Vec<crate::parser::Node> = parser.generate_ast();
+
+    let enumn: &crate::parser::nodes::EnumNode = nodes.first().unwrap().data.enumn.as_ref().unwrap();
+    assert_eq!(enumn.variants, vec![String::from("a"), String::from("b"), String::from("c")]);
+    assert_eq!(enumn.discriminants.len(), 3);
+    assert_eq!(enumn.discriminants.get(0).unwrap().as_ref().unwrap().data.num.as_ref().unwrap().left, String::from("2"));
+    assert!(enumn.discriminants.get(1).unwrap().is_none());
+    assert_eq!(enumn.discriminants.get(2).unwrap().as_ref().unwrap().tp, crate::parser::NodeType::BINARY);
+}
+
+#[test]
+fn test_parser_inferred_literals() {
+    let file_data: String = String::from("let a = 200\nlet b = 200u8\nlet c = 1.5\nlet d = 1.5f64");
+    let filename: &String = &String::from("file.ke");
+
+    let file_data_bytes: &[u8] = file_data.as_bytes();
+
+    let file_info: crate::fileinfo::FileInfo = crate::fileinfo::FileInfo {
+        data: file_data_bytes.clone(),
+        name: filename.clone(),
+        dir: String::from("."),
+    };
+
+    let mut keywords: Vec<String> = vec![   String::from("let"),
+                                            ];
+
+    let mut lexer: crate::lexer::Lexer = crate::lexer::new(file_data_bytes, &file_info);
+
+    let (_, tokens) = crate::lexer::generate_tokens(&mut lexer, &mut keywords);
+
+    let mut parser: crate::parser::Parser = crate::parser::Parser::new(tokens, &file_info);
+
+    let nodes: Vec<crate::parser::Node> = parser.generate_ast();
+
+    let literals: Vec<&crate::parser::Node> = nodes.iter().map(|x| x.data.letn.as_ref().unwrap().expr.as_ref().unwrap()).collect();
+
+    assert_eq!(literals.get(0).unwrap().tp, crate::parser::NodeType::I32);
+    assert!(literals.get(0).unwrap().data.num.as_ref().unwrap().inferred);
+
+    assert_eq!(literals.get(1).unwrap().tp, crate::parser::NodeType::U8);
+    assert!(!literals.get(1).unwrap().data.num.as_ref().unwrap().inferred);
+    assert_eq!(literals.get(1).unwrap().data.num.as_ref().unwrap().left, literals.get(0).unwrap().data.num.as_ref().unwrap().left);
+
+    assert_eq!(literals.get(2).unwrap().tp, crate::parser::NodeType::F32);
+    assert!(literals.get(2).unwrap().data.num.as_ref().unwrap().inferred);
+
+    assert_eq!(literals.get(3).unwrap().tp, crate::parser::NodeType::F64);
+    assert!(!literals.get(3).unwrap().data.num.as_ref().unwrap().inferred);
 }
\ No newline at end of file
@@ -0,0 +1,324 @@
+//Code generation tests, which run the compiler on a program as errors exit the process
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+//Compile a program in its own directory, as the output files are written to the working directory
+fn compile(name: &str, source: &str) -> (Output, String) {
+    let dir: PathBuf = std::env::temp_dir().join(format!("kestrel_test_{}", name));
+    std::fs::create_dir_all(&dir).unwrap();
+    let _ = std::fs::remove_file(dir.join("a.ll"));
+    std::fs::write(dir.join("program.ke"), source).unwrap();
+
+    let output: Output = Command::new(env!("CARGO_BIN_EXE_kestrel"))
+                                .arg("program.ke")
+                                .current_dir(&dir)
+                                .env("NO_COLOR", "1")
+                                .output()
+                                .unwrap();
+
+    let ir: String = std::fs::read_to_string(dir.join("a.ll")).unwrap_or_default();
+    return (output, ir);
+}
+
+fn expect_error(name: &str, source: &str, error: &str) {
+    let (output, _) = compile(name, source);
+    let stdout: String = String::from_utf8_lossy(&output.stdout).to_string();
+
+    assert!(!output.status.success());
+    assert!(stdout.contains(error), "{}", stdout);
+}
+
+//The LLVM IR is written before the assembler runs, so it is available without the rest of the toolchain
+fn expect_ir(name: &str, source: &str) -> String {
+    let (output, ir) = compile(name, source);
+    let stdout: String = String::from_utf8_lossy(&output.stdout).to_string();
+
+    assert!(!ir.is_empty(), "{}", stdout);
+    return ir;
+}
+
+#[test]
+fn test_template_bound_in_body() {
+    expect_error("template_bound_in_body", "fn f<T>(a: T, b: T) -> T {\n    a + b\n}\nfn main() {\n    f(1, 2)\n}", "Template type 'T' has no bound providing trait 'Add'.");
+}
+
+#[test]
+fn test_template_bound_satisfied() {
+    expect_ir("template_bound_satisfied", "fn f<T: Add>(a: T, b: T) -> T {\n    a + b\n}\nfn main() {\n    f(1, 2)\n}");
+}
+
+#[test]
+fn test_template_bound_through_let() {
+    expect_error("template_bound_through_let", "fn f<T>(a: T) -> T {\n    let y = a\n    y + y\n}\nfn main() {\n    f(1)\n}", "Template type 'T' has no bound providing trait 'Add'.");
+}
+
+#[test]
+fn test_template_bound_compound_expression() {
+    expect_error("template_bound_compound_expression", "fn f<T: Add>(a: T) -> T {\n    (a + a) * a\n}\nfn main() {\n    f(1)\n}", "Template type 'T' has no bound providing trait 'Mul'.");
+}
+
+#[test]
+fn test_template_bound_right_operand() {
+    expect_error("template_bound_right_operand", "fn f<T: Add>(a: T) -> T {\n    let x = 2\n    x * a\n}\nfn main() {\n    f(1)\n}", "Template type 'T' has no bound providing trait 'Mul'.");
+}
+
+#[test]
+fn test_template_bound_compound_receiver() {
+    expect_error("template_bound_compound_receiver", "fn f<T: Add>(a: T) -> i32 {\n    (a + a).name()\n}\nfn main() {\n    f(1)\n}", "Template type 'T' has no bound providing method 'name'.");
+}
+
+#[test]
+fn test_template_bound_compound_satisfied() {
+    expect_ir("template_bound_compound_satisfied", "fn f<T: Add + Mul>(a: T) -> T {\n    let x = 2\n    (a + a) * a + x * a\n}\nfn main() {\n    f(1)\n}");
+}
+
+#[test]
+fn test_template_bound_at_call() {
+    expect_error("template_bound_at_call", "fn f<T: Add>(a: T, b: T) -> T {\n    a + b\n}\nfn main() {\n    f(true, false)\n}", "Type 'bool' does not implement trait 'Add' required by template type 'T'.");
+}
+
+#[test]
+fn test_index_bounds_message() {
+    let ir: String = expect_ir("index_bounds_message", "fn get(arr: i32[3], i: usize) -> i32 {\n    arr[i]\n}\nfn main() {\n    get([1, 2, 3], 1usize)\n}");
+    assert!(ir.contains("index out of bounds at program.ke:2:"));
+}
+
+#[test]
+fn test_index_constant_out_of_range() {
+    expect_error("index_constant_out_of_range", "fn main() {\n    let arr = [1, 2, 3]\n    arr[3]\n}", "Index out of range for length '3'.");
+}
+
+#[test]
+fn test_inplace_attr_through_reference() {
+    expect_ir("inplace_attr_through_reference", "struct Inner {\n    x: i32\n}\nstruct Outer {\n    inner: Inner\n}\nfn main() {\n    let mut o = Outer{inner = Inner{x = 1}}\n    let r = &mut o\n    r.inner.x += 1\n}");
+}
+
+#[test]
+fn test_shift_overflow_message() {
+    let ir: String = expect_ir("shift_overflow_message", "fn shl(a: u32, b: u32) -> u32 {\n    a << b\n}\nfn main() {\n    shl(1u32, 2u32)\n}");
+    assert!(ir.contains("attempt to shift left with overflow at program.ke:2:"));
+}
+
+#[test]
+fn test_shift_constant_out_of_range() {
+    expect_error("shift_constant_out_of_range", "fn shr(a: i8) -> i8 {\n    a >> 8i8\n}\nfn main() {\n    shr(1i8)\n}", "Attempt to shift right with overflow.");
+}
+
+#[test]
+fn test_impl_function_not_in_trait() {
+    expect_error("impl_function_not_in_trait", "struct S {\n    x: i32\n}\ntrait t {\n    fn get<T>(T) -> i32\n}\nimpl t for S {\n    fn get(self: S) -> i32 {\n        self.x\n    }\n    fn extra(self: S) -> i32 {\n        self.x\n    }\n}\nfn main() {\n}", "Function 'extra' is not defined in trait 't'.");
+}
+
+#[test]
+fn test_impl_function_not_in_builtin_trait() {
+    expect_error("impl_function_not_in_builtin_trait", "struct S {\n    x: i32\n}\nimpl Neg for S {\n    fn extra(self: S) -> S {\n        self\n    }\n    fn neg(self: S) -> S {\n        self\n    }\n}\nfn main() {\n}", "Function 'extra' is not defined in trait 'Neg'.");
+}
+
+#[test]
+fn test_associated_items_keyed_by_trait() {
+    expect_ir("associated_items_keyed_by_trait", "struct S {\n    x: i32\n}\ntrait a {\n    const ID: i32\n    type Item\n    fn first<T>(T) -> i32\n}\ntrait b {\n    const ID: i64\n    type Item\n    fn second<T>(T) -> i32\n}\nimpl a for S {\n    const ID: i32 = 1\n    type Item = i32\n    fn first(self: S) -> i32 {\n        self.x\n    }\n}\nimpl b for S {\n    const ID: i64 = 2\n    type Item = i64\n    fn second(self: S) -> i32 {\n        self.x\n    }\n}\nfn get<T: a>(v: T) -> T::Item {\n    T::ID\n}\nfn main() {\n    get(S{x = 1})\n}");
+}
+
+#[test]
+fn test_associated_item_ambiguous() {
+    expect_error("associated_item_ambiguous", "struct S {\n    x: i32\n}\ntrait a {\n    const ID: i32\n    fn first<T>(T) -> i32\n}\ntrait b {\n    const ID: i32\n    fn second<T>(T) -> i32\n}\nimpl a for S {\n    const ID: i32 = 1\n    fn first(self: S) -> i32 {\n        self.x\n    }\n}\nimpl b for S {\n    const ID: i32 = 2\n    fn second(self: S) -> i32 {\n        self.x\n    }\n}\nfn main() {\n    S::ID\n}", "Associated constant 'ID' of 'S' is ambiguous, declared by traits 'a', 'b'.");
+}
+
+#[test]
+fn test_associated_type_without_bound() {
+    expect_error("associated_type_without_bound", "struct S {\n    x: i32\n}\ntrait a {\n    type Item\n    fn first<T>(T) -> i32\n}\nimpl a for S {\n    type Item = i32\n    fn first(self: S) -> i32 {\n        self.x\n    }\n}\nfn get<T>(v: T) -> T::Item {\n    1\n}\nfn main() {\n    get(S{x = 1})\n}", "Template type 'T' has no bound providing associated type 'Item'.");
+}
+
+#[test]
+fn test_supertrait_implemented_after_trait() {
+    expect_ir("supertrait_implemented_after_trait", "struct S {\n    x: i32\n}\ntrait Shape: Eq {\n}\nimpl Shape for S {\n}\nimpl Eq for S {\n    fn eq(self: S, other: S) -> bool {\n        self.x == other.x\n    }\n}\nfn main() {\n}");
+}
+
+#[test]
+fn test_supertrait_not_implemented() {
+    expect_error("supertrait_not_implemented", "struct S {\n    x: i32\n}\ntrait Shape: Eq {\n}\nimpl Shape for S {\n}\nfn main() {\n}", "Struct 'S' does not implement supertrait 'Eq' of trait 'Shape'.");
+}
+
+#[test]
+fn test_template_bound_supertrait() {
+    let ir: String = expect_ir("template_bound_supertrait", "struct S {\n    x: i32\n}\ntrait Named {\n    fn name<T>(T) -> i32\n}\ntrait Shape: Eq + Named {\n}\nimpl Named for S {\n    fn name(self: S) -> i32 {\n        self.x\n    }\n}\nimpl Eq for S {\n    fn eq(self: S, other: S) -> bool {\n        self.x == other.x\n    }\n}\nimpl Shape for S {\n}\nfn same<T: Shape>(a: T, b: T) -> bool {\n    a == b\n}\nfn get<T: Shape>(a: T) -> i32 {\n    a.name()\n}\nfn main() {\n    let s = S{x = 1}\n    let c: bool = same(s, S{x = 2})\n    let n: i32 = get(S{x = 3})\n}");
+    assert!(ir.contains("@S.name("));
+    assert!(ir.contains("@S.eq("));
+}
+
+#[test]
+fn test_dyn_builtin_supertrait_call() {
+    let ir: String = expect_ir("dyn_builtin_supertrait_call", "struct S {\n    x: i32\n}\ntrait Shape: Eq {\n}\nimpl Shape for S {\n}\nimpl Eq for S {\n    fn eq(self: S, other: S) -> bool {\n        self.x == other.x\n    }\n}\nfn main() {\n    let a: dyn Shape = S{x = 1}\n    let b: dyn Shape = S{x = 1}\n    let c: bool = a.eq(b)\n}");
+    assert!(ir.contains("S.Eq.dyn"));
+}
+
+#[test]
+fn test_derive_eq_enum_of_struct() {
+    let ir: String = expect_ir("derive_eq_enum_of_struct", "#[derive(Eq)]\nstruct P {\n    x: i32\n}\n#[derive(Eq)]\nenum Shape {\n    Dot,\n    Point<P>,\n}\nfn main() {\n    let a: bool = P{x = 1} == P{x = 2}\n}");
+    assert!(ir.contains("Shape.eq"));
+    assert!(ir.contains("P.eq"));
+}
+
+#[test]
+fn test_derive_unsupported_trait() {
+    expect_error("derive_unsupported_trait", "#[derive(Add)]\nstruct S {\n    x: i32\n}\nfn main() {\n}", "Trait 'Add' cannot be derived.");
+}
+
+#[test]
+fn test_derive_struct_traits() {
+    let ir: String = expect_ir("derive_struct_traits", "#[derive(ToString, Clone, Default, Hash)]\nstruct S {\n    x: i32,\n    ok: bool\n}\nfn main() {\n    let a = S{x = 1, ok = true}\n    let b: S = a.clone()\n    let c: S = S::default()\n    let h: u64 = a.hash()\n    let s: String = a.to_string()\n}");
+    assert!(ir.contains("define { i8*, i64 } @S.to_string("));
+    assert!(ir.contains("c\"S{\\00\""));
+    assert!(ir.contains("c\", ok = \\00\""));
+    assert!(function_ir(&ir, "S.clone").contains("insertvalue"));
+    assert!(function_ir(&ir, "S.default").contains("ret"));
+    assert!(function_ir(&ir, "S.hash").contains("mul i64"));
+    assert!(function_ir(&ir, "main").contains("@S.default()"));
+}
+
+#[test]
+fn test_derive_enum_traits() {
+    let ir: String = expect_ir("derive_enum_traits", "#[derive(ToString, Clone, Default, Hash)]\nstruct P {\n    x: i32\n}\n#[derive(ToString, Clone, Default, Hash)]\nenum Shape {\n    Dot,\n    Point<P>,\n}\nfn main() {\n    let a: Shape = Shape::default()\n    let b: Shape = a.clone()\n    let s: String = b.to_string()\n}");
+    assert!(ir.contains("c\"Shape::Dot\\00\""));
+    assert!(ir.contains("c\"Shape::Point(\\00\""));
+    assert!(function_ir(&ir, "Shape.to_string").contains("@P.to_string("));
+    assert!(function_ir(&ir, "Shape.clone").contains("@P.clone("));
+    assert!(function_ir(&ir, "Shape.hash").contains("@P.hash("));
+}
+
+#[test]
+fn test_derive_field_without_trait() {
+    expect_error("derive_field_without_trait", "struct Inner {\n    x: i32\n}\n#[derive(Clone)]\nstruct S {\n    i: Inner\n}\nfn main() {\n}", "Cannot derive 'Clone' for 'S', as field 'i' of type 'Inner' does not implement 'Clone'.");
+}
+
+#[test]
+fn test_string_runtime_length() {
+    let ir: String = expect_ir("string_runtime_length", "fn main() {\n    let x = 25\n    let s: String = x.to_string()\n    let t: String = s.clone()\n    let n: usize = t.length()\n}");
+    assert!(function_ir(&ir, "main").contains("@snprintf("));
+    assert!(function_ir(&ir, "main").contains("extractvalue { i8*, i64 }"));
+}
+
+#[test]
+fn test_derive_generic_struct() {
+    expect_error("derive_generic_struct", "#[derive(Eq)]\nstruct S<T> {\n    x: T\n}\nfn main() {\n}", "Cannot derive traits for generic type 'S'.");
+}
+
+#[test]
+fn test_move_closure_frees_env() {
+    let ir: String = expect_ir("move_closure_frees_env", "fn main() {\n    let total = 5\n    let get = move || total\n    get()\n}");
+    assert!(ir.contains("closure.drop"));
+    assert!(ir.contains("@free("));
+}
+
+#[test]
+fn test_borrowing_closure_env_on_stack() {
+    let ir: String = expect_ir("borrowing_closure_env_on_stack", "fn main() {\n    let offset = 10\n    let add = |x: i32| x + offset\n    add(1)\n}");
+    assert!(!ir.contains("malloc"));
+}
+
+#[test]
+fn test_inferred_closure_compiled_once() {
+    let ir: String = expect_ir("inferred_closure_compiled_once", "fn main() {\n    let zero = || 0\n    zero()\n}");
+    assert!(ir.contains("define i32 @closure("));
+    assert!(!ir.contains("closure.inferring"));
+    assert!(!ir.contains("@closure.1"));
+}
+
+#[test]
+fn test_move_out_of_capture() {
+    expect_error("move_out_of_capture", "fn main() {\n    let inner = move || 1\n    let outer = move || {\n        let taken = inner\n        taken()\n    }\n    outer()\n}", "Cannot move captured closure 'inner' out of the closure.");
+}
+
+#[test]
+fn test_lt_derived_from_cmp() {
+    let ir: String = expect_ir("lt_derived_from_cmp", "struct S {\n    x: i32\n}\nimpl Cmp for S {\n    fn cmp(self: S, other: S) -> i32 {\n        self.x - other.x\n    }\n}\nfn less(a: S, b: S) -> bool {\n    a < b\n}\nfn main() {\n}");
+    assert!(ir.contains("S.cmp"));
+}
+
+#[test]
+fn test_add_overflow_message() {
+    let ir: String = expect_ir("add_overflow_message", "fn add(a: i32, b: i32) -> i32 {\n    a + b\n}\nfn main() {\n    add(1, 2)\n}");
+    assert!(ir.contains("attempt to add with overflow at program.ke:2:"));
+}
+
+#[test]
+fn test_wrapping_add_does_not_trap() {
+    let ir: String = expect_ir("wrapping_add_does_not_trap", "fn add(a: u8, b: u8) -> u8 {\n    a.wrapping_add(b)\n}\nfn main() {\n    add(1u8, 2u8)\n}");
+    assert!(!ir.contains("attempt to add with overflow"));
+}
+
+#[test]
+fn test_division_messages() {
+    let ir: String = expect_ir("division_messages", "fn div(a: i32, b: i32) -> i32 {\n    a / b\n}\nfn main() {\n    div(4, 2)\n}");
+    assert!(ir.contains("attempt to divide by zero at program.ke:2:"));
+    assert!(ir.contains("attempt to divide with overflow at program.ke:2:"));
+}
+
+#[test]
+fn test_clamp_constant_inverted_range() {
+    expect_error("clamp_constant_inverted_range", "fn main() {\n    let a = 12\n    a.clamp(10, 0)\n}", "Expected 'min' not greater than 'max', got 10 and 0.");
+}
+
+#[test]
+fn test_clamp_inverted_range_message() {
+    let ir: String = expect_ir("clamp_inverted_range_message", "fn clamp(a: i32, min: i32, max: i32) -> i32 {\n    a.clamp(min, max)\n}\nfn main() {\n    clamp(12, 0, 10)\n}");
+    assert!(ir.contains("attempt to clamp with 'min' greater than 'max' at program.ke:2:"));
+}
+
+//The body of a function in the LLVM IR
+fn function_ir<'a>(ir: &'a str, name: &str) -> &'a str {
+    let start: usize = ir.lines().find(|x| x.starts_with("define") && x.contains(&format!("@{}(", name))).map(|x| ir.find(x).unwrap()).unwrap();
+    let end: usize = start + ir[start..].find("\n}").unwrap();
+    return &ir[start..end];
+}
+
+#[test]
+fn test_logical_operators_short_circuit() {
+    let ir: String = expect_ir("logical_operators_short_circuit", "fn side() -> bool {\n    true\n}\nfn both(a: bool) -> bool {\n    a && side()\n}\nfn either(a: bool) -> bool {\n    a || side()\n}\nfn negate(a: bool) -> bool {\n    !a\n}\nfn main() {\n    let a: bool = both(false) || either(true) || negate(true)\n}");
+
+    //The right side is only called in its own block, and the result joins both sides
+    for name in ["both", "either"] {
+        let body: &str = function_ir(&ir, name);
+        let rhs: usize = body.find("logical_rhs:").unwrap();
+        assert!(!body[..rhs].contains("@side("), "{}", body);
+        assert!(body[rhs..].contains("@side("), "{}", body);
+        assert!(body.contains("br i1"), "{}", body);
+        assert!(body.contains("phi i1"), "{}", body);
+    }
+
+    assert!(function_ir(&ir, "negate").contains("boolnot"));
+}
+
+#[test]
+fn test_dyn_default_and_overridden_method() {
+    let ir: String = expect_ir("dyn_default_and_overridden_method", "struct S {\n    x: i32\n}\nstruct R {\n    x: i32\n}\ntrait t {\n    fn name<T>(T) -> i32\n    fn double<T>(self: T) -> i32 {\n        self.name() * 2\n    }\n}\nimpl t for S {\n    fn name(self: S) -> i32 {\n        self.x\n    }\n}\nimpl t for R {\n    fn name(self: R) -> i32 {\n        self.x\n    }\n    fn double(self: R) -> i32 {\n        self.x * 4\n    }\n}\nfn main() {\n    let a: dyn t = S{x = 1}\n    let b: dyn t = R{x = 2}\n    let c: i32 = a.double() + b.double()\n}");
+
+    //The default of S calls its impl, while R uses its own function
+    assert!(function_ir(&ir, "S.double").contains("@S.name("));
+    assert!(!function_ir(&ir, "R.double").contains("@R.name("));
+
+    //Each vtable holds the functions in the order of the trait, whether they are defaults or not
+    let vtables: &str = ir.lines().filter(|x| x.starts_with("@vtables")).last().unwrap();
+    assert!(vtables.find("@S.name").unwrap() < vtables.find("@S.double").unwrap(), "{}", vtables);
+    assert!(vtables.find("@R.name").unwrap() < vtables.find("@R.double").unwrap(), "{}", vtables);
+
+    //The method is loaded from the vtable of the value, unless the optimizer resolved the loads to the functions
+    let main: &str = function_ir(&ir, "_main");
+    assert!(main.contains("@vtables") || (main.contains("@S.double(") && main.contains("@R.double(")), "{}", main);
+}
+
+#[test]
+fn test_range_literal_takes_bound_type() {
+    let ir: String = expect_ir("range_literal_takes_bound_type", "fn count(n: usize) -> usize {\n    let mut total = 0usize\n    for i in 0..n {\n        total += i\n    }\n    total\n}\nfn main() {\n    count(3usize)\n}");
+    assert!(function_ir(&ir, "count").contains("i64"));
+}
+
+#[test]
+fn test_index_assign_literal_takes_element_type() {
+    expect_ir("index_assign_literal_takes_element_type", "fn set(i: usize) -> u8 {\n    let mut arr = [1u8, 2u8, 3u8]\n    arr[i] = 1\n    arr[0usize]\n}\nfn main() {\n    set(1usize)\n}");
+}
+
+#[test]
+fn test_type_alias_named_in_diagnostics() {
+    expect_error("type_alias_named_in_diagnostics", "type Meters = i32\nfn main() {\n    let x: Meters = true\n}", "Expected 'Meters = i32' type, got 'bool' type.");
+}
\ No newline at end of file